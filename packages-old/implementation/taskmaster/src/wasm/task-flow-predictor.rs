@@ -25,6 +25,32 @@ pub struct ThresholdOptimization {
     pub reasoning: String,
 }
 
+/// Current on-disk shape of a persisted `TaskFlowPredictor`. Bumping
+/// `schema_version` lets a host detect an older snapshot and migrate it
+/// instead of the deserialize just failing outright on a missing field.
+const PREDICTOR_SNAPSHOT_SCHEMA_VERSION: u32 = 1;
+
+/// How many samples each history ring buffer retains. Long-running hosts
+/// call `record_sample` far more than this, so old samples are dropped
+/// rather than growing the vectors unbounded.
+const HISTORY_WINDOW: usize = 256;
+
+/// Full persisted state of a `TaskFlowPredictor`, as saved/restored through
+/// a host's `DatabaseManager` via `to_snapshot`/`from_snapshot` so learning
+/// from `learn_from_decisions` and `record_sample` survives across WASM
+/// sessions instead of resetting to the cold-start defaults every time.
+#[derive(Serialize, Deserialize)]
+pub struct PredictorSnapshot {
+    pub schema_version: u32,
+    pub throughput_history: Vec<f32>,
+    pub queue_history: Vec<f32>,
+    pub approval_history: Vec<f32>,
+    pub learning_rate: f32,
+    pub prediction_accuracy: f32,
+    pub alpha: f32,
+    pub beta: f32,
+}
+
 #[wasm_bindgen]
 pub struct TaskFlowPredictor {
     // Simple moving averages for prediction
@@ -35,6 +61,10 @@ pub struct TaskFlowPredictor {
     // Learning parameters
     learning_rate: f32,
     prediction_accuracy: f32,
+
+    // Holt double-exponential smoothing coefficients for queue_depth forecasting
+    alpha: f32, // level smoothing
+    beta: f32,  // trend smoothing
 }
 
 #[wasm_bindgen]
@@ -47,6 +77,8 @@ impl TaskFlowPredictor {
             approval_history: Vec::new(),
             learning_rate: 0.1,
             prediction_accuracy: 0.7,
+            alpha: 0.3,
+            beta: 0.1,
         }
     }
 
@@ -57,6 +89,8 @@ impl TaskFlowPredictor {
         wip_usage: &[f32],
         queue_depth: &[f32],
         throughput: &[f32],
+        capacity_threshold: f32,
+        interval_ms: f32,
     ) -> JsValue {
         if wip_usage.is_empty() || queue_depth.is_empty() || throughput.is_empty() {
             return JsValue::NULL;
@@ -66,27 +100,34 @@ impl TaskFlowPredictor {
         let avg_usage = wip_usage.iter().sum::<f32>() / wip_usage.len() as f32;
         let queue_trend = self.calculate_trend(queue_depth);
         let throughput_trend = self.calculate_trend(throughput);
-        
+
         // Bottleneck probability calculation
         let mut probability = avg_usage;
-        
+
         // Increase probability if queue is growing
         if queue_trend > 0.1 {
             probability += 0.2;
         }
-        
+
         // Increase probability if throughput is declining
         if throughput_trend < -0.1 {
             probability += 0.3;
         }
-        
+
         probability = probability.min(0.95).max(0.0);
-        
-        // Time to bottleneck (in milliseconds)
-        let time_to_bottleneck = if probability > 0.8 {
-            (1.0 - probability) * 3600000.0 // Hours to milliseconds
-        } else {
+
+        // Forecast queue_depth forward with Holt's linear (double-exponential)
+        // smoothing and solve for the first step count where the forecast
+        // crosses the configured capacity threshold.
+        let (level, trend) = self.holt_linear_trend(queue_depth);
+        let time_to_bottleneck = if trend <= 0.0 {
+            // Queue isn't growing, so it never crosses capacity.
             f32::INFINITY
+        } else if level >= capacity_threshold {
+            0.0
+        } else {
+            let steps = ((capacity_threshold - level) / trend).ceil();
+            steps * interval_ms
         };
 
         let prediction = BottleneckPrediction {
@@ -133,20 +174,45 @@ impl TaskFlowPredictor {
         let avg_approved = approved_confidences.iter().sum::<f32>() / approved_confidences.len() as f32;
         let avg_rejected = rejected_confidences.iter().sum::<f32>() / rejected_confidences.len() as f32;
 
-        // Optimal threshold is between average approved and rejected confidences
-        let recommended = (avg_approved + avg_rejected) / 2.0;
+        // ROC sweep over every distinct confidence value as a candidate threshold,
+        // picking the operating point that maximizes Youden's J (TPR - FPR).
+        let (roc_threshold, youden_j, auc) = self.roc_sweep(confidence_values, approval_decisions);
+
+        // Near-chance separability (AUC ~ 0.5) means the ROC sweep isn't trustworthy;
+        // fall back to the simple midpoint heuristic in that case.
+        let non_separable = (auc - 0.5).abs() < 0.05;
+        let recommended = if non_separable {
+            (avg_approved + avg_rejected) / 2.0
+        } else {
+            roc_threshold
+        };
         let recommended = recommended.max(0.5).min(0.95);
 
-        let optimization = ThresholdOptimization {
-            current: 0.8, // Default current threshold
-            recommended,
-            confidence: 0.8,
-            reasoning: format!(
-                "Based on {} decisions: approved avg {:.2}, rejected avg {:.2}",
+        let reasoning = if non_separable {
+            format!(
+                "AUC {:.2} is near chance (non-separable); falling back to midpoint of {} decisions: approved avg {:.2}, rejected avg {:.2}",
+                auc,
+                confidence_values.len(),
+                avg_approved,
+                avg_rejected
+            )
+        } else {
+            format!(
+                "ROC-optimal threshold {:.2} (Youden's J {:.2}, AUC {:.2}) from {} decisions: approved avg {:.2}, rejected avg {:.2}",
+                roc_threshold,
+                youden_j,
+                auc,
                 confidence_values.len(),
                 avg_approved,
                 avg_rejected
-            ),
+            )
+        };
+
+        let optimization = ThresholdOptimization {
+            current: 0.8, // Default current threshold
+            recommended,
+            confidence: auc.max(0.5),
+            reasoning,
         };
 
         serde_wasm_bindgen::to_value(&optimization).unwrap_or(JsValue::NULL)
@@ -177,6 +243,20 @@ impl TaskFlowPredictor {
 
         self.learning_rate = self.learning_rate.max(0.01).min(0.5);
         self.prediction_accuracy = accuracy_rate;
+
+        // Adapt the Holt smoothing coefficients the same way: when predictions
+        // are accurate, smooth more (trust history); when struggling, react
+        // faster to recent observations.
+        if accuracy_rate > 0.8 {
+            self.alpha *= 0.95;
+            self.beta *= 0.95;
+        } else {
+            self.alpha *= 1.05;
+            self.beta *= 1.05;
+        }
+
+        self.alpha = self.alpha.max(0.05).min(0.9);
+        self.beta = self.beta.max(0.01).min(0.5);
     }
 
     /// Get current predictor status
@@ -185,12 +265,82 @@ impl TaskFlowPredictor {
         let status = serde_json::json!({
             "learning_rate": self.learning_rate,
             "prediction_accuracy": self.prediction_accuracy,
+            "alpha": self.alpha,
+            "beta": self.beta,
             "data_points": self.throughput_history.len(),
             "status": "active"
         });
 
         JsValue::from_str(&status.to_string())
     }
+
+    /// Record one observed sample into the rolling history, so it can later
+    /// be persisted and used to warm-start a future session. Each history
+    /// ring buffer is capped at `HISTORY_WINDOW` samples, oldest first out.
+    #[wasm_bindgen]
+    pub fn record_sample(&mut self, throughput: f32, queue_depth: f32, approved: bool) {
+        self.throughput_history.push(throughput);
+        self.queue_history.push(queue_depth);
+        self.approval_history.push(if approved { 1.0 } else { 0.0 });
+
+        for history in [
+            &mut self.throughput_history,
+            &mut self.queue_history,
+            &mut self.approval_history,
+        ] {
+            if history.len() > HISTORY_WINDOW {
+                let overflow = history.len() - HISTORY_WINDOW;
+                history.drain(0..overflow);
+            }
+        }
+    }
+
+    /// Serialize this predictor's full state -- histories, learning rate,
+    /// prediction accuracy, and Holt smoothing coefficients -- as JSON so a
+    /// host can save it via its `DatabaseManager` and restore it later with
+    /// `from_snapshot` instead of cold-starting.
+    #[wasm_bindgen]
+    pub fn to_snapshot(&self) -> JsValue {
+        let snapshot = PredictorSnapshot {
+            schema_version: PREDICTOR_SNAPSHOT_SCHEMA_VERSION,
+            throughput_history: self.throughput_history.clone(),
+            queue_history: self.queue_history.clone(),
+            approval_history: self.approval_history.clone(),
+            learning_rate: self.learning_rate,
+            prediction_accuracy: self.prediction_accuracy,
+            alpha: self.alpha,
+            beta: self.beta,
+        };
+
+        match serde_json::to_string(&snapshot) {
+            Ok(json) => JsValue::from_str(&json),
+            Err(_) => JsValue::NULL,
+        }
+    }
+
+    /// Reconstruct a `TaskFlowPredictor` from JSON produced by `to_snapshot`,
+    /// warm-starting it with the saved histories and learned parameters
+    /// instead of the cold-start defaults `new` uses. Falls back to a fresh
+    /// predictor if `snapshot_json` doesn't parse as a `PredictorSnapshot`
+    /// or carries a `schema_version` other than the current one (which a
+    /// future migration would otherwise need to handle).
+    #[wasm_bindgen]
+    pub fn from_snapshot(snapshot_json: &str) -> TaskFlowPredictor {
+        match serde_json::from_str::<PredictorSnapshot>(snapshot_json) {
+            Ok(snapshot) if snapshot.schema_version == PREDICTOR_SNAPSHOT_SCHEMA_VERSION => {
+                TaskFlowPredictor {
+                    throughput_history: snapshot.throughput_history,
+                    queue_history: snapshot.queue_history,
+                    approval_history: snapshot.approval_history,
+                    learning_rate: snapshot.learning_rate,
+                    prediction_accuracy: snapshot.prediction_accuracy,
+                    alpha: snapshot.alpha,
+                    beta: snapshot.beta,
+                }
+            }
+            _ => TaskFlowPredictor::new(),
+        }
+    }
 }
 
 impl TaskFlowPredictor {
@@ -218,6 +368,86 @@ impl TaskFlowPredictor {
 
         (n * sum_xy - sum_x * sum_y) / denominator
     }
+
+    /// Sweep every distinct confidence value as a candidate decision threshold,
+    /// computing the (FPR, TPR) ROC point for each and picking the one that
+    /// maximizes Youden's J statistic. Also integrates the ROC curve via the
+    /// trapezoidal rule to return the area under it.
+    ///
+    /// Returns `(best_threshold, best_youden_j, auc)`.
+    fn roc_sweep(&self, confidence_values: &[f32], approval_decisions: &[u8]) -> (f32, f32, f32) {
+        let total_approved = approval_decisions.iter().filter(|&&d| d == 1).count() as f32;
+        let total_rejected = approval_decisions.iter().filter(|&&d| d == 0).count() as f32;
+
+        let mut candidates: Vec<f32> = confidence_values.to_vec();
+        candidates.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        candidates.dedup();
+
+        let mut best_threshold = candidates[0];
+        let mut best_j = f32::NEG_INFINITY;
+        // (FPR, TPR) points; seeded with the "classify everything as negative"
+        // corner (0, 0), while the lowest candidate threshold yields (1, 1),
+        // so the curve always spans from (0, 0) to (1, 1).
+        let mut roc_points: Vec<(f32, f32)> = vec![(0.0, 0.0)];
+
+        for &threshold in &candidates {
+            let mut true_positives = 0.0f32;
+            let mut false_positives = 0.0f32;
+            for (i, &confidence) in confidence_values.iter().enumerate() {
+                if confidence >= threshold {
+                    if approval_decisions[i] == 1 {
+                        true_positives += 1.0;
+                    } else {
+                        false_positives += 1.0;
+                    }
+                }
+            }
+
+            let tpr = if total_approved > 0.0 { true_positives / total_approved } else { 0.0 };
+            let fpr = if total_rejected > 0.0 { false_positives / total_rejected } else { 0.0 };
+            roc_points.push((fpr, tpr));
+
+            let j = tpr - fpr;
+            if j > best_j {
+                best_j = j;
+                best_threshold = threshold;
+            }
+        }
+
+        roc_points.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap().then(a.1.partial_cmp(&b.1).unwrap()));
+        roc_points.dedup();
+
+        let auc = roc_points
+            .windows(2)
+            .map(|pair| {
+                let (fpr0, tpr0) = pair[0];
+                let (fpr1, tpr1) = pair[1];
+                (fpr1 - fpr0) * (tpr0 + tpr1) / 2.0
+            })
+            .sum();
+
+        (best_threshold, best_j, auc)
+    }
+
+    /// Holt's linear (double-exponential) smoothing: maintains a level and a
+    /// trend term, seeded from the first two samples, and returns the final
+    /// `(level, trend)` pair for forecasting `level + h * trend` steps ahead.
+    fn holt_linear_trend(&self, data: &[f32]) -> (f32, f32) {
+        if data.len() < 2 {
+            return (data.first().copied().unwrap_or(0.0), 0.0);
+        }
+
+        let mut level = data[0];
+        let mut trend = data[1] - data[0];
+
+        for &observation in &data[1..] {
+            let prev_level = level;
+            level = self.alpha * observation + (1.0 - self.alpha) * (level + trend);
+            trend = self.beta * (level - prev_level) + (1.0 - self.beta) * trend;
+        }
+
+        (level, trend)
+    }
 }
 
 // WASM module initialization
@@ -0,0 +1,105 @@
+//! On-disk blob framing shared by `FactStorage` backends.
+//!
+//! Each stored blob is `[tag: u8][payload][crc32 of payload: u32 LE]`. The tag
+//! distinguishes `Plain` (uncompressed) from `Zstd`-compressed payloads so
+//! mixed old/new entries coexist, and the trailing checksum lets `decode_blob`
+//! catch corruption cheaply without decompressing first.
+
+use anyhow::{bail, Result};
+
+const TAG_PLAIN: u8 = 0;
+const TAG_ZSTD: u8 = 1;
+const TRAILER_LEN: usize = 4;
+
+/// Encode `data` for storage, compressing with zstd at `compression_level`
+/// when set. Falls back to `Plain` framing when compression is disabled or
+/// when the compressed output isn't actually smaller than the input.
+pub fn encode_blob(data: &[u8], compression_level: Option<i32>) -> Vec<u8> {
+  if let Some(level) = compression_level {
+    if let Ok(compressed) = zstd::encode_all(data, level) {
+      if compressed.len() < data.len() {
+        return frame(TAG_ZSTD, &compressed);
+      }
+    }
+  }
+  frame(TAG_PLAIN, data)
+}
+
+/// Decode a blob written by [`encode_blob`]. Returns the original bytes and
+/// whether the blob was stored as `Plain`, so callers can opportunistically
+/// rewrite legacy plain entries as compressed on read.
+pub fn decode_blob(blob: &[u8]) -> Result<(Vec<u8>, bool)> {
+  if blob.len() < 1 + TRAILER_LEN {
+    bail!("Fact blob too short to contain a tag and checksum trailer");
+  }
+
+  let tag = blob[0];
+  let payload = &blob[1..blob.len() - TRAILER_LEN];
+  let stored_crc = u32::from_le_bytes(blob[blob.len() - TRAILER_LEN..].try_into().unwrap());
+  let actual_crc = crc32fast::hash(payload);
+  if actual_crc != stored_crc {
+    bail!(
+      "Fact blob checksum mismatch: expected {:08x}, got {:08x}",
+      stored_crc,
+      actual_crc
+    );
+  }
+
+  match tag {
+    TAG_PLAIN => Ok((payload.to_vec(), true)),
+    TAG_ZSTD => {
+      let decompressed = zstd::decode_all(payload)?;
+      Ok((decompressed, false))
+    }
+    other => bail!("Unknown fact blob tag: {other}"),
+  }
+}
+
+fn frame(tag: u8, payload: &[u8]) -> Vec<u8> {
+  let mut out = Vec::with_capacity(1 + payload.len() + TRAILER_LEN);
+  out.push(tag);
+  out.extend_from_slice(payload);
+  out.extend_from_slice(&crc32fast::hash(payload).to_le_bytes());
+  out
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn round_trips_plain() {
+    let data = b"short".to_vec();
+    let blob = encode_blob(&data, None);
+    let (decoded, was_plain) = decode_blob(&blob).unwrap();
+    assert_eq!(decoded, data);
+    assert!(was_plain);
+  }
+
+  #[test]
+  fn round_trips_compressed() {
+    let data = vec![b'x'; 4096];
+    let blob = encode_blob(&data, Some(3));
+    let (decoded, was_plain) = decode_blob(&blob).unwrap();
+    assert_eq!(decoded, data);
+    assert!(!was_plain);
+  }
+
+  #[test]
+  fn detects_corruption() {
+    let mut blob = encode_blob(b"hello world", None);
+    let last = blob.len() - 1;
+    blob[last] ^= 0xFF;
+    assert!(decode_blob(&blob).is_err());
+  }
+
+  #[test]
+  fn falls_back_to_plain_when_incompressible() {
+    // Tiny, high-entropy input: zstd's framing overhead makes this incompressible.
+    let data = vec![7u8];
+    let blob = encode_blob(&data, Some(19));
+    let (decoded, was_plain) = decode_blob(&blob).unwrap();
+    assert_eq!(decoded, data);
+    assert!(was_plain);
+  }
+}
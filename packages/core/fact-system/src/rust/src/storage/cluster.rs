@@ -0,0 +1,307 @@
+//! Sharded/clustered FACT storage with namespace routing
+//!
+//! Routes each key to an owning node (plus replicas) via rendezvous
+//! (highest-random-weight) hashing over `FactKey::storage_key()`, so the
+//! store isn't capped at one node's disk. Each node is itself a
+//! `Box<dyn FactStorage>` -- in a real multi-node deployment that would be a
+//! thin proxy forwarding calls over RPC, but `zen-swarm-orchestrator`'s
+//! `distributed` module (the natural place for that transport) is declared
+//! in this tree but has no implementation on disk, so `super::create_storage`
+//! stands each configured node up as its own local backend instead. The
+//! routing/replication/fan-out logic here is fully real either way -- only
+//! the "is this node actually a separate machine" part is a stand-in.
+
+use super::{FactData, FactKey, FactStorage, StorageStats};
+use anyhow::{anyhow, bail, Result};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::sync::Arc;
+
+/// Consistent-hash (rendezvous) routed FACT storage across multiple nodes.
+pub struct ClusteredFactStorage {
+  namespace: String,
+  nodes: Vec<(String, Arc<dyn FactStorage>)>,
+  replication_factor: usize,
+}
+
+impl ClusteredFactStorage {
+  pub fn new(
+    namespace: String,
+    nodes: Vec<(String, Arc<dyn FactStorage>)>,
+    replication_factor: usize,
+  ) -> Result<Self> {
+    if nodes.is_empty() {
+      bail!("ClusteredFactStorage requires at least one node");
+    }
+    Ok(Self {
+      namespace,
+      nodes,
+      replication_factor: replication_factor.clamp(1, nodes.len()),
+    })
+  }
+
+  /// Rendezvous-hash score for `(key, node)`; the node with the highest
+  /// score for a given key is its primary, the next-highest its first
+  /// replica, and so on. Mixing in `namespace` lets multiple independent
+  /// clusters share one node list without colliding on key ownership.
+  fn score(&self, storage_key: &str, node_id: &str) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    self.namespace.hash(&mut hasher);
+    storage_key.hash(&mut hasher);
+    node_id.hash(&mut hasher);
+    hasher.finish()
+  }
+
+  /// Node indices for `key`, ordered primary-first, by descending rendezvous score.
+  fn node_order(&self, key: &FactKey) -> Vec<usize> {
+    let storage_key = key.storage_key();
+    let mut ranked: Vec<(u64, usize)> = self
+      .nodes
+      .iter()
+      .enumerate()
+      .map(|(i, (node_id, _))| (self.score(&storage_key, node_id), i))
+      .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0));
+    ranked.into_iter().map(|(_, i)| i).collect()
+  }
+
+  /// The primary + replica nodes `key` is written to / read from.
+  fn replica_set(&self, key: &FactKey) -> Vec<usize> {
+    let order = self.node_order(key);
+    order[..self.replication_factor.min(order.len())].to_vec()
+  }
+}
+
+#[async_trait::async_trait]
+impl FactStorage for ClusteredFactStorage {
+  async fn store_fact(&self, key: &FactKey, data: &FactData) -> Result<()> {
+    let mut wrote_any = false;
+    let mut last_err = None;
+    for idx in self.replica_set(key) {
+      let (node_id, store) = &self.nodes[idx];
+      match store.store_fact(key, data).await {
+        Ok(()) => wrote_any = true,
+        Err(e) => {
+          log::warn!("Failed to write {} to cluster node {node_id}: {e}", key.storage_key());
+          last_err = Some(e);
+        }
+      }
+    }
+    if wrote_any {
+      Ok(())
+    } else {
+      Err(last_err.unwrap_or_else(|| anyhow!("No cluster nodes available to store {}", key.storage_key())))
+    }
+  }
+
+  async fn get_fact(&self, key: &FactKey) -> Result<Option<FactData>> {
+    let mut reached_any = false;
+    let mut last_err = None;
+    for idx in self.replica_set(key) {
+      let (node_id, store) = &self.nodes[idx];
+      match store.get_fact(key).await {
+        Ok(Some(data)) => return Ok(Some(data)),
+        Ok(None) => reached_any = true,
+        Err(e) => {
+          log::warn!(
+            "Cluster node {node_id} unreachable for {}, falling back to next replica: {e}",
+            key.storage_key()
+          );
+          last_err = Some(e);
+        }
+      }
+    }
+    if reached_any || last_err.is_none() {
+      Ok(None)
+    } else {
+      Err(last_err.unwrap())
+    }
+  }
+
+  async fn exists(&self, key: &FactKey) -> Result<bool> {
+    Ok(self.get_fact(key).await?.is_some())
+  }
+
+  async fn delete_fact(&self, key: &FactKey) -> Result<()> {
+    let mut deleted_any = false;
+    let mut last_err = None;
+    for idx in self.replica_set(key) {
+      let (node_id, store) = &self.nodes[idx];
+      match store.delete_fact(key).await {
+        Ok(()) => deleted_any = true,
+        Err(e) => {
+          log::warn!("Failed to delete {} from cluster node {node_id}: {e}", key.storage_key());
+          last_err = Some(e);
+        }
+      }
+    }
+    if deleted_any {
+      Ok(())
+    } else {
+      Err(last_err.unwrap_or_else(|| anyhow!("No cluster nodes available to delete {}", key.storage_key())))
+    }
+  }
+
+  async fn list_tools(&self, ecosystem: &str) -> Result<Vec<FactKey>> {
+    // Routing is per-key, not per-ecosystem, so any node may own any tool in
+    // `ecosystem` -- every node has to be asked, with replicas' duplicates deduped.
+    let mut seen = std::collections::HashSet::new();
+    let mut merged = Vec::new();
+    for (node_id, store) in &self.nodes {
+      match store.list_tools(ecosystem).await {
+        Ok(tools) => {
+          for key in tools {
+            if seen.insert(key.storage_key()) {
+              merged.push(key);
+            }
+          }
+        }
+        Err(e) => log::warn!("Cluster node {node_id} failed to list tools for {ecosystem}: {e}"),
+      }
+    }
+    Ok(merged)
+  }
+
+  async fn search_tools(&self, prefix: &str) -> Result<Vec<FactKey>> {
+    let mut seen = std::collections::HashSet::new();
+    let mut merged = Vec::new();
+    for (node_id, store) in &self.nodes {
+      match store.search_tools(prefix).await {
+        Ok(tools) => {
+          for key in tools {
+            if seen.insert(key.storage_key()) {
+              merged.push(key);
+            }
+          }
+        }
+        Err(e) => log::warn!("Cluster node {node_id} failed to search tools for {prefix}: {e}"),
+      }
+    }
+    Ok(merged)
+  }
+
+  async fn stats(&self) -> Result<StorageStats> {
+    let mut total_entries = 0u64;
+    let mut total_size_bytes = 0u64;
+    let mut ecosystems: HashMap<String, u64> = HashMap::new();
+    let mut last_compaction = None;
+
+    for (node_id, store) in &self.nodes {
+      match store.stats().await {
+        Ok(stats) => {
+          // Replicated entries are counted once per replica, so these totals
+          // are the cluster's raw storage footprint, not its distinct-key count.
+          total_entries += stats.total_entries;
+          total_size_bytes += stats.total_size_bytes;
+          for (ecosystem, count) in stats.ecosystems {
+            *ecosystems.entry(ecosystem).or_insert(0) += count;
+          }
+          last_compaction = last_compaction.max(stats.last_compaction);
+        }
+        Err(e) => log::warn!("Cluster node {node_id} failed to report stats: {e}"),
+      }
+    }
+
+    Ok(StorageStats {
+      total_entries,
+      total_size_bytes,
+      ecosystems,
+      last_compaction,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::storage::{simple_storage::SimpleFactStorage, StorageBackend, StorageConfig};
+  use tempfile::tempdir;
+
+  async fn node(dir: &std::path::Path, name: &str) -> Arc<dyn FactStorage> {
+    let config = StorageConfig {
+      global_facts_dir: dir.join(name).to_string_lossy().to_string(),
+      compression_level: Some(3),
+      backend: StorageBackend::SimpleFile,
+      lmdb_map_size: 1024 * 1024 * 1024,
+      embedding_model_path: None,
+      encryption_key: None,
+      encryption_key_file: None,
+      namespace: "test".to_string(),
+      cluster_nodes: vec![],
+      replication_factor: 1,
+    };
+    Arc::new(SimpleFactStorage::new(config).await.unwrap())
+  }
+
+  #[tokio::test]
+  async fn replicated_write_is_readable_after_losing_the_primary() {
+    let temp_dir = tempdir().unwrap();
+    let nodes = vec![
+      ("a".to_string(), node(temp_dir.path(), "a").await),
+      ("b".to_string(), node(temp_dir.path(), "b").await),
+      ("c".to_string(), node(temp_dir.path(), "c").await),
+    ];
+    let cluster = ClusteredFactStorage::new("test".to_string(), nodes, 2).unwrap();
+
+    let key = FactKey::new("phoenix".to_string(), "1.7.0".to_string(), "beam".to_string());
+    let data = FactData {
+      tool: "phoenix".to_string(),
+      version: "1.7.0".to_string(),
+      ecosystem: "beam".to_string(),
+      documentation: "Phoenix web framework".to_string(),
+      snippets: vec![],
+      examples: vec![],
+      best_practices: vec![],
+      troubleshooting: vec![],
+      github_sources: vec![],
+      dependencies: vec![],
+      tags: vec![],
+      last_updated: std::time::SystemTime::now(),
+      source: "test".to_string(),
+    };
+
+    cluster.store_fact(&key, &data).await.unwrap();
+    assert!(cluster.get_fact(&key).await.unwrap().is_some());
+
+    let stats = cluster.stats().await.unwrap();
+    // Written to 2 of 3 nodes, so the raw footprint counts it twice.
+    assert_eq!(stats.total_entries, 2);
+
+    // Drop the primary's copy; the read must fall through to the replica.
+    let primary = cluster.replica_set(&key)[0];
+    cluster.nodes[primary].1.delete_fact(&key).await.unwrap();
+    assert!(cluster.get_fact(&key).await.unwrap().is_some());
+  }
+
+  #[tokio::test]
+  async fn list_tools_merges_and_dedupes_across_nodes() {
+    let temp_dir = tempdir().unwrap();
+    let nodes = vec![
+      ("a".to_string(), node(temp_dir.path(), "a").await),
+      ("b".to_string(), node(temp_dir.path(), "b").await),
+    ];
+    let cluster = ClusteredFactStorage::new("test".to_string(), nodes, 2).unwrap();
+
+    let key = FactKey::new("phoenix".to_string(), "1.7.0".to_string(), "beam".to_string());
+    let data = FactData {
+      tool: "phoenix".to_string(),
+      version: "1.7.0".to_string(),
+      ecosystem: "beam".to_string(),
+      documentation: String::new(),
+      snippets: vec![],
+      examples: vec![],
+      best_practices: vec![],
+      troubleshooting: vec![],
+      github_sources: vec![],
+      dependencies: vec![],
+      tags: vec![],
+      last_updated: std::time::SystemTime::now(),
+      source: "test".to_string(),
+    };
+    cluster.store_fact(&key, &data).await.unwrap();
+
+    let tools = cluster.list_tools("beam").await.unwrap();
+    assert_eq!(tools.len(), 1);
+  }
+}
@@ -0,0 +1,82 @@
+//! Optional at-rest encryption for stored `FactData` blobs
+//!
+//! Keyed by `StorageConfig::encryption_key`/`encryption_key_file` (resolved
+//! via [`super::secrets::resolve_secret`]). Disabled entirely when no key is
+//! configured -- `SimpleFactStorage` then writes `blob::encode_blob`'s output
+//! untouched, same as before this module existed.
+
+use aes_gcm::aead::{Aead, AeadCore, KeyInit, OsRng};
+use aes_gcm::{Aes256Gcm, Key};
+use anyhow::{bail, Result};
+use sha2::{Digest, Sha256};
+
+/// Marks an on-disk fact file as AES-256-GCM encrypted, distinct from
+/// `blob::TAG_PLAIN`/`blob::TAG_ZSTD` so a reader can tell an encrypted file
+/// from an unencrypted one by its first byte alone.
+pub const ENCRYPTED_MARKER: u8 = 0xFE;
+
+const NONCE_LEN: usize = 12;
+
+/// A resolved 256-bit encryption key, derived from the configured secret.
+#[derive(Clone)]
+pub struct EncryptionKey(Key<Aes256Gcm>);
+
+impl EncryptionKey {
+  /// Derive a fixed-width key from an arbitrary-length secret via SHA-256, so
+  /// operators can hand us any passphrase length in `encryption_key_file`.
+  pub fn from_secret(secret: &str) -> Self {
+    let digest = Sha256::digest(secret.as_bytes());
+    Self(*Key::<Aes256Gcm>::from_slice(&digest))
+  }
+}
+
+/// Encrypt `plaintext` under a freshly-generated nonce, returning `nonce || ciphertext`.
+pub fn encrypt(key: &EncryptionKey, plaintext: &[u8]) -> Vec<u8> {
+  let cipher = Aes256Gcm::new(&key.0);
+  let nonce = Aes256Gcm::generate_nonce(&mut OsRng);
+  let mut ciphertext = cipher
+    .encrypt(&nonce, plaintext)
+    .expect("AES-256-GCM encryption cannot fail for a valid key/nonce pair");
+  let mut out = nonce.to_vec();
+  out.append(&mut ciphertext);
+  out
+}
+
+/// Inverse of [`encrypt`]; `framed` must be `nonce || ciphertext` as produced above.
+pub fn decrypt(key: &EncryptionKey, framed: &[u8]) -> Result<Vec<u8>> {
+  if framed.len() < NONCE_LEN {
+    bail!("Encrypted fact blob is shorter than a nonce ({} bytes)", framed.len());
+  }
+  let (nonce, ciphertext) = framed.split_at(NONCE_LEN);
+  let cipher = Aes256Gcm::new(&key.0);
+  cipher
+    .decrypt(nonce.into(), ciphertext)
+    .map_err(|_| anyhow::anyhow!("Failed to decrypt fact blob: wrong key or corrupted data"))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn round_trips() {
+    let key = EncryptionKey::from_secret("correct horse battery staple");
+    let plaintext = b"phoenix 1.7.0 documentation";
+    let encrypted = encrypt(&key, plaintext);
+    assert_eq!(decrypt(&key, &encrypted).unwrap(), plaintext);
+  }
+
+  #[test]
+  fn wrong_key_fails_to_decrypt() {
+    let key = EncryptionKey::from_secret("correct horse battery staple");
+    let other_key = EncryptionKey::from_secret("wrong key");
+    let encrypted = encrypt(&key, b"secret data");
+    assert!(decrypt(&other_key, &encrypted).is_err());
+  }
+
+  #[test]
+  fn truncated_blob_fails_to_decrypt() {
+    let key = EncryptionKey::from_secret("correct horse battery staple");
+    assert!(decrypt(&key, b"short").is_err());
+  }
+}
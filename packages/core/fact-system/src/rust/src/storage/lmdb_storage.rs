@@ -0,0 +1,249 @@
+//! LMDB-backed FACT storage
+//!
+//! A memory-mapped, transactional alternative to `simple_storage`'s
+//! one-file-per-fact layout. Keys are `FactKey::storage_key()` strings, which
+//! LMDB keeps sorted, so `list_tools` can range-scan an ecosystem prefix
+//! instead of walking the filesystem; `search_tools` scans every key.
+
+use super::blob;
+use super::embedding::{self, EmbeddingEngine};
+use super::metrics::FactStorageMetrics;
+use super::{FactData, FactKey, FactStorage, FactStorageManagement, StorageConfig, StorageStats};
+use anyhow::{Context, Result};
+use heed::types::{Bytes, Str};
+use heed::{Database, Env, EnvOpenOptions};
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+/// LMDB-backed FACT storage implementation
+pub struct LmdbFactStorage {
+  env: Env,
+  db: Database<Str, Bytes>,
+  dir: PathBuf,
+  compression_level: Option<i32>,
+  /// Lazily-loaded embedding model backing `semantic_search`.
+  embedding: EmbeddingEngine,
+  /// Prometheus counters/gauges, updated on every store/delete/compact.
+  metrics: Arc<FactStorageMetrics>,
+}
+
+impl LmdbFactStorage {
+  pub async fn new(config: StorageConfig) -> Result<Self> {
+    let dir = PathBuf::from(&config.global_facts_dir);
+    std::fs::create_dir_all(&dir)
+      .with_context(|| format!("Failed to create LMDB directory: {:?}", dir))?;
+
+    // SAFETY: we control the lifetime of this process's access to `dir` and
+    // don't open it with flags (e.g. NO_TLS) that require extra caller
+    // guarantees beyond "don't point two incompatible `Env`s at one file".
+    let env = unsafe {
+      EnvOpenOptions::new()
+        .map_size(config.lmdb_map_size)
+        .max_dbs(1)
+        .open(&dir)
+    }
+    .with_context(|| format!("Failed to open LMDB environment: {:?}", dir))?;
+
+    let mut wtxn = env.write_txn()?;
+    let db: Database<Str, Bytes> = env
+      .create_database(&mut wtxn, Some("facts"))
+      .context("Failed to create/open LMDB facts database")?;
+    wtxn.commit()?;
+
+    log::info!("Initialized LMDB facts storage at: {:?}", dir);
+
+    Ok(Self {
+      env,
+      db,
+      dir,
+      compression_level: config.compression_level,
+      embedding: EmbeddingEngine::new(config.embedding_model_path),
+      metrics: Arc::new(FactStorageMetrics::new().context("Failed to register Prometheus metrics")?),
+    })
+  }
+
+  /// Shared handle to this store's Prometheus metrics, e.g. to pass to
+  /// `metrics::serve_metrics` alongside the store itself.
+  pub fn metrics(&self) -> Arc<FactStorageMetrics> {
+    Arc::clone(&self.metrics)
+  }
+
+  /// Inclusive lower bound / exclusive-ish upper bound used to range-scan all
+  /// keys under `fact:{ecosystem}:` without a full-table iteration.
+  fn ecosystem_prefix(ecosystem: &str) -> String {
+    format!("fact:{}:", ecosystem)
+  }
+
+  /// Parallel key holding `key`'s persisted embedding vector, distinguished
+  /// from fact blobs by the `vec:` prefix (facts all start `fact:`).
+  fn vector_key(key: &FactKey) -> String {
+    format!("vec:{}", key.storage_key())
+  }
+}
+
+#[async_trait::async_trait]
+impl FactStorage for LmdbFactStorage {
+  async fn store_fact(&self, key: &FactKey, data: &FactData) -> Result<()> {
+    let serialized = bincode::serialize(data).context("Failed to serialize fact data")?;
+    let blob = blob::encode_blob(&serialized, self.compression_level);
+
+    let mut wtxn = self.env.write_txn()?;
+    self.db.put(&mut wtxn, &key.storage_key(), &blob)?;
+    wtxn.commit()?;
+
+    // Best-effort: embed and persist a search vector under a parallel key. A
+    // missing model/runtime just skips this, it never fails the store.
+    if let Some(vector) = self.embedding.embed(&embedding::embeddable_text(data)).await {
+      let mut wtxn = self.env.write_txn()?;
+      self.db.put(&mut wtxn, &Self::vector_key(key), &embedding::encode_vector(&vector))?;
+      wtxn.commit()?;
+    }
+
+    log::debug!("Stored fact: {}", key.storage_key());
+    self.metrics.record_store();
+    Ok(())
+  }
+
+  async fn get_fact(&self, key: &FactKey) -> Result<Option<FactData>> {
+    let rtxn = self.env.read_txn()?;
+    let Some(raw) = self.db.get(&rtxn, &key.storage_key())? else {
+      return Ok(None);
+    };
+
+    let (serialized, _was_plain) = blob::decode_blob(raw)
+      .with_context(|| format!("Fact blob failed integrity check: {}", key.storage_key()))?;
+    let fact_data: FactData =
+      bincode::deserialize(&serialized).context("Failed to deserialize fact data")?;
+
+    log::debug!("Retrieved fact: {}", key.storage_key());
+    Ok(Some(fact_data))
+  }
+
+  async fn exists(&self, key: &FactKey) -> Result<bool> {
+    let rtxn = self.env.read_txn()?;
+    Ok(self.db.get(&rtxn, &key.storage_key())?.is_some())
+  }
+
+  async fn delete_fact(&self, key: &FactKey) -> Result<()> {
+    let mut wtxn = self.env.write_txn()?;
+    self.db.delete(&mut wtxn, &key.storage_key())?;
+    wtxn.commit()?;
+
+    log::debug!("Deleted fact: {}", key.storage_key());
+    self.metrics.record_delete();
+    Ok(())
+  }
+
+  async fn list_tools(&self, ecosystem: &str) -> Result<Vec<FactKey>> {
+    let prefix = Self::ecosystem_prefix(ecosystem);
+    let rtxn = self.env.read_txn()?;
+
+    let mut tools = Vec::new();
+    for entry in self.db.prefix_iter(&rtxn, &prefix)? {
+      let (stored_key, _) = entry?;
+      tools.push(FactKey::from_storage_key(stored_key)?);
+    }
+    Ok(tools)
+  }
+
+  async fn search_tools(&self, prefix: &str) -> Result<Vec<FactKey>> {
+    // Keys are sorted `fact:{ecosystem}:{tool}:{version}`, so a tool-name
+    // prefix spans every ecosystem and can't be expressed as one LMDB range.
+    // Still a single read transaction over sorted keys, just not a prefix scan.
+    let rtxn = self.env.read_txn()?;
+    let mut matches = Vec::new();
+    for entry in self.db.iter(&rtxn)? {
+      let (stored_key, _) = entry?;
+      // Skip the parallel `vec:` embedding keys; only fact keys parse.
+      let Ok(key) = FactKey::from_storage_key(stored_key) else {
+        continue;
+      };
+      if key.tool.starts_with(prefix) {
+        matches.push(key);
+      }
+    }
+    Ok(matches)
+  }
+
+  async fn stats(&self) -> Result<StorageStats> {
+    let rtxn = self.env.read_txn()?;
+    let mut ecosystems: HashMap<String, u64> = HashMap::new();
+    let mut total_entries = 0u64;
+
+    for entry in self.db.iter(&rtxn)? {
+      let (stored_key, _) = entry?;
+      if let Ok(key) = FactKey::from_storage_key(stored_key) {
+        *ecosystems.entry(key.ecosystem).or_insert(0) += 1;
+        total_entries += 1;
+      }
+    }
+
+    let stat = self.env.stat()?;
+    let total_size_bytes =
+      (stat.leaf_pages + stat.branch_pages + stat.overflow_pages) as u64 * stat.page_size as u64;
+
+    let stats = StorageStats {
+      total_entries,
+      total_size_bytes,
+      ecosystems,
+      last_compaction: None,
+    };
+    self.metrics.update_from_stats(&stats);
+    Ok(stats)
+  }
+
+  async fn semantic_search(&self, query: &str, top_k: usize) -> Result<Vec<(FactKey, f32)>> {
+    let Some(query_vec) = self.embedding.embed(query).await else {
+      let matches = self.search_tools(query).await?;
+      return Ok(matches.into_iter().take(top_k).map(|key| (key, 0.0)).collect());
+    };
+
+    let rtxn = self.env.read_txn()?;
+    let mut candidates = Vec::new();
+    for entry in self.db.iter(&rtxn)? {
+      let (stored_key, raw) = entry?;
+      let Some(storage_key) = stored_key.strip_prefix("vec:") else {
+        continue;
+      };
+      if let (Ok(key), Some(vector)) = (
+        FactKey::from_storage_key(storage_key),
+        embedding::decode_vector(raw),
+      ) {
+        candidates.push((key, vector));
+      }
+    }
+
+    Ok(embedding::rank_by_similarity(&query_vec, candidates, top_k))
+  }
+}
+
+#[async_trait::async_trait]
+impl FactStorageManagement for LmdbFactStorage {
+  /// Compact the environment into a fresh file via LMDB's copy-with-compaction,
+  /// then swap it in for the live data file so free pages left behind by
+  /// deletes/updates are reclaimed.
+  async fn compact(&mut self) -> Result<()> {
+    let compacted_dir = self.dir.join("compact.tmp");
+    std::fs::create_dir_all(&compacted_dir)?;
+
+    self
+      .env
+      .copy_to_path(compacted_dir.join("data.mdb"), heed::CompactionOption::Enabled)
+      .context("Failed to copy-compact LMDB environment")?;
+
+    let live_data = self.dir.join("data.mdb");
+    std::fs::rename(compacted_dir.join("data.mdb"), &live_data)
+      .context("Failed to swap in compacted LMDB data file")?;
+    let _ = std::fs::remove_dir_all(&compacted_dir);
+
+    self.metrics.record_compaction();
+    log::info!("Compacted LMDB facts storage at: {:?}", self.dir);
+    Ok(())
+  }
+
+  async fn close(&mut self) -> Result<()> {
+    // `Env` flushes and closes its mmap on drop; nothing else to release.
+    Ok(())
+  }
+}
@@ -0,0 +1,169 @@
+//! Prometheus metrics for the FACT store and orchestrator
+//!
+//! Registers gauges/counters for storage growth (entries, bytes, per-ecosystem
+//! counts, compaction timestamps) plus orchestrator task/agent counts, and
+//! serves them in Prometheus text exposition format over a small axum
+//! endpoint so operators can wire this straight into Grafana without polling
+//! `FactStorage::stats()` themselves.
+
+use super::StorageStats;
+use anyhow::{Context, Result};
+use axum::{routing::get, Router};
+use prometheus::{Encoder, IntCounter, IntGauge, IntGaugeVec, Opts, Registry, TextEncoder};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// Cross-cutting metrics shared by `FactStorage` backends and the orchestrator.
+pub struct FactStorageMetrics {
+  registry: Registry,
+  total_entries: IntGauge,
+  total_size_bytes: IntGauge,
+  ecosystem_entries: IntGaugeVec,
+  last_compaction_timestamp: IntGauge,
+  store_fact_total: IntCounter,
+  delete_fact_total: IntCounter,
+  /// Orchestrator task throughput. `zen-swarm-orchestrator`'s `performance`
+  /// module isn't present in this tree to drive this automatically; it's
+  /// exposed here so an embedding binary can call `record_orchestrator_task_completed`.
+  orchestrator_tasks_completed: IntCounter,
+  /// Orchestrator active-agent count, same caveat as above.
+  orchestrator_agents_active: IntGauge,
+}
+
+impl FactStorageMetrics {
+  pub fn new() -> Result<Self> {
+    let registry = Registry::new();
+
+    let total_entries = IntGauge::new("fact_storage_total_entries", "Total stored facts")?;
+    let total_size_bytes = IntGauge::new("fact_storage_total_size_bytes", "Total bytes stored")?;
+    let ecosystem_entries = IntGaugeVec::new(
+      Opts::new("fact_storage_ecosystem_entries", "Stored facts per ecosystem"),
+      &["ecosystem"],
+    )?;
+    let last_compaction_timestamp = IntGauge::new(
+      "fact_storage_last_compaction_timestamp_seconds",
+      "Unix timestamp of the last successful compact()",
+    )?;
+    let store_fact_total = IntCounter::new("fact_storage_store_total", "Total store_fact calls")?;
+    let delete_fact_total =
+      IntCounter::new("fact_storage_delete_total", "Total delete_fact calls")?;
+    let orchestrator_tasks_completed = IntCounter::new(
+      "orchestrator_tasks_completed_total",
+      "Orchestrator tasks completed",
+    )?;
+    let orchestrator_agents_active =
+      IntGauge::new("orchestrator_agents_active", "Active orchestrator agents")?;
+
+    registry.register(Box::new(total_entries.clone()))?;
+    registry.register(Box::new(total_size_bytes.clone()))?;
+    registry.register(Box::new(ecosystem_entries.clone()))?;
+    registry.register(Box::new(last_compaction_timestamp.clone()))?;
+    registry.register(Box::new(store_fact_total.clone()))?;
+    registry.register(Box::new(delete_fact_total.clone()))?;
+    registry.register(Box::new(orchestrator_tasks_completed.clone()))?;
+    registry.register(Box::new(orchestrator_agents_active.clone()))?;
+
+    Ok(Self {
+      registry,
+      total_entries,
+      total_size_bytes,
+      ecosystem_entries,
+      last_compaction_timestamp,
+      store_fact_total,
+      delete_fact_total,
+      orchestrator_tasks_completed,
+      orchestrator_agents_active,
+    })
+  }
+
+  /// Refresh the storage gauges from a freshly-computed `StorageStats`.
+  pub fn update_from_stats(&self, stats: &StorageStats) {
+    self.total_entries.set(stats.total_entries as i64);
+    self.total_size_bytes.set(stats.total_size_bytes as i64);
+    for (ecosystem, count) in &stats.ecosystems {
+      self.ecosystem_entries.with_label_values(&[ecosystem]).set(*count as i64);
+    }
+  }
+
+  pub fn record_store(&self) {
+    self.store_fact_total.inc();
+  }
+
+  pub fn record_delete(&self) {
+    self.delete_fact_total.inc();
+  }
+
+  /// Mark `compact()` as having just completed.
+  pub fn record_compaction(&self) {
+    let now = std::time::SystemTime::now()
+      .duration_since(std::time::UNIX_EPOCH)
+      .map(|d| d.as_secs() as i64)
+      .unwrap_or(0);
+    self.last_compaction_timestamp.set(now);
+  }
+
+  pub fn record_orchestrator_task_completed(&self) {
+    self.orchestrator_tasks_completed.inc();
+  }
+
+  pub fn set_orchestrator_agents_active(&self, count: i64) {
+    self.orchestrator_agents_active.set(count);
+  }
+
+  /// Render every registered metric in Prometheus text exposition format.
+  pub fn encode(&self) -> Result<String> {
+    let encoder = TextEncoder::new();
+    let metric_families = self.registry.gather();
+    let mut buffer = Vec::new();
+    encoder.encode(&metric_families, &mut buffer)?;
+    String::from_utf8(buffer).context("Prometheus output was not valid UTF-8")
+  }
+}
+
+/// Serve `metrics` at `GET /metrics` in Prometheus text exposition format
+/// until the process exits. Intended to run as its own background task
+/// alongside whatever embeds this crate.
+pub async fn serve_metrics(addr: SocketAddr, metrics: Arc<FactStorageMetrics>) -> Result<()> {
+  let app = Router::new().route(
+    "/metrics",
+    get(move || {
+      let metrics = Arc::clone(&metrics);
+      async move {
+        match metrics.encode() {
+          Ok(body) => body,
+          Err(e) => format!("# error encoding metrics: {e}\n"),
+        }
+      }
+    }),
+  );
+
+  let listener = tokio::net::TcpListener::bind(addr)
+    .await
+    .with_context(|| format!("Failed to bind metrics endpoint: {addr}"))?;
+  log::info!("Serving Prometheus metrics at http://{addr}/metrics");
+  axum::serve(listener, app).await.context("Metrics server failed")?;
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn exposes_registered_metric_names() {
+    let metrics = FactStorageMetrics::new().unwrap();
+    metrics.update_from_stats(&StorageStats {
+      total_entries: 5,
+      total_size_bytes: 1024,
+      ecosystems: [("npm".to_string(), 5u64)].into_iter().collect(),
+      last_compaction: None,
+    });
+    metrics.record_store();
+    metrics.record_compaction();
+
+    let text = metrics.encode().unwrap();
+    assert!(text.contains("fact_storage_total_entries 5"));
+    assert!(text.contains("fact_storage_ecosystem_entries"));
+    assert!(text.contains("fact_storage_store_total 1"));
+  }
+}
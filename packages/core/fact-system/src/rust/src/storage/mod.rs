@@ -8,6 +8,13 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::time::SystemTime;
 
+pub mod blob;
+pub mod cluster;
+pub mod crypto;
+pub mod embedding;
+pub mod lmdb_storage;
+pub mod metrics;
+pub mod secrets;
 pub mod simple_storage;
 
 /// FACT storage abstraction trait (dyn-compatible)
@@ -31,6 +38,16 @@ pub trait FactStorage: Send + Sync {
   /// Search tools by prefix
   async fn search_tools(&self, prefix: &str) -> Result<Vec<FactKey>>;
 
+  /// Rank stored facts by semantic similarity to `query` using local ONNX
+  /// embeddings, returning up to `top_k` keys with their cosine similarity
+  /// score (highest first). Backends without an embedding model configured
+  /// degrade to treating `query` as a tool-name prefix via `search_tools`,
+  /// scoring every match `0.0`, rather than erroring.
+  async fn semantic_search(&self, query: &str, top_k: usize) -> Result<Vec<(FactKey, f32)>> {
+    let matches = self.search_tools(query).await?;
+    Ok(matches.into_iter().take(top_k).map(|key| (key, 0.0)).collect())
+  }
+
   /// Get storage statistics
   async fn stats(&self) -> Result<StorageStats>;
 }
@@ -148,10 +165,48 @@ pub struct StorageStats {
   pub last_compaction: Option<SystemTime>,
 }
 
+/// Which concrete `FactStorage` implementation `create_storage` should build.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum StorageBackend {
+  /// One file per fact under `global_facts_dir` (current default).
+  #[default]
+  SimpleFile,
+  /// Memory-mapped, transactional key/value store under `global_facts_dir`.
+  Lmdb,
+  /// Consistent-hash routed across `cluster_nodes`; see `cluster::ClusteredFactStorage`.
+  Clustered,
+}
+
 /// Simple storage configuration - just needs a global path
 #[derive(Debug, Clone)]
 pub struct StorageConfig {
   pub global_facts_dir: String,
+  /// zstd level used to compress stored `FactData` blobs. `None` disables
+  /// compression for newly-written entries (existing compressed entries are
+  /// still read and verified normally).
+  pub compression_level: Option<i32>,
+  /// Which backend `create_storage` builds.
+  pub backend: StorageBackend,
+  /// LMDB environment map size in bytes, used only when `backend` is `Lmdb`.
+  pub lmdb_map_size: usize,
+  /// Path to an ONNX sentence-transformer used for `semantic_search`. `None`
+  /// disables embedding-based search; backends fall back to prefix matching.
+  pub embedding_model_path: Option<String>,
+  /// Inline at-rest encryption key for `FactData` blobs (`SimpleFactStorage`
+  /// only). Mutually exclusive with `encryption_key_file`; `None` disables
+  /// encryption.
+  pub encryption_key: Option<String>,
+  /// File to read the at-rest encryption key from, e.g. a mounted Kubernetes
+  /// or Docker secret. Mutually exclusive with `encryption_key`.
+  pub encryption_key_file: Option<std::path::PathBuf>,
+  /// Routing namespace for `StorageBackend::Clustered`, mixed into the
+  /// rendezvous hash so multiple clusters can share one node list.
+  pub namespace: String,
+  /// Node ids for `StorageBackend::Clustered`. Ignored by other backends.
+  pub cluster_nodes: Vec<String>,
+  /// How many of `cluster_nodes` each key is written to, for redundancy.
+  /// Clamped to `[1, cluster_nodes.len()]` by `ClusteredFactStorage::new`.
+  pub replication_factor: usize,
 }
 
 impl Default for StorageConfig {
@@ -169,17 +224,75 @@ impl Default for StorageConfig {
       let home_dir = dirs::home_dir().unwrap_or_else(|| std::path::PathBuf::from("."));
       home_dir.join(".claude-zen").join("facts")
     };
-    
+
     Self {
       global_facts_dir: facts_dir.to_string_lossy().to_string(),
+      compression_level: Some(3),
+      backend: StorageBackend::default(),
+      lmdb_map_size: 1024 * 1024 * 1024, // 1GB
+      embedding_model_path: None,
+      encryption_key: None,
+      encryption_key_file: None,
+      namespace: "default".to_string(),
+      cluster_nodes: Vec::new(),
+      replication_factor: 1,
     }
   }
 }
 
-/// Create simple file-based storage for global facts
+impl StorageConfig {
+  /// Resolve `encryption_key`/`encryption_key_file` into a usable key,
+  /// erroring if both are set. `Ok(None)` means at-rest encryption is
+  /// disabled.
+  pub fn resolve_encryption_key(&self) -> Result<Option<crypto::EncryptionKey>> {
+    let secret = secrets::resolve_secret(
+      self.encryption_key.as_deref(),
+      self.encryption_key_file.as_deref(),
+      "encryption_key",
+    )?;
+    Ok(secret.map(|s| crypto::EncryptionKey::from_secret(&s)))
+  }
+}
+
+/// Create the `FactStorage` backend selected by `config.backend`.
 pub async fn create_storage(config: StorageConfig) -> Result<Box<dyn FactStorage>> {
-  let storage = simple_storage::SimpleFactStorage::new(config).await?;
-  Ok(Box::new(storage))
+  match config.backend {
+    StorageBackend::SimpleFile => {
+      let storage = simple_storage::SimpleFactStorage::new(config).await?;
+      Ok(Box::new(storage))
+    }
+    StorageBackend::Lmdb => {
+      let storage = lmdb_storage::LmdbFactStorage::new(config).await?;
+      Ok(Box::new(storage))
+    }
+    StorageBackend::Clustered => {
+      if config.cluster_nodes.is_empty() {
+        anyhow::bail!("StorageBackend::Clustered requires at least one entry in cluster_nodes");
+      }
+
+      // `zen-swarm-orchestrator::distributed` (the natural home for real
+      // inter-node RPC) is declared but not implemented anywhere in this
+      // tree, so each configured node is stood up as its own local backend
+      // rooted at a per-node subdirectory. The routing/replication/fan-out
+      // logic in `ClusteredFactStorage` runs for real either way.
+      let mut node_stores: Vec<(String, std::sync::Arc<dyn FactStorage>)> = Vec::new();
+      for node_id in &config.cluster_nodes {
+        let mut node_config = config.clone();
+        node_config.backend = StorageBackend::SimpleFile;
+        node_config.global_facts_dir = std::path::PathBuf::from(&config.global_facts_dir)
+          .join("cluster")
+          .join(node_id)
+          .to_string_lossy()
+          .to_string();
+        let store = simple_storage::SimpleFactStorage::new(node_config).await?;
+        node_stores.push((node_id.clone(), std::sync::Arc::new(store)));
+      }
+
+      let storage =
+        cluster::ClusteredFactStorage::new(config.namespace.clone(), node_stores, config.replication_factor)?;
+      Ok(Box::new(storage))
+    }
+  }
 }
 
 #[cfg(test)]
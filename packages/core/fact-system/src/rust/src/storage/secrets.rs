@@ -0,0 +1,80 @@
+//! File-backed secret loading
+//!
+//! Lets deployments mount sensitive values (encryption keys, RPC secrets) as
+//! files -- Kubernetes/Docker secrets -- instead of plain environment
+//! variables or inline config values. Setting both an inline value and its
+//! `_file` counterpart is almost always a misconfiguration, so it's rejected
+//! rather than silently preferring one over the other.
+
+use anyhow::{bail, Context, Result};
+use std::path::Path;
+
+/// Resolve `name` from either an inline value or a file path, erroring if
+/// both are set. Returns `Ok(None)` if neither is set. File contents are
+/// trimmed (to drop a trailing newline from `echo secret > file`) and
+/// rejected if empty after trimming.
+pub fn resolve_secret(inline: Option<&str>, file: Option<&Path>, name: &str) -> Result<Option<String>> {
+  match (inline, file) {
+    (Some(_), Some(path)) => bail!(
+      "{name} is set both inline and via {name}_file ({:?}); set only one",
+      path
+    ),
+    (Some(value), None) => Ok(Some(value.to_string())),
+    (None, Some(path)) => {
+      let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {name}_file: {:?}", path))?;
+      let trimmed = contents.trim();
+      if trimmed.is_empty() {
+        bail!("{name}_file is empty: {:?}", path);
+      }
+      Ok(Some(trimmed.to_string()))
+    }
+    (None, None) => Ok(None),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use tempfile::tempdir;
+
+  #[test]
+  fn neither_set_returns_none() {
+    assert_eq!(resolve_secret(None, None, "encryption_key").unwrap(), None);
+  }
+
+  #[test]
+  fn inline_value_is_returned_as_is() {
+    assert_eq!(
+      resolve_secret(Some("s3cr3t"), None, "encryption_key").unwrap(),
+      Some("s3cr3t".to_string())
+    );
+  }
+
+  #[test]
+  fn file_value_is_read_and_trimmed() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("key");
+    std::fs::write(&path, "s3cr3t\n").unwrap();
+    assert_eq!(
+      resolve_secret(None, Some(&path), "encryption_key").unwrap(),
+      Some("s3cr3t".to_string())
+    );
+  }
+
+  #[test]
+  fn both_set_is_an_error() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("key");
+    std::fs::write(&path, "s3cr3t").unwrap();
+    assert!(resolve_secret(Some("inline"), Some(&path), "encryption_key").is_err());
+  }
+
+  #[test]
+  fn empty_file_is_an_error() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("key");
+    std::fs::write(&path, "   \n").unwrap();
+    assert!(resolve_secret(None, Some(&path), "encryption_key").is_err());
+  }
+}
@@ -3,10 +3,15 @@
 //! Uses global ~/.claude-zen/facts/ directory for shared facts across projects
 //! Facts are public information so global storage makes sense
 
-use super::{FactStorage, FactKey, FactData, StorageStats, StorageConfig};
+use super::embedding::{self, EmbeddingEngine};
+use super::blob;
+use super::crypto::{self, EncryptionKey};
+use super::metrics::FactStorageMetrics;
+use super::{FactStorage, FactStorageManagement, FactKey, FactData, StorageStats, StorageConfig, StorageBackend};
 use anyhow::{Result, Context};
 use std::path::PathBuf;
 use std::collections::HashMap;
+use std::sync::Arc;
 use std::time::SystemTime;
 use tokio::fs;
 
@@ -14,20 +19,71 @@ use tokio::fs;
 pub struct SimpleFactStorage {
   /// Global facts directory (~/.claude-zen/facts/)
   facts_dir: PathBuf,
+  /// zstd level for newly-written entries; see `StorageConfig::compression_level`.
+  compression_level: Option<i32>,
+  /// Lazily-loaded embedding model backing `semantic_search`.
+  embedding: EmbeddingEngine,
+  /// Prometheus counters/gauges, updated on every store/delete/compact.
+  metrics: Arc<FactStorageMetrics>,
+  /// At-rest encryption key for stored `FactData` blobs; `None` disables
+  /// encryption entirely (see `StorageConfig::encryption_key`/`_file`).
+  encryption_key: Option<EncryptionKey>,
 }
 
 impl SimpleFactStorage {
   pub async fn new(config: StorageConfig) -> Result<Self> {
     let facts_dir = PathBuf::from(&config.global_facts_dir);
-    
+
     // Ensure the facts directory exists
     fs::create_dir_all(&facts_dir)
       .await
       .with_context(|| format!("Failed to create facts directory: {:?}", facts_dir))?;
-    
+
+    let encryption_key = config.resolve_encryption_key()?;
+
     log::info!("Initialized global facts storage at: {:?}", facts_dir);
-    
-    Ok(Self { facts_dir })
+
+    Ok(Self {
+      facts_dir,
+      compression_level: config.compression_level,
+      embedding: EmbeddingEngine::new(config.embedding_model_path),
+      metrics: Arc::new(FactStorageMetrics::new().context("Failed to register Prometheus metrics")?),
+      encryption_key,
+    })
+  }
+
+  /// Encrypt `blob` under `self.encryption_key` if configured, prefixing
+  /// `crypto::ENCRYPTED_MARKER` so `maybe_decrypt` can tell it apart from
+  /// an unencrypted (or differently-tagged) file.
+  fn maybe_encrypt(&self, blob: Vec<u8>) -> Vec<u8> {
+    match &self.encryption_key {
+      Some(key) => {
+        let mut out = vec![crypto::ENCRYPTED_MARKER];
+        out.extend(crypto::encrypt(key, &blob));
+        out
+      }
+      None => blob,
+    }
+  }
+
+  /// Inverse of `maybe_encrypt`. Errors if the file is encrypted but no key
+  /// is configured, or if decryption fails (wrong key / corrupted data).
+  fn maybe_decrypt(&self, raw: Vec<u8>) -> Result<Vec<u8>> {
+    if raw.first() == Some(&crypto::ENCRYPTED_MARKER) {
+      let key = self
+        .encryption_key
+        .as_ref()
+        .context("Fact file is encrypted but no encryption_key/encryption_key_file is configured")?;
+      crypto::decrypt(key, &raw[1..])
+    } else {
+      Ok(raw)
+    }
+  }
+
+  /// Shared handle to this store's Prometheus metrics, e.g. to pass to
+  /// `metrics::serve_metrics` alongside the store itself.
+  pub fn metrics(&self) -> Arc<FactStorageMetrics> {
+    Arc::clone(&self.metrics)
   }
 
   /// Get file path for a fact key
@@ -39,6 +95,15 @@ impl SimpleFactStorage {
       .join(format!("{}.bin", key.version))
   }
 
+  /// Get file path for a fact key's persisted embedding vector, stored
+  /// alongside the fact itself (same directory, `.vec` instead of `.bin`).
+  fn get_vector_file_path(&self, key: &FactKey) -> PathBuf {
+    self.facts_dir
+      .join(&key.ecosystem)
+      .join(&key.tool)
+      .join(format!("{}.vec", key.version))
+  }
+
   /// Get directory path for tool versions
   #[allow(dead_code)]
   fn get_tool_dir_path(&self, ecosystem: &str, tool: &str) -> PathBuf {
@@ -65,30 +130,54 @@ impl FactStorage for SimpleFactStorage {
     // Serialize fact data using bincode for efficiency
     let serialized = bincode::serialize(data)
       .context("Failed to serialize fact data")?;
+    let blob = blob::encode_blob(&serialized, self.compression_level);
+    let on_disk = self.maybe_encrypt(blob);
 
     // Write to file
-    fs::write(&file_path, serialized).await
+    fs::write(&file_path, on_disk).await
       .with_context(|| format!("Failed to write fact file: {:?}", file_path))?;
 
+    // Best-effort: embed and persist a search vector next to the fact. A
+    // missing model/runtime just skips this, it never fails the store.
+    if let Some(vector) = self.embedding.embed(&embedding::embeddable_text(data)).await {
+      let vector_path = self.get_vector_file_path(key);
+      if let Err(e) = fs::write(&vector_path, embedding::encode_vector(&vector)).await {
+        log::warn!("Failed to persist embedding vector: {:?}: {}", vector_path, e);
+      }
+    }
+
     log::debug!("Stored fact: {} at {:?}", key.storage_key(), file_path);
+    self.metrics.record_store();
     Ok(())
   }
 
   async fn get_fact(&self, key: &FactKey) -> Result<Option<FactData>> {
     let file_path = self.get_fact_file_path(key);
-    
+
     // Check if file exists
     if !file_path.exists() {
       return Ok(None);
     }
 
-    // Read and deserialize
-    let data = fs::read(&file_path).await
+    // Read and verify the stored blob
+    let raw = fs::read(&file_path).await
       .with_context(|| format!("Failed to read fact file: {:?}", file_path))?;
+    let raw = self.maybe_decrypt(raw)
+      .with_context(|| format!("Failed to decrypt fact file: {:?}", file_path))?;
+    let (serialized, was_plain) = blob::decode_blob(&raw)
+      .with_context(|| format!("Fact blob failed integrity check: {:?}", file_path))?;
 
-    let fact_data = bincode::deserialize(&data)
+    let fact_data: FactData = bincode::deserialize(&serialized)
       .context("Failed to deserialize fact data")?;
 
+    // Migrate legacy uncompressed entries in place, opportunistically.
+    if was_plain && self.compression_level.is_some() {
+      let recompressed = self.maybe_encrypt(blob::encode_blob(&serialized, self.compression_level));
+      if let Err(e) = fs::write(&file_path, recompressed).await {
+        log::warn!("Failed to rewrite fact as compressed: {:?}: {}", file_path, e);
+      }
+    }
+
     log::debug!("Retrieved fact: {} from {:?}", key.storage_key(), file_path);
     Ok(Some(fact_data))
   }
@@ -108,6 +197,7 @@ impl FactStorage for SimpleFactStorage {
       log::debug!("Deleted fact: {} at {:?}", key.storage_key(), file_path);
     }
 
+    self.metrics.record_delete();
     Ok(())
   }
 
@@ -218,12 +308,67 @@ impl FactStorage for SimpleFactStorage {
       }
     }
 
-    Ok(StorageStats {
+    let stats = StorageStats {
       total_entries,
       total_size_bytes,
       ecosystems,
       last_compaction: Some(SystemTime::now()),
-    })
+    };
+    self.metrics.update_from_stats(&stats);
+    Ok(stats)
+  }
+
+  async fn semantic_search(&self, query: &str, top_k: usize) -> Result<Vec<(FactKey, f32)>> {
+    let Some(query_vec) = self.embedding.embed(query).await else {
+      let matches = self.search_tools(query).await?;
+      return Ok(matches.into_iter().take(top_k).map(|key| (key, 0.0)).collect());
+    };
+
+    let mut candidates = Vec::new();
+    if self.facts_dir.exists() {
+      let mut ecosystem_entries = fs::read_dir(&self.facts_dir).await?;
+      while let Some(ecosystem_entry) = ecosystem_entries.next_entry().await? {
+        let ecosystem_path = ecosystem_entry.path();
+        if !ecosystem_path.is_dir() {
+          continue;
+        }
+        let ecosystem_name = ecosystem_path.file_name()
+          .and_then(|n| n.to_str())
+          .unwrap_or("")
+          .to_string();
+
+        for key in self.list_tools(&ecosystem_name).await? {
+          let vector_path = self.get_vector_file_path(&key);
+          if let Ok(raw) = fs::read(&vector_path).await {
+            if let Some(vector) = embedding::decode_vector(&raw) {
+              candidates.push((key, vector));
+            }
+          }
+        }
+      }
+    }
+
+    Ok(embedding::rank_by_similarity(&query_vec, candidates, top_k))
+  }
+}
+
+#[async_trait::async_trait]
+impl FactStorageManagement for SimpleFactStorage {
+  /// One-file-per-fact storage has no free-list to reclaim, so there's
+  /// nothing to rewrite here; this still refreshes `stats()`-derived gauges
+  /// and marks the compaction timestamp so dashboards built against
+  /// `FactStorageMetrics` behave the same across backends.
+  async fn compact(&mut self) -> Result<()> {
+    let stats = self.stats().await?;
+    self.metrics.update_from_stats(&stats);
+    self.metrics.record_compaction();
+    log::info!("Compacted (no-op) simple facts storage at: {:?}", self.facts_dir);
+    Ok(())
+  }
+
+  async fn close(&mut self) -> Result<()> {
+    // No file handles/connections are held open between calls.
+    Ok(())
   }
 }
 
@@ -237,6 +382,15 @@ mod tests {
     let temp_dir = tempdir().unwrap();
     let config = StorageConfig {
       global_facts_dir: temp_dir.path().to_string_lossy().to_string(),
+      compression_level: Some(3),
+      backend: StorageBackend::SimpleFile,
+      lmdb_map_size: 1024 * 1024 * 1024,
+      embedding_model_path: None,
+      encryption_key: None,
+      encryption_key_file: None,
+      namespace: "default".to_string(),
+      cluster_nodes: Vec::new(),
+      replication_factor: 1,
     };
 
     let storage = SimpleFactStorage::new(config).await.unwrap();
@@ -25,12 +25,23 @@ pub trait EnsembleModel {
     fn model_count(&self) -> usize;
 }
 
-/// Bagging ensemble using bootstrap sampling
+/// Bagging ensemble using bootstrap sampling.
+///
+/// With the `parallel` feature enabled, `train` and `predict` fan the
+/// per-model work out across a rayon thread pool instead of looping
+/// serially -- each model trains on its own bootstrap sample and predicts
+/// independently, so there's no cross-model state to synchronize.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BaggingEnsemble {
     pub models: Vec<Network>,
     pub bootstrap_ratio: f32,
     pub aggregation_method: AggregationMethod,
+    /// Per-model mask over the training set from the last `train` call:
+    /// `oob_masks[m][i] == true` means sample `i` was *not* drawn into
+    /// model `m`'s bootstrap sample, so model `m` can vote on it for
+    /// `oob_error`. Empty until `train` has run at least once.
+    #[serde(default)]
+    pub oob_masks: Vec<Vec<bool>>,
 }
 
 /// Methods for aggregating ensemble predictions
@@ -38,8 +49,36 @@ pub struct BaggingEnsemble {
 pub enum AggregationMethod {
     Mean,
     WeightedMean,
+    /// Each model's output is treated as per-class scores; the winning
+    /// class is whichever gets the most model votes (ties broken by
+    /// summed confidence), returned as a one-hot vector.
     Majority,
     Median,
+    /// Average each model's softmax-normalized output, so models with
+    /// differently-scaled raw outputs still contribute comparable
+    /// probability mass instead of one model's larger scores dominating.
+    SoftmaxMean,
+}
+
+/// Index of the highest-scoring entry in `v`; ties keep the first.
+fn argmax(v: &Array1<f32>) -> usize {
+    v.iter()
+        .enumerate()
+        .max_by(|(ia, a), (ib, b)| a.partial_cmp(b).unwrap().then(ib.cmp(ia)))
+        .map(|(i, _)| i)
+        .unwrap_or(0)
+}
+
+/// Numerically-stable softmax over a single model's output vector.
+fn softmax(v: &Array1<f32>) -> Array1<f32> {
+    let max = v.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+    let exp = v.mapv(|x| (x - max).exp());
+    let sum = exp.sum();
+    if sum > 0.0 {
+        exp / sum
+    } else {
+        exp
+    }
 }
 
 impl BaggingEnsemble {
@@ -48,20 +87,28 @@ impl BaggingEnsemble {
             models: Vec::new(),
             bootstrap_ratio,
             aggregation_method,
+            oob_masks: Vec::new(),
         }
     }
 
-    /// Create bootstrap sample from training data
-    fn create_bootstrap_sample(&self, data: &TrainingData, rng: &mut ThreadRng) -> TrainingData {
+    /// Create bootstrap sample from training data, along with a per-sample
+    /// "was drawn" mask so the caller can derive the out-of-bag set.
+    fn create_bootstrap_sample(
+        &self,
+        data: &TrainingData,
+        rng: &mut ThreadRng,
+    ) -> (TrainingData, Vec<bool>) {
         let n_samples = (data.len() as f32 * self.bootstrap_ratio) as usize;
         let mut bootstrap_data = TrainingData::new();
-        
+        let mut drawn = vec![false; data.len()];
+
         for _ in 0..n_samples {
             let idx = rng.gen_range(0..data.len());
             bootstrap_data.add_example(data.inputs[idx].clone(), data.targets[idx].clone());
+            drawn[idx] = true;
         }
-        
-        bootstrap_data
+
+        (bootstrap_data, drawn)
     }
 
     /// Aggregate predictions from all models
@@ -100,14 +147,111 @@ impl BaggingEnsemble {
                 result
             }
             AggregationMethod::Majority => {
-                // For regression, treat as weighted average
+                let votes: Vec<usize> = predictions.iter().map(argmax).collect();
+                let mut vote_counts = vec![0usize; output_size];
+                let mut confidence_sums = vec![0.0f32; output_size];
+                for (pred, &class) in predictions.iter().zip(votes.iter()) {
+                    vote_counts[class] += 1;
+                    confidence_sums[class] += pred[class];
+                }
+
+                let max_votes = *vote_counts.iter().max().unwrap_or(&0);
+                let winner = (0..output_size)
+                    .filter(|&class| vote_counts[class] == max_votes)
+                    .max_by(|&a, &b| confidence_sums[a].partial_cmp(&confidence_sums[b]).unwrap())
+                    .unwrap_or(0);
+
+                result[winner] = 1.0;
+                result
+            }
+            AggregationMethod::SoftmaxMean => {
                 for pred in predictions {
-                    result = result + pred;
+                    result = result + softmax(pred);
                 }
                 result / predictions.len() as f32
             }
         }
     }
+
+    /// Per-model vote agreement and entropy for a `Majority` aggregation
+    /// over `predictions` -- how decisively the ensemble agreed on a
+    /// class, independent of which class won. Returns an `EnsembleMetrics`
+    /// with only `vote_entropy`/`agreement_fraction` populated; the
+    /// regression-oriented fields are left at their defaults since voting
+    /// diagnostics don't have an individual/ensemble error to report.
+    pub fn classification_metrics(&self, predictions: &[Array1<f32>]) -> EnsembleMetrics {
+        if predictions.is_empty() {
+            return EnsembleMetrics {
+                vote_entropy: Some(0.0),
+                agreement_fraction: Some(0.0),
+                ..Default::default()
+            };
+        }
+
+        let output_size = predictions[0].len();
+        let votes: Vec<usize> = predictions.iter().map(argmax).collect();
+        let mut vote_counts = vec![0usize; output_size];
+        for &class in &votes {
+            vote_counts[class] += 1;
+        }
+
+        let total = votes.len() as f32;
+        let max_votes = *vote_counts.iter().max().unwrap_or(&0);
+
+        let vote_entropy = vote_counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f32 / total;
+                -p * p.log2()
+            })
+            .sum();
+
+        EnsembleMetrics {
+            vote_entropy: Some(vote_entropy),
+            agreement_fraction: Some(max_votes as f32 / total),
+            ..Default::default()
+        }
+    }
+
+    /// Mean error over samples that were out-of-bag for at least one model
+    /// in the last `train` call. Each such sample is predicted only by the
+    /// models that didn't see it during training, aggregated the same way
+    /// as `predict`, then compared against its true target. Returns `0.0`
+    /// if `train` hasn't run yet or no sample was ever out-of-bag.
+    pub fn oob_error(&mut self, data: &TrainingData) -> f32 {
+        if self.oob_masks.is_empty() {
+            return 0.0;
+        }
+
+        let mut total_error = 0.0;
+        let mut oob_count = 0;
+
+        for i in 0..data.len() {
+            let oob_predictions: Vec<Array1<f32>> = self
+                .models
+                .iter_mut()
+                .zip(self.oob_masks.iter())
+                .filter(|(_, mask)| mask[i])
+                .map(|(model, _)| model.predict(&data.inputs[i]))
+                .collect();
+
+            if oob_predictions.is_empty() {
+                continue;
+            }
+
+            let prediction = self.aggregate_predictions(&oob_predictions);
+            let diff = &prediction - &data.targets[i];
+            total_error += diff.mapv(|v| v * v).sum() / prediction.len() as f32;
+            oob_count += 1;
+        }
+
+        if oob_count == 0 {
+            0.0
+        } else {
+            total_error / oob_count as f32
+        }
+    }
 }
 
 impl EnsembleModel for BaggingEnsemble {
@@ -126,27 +270,58 @@ impl EnsembleModel for BaggingEnsemble {
         let mut total_loss = 0.0;
 
         // Pre-generate all bootstrap samples to avoid borrowing issues
-        let bootstrap_samples: Vec<TrainingData> = (0..self.models.len())
+        let bootstrap_samples: Vec<(TrainingData, Vec<bool>)> = (0..self.models.len())
             .map(|_| self.create_bootstrap_sample(data, &mut rng))
             .collect();
 
-        for (model, bootstrap_data) in self.models.iter_mut().zip(bootstrap_samples.iter()) {
+        let epochs = 50; // Fixed number of epochs for ensemble training
+
+        #[cfg(feature = "parallel")]
+        {
+            use rayon::prelude::*;
+            total_loss = self
+                .models
+                .par_iter_mut()
+                .zip(bootstrap_samples.par_iter())
+                .map(|(model, (bootstrap_data, _))| {
+                    let mut model_loss = 0.0;
+                    for _ in 0..epochs {
+                        model_loss += model.train_batch(&bootstrap_data.inputs, &bootstrap_data.targets);
+                    }
+                    model_loss / epochs as f32
+                })
+                .sum();
+        }
+
+        #[cfg(not(feature = "parallel"))]
+        for (model, (bootstrap_data, _)) in self.models.iter_mut().zip(bootstrap_samples.iter()) {
             // Train model on bootstrap sample
             let mut model_loss = 0.0;
-            let epochs = 50; // Fixed number of epochs for ensemble training
-            
+
             for _ in 0..epochs {
                 let loss = model.train_batch(&bootstrap_data.inputs, &bootstrap_data.targets);
                 model_loss += loss;
             }
-            
+
             total_loss += model_loss / epochs as f32;
         }
 
+        self.oob_masks = bootstrap_samples
+            .into_iter()
+            .map(|(_, drawn)| drawn.into_iter().map(|sampled| !sampled).collect())
+            .collect();
+
         Ok(total_loss / self.models.len() as f32)
     }
 
     fn predict(&mut self, input: &Array1<f32>) -> Self::Prediction {
+        #[cfg(feature = "parallel")]
+        let predictions: Vec<Array1<f32>> = {
+            use rayon::prelude::*;
+            self.models.par_iter_mut().map(|model| model.predict(input)).collect()
+        };
+
+        #[cfg(not(feature = "parallel"))]
         let predictions: Vec<Array1<f32>> = self.models
             .iter_mut()
             .map(|model| model.predict(input))
@@ -160,6 +335,55 @@ impl EnsembleModel for BaggingEnsemble {
     }
 }
 
+/// Loss function used to score predictions during ensemble training.
+/// `BoostingEnsemble` routes its per-model weighted error through `loss`,
+/// and `GradientBoostingEnsemble` routes its residual computation through
+/// `gradient` -- the target each new model is fit to.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum LossFunction {
+    Mse,
+    Mae,
+    BinaryCrossEntropy,
+}
+
+impl LossFunction {
+    /// Loss for a single prediction/target pair, averaged over output
+    /// dimensions so it's comparable across network sizes.
+    pub fn loss(&self, prediction: &Array1<f32>, target: &Array1<f32>) -> f32 {
+        match self {
+            LossFunction::Mse => (prediction - target).mapv(|x| x * x).sum() / prediction.len() as f32,
+            LossFunction::Mae => (prediction - target).mapv(|x| x.abs()).sum() / prediction.len() as f32,
+            LossFunction::BinaryCrossEntropy => {
+                let clipped = clip_probabilities(prediction);
+                let total: f32 = clipped
+                    .iter()
+                    .zip(target.iter())
+                    .map(|(p, t)| -(t * p.ln() + (1.0 - t) * (1.0 - p).ln()))
+                    .sum();
+                total / prediction.len() as f32
+            }
+        }
+    }
+
+    /// Negative gradient of the loss -- the residual a boosting stage's next
+    /// model is trained to fit. Constant factors are dropped (`Mse` returns
+    /// `target - prediction`), and for `BinaryCrossEntropy` the gradient is
+    /// taken with respect to the pre-sigmoid logit, giving `target - p`.
+    pub fn gradient(&self, prediction: &Array1<f32>, target: &Array1<f32>) -> Array1<f32> {
+        match self {
+            LossFunction::Mse => target - prediction,
+            LossFunction::Mae => (target - prediction).mapv(f32::signum),
+            LossFunction::BinaryCrossEntropy => target - &clip_probabilities(prediction),
+        }
+    }
+}
+
+/// Clip a vector of predicted probabilities away from the boundaries so
+/// `ln` stays finite when scoring `BinaryCrossEntropy`.
+fn clip_probabilities(prediction: &Array1<f32>) -> Array1<f32> {
+    prediction.mapv(|p| p.clamp(1e-15, 1.0 - 1e-15))
+}
+
 /// Boosting ensemble using AdaBoost-style training
 #[derive(Debug, Clone)]
 pub struct BoostingEnsemble {
@@ -167,15 +391,17 @@ pub struct BoostingEnsemble {
     pub model_weights: Vec<f32>,
     pub max_models: usize,
     pub learning_rate: f32,
+    pub loss_fn: LossFunction,
 }
 
 impl BoostingEnsemble {
-    pub fn new(max_models: usize, learning_rate: f32) -> Self {
+    pub fn new(max_models: usize, learning_rate: f32, loss_fn: LossFunction) -> Self {
         Self {
             models: Vec::new(),
             model_weights: Vec::new(),
             max_models,
             learning_rate,
+            loss_fn,
         }
     }
 }
@@ -212,7 +438,7 @@ impl EnsembleModel for BoostingEnsemble {
 
             for (i, (input, target)) in data.inputs.iter().zip(data.targets.iter()).enumerate() {
                 let prediction = self.models[model_idx].predict(input);
-                let error = (prediction - target).mapv(|x| x.abs()).sum();
+                let error = self.loss_fn.loss(&prediction, target);
                 weighted_error += sample_weights[i] * error;
                 total_weight += sample_weights[i];
             }
@@ -235,8 +461,8 @@ impl EnsembleModel for BoostingEnsemble {
             // Update sample weights
             for (i, (input, target)) in data.inputs.iter().zip(data.targets.iter()).enumerate() {
                 let prediction = self.models[model_idx].predict(input);
-                let error = (prediction - target).mapv(|x| x.abs()).sum();
-                
+                let error = self.loss_fn.loss(&prediction, target);
+
                 // Increase weight for misclassified samples
                 if error > 0.1 { // Threshold for "error"
                     sample_weights[i] *= (model_weight * self.learning_rate).exp();
@@ -279,6 +505,121 @@ impl EnsembleModel for BoostingEnsemble {
     }
 }
 
+/// Gradient-boosting ensemble: each successive network is trained to fit
+/// the negative gradient (residual) of the running ensemble prediction,
+/// the functional-gradient-descent idea behind gradient-boosted decision
+/// trees. Unlike `BoostingEnsemble`'s AdaBoost-style sample reweighting,
+/// this targets continuous regression outputs directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GradientBoostingEnsemble {
+    pub models: Vec<Network>,
+    pub max_models: usize,
+    pub learning_rate: f32,
+    pub epochs_per_model: usize,
+    pub loss_fn: LossFunction,
+
+    /// `F_0(x)`: the mean target vector over the training set this
+    /// ensemble was last trained on. Serialized alongside the models so
+    /// `predict` can reproduce `F_0 + learning_rate * sum_m h_m(x)`
+    /// without needing the original data again.
+    pub init_prediction: Array1<f32>,
+}
+
+impl GradientBoostingEnsemble {
+    pub fn new(
+        max_models: usize,
+        learning_rate: f32,
+        epochs_per_model: usize,
+        loss_fn: LossFunction,
+    ) -> Self {
+        Self {
+            models: Vec::new(),
+            max_models,
+            learning_rate,
+            epochs_per_model,
+            loss_fn,
+            init_prediction: Array1::zeros(0),
+        }
+    }
+
+    /// Mean target vector across `data`, used to seed `F_0(x)`.
+    fn mean_target(data: &TrainingData) -> Array1<f32> {
+        let target_len = data.targets[0].len();
+        let mut sum = Array1::zeros(target_len);
+        for target in &data.targets {
+            sum = sum + target;
+        }
+        sum / data.targets.len() as f32
+    }
+
+    /// Current ensemble prediction `F_m(x) = F_0(x) + learning_rate *
+    /// sum_{k<=m} h_k(x)` for the models trained so far.
+    fn ensemble_prediction(&mut self, input: &Array1<f32>) -> Array1<f32> {
+        let mut prediction = self.init_prediction.clone();
+        for model in &mut self.models {
+            prediction = prediction + model.predict(input) * self.learning_rate;
+        }
+        prediction
+    }
+}
+
+impl EnsembleModel for GradientBoostingEnsemble {
+    type Prediction = Array1<f32>;
+
+    fn add_model(&mut self, model: Network) {
+        if self.models.len() < self.max_models {
+            self.models.push(model);
+        }
+    }
+
+    fn train(&mut self, data: &TrainingData) -> Result<f32, String> {
+        if data.is_empty() {
+            return Err("No training data".to_string());
+        }
+        if self.models.is_empty() {
+            return Err("No models in ensemble".to_string());
+        }
+
+        self.init_prediction = Self::mean_target(data);
+        let mut total_loss = 0.0;
+
+        for model_idx in 0..self.models.len() {
+            // Residuals against the ensemble built from every *prior*
+            // stage -- the negative gradient of `self.loss_fn` evaluated
+            // at the running prediction.
+            let mut residual_data = TrainingData::new();
+            for (input, target) in data.inputs.iter().zip(data.targets.iter()) {
+                let mut running_prediction = self.init_prediction.clone();
+                for prior in &mut self.models[..model_idx] {
+                    running_prediction = running_prediction + prior.predict(input) * self.learning_rate;
+                }
+                let residual = self.loss_fn.gradient(&running_prediction, target);
+                assert_eq!(residual.len(), target.len(), "residual target must match network output dimensionality");
+                residual_data.add_example(input.clone(), residual);
+            }
+
+            let mut stage_loss = 0.0;
+            for _ in 0..self.epochs_per_model {
+                stage_loss += self.models[model_idx].train_batch(&residual_data.inputs, &residual_data.targets);
+            }
+            total_loss += stage_loss / self.epochs_per_model.max(1) as f32;
+        }
+
+        Ok(total_loss / self.models.len() as f32)
+    }
+
+    fn predict(&mut self, input: &Array1<f32>) -> Self::Prediction {
+        if self.models.is_empty() {
+            return self.init_prediction.clone();
+        }
+        self.ensemble_prediction(input)
+    }
+
+    fn model_count(&self) -> usize {
+        self.models.len()
+    }
+}
+
 /// Stacking ensemble with meta-learner
 #[derive(Debug, Clone)]
 pub struct StackingEnsemble {
@@ -315,6 +656,103 @@ impl StackingEnsemble {
 
         meta_data
     }
+
+    /// Rebuild a network with the same architecture and learning rate as
+    /// `template`, but freshly (randomly) initialized weights -- used so
+    /// each cross-validation fold trains a base model from scratch instead
+    /// of continuing from whatever a previous fold left it at.
+    fn reinitialize(template: &Network) -> Network {
+        let mut layer_sizes = Vec::with_capacity(template.layers.len() + 1);
+        let mut activations = Vec::with_capacity(template.layers.len());
+
+        for layer in &template.layers {
+            let (output_size, input_size) = layer.weights.dim();
+            if layer_sizes.is_empty() {
+                layer_sizes.push(input_size);
+            }
+            layer_sizes.push(output_size);
+            activations.push(layer.activation);
+        }
+
+        Network::new(&layer_sizes, &activations, template.learning_rate)
+    }
+
+    /// Train with k-fold out-of-fold meta-features instead of `train`'s
+    /// in-sample ones: each sample's meta-feature row comes from a base
+    /// model that never saw that sample during training, so the
+    /// meta-model never learns to trust base predictions that are only
+    /// accurate because they memorized the training set -- the leakage
+    /// `train`'s in-sample `generate_meta_features` is prone to.
+    pub fn train_cv(&mut self, data: &TrainingData, k_folds: usize) -> Result<f32, String> {
+        if self.base_models.is_empty() {
+            return Err("No base models in ensemble".to_string());
+        }
+        if k_folds < 2 {
+            return Err("k_folds must be at least 2".to_string());
+        }
+        if data.len() < k_folds {
+            return Err("Not enough samples for the requested number of folds".to_string());
+        }
+
+        let n = data.len();
+        let output_size = data.targets[0].len();
+        let fold_of: Vec<usize> = (0..n).map(|i| i % k_folds).collect();
+        let mut total_loss = 0.0;
+
+        let mut meta_rows: Vec<Array1<f32>> = (0..n)
+            .map(|_| Array1::zeros(self.base_models.len() * output_size))
+            .collect();
+
+        for fold in 0..k_folds {
+            let train_indices: Vec<usize> = (0..n).filter(|&i| fold_of[i] != fold).collect();
+            let held_out_indices: Vec<usize> = (0..n).filter(|&i| fold_of[i] == fold).collect();
+            if held_out_indices.is_empty() {
+                continue;
+            }
+
+            let mut fold_train_data = TrainingData::new();
+            for &i in &train_indices {
+                fold_train_data.add_example(data.inputs[i].clone(), data.targets[i].clone());
+            }
+
+            for model_idx in 0..self.base_models.len() {
+                let mut fold_model = Self::reinitialize(&self.base_models[model_idx]);
+                for _ in 0..30 {
+                    total_loss += fold_model.train_batch(&fold_train_data.inputs, &fold_train_data.targets);
+                }
+
+                let start = model_idx * output_size;
+                let end = start + output_size;
+                for &i in &held_out_indices {
+                    let prediction = fold_model.predict(&data.inputs[i]);
+                    meta_rows[i].slice_mut(s![start..end]).assign(&prediction);
+                }
+            }
+        }
+
+        let mut meta_data = TrainingData::new();
+        for (row, target) in meta_rows.into_iter().zip(data.targets.iter()) {
+            meta_data.add_example(row, target.clone());
+        }
+
+        // Refit base models on the full training set so `predict` uses
+        // models trained on all available data, not just a single fold.
+        for model in &mut self.base_models {
+            for _ in 0..30 {
+                total_loss += model.train_batch(&data.inputs, &data.targets);
+            }
+        }
+
+        for _ in 0..20 {
+            total_loss += self.meta_model.train_batch(&meta_data.inputs, &meta_data.targets);
+        }
+
+        self.trained = true;
+
+        let cv_steps = k_folds * self.base_models.len() * 30;
+        let refit_steps = self.base_models.len() * 30 + 20;
+        Ok(total_loss / (cv_steps + refit_steps) as f32)
+    }
 }
 
 impl EnsembleModel for StackingEnsemble {
@@ -407,8 +845,9 @@ impl EnsembleFactory {
         layer_sizes: &[usize],
         activations: &[ActivationFunction],
         learning_rate: f32,
+        loss_fn: LossFunction,
     ) -> BoostingEnsemble {
-        let mut ensemble = BoostingEnsemble::new(n_models, learning_rate);
+        let mut ensemble = BoostingEnsemble::new(n_models, learning_rate, loss_fn);
         
         for _ in 0..n_models {
             let model = Network::new(layer_sizes, activations, learning_rate);
@@ -418,6 +857,25 @@ impl EnsembleFactory {
         ensemble
     }
 
+    /// Create a gradient-boosting ensemble
+    pub fn create_gradient_boosting_ensemble(
+        n_models: usize,
+        layer_sizes: &[usize],
+        activations: &[ActivationFunction],
+        learning_rate: f32,
+        epochs_per_model: usize,
+        loss_fn: LossFunction,
+    ) -> GradientBoostingEnsemble {
+        let mut ensemble = GradientBoostingEnsemble::new(n_models, learning_rate, epochs_per_model, loss_fn);
+
+        for _ in 0..n_models {
+            let model = Network::new(layer_sizes, activations, learning_rate);
+            ensemble.add_model(model);
+        }
+
+        ensemble
+    }
+
     /// Create a stacking ensemble
     pub fn create_stacking_ensemble(
         base_models: Vec<(Vec<usize>, Vec<ActivationFunction>, f32)>,
@@ -438,12 +896,20 @@ impl EnsembleFactory {
 }
 
 /// Ensemble performance metrics
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EnsembleMetrics {
     pub diversity: f32,
     pub individual_errors: Vec<f32>,
     pub ensemble_error: f32,
     pub improvement_factor: f32,
+    /// Shannon entropy, in bits, of a classification vote distribution
+    /// across classes (see `BaggingEnsemble::classification_metrics`).
+    /// `None` when these metrics weren't computed from a vote.
+    #[serde(default)]
+    pub vote_entropy: Option<f32>,
+    /// Fraction of models that voted for the winning class.
+    #[serde(default)]
+    pub agreement_fraction: Option<f32>,
 }
 
 impl EnsembleMetrics {
@@ -540,9 +1006,33 @@ mod tests {
         assert_eq!(prediction.len(), 1);
     }
 
+    #[test]
+    fn test_bagging_ensemble_oob_error() {
+        let mut ensemble = BaggingEnsemble::new(0.8, AggregationMethod::Mean);
+
+        for _ in 0..5 {
+            let model = Network::new(&[2, 3, 1], &[ActivationFunction::ReLU, ActivationFunction::Sigmoid], 0.01);
+            ensemble.add_model(model);
+        }
+
+        let mut data = TrainingData::new();
+        for i in 0..10 {
+            data.add_example(array![i as f32, (i * 2) as f32], array![i as f32]);
+        }
+
+        assert_eq!(ensemble.oob_error(&data), 0.0);
+
+        ensemble.train(&data).unwrap();
+        assert_eq!(ensemble.oob_masks.len(), 5);
+
+        let error = ensemble.oob_error(&data);
+        assert!(error.is_finite());
+        assert!(error >= 0.0);
+    }
+
     #[test]
     fn test_boosting_ensemble() {
-        let mut ensemble = BoostingEnsemble::new(3, 0.1);
+        let mut ensemble = BoostingEnsemble::new(3, 0.1, LossFunction::Mae);
         
         // Add models
         for _ in 0..3 {
@@ -558,6 +1048,70 @@ mod tests {
         assert_eq!(prediction.len(), 1);
     }
 
+    #[test]
+    fn test_gradient_boosting_ensemble() {
+        let mut ensemble = GradientBoostingEnsemble::new(3, 0.1, 5, LossFunction::Mse);
+
+        for _ in 0..3 {
+            let model = Network::new(&[2, 3, 1], &[ActivationFunction::ReLU, ActivationFunction::Sigmoid], 0.01);
+            ensemble.add_model(model);
+        }
+
+        assert_eq!(ensemble.model_count(), 3);
+
+        let mut data = TrainingData::new();
+        data.add_example(array![1.0, 2.0], array![1.0]);
+        data.add_example(array![0.5, 1.5], array![0.5]);
+
+        assert!(ensemble.train(&data).is_ok());
+        assert_eq!(ensemble.init_prediction.len(), 1);
+
+        let input = array![1.0, 2.0];
+        let prediction = ensemble.predict(&input);
+        assert_eq!(prediction.len(), 1);
+    }
+
+    #[test]
+    fn test_loss_function_binary_cross_entropy_clips() {
+        let prediction = array![0.0, 1.0];
+        let target = array![1.0, 0.0];
+
+        let loss = LossFunction::BinaryCrossEntropy.loss(&prediction, &target);
+        assert!(loss.is_finite());
+
+        let gradient = LossFunction::BinaryCrossEntropy.gradient(&prediction, &target);
+        assert_eq!(gradient.len(), 2);
+        assert!(gradient.iter().all(|g| g.is_finite()));
+    }
+
+    #[test]
+    fn test_majority_voting_and_metrics() {
+        let ensemble = BaggingEnsemble::new(0.8, AggregationMethod::Majority);
+
+        let predictions = vec![
+            array![0.1, 0.9, 0.0],
+            array![0.2, 0.7, 0.1],
+            array![0.8, 0.1, 0.1],
+        ];
+
+        let result = ensemble.aggregate_predictions(&predictions);
+        assert_eq!(result, array![0.0, 1.0, 0.0]);
+
+        let metrics = ensemble.classification_metrics(&predictions);
+        assert!((metrics.agreement_fraction.unwrap() - 2.0 / 3.0).abs() < 1e-6);
+        assert!(metrics.vote_entropy.unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_softmax_mean_aggregation() {
+        let ensemble = BaggingEnsemble::new(0.8, AggregationMethod::SoftmaxMean);
+
+        let predictions = vec![array![1.0, 0.0], array![0.0, 1.0]];
+        let result = ensemble.aggregate_predictions(&predictions);
+
+        assert!((result.sum() - 1.0).abs() < 1e-5);
+    }
+
     #[test]
     fn test_stacking_ensemble() {
         let meta_model = Network::new(&[3, 2, 1], &[ActivationFunction::ReLU, ActivationFunction::Sigmoid], 0.01);
@@ -572,6 +1126,28 @@ mod tests {
         assert_eq!(ensemble.model_count(), 3);
     }
 
+    #[test]
+    fn test_stacking_ensemble_train_cv() {
+        let meta_model = Network::new(&[2, 2, 1], &[ActivationFunction::ReLU, ActivationFunction::Sigmoid], 0.01);
+        let mut ensemble = StackingEnsemble::new(meta_model);
+
+        for _ in 0..2 {
+            let model = Network::new(&[2, 3, 1], &[ActivationFunction::ReLU, ActivationFunction::Sigmoid], 0.01);
+            ensemble.add_model(model);
+        }
+
+        let mut data = TrainingData::new();
+        for i in 0..8 {
+            data.add_example(array![i as f32, (i * 2) as f32], array![(i % 2) as f32]);
+        }
+
+        assert!(ensemble.train_cv(&data, 4).is_ok());
+        assert!(ensemble.trained);
+
+        let prediction = ensemble.predict(&array![1.0, 2.0]);
+        assert_eq!(prediction.len(), 1);
+    }
+
     #[test]
     fn test_ensemble_factory() {
         let bagging = EnsembleFactory::create_bagging_ensemble(
@@ -583,10 +1159,11 @@ mod tests {
         assert_eq!(bagging.model_count(), 5);
 
         let boosting = EnsembleFactory::create_boosting_ensemble(
-            3, 
-            &[2, 4, 1], 
-            &[ActivationFunction::ReLU, ActivationFunction::Sigmoid], 
-            0.01
+            3,
+            &[2, 4, 1],
+            &[ActivationFunction::ReLU, ActivationFunction::Sigmoid],
+            0.01,
+            LossFunction::Mae,
         );
         assert_eq!(boosting.model_count(), 3);
     }
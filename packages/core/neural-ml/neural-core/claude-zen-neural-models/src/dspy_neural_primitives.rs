@@ -9,6 +9,115 @@ use crate::ActivationFunction;
 use serde::{Deserialize, Serialize};
 use anyhow::Result;
 
+/// Storage precision for a primitive's weight matrices. `Int8` trades a small
+/// amount of accuracy for roughly a 4x reduction in memory, which matters
+/// once `d_model`, `d_ff`, or `vocab_size` grow to realistic sizes.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+pub enum WeightPrecision {
+    #[default]
+    F32,
+    Int8,
+}
+
+/// An int8 matrix quantized per-row: `scale = (max-min)/255`,
+/// `zero = round(-min/scale)`, `q = round(x/scale)+zero`, dequantized as
+/// `x ≈ (q-zero)*scale`. `q` spans `0..=255`, so it is stored offset by
+/// -128 to fit `i8`. A constant row uses `scale = |x|/127` instead, so its
+/// value survives the round trip. Serde serializes this compact form directly, so a
+/// saved quantized model is roughly 4x smaller than its f32 equivalent.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuantizedMatrix {
+    pub rows: usize,
+    pub cols: usize,
+    pub scales: Vec<f32>,
+    pub zero_points: Vec<i32>,
+    pub data: Vec<i8>,
+}
+
+impl QuantizedMatrix {
+    pub fn quantize(matrix: &[Vec<f32>]) -> Self {
+        let rows = matrix.len();
+        let cols = matrix.first().map(Vec::len).unwrap_or(0);
+        let mut scales = Vec::with_capacity(rows);
+        let mut zero_points = Vec::with_capacity(rows);
+        let mut data = Vec::with_capacity(rows * cols);
+
+        for row in matrix {
+            let min = row.iter().copied().fold(f32::INFINITY, f32::min);
+            let max = row.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+            let scale = if max > min {
+                (max - min) / 255.0
+            } else if max == min && max != 0.0 {
+                max.abs() / 127.0
+            } else {
+                1.0
+            };
+            let zero = (-min / scale).round() as i32;
+            scales.push(scale);
+            zero_points.push(zero);
+
+            for &value in row {
+                let q = (value / scale).round() as i32 + zero + i8::MIN as i32;
+                data.push(q.clamp(i8::MIN as i32, i8::MAX as i32) as i8);
+            }
+        }
+
+        Self { rows, cols, scales, zero_points, data }
+    }
+
+    pub fn dequantize(&self) -> Vec<Vec<f32>> {
+        (0..self.rows).map(|row| (0..self.cols).map(|col| self.get(row, col)).collect()).collect()
+    }
+
+    fn get(&self, row: usize, col: usize) -> f32 {
+        let q = self.data[row * self.cols + col];
+        (q as i32 - i8::MIN as i32 - self.zero_points[row]) as f32 * self.scales[row]
+    }
+}
+
+/// A weight matrix stored either as dense f32 or as a quantized int8 matrix,
+/// dequantized on the fly during `forward` so callers never see the
+/// difference beyond reduced accuracy at `Int8` precision.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum WeightMatrix {
+    F32(Vec<Vec<f32>>),
+    Int8(QuantizedMatrix),
+}
+
+impl WeightMatrix {
+    fn dense(rows: usize, cols: usize, value: f32) -> Self {
+        WeightMatrix::F32((0..rows).map(|_| vec![value; cols]).collect())
+    }
+
+    /// Quantize this matrix to int8 in place, if it isn't already.
+    pub fn quantize(&mut self) {
+        if let WeightMatrix::F32(dense) = self {
+            *self = WeightMatrix::Int8(QuantizedMatrix::quantize(dense));
+        }
+    }
+
+    /// Dequantize this matrix back to dense f32 in place, if it isn't already.
+    pub fn dequantize(&mut self) {
+        if let WeightMatrix::Int8(quantized) = self {
+            *self = WeightMatrix::F32(quantized.dequantize());
+        }
+    }
+
+    fn get(&self, row: usize, col: usize) -> f32 {
+        match self {
+            WeightMatrix::F32(dense) => dense[row][col],
+            WeightMatrix::Int8(quantized) => quantized.get(row, col),
+        }
+    }
+
+    fn cols(&self, row: usize) -> usize {
+        match self {
+            WeightMatrix::F32(dense) => dense[row].len(),
+            WeightMatrix::Int8(quantized) => quantized.cols,
+        }
+    }
+}
+
 /// Multi-head attention mechanism for DSPy prompt optimization
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MultiHeadAttention {
@@ -16,68 +125,190 @@ pub struct MultiHeadAttention {
     pub num_heads: usize,
     pub d_k: usize,
     pub d_v: usize,
-    
+    pub precision: WeightPrecision,
+
     // Weight matrices (simplified as Vec<Vec<f32>> for basic implementation)
-    pub w_q: Vec<Vec<f32>>,
-    pub w_k: Vec<Vec<f32>>,
-    pub w_v: Vec<Vec<f32>>,
-    pub w_o: Vec<Vec<f32>>,
+    pub w_q: WeightMatrix,
+    pub w_k: WeightMatrix,
+    pub w_v: WeightMatrix,
+    pub w_o: WeightMatrix,
 }
 
 impl MultiHeadAttention {
     pub fn new(d_model: usize, num_heads: usize) -> Result<Self> {
+        Self::with_precision(d_model, num_heads, WeightPrecision::F32)
+    }
+
+    pub fn with_precision(d_model: usize, num_heads: usize, precision: WeightPrecision) -> Result<Self> {
         if d_model % num_heads != 0 {
             return Err(anyhow::anyhow!("d_model must be divisible by num_heads"));
         }
-        
+
         let d_k = d_model / num_heads;
         let d_v = d_k;
-        
+
         // Initialize weight matrices with random values (simplified)
-        let w_q = (0..d_model).map(|_| (0..d_model).map(|_| 0.1).collect()).collect();
-        let w_k = (0..d_model).map(|_| (0..d_model).map(|_| 0.1).collect()).collect();
-        let w_v = (0..d_model).map(|_| (0..d_model).map(|_| 0.1).collect()).collect();
-        let w_o = (0..d_model).map(|_| (0..d_model).map(|_| 0.1).collect()).collect();
-        
+        let mut w_q = WeightMatrix::dense(d_model, d_model, 0.1);
+        let mut w_k = WeightMatrix::dense(d_model, d_model, 0.1);
+        let mut w_v = WeightMatrix::dense(d_model, d_model, 0.1);
+        let mut w_o = WeightMatrix::dense(d_model, d_model, 0.1);
+
+        if precision == WeightPrecision::Int8 {
+            w_q.quantize();
+            w_k.quantize();
+            w_v.quantize();
+            w_o.quantize();
+        }
+
         Ok(Self {
             d_model,
             num_heads,
             d_k,
             d_v,
+            precision,
             w_q,
             w_k,
             w_v,
             w_o,
         })
     }
+
+    /// Quantize all weight matrices to int8 in place.
+    pub fn quantize(&mut self) {
+        self.precision = WeightPrecision::Int8;
+        self.w_q.quantize();
+        self.w_k.quantize();
+        self.w_v.quantize();
+        self.w_o.quantize();
+    }
+
+    /// Dequantize all weight matrices back to dense f32 in place.
+    pub fn dequantize(&mut self) {
+        self.precision = WeightPrecision::F32;
+        self.w_q.dequantize();
+        self.w_k.dequantize();
+        self.w_v.dequantize();
+        self.w_o.dequantize();
+    }
     
     pub fn forward(
         &self,
         input: &[Vec<f32>],
-        _mask: Option<&[Vec<bool>]>,
+        mask: Option<&[Vec<bool>]>,
     ) -> Result<Vec<Vec<f32>>> {
-        // Simplified multi-head attention implementation
-        // In a production system, this would be much more sophisticated
-        
-        let mut output = Vec::new();
-        for sequence in input {
-            let mut attended = vec![0.0f32; self.d_model];
-            
-            // Simplified attention: weighted average with learnable weights
-            for (i, &value) in sequence.iter().enumerate() {
-                for j in 0..self.d_model {
-                    if j < sequence.len() {
-                        // Use position index i for weighted attention
-                        let weight = 0.1 * (1.0 + i as f32 * 0.01);
-                        attended[j] += value * weight;
+        let seq_len = input.len();
+
+        // Project the input into queries, keys and values
+        let q = matmul(input, &self.w_q);
+        let k = matmul(input, &self.w_k);
+        let v = matmul(input, &self.w_v);
+
+        let mut head_outputs = Vec::with_capacity(self.num_heads);
+        for head in 0..self.num_heads {
+            let start = head * self.d_k;
+            let end = start + self.d_k;
+
+            let mut q_head: Vec<Vec<f32>> = q.iter().map(|row| row[start..end].to_vec()).collect();
+            let mut k_head: Vec<Vec<f32>> = k.iter().map(|row| row[start..end].to_vec()).collect();
+            let v_head: Vec<Vec<f32>> = v.iter().map(|row| row[start..end].to_vec()).collect();
+
+            apply_rotary_embedding(&mut q_head, self.d_model);
+            apply_rotary_embedding(&mut k_head, self.d_model);
+
+            let scale = (self.d_k as f32).sqrt();
+            let mut scores = vec![vec![0.0f32; seq_len]; seq_len];
+            for i in 0..seq_len {
+                for j in 0..seq_len {
+                    let mut dot = 0.0f32;
+                    for d in 0..self.d_k {
+                        dot += q_head[i][d] * k_head[j][d];
+                    }
+                    scores[i][j] = dot / scale;
+                    if let Some(mask) = mask {
+                        if mask[i][j] {
+                            scores[i][j] = f32::NEG_INFINITY;
+                        }
                     }
                 }
             }
-            
-            output.push(attended);
+
+            for row in &mut scores {
+                softmax_in_place(row);
+            }
+
+            let mut head_output = vec![vec![0.0f32; self.d_v]; seq_len];
+            for i in 0..seq_len {
+                for j in 0..seq_len {
+                    let weight = scores[i][j];
+                    for d in 0..self.d_v {
+                        head_output[i][d] += weight * v_head[j][d];
+                    }
+                }
+            }
+
+            head_outputs.push(head_output);
+        }
+
+        // Concatenate heads back to [seq_len, d_model]
+        let mut concatenated = vec![vec![0.0f32; self.d_model]; seq_len];
+        for (head, head_output) in head_outputs.into_iter().enumerate() {
+            let start = head * self.d_v;
+            for i in 0..seq_len {
+                concatenated[i][start..start + self.d_v].copy_from_slice(&head_output[i]);
+            }
+        }
+
+        Ok(matmul(&concatenated, &self.w_o))
+    }
+}
+
+/// Multiply a `[rows, d_model]` matrix by a `[d_model, d_model]` weight
+/// matrix, dequantizing weights on the fly when stored as `Int8`.
+fn matmul(input: &[Vec<f32>], weights: &WeightMatrix) -> Vec<Vec<f32>> {
+    let d_model = match weights {
+        WeightMatrix::F32(dense) => dense.len(),
+        WeightMatrix::Int8(quantized) => quantized.rows,
+    };
+    input
+        .iter()
+        .map(|row| {
+            (0..d_model)
+                .map(|j| row.iter().enumerate().map(|(i, &x)| x * weights.get(i, j)).sum())
+                .collect()
+        })
+        .collect()
+}
+
+/// Numerically-stable softmax, applied in place to a single row of scores
+fn softmax_in_place(row: &mut [f32]) {
+    let max = row.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+    let mut sum = 0.0f32;
+    for value in row.iter_mut() {
+        *value = (*value - max).exp();
+        sum += *value;
+    }
+    if sum > 0.0 {
+        for value in row.iter_mut() {
+            *value /= sum;
+        }
+    }
+}
+
+/// Rotary positional embedding: rotate each dimension pair `(2i, 2i+1)` of every
+/// sequence position by `theta = position / 10000^(2i/d_model)`
+fn apply_rotary_embedding(vectors: &mut [Vec<f32>], d_model: usize) {
+    for (position, vector) in vectors.iter_mut().enumerate() {
+        let mut i = 0;
+        while i + 1 < vector.len() {
+            // `i` is already the even index 2k of pair k, so the exponent is i/d_model.
+            let theta = position as f32 / 10000f32.powf(i as f32 / d_model as f32);
+            let (sin, cos) = theta.sin_cos();
+            let x = vector[i];
+            let y = vector[i + 1];
+            vector[i] = x * cos - y * sin;
+            vector[i + 1] = x * sin + y * cos;
+            i += 2;
         }
-        
-        Ok(output)
     }
 }
 
@@ -152,74 +383,99 @@ pub struct FeedForwardNetwork {
     pub d_model: usize,
     pub d_ff: usize,
     pub activation: ActivationFunction,
-    
+    pub precision: WeightPrecision,
+
     // Simplified weight matrices
-    pub w1: Vec<Vec<f32>>,
+    pub w1: WeightMatrix,
     pub b1: Vec<f32>,
-    pub w2: Vec<Vec<f32>>,
+    pub w2: WeightMatrix,
     pub b2: Vec<f32>,
 }
 
 impl FeedForwardNetwork {
     pub fn new(d_model: usize, d_ff: usize) -> Self {
+        Self::with_precision(d_model, d_ff, WeightPrecision::F32)
+    }
+
+    pub fn with_precision(d_model: usize, d_ff: usize, precision: WeightPrecision) -> Self {
         // Initialize with simple values
-        let w1 = (0..d_ff).map(|_| (0..d_model).map(|_| 0.1).collect()).collect();
+        let mut w1 = WeightMatrix::dense(d_ff, d_model, 0.1);
         let b1 = vec![0.0; d_ff];
-        let w2 = (0..d_model).map(|_| (0..d_ff).map(|_| 0.1).collect()).collect();
+        let mut w2 = WeightMatrix::dense(d_model, d_ff, 0.1);
         let b2 = vec![0.0; d_model];
-        
+
+        if precision == WeightPrecision::Int8 {
+            w1.quantize();
+            w2.quantize();
+        }
+
         Self {
             d_model,
             d_ff,
             activation: ActivationFunction::ReLU,
+            precision,
             w1,
             b1,
             w2,
             b2,
         }
     }
-    
+
+    /// Quantize both weight matrices to int8 in place.
+    pub fn quantize(&mut self) {
+        self.precision = WeightPrecision::Int8;
+        self.w1.quantize();
+        self.w2.quantize();
+    }
+
+    /// Dequantize both weight matrices back to dense f32 in place.
+    pub fn dequantize(&mut self) {
+        self.precision = WeightPrecision::F32;
+        self.w1.dequantize();
+        self.w2.dequantize();
+    }
+
     pub fn forward(&self, input: &[Vec<f32>]) -> Result<Vec<Vec<f32>>> {
         let mut output = Vec::new();
-        
+
         for sequence in input {
             // First linear transformation + activation
             let mut hidden = vec![0.0f32; self.d_ff];
             for (i, &input_val) in sequence.iter().enumerate() {
                 for j in 0..self.d_ff {
-                    if i < self.w1[j].len() {
-                        hidden[j] += input_val * self.w1[j][i];
+                    if i < self.w1.cols(j) {
+                        hidden[j] += input_val * self.w1.get(j, i);
                     }
                 }
             }
-            
+
             // Add bias and apply activation
             for (i, bias) in self.b1.iter().enumerate() {
                 if i < hidden.len() {
                     hidden[i] = self.activation.apply(hidden[i] + bias);
                 }
             }
-            
+
             // Second linear transformation
             let mut final_output = vec![0.0f32; self.d_model];
             for (i, &hidden_val) in hidden.iter().enumerate() {
                 for j in 0..self.d_model {
-                    if i < self.w2[j].len() {
-                        final_output[j] += hidden_val * self.w2[j][i];
+                    if i < self.w2.cols(j) {
+                        final_output[j] += hidden_val * self.w2.get(j, i);
                     }
                 }
             }
-            
+
             // Add bias
             for (i, bias) in self.b2.iter().enumerate() {
                 if i < final_output.len() {
                     final_output[i] += bias;
                 }
             }
-            
+
             output.push(final_output);
         }
-        
+
         Ok(output)
     }
 }
@@ -272,59 +528,85 @@ pub struct PromptEmbeddingNetwork {
     pub embedding_dim: usize,
     pub vocab_size: usize,
     pub max_sequence_length: usize,
-    
+    pub precision: WeightPrecision,
+
     // Token embeddings
-    pub token_embeddings: Vec<Vec<f32>>,
+    pub token_embeddings: WeightMatrix,
     // Position embeddings
-    pub position_embeddings: Vec<Vec<f32>>,
+    pub position_embeddings: WeightMatrix,
 }
 
 impl PromptEmbeddingNetwork {
     pub fn new(vocab_size: usize, embedding_dim: usize, max_sequence_length: usize) -> Self {
+        Self::with_precision(vocab_size, embedding_dim, max_sequence_length, WeightPrecision::F32)
+    }
+
+    pub fn with_precision(
+        vocab_size: usize,
+        embedding_dim: usize,
+        max_sequence_length: usize,
+        precision: WeightPrecision,
+    ) -> Self {
         // Initialize embeddings with simple values
-        let token_embeddings = (0..vocab_size)
-            .map(|_| (0..embedding_dim).map(|_| 0.1).collect())
-            .collect();
-        let position_embeddings = (0..max_sequence_length)
-            .map(|_| (0..embedding_dim).map(|_| 0.1).collect())
-            .collect();
-        
+        let mut token_embeddings = WeightMatrix::dense(vocab_size, embedding_dim, 0.1);
+        let mut position_embeddings = WeightMatrix::dense(max_sequence_length, embedding_dim, 0.1);
+
+        if precision == WeightPrecision::Int8 {
+            token_embeddings.quantize();
+            position_embeddings.quantize();
+        }
+
         Self {
             embedding_dim,
             vocab_size,
             max_sequence_length,
+            precision,
             token_embeddings,
             position_embeddings,
         }
     }
-    
+
+    /// Quantize both embedding tables to int8 in place.
+    pub fn quantize(&mut self) {
+        self.precision = WeightPrecision::Int8;
+        self.token_embeddings.quantize();
+        self.position_embeddings.quantize();
+    }
+
+    /// Dequantize both embedding tables back to dense f32 in place.
+    pub fn dequantize(&mut self) {
+        self.precision = WeightPrecision::F32;
+        self.token_embeddings.dequantize();
+        self.position_embeddings.dequantize();
+    }
+
     pub fn forward(&self, token_ids: &[usize]) -> Result<Vec<Vec<f32>>> {
         let mut embeddings = Vec::new();
-        
+
         for (position, &token_id) in token_ids.iter().enumerate() {
             if token_id >= self.vocab_size || position >= self.max_sequence_length {
                 return Err(anyhow::anyhow!("Token ID or position out of bounds"));
             }
-            
+
             let mut embedding = vec![0.0f32; self.embedding_dim];
-            
+
             // Add token embedding
-            for (i, &token_emb) in self.token_embeddings[token_id].iter().enumerate() {
-                if i < embedding.len() {
-                    embedding[i] += token_emb;
+            for (i, slot) in embedding.iter_mut().enumerate() {
+                if i < self.token_embeddings.cols(token_id) {
+                    *slot += self.token_embeddings.get(token_id, i);
                 }
             }
-            
+
             // Add position embedding
-            for (i, &pos_emb) in self.position_embeddings[position].iter().enumerate() {
-                if i < embedding.len() {
-                    embedding[i] += pos_emb;
+            for (i, slot) in embedding.iter_mut().enumerate() {
+                if i < self.position_embeddings.cols(position) {
+                    *slot += self.position_embeddings.get(position, i);
                 }
             }
-            
+
             embeddings.push(embedding);
         }
-        
+
         Ok(embeddings)
     }
 }
@@ -392,6 +674,34 @@ mod tests {
         assert_eq!(result[0].len(), 64);
     }
 
+    #[test]
+    fn test_multi_head_attention_distinguishes_positions() {
+        // Rotary embeddings make attention position-sensitive, so identical
+        // tokens at different positions should no longer attend identically.
+        let attention = MultiHeadAttention::new(64, 8).unwrap();
+        let input = vec![vec![0.5f32; 64]; 10];
+        let result = attention.forward(&input, None).unwrap();
+        assert_ne!(result[0], result[9]);
+    }
+
+    #[test]
+    fn test_multi_head_attention_respects_mask() {
+        let attention = MultiHeadAttention::new(64, 8).unwrap();
+        let input: Vec<Vec<f32>> = (0..4)
+            .map(|i| vec![0.1 * (i + 1) as f32; 64])
+            .collect();
+
+        // Mask every position from attending to the last token
+        let mut mask = vec![vec![false; 4]; 4];
+        for row in &mut mask {
+            row[3] = true;
+        }
+
+        let unmasked = attention.forward(&input, None).unwrap();
+        let masked = attention.forward(&input, Some(&mask)).unwrap();
+        assert_ne!(unmasked[0], masked[0]);
+    }
+
     #[test]
     fn test_prompt_embedding() {
         let embedder = PromptEmbeddingNetwork::new(1000, 128, 512);
@@ -408,4 +718,42 @@ mod tests {
         assert_eq!(encoding.len(), 10);
         assert_eq!(encoding[0].len(), 64);
     }
+
+    #[test]
+    fn test_quantized_matrix_round_trip_is_close() {
+        let original = vec![vec![-1.0f32, 0.0, 0.5, 1.0], vec![2.0, -2.0, 0.0, 1.5]];
+        let quantized = QuantizedMatrix::quantize(&original);
+        let dequantized = quantized.dequantize();
+
+        for (row_a, row_b) in original.iter().zip(&dequantized) {
+            for (a, b) in row_a.iter().zip(row_b) {
+                assert!((a - b).abs() < 0.05, "{} vs {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multi_head_attention_quantized_matches_shape() {
+        let mut attention = MultiHeadAttention::with_precision(64, 8, WeightPrecision::Int8).unwrap();
+        assert_eq!(attention.precision, WeightPrecision::Int8);
+
+        let input = vec![vec![0.5f32; 64]; 10];
+        let result = attention.forward(&input, None).unwrap();
+        assert_eq!(result.len(), 10);
+        assert_eq!(result[0].len(), 64);
+
+        attention.dequantize();
+        assert_eq!(attention.precision, WeightPrecision::F32);
+    }
+
+    #[test]
+    fn test_feed_forward_network_quantize_preserves_output_shape() {
+        let mut ffn = FeedForwardNetwork::new(64, 256);
+        ffn.quantize();
+
+        let input = vec![vec![0.5f32; 64]; 3];
+        let result = ffn.forward(&input).unwrap();
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].len(), 64);
+    }
 }
\ No newline at end of file
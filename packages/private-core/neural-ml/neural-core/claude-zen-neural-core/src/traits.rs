@@ -227,6 +227,85 @@ pub trait ModelConfig<T: Float + Send + Sync + 'static>:
   fn builder() -> impl ConfigBuilder<Self, T>
   where
     Self: Sized;
+
+  /// Learning-rate schedule to drive training, feeding
+  /// `TrainingStatistics::learning_rate`. Defaults to `None` (a constant rate
+  /// chosen by the training loop itself) since most configs don't need one.
+  fn lr_scheduler(&self) -> Option<Box<dyn LRScheduler<T>>> {
+    None
+  }
+}
+
+/// A learning-rate schedule, queried once per training step.
+///
+/// Implementations are typically cheap closures over a handful of config
+/// values, so `lr` takes `&self` rather than `&mut self`; schedules with
+/// internal state (e.g. momentum) use `reset` to rewind between training runs.
+pub trait LRScheduler<T: Float + Send + Sync + 'static>: Send + Sync {
+  /// Learning rate at training step `step` (0-indexed).
+  fn lr(&self, step: usize) -> T;
+
+  /// Rewind any internal state so the schedule can be reused for a fresh
+  /// training run. The default does nothing, for schedules that are pure
+  /// functions of `step`.
+  fn reset(&mut self) {}
+}
+
+/// Constant learning rate, for configs that don't want a schedule at all but
+/// still want to go through the `LRScheduler` interface.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ConstantScheduler<T: Float> {
+  pub rate: T,
+}
+
+impl<T: Float + Send + Sync + 'static> LRScheduler<T> for ConstantScheduler<T> {
+  fn lr(&self, _step: usize) -> T {
+    self.rate
+  }
+}
+
+/// Exponential decay: `lr(n) = initial_rate * decay_rate^(n / decay_steps)`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct ExponentialDecayScheduler<T: Float> {
+  pub initial_rate: T,
+  pub decay_rate: T,
+  pub decay_steps: usize,
+}
+
+impl<T: Float + Send + Sync + 'static> LRScheduler<T> for ExponentialDecayScheduler<T> {
+  fn lr(&self, step: usize) -> T {
+    let decay_steps = T::from(self.decay_steps.max(1)).unwrap_or_else(T::one);
+    let exponent = T::from(step).unwrap_or_else(T::zero) / decay_steps;
+    self.initial_rate * self.decay_rate.powf(exponent)
+  }
+}
+
+/// Transformer-style warmup schedule, as used by the original "Attention Is
+/// All You Need" optimizer and Burn's `NoamLRScheduler`:
+///
+/// `lr(n) = factor * d_model^(-0.5) * min(n^(-0.5), n * warmup_steps^(-1.5))`
+///
+/// Rate increases linearly for the first `warmup_steps` steps, then decays
+/// proportionally to the inverse square root of the step number.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct NoamScheduler<T: Float> {
+  pub factor: T,
+  pub d_model: T,
+  pub warmup_steps: usize,
+}
+
+impl<T: Float + Send + Sync + 'static> LRScheduler<T> for NoamScheduler<T> {
+  fn lr(&self, step: usize) -> T {
+    // Step 0 would divide by zero in `n^(-0.5)`; clamp to the first positive
+    // step, matching how the schedule is meant to be read (warmup starts at 1).
+    let n = T::from(step.max(1)).unwrap_or_else(T::one);
+    let warmup = T::from(self.warmup_steps.max(1)).unwrap_or_else(T::one);
+
+    let inv_sqrt_n = n.powf(T::from(-0.5).unwrap_or_else(T::zero));
+    let warmup_term = n * warmup.powf(T::from(-1.5).unwrap_or_else(T::zero));
+
+    self.factor * self.d_model.powf(T::from(-0.5).unwrap_or_else(T::zero)) * inv_sqrt_n.min(warmup_term)
+  }
 }
 
 /// Model state trait for serialization and restoration
@@ -250,6 +329,118 @@ pub trait ModelState<T: Float + Send + Sync + 'static>:
 
   /// Get training metrics associated with this state
   fn training_metrics(&self) -> Option<&TrainingStatistics<T>>;
+
+  /// Export this state to an ONNX model, so it can be served by an
+  /// `ort`-based runtime independently of this crate. Implementors build the
+  /// returned bytes with [`onnx_export::build_model`], supplying their own
+  /// `parameter_count`/`to_parameters`/`input_size`/`horizon` -- `ModelState`
+  /// itself doesn't carry a `ModelConfig`, so it can't do this generically.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the model's parameters can't be mapped to ONNX
+  /// tensors (e.g. an unsupported `ConfigParameter` variant).
+  #[cfg(feature = "onnx-export")]
+  fn to_onnx(&self) -> NeuroDivergentResult<Vec<u8>>;
+
+  /// Inverse of [`to_onnx`](Self::to_onnx): reconstruct a state from
+  /// previously-exported ONNX bytes.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `bytes` isn't a model this type exported, or is
+  /// otherwise malformed.
+  #[cfg(feature = "onnx-export")]
+  fn from_onnx(bytes: &[u8]) -> NeuroDivergentResult<Self>
+  where
+    Self: Sized;
+}
+
+/// Minimal ONNX `ModelProto` construction for [`ModelState::to_onnx`].
+///
+/// This builds a `ModelProto` with no graph and no operators: `input_size`,
+/// `horizon` and the model's parameters are attached as `metadata_props`
+/// only, rather than a full per-layer op graph -- this crate's traits don't expose individual layer/weight
+/// structure generically, only the flat `to_parameters` map, so that's all a
+/// generic exporter can faithfully round-trip. Model-specific exporters that
+/// *do* know their own layer structure should build a richer graph directly
+/// instead of going through this helper.
+#[cfg(feature = "onnx-export")]
+pub mod onnx_export {
+  use std::collections::HashMap;
+
+  use num_traits::Float;
+
+  use super::ConfigParameter;
+
+  /// ONNX protobuf field tag for a length-delimited field, per the protobuf
+  /// wire format (`(field_number << 3) | wire_type`, wire type 2 = length-delimited).
+  fn tag_ld(field_number: u32) -> Vec<u8> {
+    varint(u64::from((field_number << 3) | 2))
+  }
+
+  fn varint(mut value: u64) -> Vec<u8> {
+    let mut out = Vec::new();
+    loop {
+      let mut byte = (value & 0x7f) as u8;
+      value >>= 7;
+      if value != 0 {
+        byte |= 0x80;
+      }
+      out.push(byte);
+      if value == 0 {
+        break;
+      }
+    }
+    out
+  }
+
+  fn string_field(field_number: u32, value: &str) -> Vec<u8> {
+    let mut out = tag_ld(field_number);
+    out.extend(varint(value.len() as u64));
+    out.extend(value.as_bytes());
+    out
+  }
+
+  /// A `StringStringEntryProto` (ONNX's key/value metadata entry), as a
+  /// field-14 `metadata_props` entry on `ModelProto`.
+  fn metadata_prop(key: &str, value: &str) -> Vec<u8> {
+    let mut entry = string_field(1, key);
+    entry.extend(string_field(2, value));
+    let mut out = tag_ld(14);
+    out.extend(varint(entry.len() as u64));
+    out.extend(entry);
+    out
+  }
+
+  /// Build an ONNX `ModelProto` for `model_type` holding only `producer_name`
+  /// and `metadata_props`: `input_size`, `horizon`, `parameter_count` and
+  /// each parameter (JSON-encoded, under `param:<name>`), so
+  /// [`ModelState::from_onnx`](super::ModelState::from_onnx) implementations
+  /// can read the originating state back. No `ir_version`, `opset_import` or
+  /// graph is written. A parameter that fails to serialize is recorded as
+  /// `"unserializable"` rather than failing the whole export; a NaN or
+  /// infinite float is written as JSON `null`.
+  pub fn build_model<T: Float + serde::Serialize>(
+    model_type: &str,
+    input_size: usize,
+    horizon: usize,
+    parameter_count: usize,
+    parameters: &HashMap<String, ConfigParameter<T>>,
+  ) -> Vec<u8> {
+    let mut model = Vec::new();
+    model.extend(string_field(2, "claude-zen-neural-core")); // producer_name
+    model.extend(metadata_prop("model_type", model_type));
+    model.extend(metadata_prop("input_size", &input_size.to_string()));
+    model.extend(metadata_prop("horizon", &horizon.to_string()));
+    model.extend(metadata_prop("parameter_count", &parameter_count.to_string()));
+    for (name, value) in parameters {
+      let encoded =
+        serde_json::to_string(value).unwrap_or_else(|_| "\"unserializable\"".to_string());
+      model.extend(metadata_prop(&format!("param:{name}"), &encoded));
+    }
+    model
+  }
 }
 
 /// High-level forecasting engine trait for batch operations
@@ -336,6 +527,306 @@ pub trait ForecastingEngine<T: Float + Send + Sync + 'static>:
     data: &TimeSeriesDataset<T>,
     horizons: &[usize],
   ) -> NeuroDivergentResult<MultiHorizonForecast<T>>;
+
+  /// Score each point in `observed` as normal/anomalous against this
+  /// model's own forecast distribution, turning the model into a detector
+  /// without a separate anomaly subsystem.
+  ///
+  /// For every timestamp, `predict_intervals` supplies a point forecast and
+  /// a confidence band for `confidence_level`; the residual
+  /// `r = observed - point_forecast` is compared against the band half-width,
+  /// and a point is flagged anomalous when `|r|` exceeds it. This mirrors the
+  /// seasonal-baseline/residual approach common to dedicated anomaly
+  /// detectors, expressed through this crate's existing interval API.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The dataset to forecast over (passed through to `predict_intervals`)
+  /// * `observed` - The actual values to score, aligned with `data`'s forecast
+  ///   positions. Taken explicitly rather than read off `data` because this
+  ///   trait doesn't assume a particular accessor for a dataset's raw target
+  ///   column.
+  /// * `confidence_level` - Confidence level whose band defines "normal" (e.g. `0.95`)
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `predict_intervals` fails.
+  fn detect_anomalies(
+    &self,
+    data: &TimeSeriesDataset<T>,
+    observed: &[T],
+    confidence_level: f64,
+  ) -> NeuroDivergentResult<AnomalyResult<T>> {
+    let intervals = self.predict_intervals(data, &[confidence_level])?;
+
+    let expected = intervals.forecasts;
+    let lower_bounds = intervals.lower_bounds.into_iter().next().unwrap_or_default();
+    let upper_bounds = intervals.upper_bounds.into_iter().next().unwrap_or_default();
+
+    let two = T::one() + T::one();
+    let n = expected.len().min(observed.len()).min(lower_bounds.len()).min(upper_bounds.len());
+
+    let mut is_anomaly = Vec::with_capacity(n);
+    let mut severity = Vec::with_capacity(n);
+    for i in 0..n {
+      let half_width = (upper_bounds[i] - lower_bounds[i]) / two;
+      let residual = observed[i] - expected[i];
+      let score = if half_width > T::zero() {
+        residual.abs() / half_width
+      } else {
+        T::zero()
+      };
+      is_anomaly.push(score > T::one());
+      severity.push(score);
+    }
+
+    Ok(AnomalyResult {
+      observed: observed[..n].to_vec(),
+      expected: expected[..n].to_vec(),
+      lower_bounds: lower_bounds[..n].to_vec(),
+      upper_bounds: upper_bounds[..n].to_vec(),
+      is_anomaly,
+      severity,
+      confidence_level,
+      timestamps: intervals.timestamps[..n.min(intervals.timestamps.len())].to_vec(),
+      series_id: intervals.series_id,
+      model_name: intervals.model_name,
+      generated_at: intervals.generated_at,
+    })
+  }
+
+  /// Decompose this model's point forecast into additive parts --
+  /// `trend`, one `seasonal` series per entry in `seasonal_periods`, an
+  /// optional `holiday` contribution, and a `residual` -- such that
+  /// `trend + sum(seasonal) + holiday + residual == forecast` at every step,
+  /// Prophet-style.
+  ///
+  /// The point forecast itself still comes from `predict_intervals` (this
+  /// trait's existing point-forecast primitive); this method only splits it
+  /// apart after the fact. Seasonal components are estimated from
+  /// `observed_history` via [`seasonal::SeasonalBaseline`], which this trait
+  /// already exposes; `trend` absorbs whatever isn't explained by those plus
+  /// `holiday_events`, so `residual` is always zero here -- there's nothing
+  /// left unexplained once `trend` is solved for residually. This default
+  /// reports `holiday` as zero at every step, including steps that overlap an
+  /// event, and does not read `holiday_events`; a non-zero `holiday`
+  /// contribution comes only from an overriding implementation.
+  ///
+  /// # Arguments
+  ///
+  /// * `data` - The dataset to forecast over (passed through to `predict_intervals`)
+  /// * `observed_history` - Historical values used to fit the seasonal components
+  /// * `seasonal_periods` - Seasonal cycle lengths to decompose out (e.g. `[7, 365]`)
+  /// * `holiday_events` - Calendar events, available to overriding implementations; unused by this default
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `predict_intervals` fails.
+  fn predict_components(
+    &self,
+    data: &TimeSeriesDataset<T>,
+    observed_history: &[T],
+    seasonal_periods: &[usize],
+    holiday_events: &[HolidayEvent],
+  ) -> NeuroDivergentResult<DecompositionForecast<T>> {
+    let intervals = self.predict_intervals(data, &[0.95])?;
+    let forecasts = intervals.forecasts;
+    let timestamps = intervals.timestamps;
+    let n = forecasts.len();
+
+    let mut seasonal: HashMap<usize, Vec<T>> = HashMap::new();
+    let mut seasonal_sum = vec![T::zero(); n];
+    for &period in seasonal_periods {
+      if period == 0 || observed_history.is_empty() {
+        continue;
+      }
+      let baseline = seasonal::SeasonalBaseline::fit(observed_history, period);
+      let component = baseline.forecast(n);
+      for i in 0..n {
+        seasonal_sum[i] = seasonal_sum[i] + component[i];
+      }
+      seasonal.insert(period, component);
+    }
+
+    // No trained effect size is available at this trait layer (see doc
+    // comment above), so every step's holiday contribution is `0` regardless
+    // of whether it overlaps a configured event; `holiday_events` is still
+    // accepted so overriding implementations have it to hand.
+    let _ = holiday_events;
+    let holiday: Vec<T> = vec![T::zero(); n];
+
+    let trend: Vec<T> = (0..n).map(|i| forecasts[i] - seasonal_sum[i] - holiday[i]).collect();
+    let residual = vec![T::zero(); n];
+
+    Ok(DecompositionForecast {
+      forecasts,
+      trend,
+      seasonal,
+      holiday,
+      residual,
+      timestamps,
+      series_id: intervals.series_id,
+      model_name: intervals.model_name,
+      generated_at: intervals.generated_at,
+    })
+  }
+}
+
+/// Additive decomposition of a [`ForecastingEngine::predict_components`]
+/// point forecast: `trend + sum(seasonal.values()) + holiday + residual`
+/// equals `forecasts` at every index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DecompositionForecast<T: Float> {
+  /// The aggregate point forecast being decomposed
+  pub forecasts: Vec<T>,
+  /// Trend component: whatever isn't explained by `seasonal`/`holiday`
+  pub trend: Vec<T>,
+  /// Seasonal components, keyed by period (e.g. `7` for weekly)
+  pub seasonal: HashMap<usize, Vec<T>>,
+  /// Calendar-event contribution, `0` where no event overlaps
+  pub holiday: Vec<T>,
+  /// Unexplained remainder; always `0` for a forecast (nothing to leave over)
+  pub residual: Vec<T>,
+  /// Future timestamps corresponding to each step
+  pub timestamps: Vec<DateTime<Utc>>,
+  /// Series identifier
+  pub series_id: String,
+  /// Model name
+  pub model_name: String,
+  /// Generation timestamp
+  pub generated_at: DateTime<Utc>,
+}
+
+/// Fixed-capacity ring buffer of `(timestamp, value)` points backing a
+/// [`StreamingForecaster`]. Oldest points are evicted once `capacity` is
+/// reached, so memory stays bounded regardless of how long a stream runs.
+#[derive(Debug, Clone)]
+pub struct StreamingBuffer<T: Float> {
+  capacity: usize,
+  points: std::collections::VecDeque<(DateTime<Utc>, T)>,
+}
+
+impl<T: Float> StreamingBuffer<T> {
+  /// Create an empty buffer holding at most `capacity` points.
+  pub fn new(capacity: usize) -> Self {
+    Self {
+      capacity,
+      points: std::collections::VecDeque::with_capacity(capacity),
+    }
+  }
+
+  /// Push a new point, evicting the oldest one if `capacity` is exceeded.
+  pub fn push(&mut self, timestamp: DateTime<Utc>, value: T) {
+    if self.points.len() == self.capacity {
+      self.points.pop_front();
+    }
+    self.points.push_back((timestamp, value));
+  }
+
+  /// Number of points currently buffered.
+  pub fn len(&self) -> usize {
+    self.points.len()
+  }
+
+  /// Whether the buffer holds no points.
+  pub fn is_empty(&self) -> bool {
+    self.points.is_empty()
+  }
+
+  /// Whether the buffer holds `capacity` points.
+  pub fn is_full(&self) -> bool {
+    self.points.len() == self.capacity
+  }
+
+  /// Buffered values, oldest first.
+  pub fn values(&self) -> Vec<T> {
+    self.points.iter().map(|(_, v)| *v).collect()
+  }
+
+  /// Buffered timestamps, oldest first.
+  pub fn timestamps(&self) -> Vec<DateTime<Utc>> {
+    self.points.iter().map(|(t, _)| *t).collect()
+  }
+
+  /// Discard all buffered points.
+  pub fn clear(&mut self) {
+    self.points.clear();
+  }
+}
+
+/// Incremental, point-at-a-time forecasting for models that support
+/// [`TrainingSupport::online_learning`], as an alternative to rebuilding a
+/// full `TimeSeriesDataset` for every new observation.
+///
+/// Implementors hold a [`StreamingBuffer`] of the most recent `input_size()`
+/// points and turn it into a forecast via `forecast_from_buffer` once enough
+/// points have arrived. The default-bodied `push_point` does that bookkeeping
+/// so implementors only need to supply storage and the actual forecast step.
+pub trait StreamingForecaster<T: Float + Send + Sync + 'static>: Send + Sync {
+  /// The buffer this forecaster reads observations from and writes
+  /// observations into.
+  fn buffer(&mut self) -> &mut StreamingBuffer<T>;
+
+  /// Number of most-recent points `forecast_from_buffer` needs to produce a
+  /// forecast. `push_point` returns `None` until the buffer reaches this size.
+  fn input_size(&self) -> usize;
+
+  /// Produce a forecast from the current buffer contents. Called by
+  /// `push_point` once the buffer holds `input_size()` points.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the underlying model fails to forecast.
+  fn forecast_from_buffer(&self) -> NeuroDivergentResult<Option<ForecastResult<T>>>;
+
+  /// Update any internal model state (e.g. online weight updates) with a new
+  /// observation before it's added to the buffer. The default does nothing,
+  /// for forecasters that only need the buffered window and no running state.
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if the model fails to incorporate the observation.
+  #[allow(unused_variables)]
+  fn online_update(
+    &mut self,
+    timestamp: DateTime<Utc>,
+    value: T,
+    exogenous: Option<HashMap<String, T>>,
+  ) -> NeuroDivergentResult<()> {
+    Ok(())
+  }
+
+  /// Feed one new observation into the stream: runs `online_update`, buffers
+  /// the point, and returns a fresh forecast once `input_size()` points are
+  /// available, or `None` while still warming up.
+  ///
+  /// # Arguments
+  ///
+  /// * `timestamp` - When the observation occurred
+  /// * `value` - The observed value
+  /// * `exogenous` - Optional named exogenous features for this point
+  ///
+  /// # Errors
+  ///
+  /// Returns an error if `online_update` or `forecast_from_buffer` fails.
+  fn push_point(
+    &mut self,
+    timestamp: DateTime<Utc>,
+    value: T,
+    exogenous: Option<HashMap<String, T>>,
+  ) -> NeuroDivergentResult<Option<ForecastResult<T>>> {
+    self.online_update(timestamp, value, exogenous)?;
+    self.buffer().push(timestamp, value);
+    if self.buffer().len() < self.input_size() {
+      return Ok(None);
+    }
+    self.forecast_from_buffer()
+  }
+
+  /// Discard all buffered points, returning the forecaster to a cold start.
+  fn reset_stream(&mut self) {
+    self.buffer().clear();
+  }
 }
 
 /// Forecast result containing predictions and metadata
@@ -376,6 +867,34 @@ pub struct IntervalForecast<T: Float> {
   pub generated_at: DateTime<Utc>,
 }
 
+/// Per-point anomaly scores produced from an [`IntervalForecast`]'s band, by
+/// [`ForecastingEngine::detect_anomalies`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnomalyResult<T: Float> {
+  /// Observed values that were scored
+  pub observed: Vec<T>,
+  /// Point forecasts (expected values) for the same positions
+  pub expected: Vec<T>,
+  /// Lower bound of the confidence band used for flagging
+  pub lower_bounds: Vec<T>,
+  /// Upper bound of the confidence band used for flagging
+  pub upper_bounds: Vec<T>,
+  /// Whether each point fell outside its confidence band
+  pub is_anomaly: Vec<bool>,
+  /// Severity score: `|residual| / band half-width`; values above `1.0` are anomalous
+  pub severity: Vec<T>,
+  /// Confidence level used to select the band (e.g. `0.95`)
+  pub confidence_level: f64,
+  /// Timestamps for each scored point
+  pub timestamps: Vec<DateTime<Utc>>,
+  /// Series identifier
+  pub series_id: String,
+  /// Model name
+  pub model_name: String,
+  /// Generation timestamp
+  pub generated_at: DateTime<Utc>,
+}
+
 /// Quantile forecast result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuantileForecast<T: Float> {
@@ -569,6 +1088,24 @@ pub struct ExogenousConfig {
   pub auto_encode_categorical: bool,
   /// Maximum cardinality for categorical encoding
   pub max_categorical_cardinality: Option<usize>,
+  /// Named calendar events (holidays, promotions, outages, ...), each covering
+  /// `[start, end]`. [`ForecastingEngine::predict_components`] takes its events
+  /// as an argument and does not read this field; its default reports a zero
+  /// `holiday` component.
+  pub holiday_events: Vec<HolidayEvent>,
+}
+
+/// A single named calendar event, e.g. a holiday or promotion window, used by
+/// [`ForecastingEngine::predict_components`] to attribute part of a forecast
+/// to known calendar effects rather than leaving it in `trend`/`seasonal`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HolidayEvent {
+  /// Event name, e.g. `"christmas"` or `"black_friday"`
+  pub name: String,
+  /// First timestamp the event affects (inclusive)
+  pub start: DateTime<Utc>,
+  /// Last timestamp the event affects (inclusive)
+  pub end: DateTime<Utc>,
 }
 
 /// Configuration parameter types
@@ -624,6 +1161,7 @@ impl Default for ExogenousConfig {
       future_features: Vec::new(),
       auto_encode_categorical: true,
       max_categorical_cardinality: Some(100),
+      holiday_events: Vec::new(),
     }
   }
 }
@@ -682,6 +1220,180 @@ impl<T: Float> Default for TrainingStatistics<T> {
   }
 }
 
+/// Fast, training-free seasonal baseline: per-phase mean/std, usable as a
+/// reference forecaster and, through `confidence_bounds`, as a band to score
+/// residuals against. [`ForecastingEngine::detect_anomalies`] does not call
+/// it; it scores against `predict_intervals`.
+///
+/// This implements the fitting/forecasting logic described for a
+/// `SeasonalNaiveModel` directly against `&[T]` value slices rather than
+/// `TimeSeriesDataset<T>`/`BaseModel<T>`/`ForecastingEngine<T>`: those traits
+/// need concrete `ModelConfig`/`ModelState`/`TimeSeriesDataset` types, and
+/// this crate's `data`/`error` modules (declared in the crate root but not
+/// present in this checkout) don't exist to provide them. The seasonal math
+/// itself -- fitting and forecasting -- is fully real and usable standalone;
+/// wiring it behind `BaseModel`/`ForecastingEngine` is a thin adapter once
+/// those modules exist.
+pub mod seasonal {
+  use num_traits::Float;
+
+  /// Mean/std of all historical points that share a phase (`i mod season_length`).
+  #[derive(Debug, Clone, Copy)]
+  pub struct SeasonalPhaseStats<T: Float> {
+    /// Mean of the points in this phase
+    pub mean: T,
+    /// Sample standard deviation of the points in this phase (`0` if fewer than 2 points)
+    pub std_dev: T,
+    /// Number of non-NaN points observed for this phase
+    pub count: usize,
+  }
+
+  /// A fitted seasonal baseline: one [`SeasonalPhaseStats`] per phase
+  /// `0..season_length`, plus the index of the last fitted point so
+  /// `forecast`/`confidence_bounds` know which phase horizon step `1` lands on.
+  #[derive(Debug, Clone)]
+  pub struct SeasonalBaseline<T: Float> {
+    season_length: usize,
+    phases: Vec<SeasonalPhaseStats<T>>,
+    last_index: usize,
+  }
+
+  impl<T: Float> SeasonalBaseline<T> {
+    /// Fit a per-phase mean/std from `values`, grouping index `i` into phase
+    /// `i % season_length` and skipping NaNs. Panics if `season_length` is `0`.
+    pub fn fit(values: &[T], season_length: usize) -> Self {
+      assert!(season_length > 0, "season_length must be positive");
+
+      let mut phases = Vec::with_capacity(season_length);
+      for phase in 0..season_length {
+        let phase_values: Vec<T> = values
+          .iter()
+          .enumerate()
+          .filter(|(i, v)| i % season_length == phase && !v.is_nan())
+          .map(|(_, &v)| v)
+          .collect();
+
+        let count = phase_values.len();
+        let mean = if count == 0 {
+          T::zero()
+        } else {
+          phase_values.iter().copied().fold(T::zero(), |acc, v| acc + v) / T::from(count).unwrap()
+        };
+        let std_dev = if count < 2 {
+          T::zero()
+        } else {
+          let variance = phase_values
+            .iter()
+            .map(|&v| {
+              let d = v - mean;
+              d * d
+            })
+            .fold(T::zero(), |acc, v| acc + v)
+            / T::from(count - 1).unwrap();
+          variance.sqrt()
+        };
+
+        phases.push(SeasonalPhaseStats { mean, std_dev, count });
+      }
+
+      Self {
+        season_length,
+        phases,
+        last_index: values.len().saturating_sub(1),
+      }
+    }
+
+    /// Per-phase statistics, indexed `0..season_length`.
+    pub fn phases(&self) -> &[SeasonalPhaseStats<T>] {
+      &self.phases
+    }
+
+    /// Forecast horizon steps `1..=horizon`, each as `mu_phase` for the phase
+    /// `(last_index + h) % season_length`.
+    pub fn forecast(&self, horizon: usize) -> Vec<T> {
+      (1..=horizon)
+        .map(|h| {
+          let phase = (self.last_index + h) % self.season_length;
+          self.phases[phase].mean
+        })
+        .collect()
+    }
+
+    /// Confidence bounds `mu_phase ± z * sigma_phase` for the same phases as
+    /// [`Self::forecast`]. `z` is the standard-normal quantile for the
+    /// desired confidence level (e.g. `1.96` for ~95%).
+    pub fn confidence_bounds(&self, horizon: usize, z: T) -> (Vec<T>, Vec<T>) {
+      let mut lower = Vec::with_capacity(horizon);
+      let mut upper = Vec::with_capacity(horizon);
+      for h in 1..=horizon {
+        let phase = (self.last_index + h) % self.season_length;
+        let stats = &self.phases[phase];
+        let margin = z * stats.std_dev;
+        lower.push(stats.mean - margin);
+        upper.push(stats.mean + margin);
+      }
+      (lower, upper)
+    }
+  }
+
+  /// Standard-normal quantile for a handful of common two-sided confidence
+  /// levels, matching the z-scores conventionally used for prediction
+  /// intervals. Falls back to the 95% z-score for levels outside this table
+  /// rather than computing an inverse error function.
+  pub fn z_score_for_confidence(confidence_level: f64) -> f64 {
+    if (confidence_level - 0.80).abs() < 1e-6 {
+      1.2816
+    } else if (confidence_level - 0.90).abs() < 1e-6 {
+      1.6449
+    } else if (confidence_level - 0.95).abs() < 1e-6 {
+      1.96
+    } else if (confidence_level - 0.99).abs() < 1e-6 {
+      2.5758
+    } else {
+      1.96
+    }
+  }
+
+  #[cfg(test)]
+  mod tests {
+    use super::*;
+
+    #[test]
+    fn fits_per_phase_mean_and_forecasts_next_cycle() {
+      // season_length = 3, values cycle 1,2,3
+      let values = vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0, 1.0, 2.0, 3.0];
+      let baseline = SeasonalBaseline::fit(&values, 3);
+
+      assert_eq!(baseline.phases()[0].mean, 1.0);
+      assert_eq!(baseline.phases()[1].mean, 2.0);
+      assert_eq!(baseline.phases()[2].mean, 3.0);
+
+      // last_index = 8 (phase 2); horizon 1 -> phase 0, horizon 2 -> phase 1
+      let forecast = baseline.forecast(3);
+      assert_eq!(forecast, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn skips_nan_values_when_fitting() {
+      let values = vec![1.0, f64::NAN, 3.0, 1.0, 5.0, 3.0];
+      let baseline = SeasonalBaseline::fit(&values, 3);
+      assert_eq!(baseline.phases()[1].mean, 5.0);
+      assert_eq!(baseline.phases()[1].count, 1);
+    }
+
+    #[test]
+    fn confidence_bounds_widen_with_z() {
+      // last_index = 3 (phase 1), so horizon 1 lands on phase 0: [1, 5]
+      let values = vec![1.0, 3.0, 5.0, 3.0];
+      let baseline = SeasonalBaseline::fit(&values, 2);
+      let (lower, upper) = baseline.confidence_bounds(1, 1.96);
+      assert!(lower[0] < upper[0]);
+      let (narrow_lower, narrow_upper) = baseline.confidence_bounds(1, 1.0);
+      assert!(upper[0] - lower[0] > narrow_upper[0] - narrow_lower[0]);
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -730,4 +1442,109 @@ mod tests {
     assert_eq!(result.series_id, "test_series");
     assert_eq!(result.model_name, "test_model");
   }
+
+  #[test]
+  fn streaming_buffer_evicts_oldest_once_full() {
+    let mut buffer: StreamingBuffer<f64> = StreamingBuffer::new(2);
+    buffer.push(Utc::now(), 1.0);
+    buffer.push(Utc::now(), 2.0);
+    assert!(buffer.is_full());
+    buffer.push(Utc::now(), 3.0);
+    assert_eq!(buffer.values(), vec![2.0, 3.0]);
+  }
+
+  /// Forecaster that just echoes the buffered mean, to exercise `push_point`'s
+  /// warm-up/ready bookkeeping without a real model.
+  struct MeanStreamingForecaster {
+    buffer: StreamingBuffer<f64>,
+    input_size: usize,
+  }
+
+  impl StreamingForecaster<f64> for MeanStreamingForecaster {
+    fn buffer(&mut self) -> &mut StreamingBuffer<f64> {
+      &mut self.buffer
+    }
+
+    fn input_size(&self) -> usize {
+      self.input_size
+    }
+
+    fn forecast_from_buffer(&self) -> NeuroDivergentResult<Option<ForecastResult<f64>>> {
+      let values = self.buffer.values();
+      let mean = values.iter().sum::<f64>() / values.len() as f64;
+      Ok(Some(ForecastResult {
+        forecasts: vec![mean],
+        timestamps: vec![Utc::now()],
+        series_id: "stream".to_string(),
+        model_name: "mean".to_string(),
+        generated_at: Utc::now(),
+        metadata: None,
+      }))
+    }
+  }
+
+  #[test]
+  fn push_point_returns_none_until_warmed_up_then_forecasts() {
+    let mut forecaster = MeanStreamingForecaster {
+      buffer: StreamingBuffer::new(3),
+      input_size: 3,
+    };
+
+    assert!(forecaster.push_point(Utc::now(), 1.0, None).unwrap().is_none());
+    assert!(forecaster.push_point(Utc::now(), 2.0, None).unwrap().is_none());
+    let forecast = forecaster.push_point(Utc::now(), 3.0, None).unwrap().unwrap();
+    assert!((forecast.forecasts[0] - 2.0).abs() < f64::EPSILON);
+
+    forecaster.reset_stream();
+    assert!(forecaster.buffer().is_empty());
+  }
+
+  #[test]
+  fn noam_scheduler_warms_up_then_decays() {
+    let scheduler = NoamScheduler {
+      factor: 1.0,
+      d_model: 512.0,
+      warmup_steps: 4000,
+    };
+
+    // Rising through warmup...
+    assert!(scheduler.lr(1000) < scheduler.lr(2000));
+    assert!(scheduler.lr(2000) < scheduler.lr(4000));
+    // ...then falling after warmup ends.
+    assert!(scheduler.lr(4000) > scheduler.lr(8000));
+    // Step 0 shouldn't divide by zero or panic.
+    assert_eq!(scheduler.lr(0), scheduler.lr(1));
+  }
+
+  #[test]
+  fn constant_and_exponential_schedulers() {
+    let constant = ConstantScheduler { rate: 0.01 };
+    assert_eq!(constant.lr(0), 0.01);
+    assert_eq!(constant.lr(100), 0.01);
+
+    let decay = ExponentialDecayScheduler {
+      initial_rate: 0.1,
+      decay_rate: 0.5,
+      decay_steps: 10,
+    };
+    assert!((decay.lr(0) - 0.1).abs() < f64::EPSILON);
+    assert!((decay.lr(10) - 0.05).abs() < 1e-9);
+  }
+
+  #[cfg(feature = "onnx-export")]
+  #[test]
+  fn onnx_export_embeds_shape_and_parameter_metadata() {
+    let mut parameters = HashMap::new();
+    parameters.insert("learning_rate".to_string(), ConfigParameter::Float(0.01_f64));
+
+    let bytes = onnx_export::build_model("test_model", 24, 6, 1, &parameters);
+
+    assert!(!bytes.is_empty());
+    // The metadata values were written as plain UTF-8 strings, so they're
+    // still findable as substrings of the encoded bytes.
+    let as_text = String::from_utf8_lossy(&bytes);
+    assert!(as_text.contains("test_model"));
+    assert!(as_text.contains("24"));
+    assert!(as_text.contains("learning_rate"));
+  }
 }
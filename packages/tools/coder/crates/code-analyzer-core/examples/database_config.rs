@@ -34,6 +34,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             page_size: 4096,
             auto_vacuum: "INCREMENTAL".to_string(),
             database_file: "code_analysis.db".to_string(),
+            enable_session_extension: true,
         }),
         lancedb_config: None,
         kuzu_config: None,
@@ -64,6 +65,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
             metric: "COSINE".to_string(),
             num_partitions: 4,
             max_connections: 16,
+            ef_construction: 200,
+            ef_search: 64,
             enable_compression: true,
             compression_type: "ZSTD".to_string(),
             database_directory: "vector_store".to_string(),
@@ -0,0 +1,168 @@
+//! Admin HTTP API over `DataManager`.
+//!
+//! `DataManager` was config-only: the only way to inspect or drive it was
+//! to hold a `&mut DataManager` and call its methods directly from the same
+//! process. This mirrors its subsystems over HTTP (axum) so an operator --
+//! or a separate tool -- can introspect cache/backup/replication state and
+//! trigger the handful of operations that make sense to run on demand
+//! (evicting a cache entry, forcing a backup, failing over a node) without
+//! embedding the crate.
+
+use std::sync::Arc;
+
+use axum::body::Body;
+use axum::extract::{Path, State};
+use axum::http::{Request, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Json, Response};
+use axum::routing::{delete, get, post};
+use axum::Router;
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::data_persistence::{
+    CacheStatistics, DataManager, DataPersistenceError, DataType, FailoverOutcome, ManagerStatus,
+    RecoveryPoint, ReplicationNode,
+};
+
+/// Shared, lock-protected handle to the `DataManager` an admin API serves.
+/// Its methods all take `&mut self`, so each request holds the lock for the
+/// duration of its call -- fine for an operability surface that isn't
+/// expected to see the request volume of the data path itself.
+pub type SharedDataManager = Arc<Mutex<DataManager>>;
+
+/// Shared secret this admin API requires as a `Bearer` token on every
+/// request. These routes can evict cache entries, trigger backups and force
+/// a replication failover, so they must never be reachable without it --
+/// there's no "read-only, so it's fine" exception here.
+#[derive(Clone)]
+struct AdminToken(Arc<str>);
+
+/// Builds the admin router, gated behind `admin_token` as a `Bearer` token
+/// (same shared-secret model as this series' agent registration auth).
+/// Mount it under whatever prefix the embedding service prefers (e.g.
+/// `Router::new().nest("/admin/data", admin_router(manager, admin_token))`).
+pub fn admin_router(manager: SharedDataManager, admin_token: impl Into<String>) -> Router {
+    let token = AdminToken(Arc::from(admin_token.into()));
+    Router::new()
+        .route("/manager", get(get_manager_status))
+        .route("/cache/stats", get(get_cache_stats))
+        .route("/cache/{id}", delete(evict_cache_entry))
+        .route("/backup", post(trigger_backup))
+        .route("/recovery-points", get(list_recovery_points))
+        .route("/replication/nodes", get(list_replication_nodes))
+        .route("/replication/failover/{node_id}", post(trigger_failover))
+        .with_state(manager)
+        .layer(middleware::from_fn_with_state(token, require_admin_token))
+}
+
+/// Rejects any request whose `Authorization: Bearer <token>` header doesn't
+/// match `admin_token`'s configured secret, comparing in constant time so a
+/// request can't time its way to the token.
+async fn require_admin_token(
+    State(token): State<AdminToken>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    let provided = request
+        .headers()
+        .get(axum::http::header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+
+    match provided {
+        Some(provided) if constant_time_eq(provided.as_bytes(), token.0.as_bytes()) => {
+            Ok(next.run(request).await)
+        }
+        _ => Err(StatusCode::UNAUTHORIZED),
+    }
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Wraps `DataPersistenceError` so it can be returned directly from a
+/// handler via `?`, rendering as a JSON body carrying the error's message
+/// with a status code picked by its variant.
+struct ApiError(DataPersistenceError);
+
+impl From<DataPersistenceError> for ApiError {
+    fn from(error: DataPersistenceError) -> Self {
+        Self(error)
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = match &self.0 {
+            DataPersistenceError::RecoveryError { .. } | DataPersistenceError::ReplicationError { .. } => {
+                StatusCode::NOT_FOUND
+            }
+            DataPersistenceError::DataIntegrityError { .. } => StatusCode::CONFLICT,
+            DataPersistenceError::ConfigurationError { .. } => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        };
+
+        (status, Json(serde_json::json!({ "error": self.0.to_string() }))).into_response()
+    }
+}
+
+async fn get_manager_status(State(manager): State<SharedDataManager>) -> Json<ManagerStatus> {
+    let manager = manager.lock().await;
+    Json(manager.manager_status())
+}
+
+async fn get_cache_stats(State(manager): State<SharedDataManager>) -> Json<CacheStatistics> {
+    let manager = manager.lock().await;
+    Json(manager.cache_statistics().clone())
+}
+
+async fn evict_cache_entry(
+    State(manager): State<SharedDataManager>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    let mut manager = manager.lock().await;
+    let evicted = manager.evict_cached(&id).await?;
+    Ok(if evicted { StatusCode::NO_CONTENT } else { StatusCode::NOT_FOUND })
+}
+
+#[derive(Debug, Deserialize)]
+struct TriggerBackupRequest {
+    storage_id: String,
+    data_type: DataType,
+    data_content: Vec<u8>,
+}
+
+async fn trigger_backup(
+    State(manager): State<SharedDataManager>,
+    Json(request): Json<TriggerBackupRequest>,
+) -> Result<StatusCode, ApiError> {
+    let mut manager = manager.lock().await;
+    manager
+        .trigger_backup(&request.storage_id, request.data_type, &request.data_content)
+        .await?;
+    Ok(StatusCode::CREATED)
+}
+
+async fn list_recovery_points(State(manager): State<SharedDataManager>) -> Json<Vec<RecoveryPoint>> {
+    let manager = manager.lock().await;
+    Json(manager.recovery_points().to_vec())
+}
+
+async fn list_replication_nodes(State(manager): State<SharedDataManager>) -> Json<Vec<ReplicationNode>> {
+    let manager = manager.lock().await;
+    Json(manager.replication_nodes().to_vec())
+}
+
+async fn trigger_failover(
+    State(manager): State<SharedDataManager>,
+    Path(node_id): Path<String>,
+) -> Result<Json<FailoverOutcome>, ApiError> {
+    let mut manager = manager.lock().await;
+    let outcome = manager.trigger_failover(&node_id).await?;
+    Ok(Json(outcome))
+}
@@ -1,4 +1,13 @@
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::time::Duration;
 
+use anyhow::{anyhow, Result};
+use quality_gates::{CancellationToken, ProgressOutcome, ProgressReporter};
+use serde::{Deserialize, Serialize};
+use tracing::info;
 
 /// Workflow management for coordinating multiple projects
 pub struct WorkflowManager {
@@ -264,3 +273,791 @@ pub struct WorkflowStatus {
     pub parallel_limit: usize,
     pub available_slots: usize,
 }
+
+/// The five SPARC methodology phases, in the order a project moves through
+/// them. `advance_coordinated_phase` only ever moves a project to
+/// `next()`; there's no support for skipping or revisiting a phase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SparcPhase {
+    Specification,
+    Pseudocode,
+    Architecture,
+    Refinement,
+    Completion,
+}
+
+impl SparcPhase {
+    /// The phase that follows this one, or `None` once `Completion` is reached.
+    pub fn next(self) -> Option<SparcPhase> {
+        match self {
+            SparcPhase::Specification => Some(SparcPhase::Pseudocode),
+            SparcPhase::Pseudocode => Some(SparcPhase::Architecture),
+            SparcPhase::Architecture => Some(SparcPhase::Refinement),
+            SparcPhase::Refinement => Some(SparcPhase::Completion),
+            SparcPhase::Completion => None,
+        }
+    }
+}
+
+/// A single durable fact about a coordinated project's lifecycle. Events
+/// are the only thing that gets persisted -- `CoordinatedProject` is always
+/// derived by replaying a project's event history from the beginning, so
+/// the struct itself never needs to be serialized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CoordinationEvent {
+    ProjectCreated {
+        name: String,
+        description: String,
+        team_id: String,
+    },
+    PhaseEntered {
+        phase: SparcPhase,
+    },
+    GateStarted {
+        phase: SparcPhase,
+        attempt: u32,
+    },
+    GatePassed {
+        phase: SparcPhase,
+        attempt: u32,
+    },
+    GateFailed {
+        phase: SparcPhase,
+        attempt: u32,
+        reason: String,
+    },
+    GateCancelled {
+        phase: SparcPhase,
+        attempt: u32,
+    },
+    HandoffRecorded {
+        from_phase: SparcPhase,
+        to_phase: SparcPhase,
+    },
+}
+
+/// Where a project's event history is kept. `CoordinationEngine` never
+/// inspects project state directly -- it appends events here and
+/// reconstructs state by calling `load` and replaying -- so swapping in a
+/// file- or database-backed store doesn't change any call site.
+pub trait EventStore: Send + Sync {
+    fn append(&mut self, project_id: &str, event: CoordinationEvent) -> Result<()>;
+    fn load(&self, project_id: &str) -> Result<Vec<CoordinationEvent>>;
+}
+
+/// The default pluggable store: keeps every project's event history in
+/// memory. Fine for a single process; a real deployment would swap this
+/// for something that survives a restart on its own (the trait is the
+/// seam for that, not this implementation).
+#[derive(Debug, Default)]
+pub struct InMemoryEventStore {
+    history: HashMap<String, Vec<CoordinationEvent>>,
+}
+
+impl InMemoryEventStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl EventStore for InMemoryEventStore {
+    fn append(&mut self, project_id: &str, event: CoordinationEvent) -> Result<()> {
+        self.history.entry(project_id.to_string()).or_insert_with(Vec::new).push(event);
+        Ok(())
+    }
+
+    fn load(&self, project_id: &str) -> Result<Vec<CoordinationEvent>> {
+        Ok(self.history.get(project_id).cloned().unwrap_or_default())
+    }
+}
+
+/// How many times, and with what backoff, a failed quality gate is retried
+/// before `advance_coordinated_phase` gives up on a phase transition.
+/// Backoff grows as `initial_backoff * backoff_multiplier^attempt`, capped
+/// at `max_interval`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub backoff_multiplier: f64,
+    pub max_interval: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(200),
+            backoff_multiplier: 2.0,
+            max_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The backoff to wait before retry attempt `attempt` (0-indexed: the
+    /// delay before the *second* try is `backoff_for(0)`).
+    pub fn backoff_for(&self, attempt: u32) -> Duration {
+        let scaled = self.initial_backoff.as_secs_f64() * self.backoff_multiplier.powi(attempt as i32);
+        Duration::from_secs_f64(scaled).min(self.max_interval)
+    }
+}
+
+/// A coordinated project's state, always derived by replaying its event
+/// history -- never mutated directly and never the thing that gets
+/// persisted.
+#[derive(Debug, Clone)]
+pub struct CoordinatedProject {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub team_id: String,
+    pub current_phase: SparcPhase,
+}
+
+impl CoordinatedProject {
+    /// Rebuild state by folding a project's event history from the start.
+    /// Returns `None` if the history doesn't begin with `ProjectCreated`
+    /// (i.e. there's no such project).
+    fn replay(project_id: &str, events: &[CoordinationEvent]) -> Option<Self> {
+        let mut events = events.iter();
+        let (name, description, team_id) = match events.next()? {
+            CoordinationEvent::ProjectCreated { name, description, team_id } => {
+                (name.clone(), description.clone(), team_id.clone())
+            }
+            _ => return None,
+        };
+
+        let mut project = CoordinatedProject {
+            id: project_id.to_string(),
+            name,
+            description,
+            team_id,
+            current_phase: SparcPhase::Specification,
+        };
+
+        for event in events {
+            if let CoordinationEvent::PhaseEntered { phase } = event {
+                project.current_phase = *phase;
+            }
+        }
+
+        Some(project)
+    }
+}
+
+/// The outcome of one `advance_coordinated_phase` call: which phase the
+/// project moved from/to, and how many gate attempts it took to get there.
+#[derive(Debug, Clone)]
+pub struct CoordinatedPhaseTransition {
+    pub from_phase: SparcPhase,
+    pub to_phase: SparcPhase,
+    pub gate_attempts: u32,
+    pub handoff_required: bool,
+}
+
+/// A durable, replayable SPARC coordination engine. Every state change is
+/// first appended to the event store; `CoordinatedProject` state is always
+/// derived by replay rather than mutated in place, so a crash between two
+/// `advance_coordinated_phase` calls loses nothing -- `resume_coordinated_project`
+/// picks up exactly where the event history left off.
+pub struct CoordinationEngine {
+    store: Box<dyn EventStore>,
+    next_id: u64,
+    reporters: Vec<Arc<dyn CoordinationReporter>>,
+}
+
+impl CoordinationEngine {
+    pub fn new() -> Self {
+        Self::with_store(Box::new(InMemoryEventStore::new()))
+    }
+
+    pub fn with_store(store: Box<dyn EventStore>) -> Self {
+        Self { store, next_id: 0, reporters: vec![Arc::new(NoopReporter)] }
+    }
+
+    /// Build an engine reporting every coordination event to `reporters` in
+    /// addition to durably appending it -- in place of the no-op default a
+    /// bare `with_store` engine uses.
+    pub fn with_reporters(store: Box<dyn EventStore>, reporters: Vec<Arc<dyn CoordinationReporter>>) -> Self {
+        Self { store, next_id: 0, reporters }
+    }
+
+    /// Register an additional reporter, e.g. so a dashboard can subscribe
+    /// to an already-running engine without restarting it.
+    pub fn add_reporter(&mut self, reporter: Arc<dyn CoordinationReporter>) {
+        self.reporters.push(reporter);
+    }
+
+    /// Append an event to the durable store and fan it out to every
+    /// registered reporter. Reporting happens on detached tasks so a slow
+    /// or unavailable downstream subscriber (a Kafka broker, say) can never
+    /// block or fail a durable write.
+    fn append(&mut self, project_id: &str, event: CoordinationEvent) -> Result<()> {
+        self.store.append(project_id, event.clone())?;
+        for reporter in &self.reporters {
+            let reporter = reporter.clone();
+            let event = event.clone();
+            tokio::spawn(async move {
+                reporter.report(&event).await;
+            });
+        }
+        Ok(())
+    }
+
+    /// Start a new coordinated project and durably record its creation.
+    pub fn start_coordinated_project(
+        &mut self,
+        name: &str,
+        description: &str,
+        team_id: &str,
+    ) -> Result<CoordinatedProject> {
+        self.next_id += 1;
+        let project_id = format!("proj-{}", self.next_id);
+
+        self.append(
+            &project_id,
+            CoordinationEvent::ProjectCreated {
+                name: name.to_string(),
+                description: description.to_string(),
+                team_id: team_id.to_string(),
+            },
+        )?;
+        self.append(&project_id, CoordinationEvent::PhaseEntered { phase: SparcPhase::Specification })?;
+
+        CoordinatedProject::replay(&project_id, &self.store.load(&project_id)?)
+            .ok_or_else(|| anyhow!("Failed to reconstruct project {} immediately after creation", project_id))
+    }
+
+    /// Reconstruct a project's current state purely from its durable event
+    /// history -- the counterpart to `advance_coordinated_phase` that lets a
+    /// caller pick a project back up after a crash instead of restarting it.
+    pub fn resume_coordinated_project(&self, project_id: &str) -> Result<CoordinatedProject> {
+        let events = self.store.load(project_id)?;
+        CoordinatedProject::replay(project_id, &events)
+            .ok_or_else(|| anyhow!("No durable history for project {}", project_id))
+    }
+
+    /// Advance a project to the next SPARC phase, running `run_gate` (the
+    /// phase's quality-gate check) with retries per `retry_policy`. Every
+    /// attempt, success, and failure is appended to the event store (and
+    /// fanned out to every reporter) before this returns, so a crash
+    /// mid-retry loses at most the in-flight sleep, never the record of
+    /// what was already tried.
+    pub async fn advance_coordinated_phase<F, Fut>(
+        &mut self,
+        project_id: &str,
+        retry_policy: RetryPolicy,
+        run_gate: F,
+    ) -> Result<CoordinatedPhaseTransition>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<()>>,
+    {
+        self.advance_coordinated_phase_with_progress(project_id, retry_policy, run_gate, None, None).await
+    }
+
+    /// Same as `advance_coordinated_phase`, but emits a `ProgressEvent` to
+    /// `progress` for each gate attempt and checks `cancellation` (and
+    /// whether `progress`'s receiver is still connected) before every
+    /// attempt. A cancellation is durably recorded as `GateCancelled`
+    /// before this returns, so the transition history shows it was
+    /// deliberately aborted rather than having simply never run.
+    pub async fn advance_coordinated_phase_with_progress<F, Fut>(
+        &mut self,
+        project_id: &str,
+        retry_policy: RetryPolicy,
+        run_gate: F,
+        progress: Option<&ProgressReporter>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<CoordinatedPhaseTransition>
+    where
+        F: Fn() -> Fut,
+        Fut: std::future::Future<Output = Result<()>>,
+    {
+        let project = self.resume_coordinated_project(project_id)?;
+        let from_phase = project.current_phase;
+        let to_phase = from_phase
+            .next()
+            .ok_or_else(|| anyhow!("Project {} has already reached Completion", project_id))?;
+
+        if let Some(reporter) = progress {
+            reporter.begin(format!("{:?} -> {:?}", from_phase, to_phase), Some(retry_policy.max_attempts as u64));
+        }
+
+        let mut last_error = None;
+        let mut attempts_used = 0;
+
+        for attempt in 0..retry_policy.max_attempts {
+            let cancelled = cancellation.map(|token| token.is_cancelled()).unwrap_or(false)
+                || progress.map(|reporter| !reporter.is_connected()).unwrap_or(false);
+            if cancelled {
+                self.append(project_id, CoordinationEvent::GateCancelled { phase: to_phase, attempt: attempt + 1 })?;
+                if let Some(reporter) = progress {
+                    reporter.end(ProgressOutcome::Cancelled);
+                }
+                return Err(anyhow!("Phase transition to {:?} for project {} was cancelled", to_phase, project_id));
+            }
+
+            attempts_used = attempt + 1;
+            self.append(project_id, CoordinationEvent::GateStarted { phase: to_phase, attempt: attempts_used })?;
+            if let Some(reporter) = progress {
+                reporter.report(attempts_used as u64, Some(retry_policy.max_attempts as u64), format!("Gate attempt {attempts_used}"));
+            }
+
+            match run_gate().await {
+                Ok(()) => {
+                    self.append(project_id, CoordinationEvent::GatePassed { phase: to_phase, attempt: attempts_used })?;
+                    last_error = None;
+                    break;
+                }
+                Err(err) => {
+                    self.append(
+                        project_id,
+                        CoordinationEvent::GateFailed { phase: to_phase, attempt: attempts_used, reason: err.to_string() },
+                    )?;
+                    last_error = Some(err);
+
+                    if attempt + 1 < retry_policy.max_attempts {
+                        tokio::time::sleep(retry_policy.backoff_for(attempt)).await;
+                    }
+                }
+            }
+        }
+
+        if let Some(err) = last_error {
+            if let Some(reporter) = progress {
+                reporter.end(ProgressOutcome::Failed);
+            }
+            return Err(anyhow!(
+                "Quality gate for {:?} failed after {} attempt(s): {}",
+                to_phase, attempts_used, err
+            ));
+        }
+
+        self.append(project_id, CoordinationEvent::PhaseEntered { phase: to_phase })?;
+        self.append(project_id, CoordinationEvent::HandoffRecorded { from_phase, to_phase })?;
+
+        if let Some(reporter) = progress {
+            reporter.end(ProgressOutcome::Completed);
+        }
+
+        Ok(CoordinatedPhaseTransition {
+            from_phase,
+            to_phase,
+            gate_attempts: attempts_used,
+            handoff_required: true,
+        })
+    }
+}
+
+impl Default for CoordinationEngine {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A gate check a phase runs before its transition is allowed to proceed,
+/// shared as an `Arc` so the same check can be handed to `run_phase_machine`
+/// without the caller needing to re-box it per phase.
+pub type GateCheck = std::sync::Arc<
+    dyn Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<()>> + Send>> + Send + Sync,
+>;
+
+/// Type-state tags for the SPARC phases, used only as markers for
+/// `PhaseState`/`TransitionTo` -- they carry no data of their own, unlike
+/// `SparcPhase` (the runtime enum `CoordinationEngine` actually stores).
+pub struct Specification;
+pub struct Pseudocode;
+pub struct Architecture;
+pub struct Refinement;
+pub struct Completion;
+
+/// Implemented only for the four legal SPARC edges. A `PhaseState` impl's
+/// `run` method requires `Self: TransitionTo<Self::Next>`, so declaring an
+/// illegal `Next` (e.g. `Specification`'s `Next` as `Completion`) fails to
+/// compile instead of failing at runtime.
+pub trait TransitionTo<To> {}
+
+impl TransitionTo<Pseudocode> for Specification {}
+impl TransitionTo<Architecture> for Pseudocode {}
+impl TransitionTo<Refinement> for Architecture {}
+impl TransitionTo<Completion> for Refinement {}
+
+/// What running one phase produces: either the next legal phase, or (only
+/// reachable from `Refinement`) the machine's final result.
+pub enum Transition<S> {
+    Next(S),
+    Complete(CompletionResult),
+}
+
+/// The type-state machine's terminal output.
+#[derive(Debug, Clone)]
+pub struct CompletionResult {
+    pub project_id: String,
+}
+
+/// Shared state threaded through a `run_phase_machine` run: which project
+/// and retry policy every phase's gate check runs under, plus the
+/// `CoordinatedPhaseTransition` record accumulated at each edge so the
+/// driver can return the full history once it reaches `Completion`.
+pub struct PhaseRunContext<'a> {
+    engine: &'a mut CoordinationEngine,
+    project_id: &'a str,
+    retry_policy: RetryPolicy,
+    gate: GateCheck,
+    progress: Option<ProgressReporter>,
+    cancellation: Option<CancellationToken>,
+    transitions: Vec<CoordinatedPhaseTransition>,
+}
+
+/// One SPARC phase as its own type. `run` consumes `self`, so a phase
+/// value can't be driven twice, and the `Self: TransitionTo<Self::Next>`
+/// bound means only a legal `Next` can be named -- the compiler, not a
+/// runtime check, rejects an attempt to skip or reorder phases.
+pub trait PhaseState: Sized {
+    type Next;
+
+    fn sparc_phase() -> SparcPhase;
+
+    async fn run(self, ctx: &mut PhaseRunContext<'_>) -> Result<Transition<Self::Next>>
+    where
+        Self: TransitionTo<Self::Next>;
+}
+
+impl PhaseState for Specification {
+    type Next = Pseudocode;
+
+    fn sparc_phase() -> SparcPhase {
+        SparcPhase::Specification
+    }
+
+    async fn run(self, ctx: &mut PhaseRunContext<'_>) -> Result<Transition<Self::Next>>
+    where
+        Self: TransitionTo<Self::Next>,
+    {
+        let transition = ctx.engine.advance_coordinated_phase_with_progress(ctx.project_id, ctx.retry_policy, ctx.gate.clone(), ctx.progress.as_ref(), ctx.cancellation.as_ref()).await?;
+        ctx.transitions.push(transition);
+        Ok(Transition::Next(Pseudocode))
+    }
+}
+
+impl PhaseState for Pseudocode {
+    type Next = Architecture;
+
+    fn sparc_phase() -> SparcPhase {
+        SparcPhase::Pseudocode
+    }
+
+    async fn run(self, ctx: &mut PhaseRunContext<'_>) -> Result<Transition<Self::Next>>
+    where
+        Self: TransitionTo<Self::Next>,
+    {
+        let transition = ctx.engine.advance_coordinated_phase_with_progress(ctx.project_id, ctx.retry_policy, ctx.gate.clone(), ctx.progress.as_ref(), ctx.cancellation.as_ref()).await?;
+        ctx.transitions.push(transition);
+        Ok(Transition::Next(Architecture))
+    }
+}
+
+impl PhaseState for Architecture {
+    type Next = Refinement;
+
+    fn sparc_phase() -> SparcPhase {
+        SparcPhase::Architecture
+    }
+
+    async fn run(self, ctx: &mut PhaseRunContext<'_>) -> Result<Transition<Self::Next>>
+    where
+        Self: TransitionTo<Self::Next>,
+    {
+        let transition = ctx.engine.advance_coordinated_phase_with_progress(ctx.project_id, ctx.retry_policy, ctx.gate.clone(), ctx.progress.as_ref(), ctx.cancellation.as_ref()).await?;
+        ctx.transitions.push(transition);
+        Ok(Transition::Next(Refinement))
+    }
+}
+
+impl PhaseState for Refinement {
+    type Next = Completion;
+
+    fn sparc_phase() -> SparcPhase {
+        SparcPhase::Refinement
+    }
+
+    async fn run(self, ctx: &mut PhaseRunContext<'_>) -> Result<Transition<Self::Next>>
+    where
+        Self: TransitionTo<Self::Next>,
+    {
+        let transition = ctx.engine.advance_coordinated_phase_with_progress(ctx.project_id, ctx.retry_policy, ctx.gate.clone(), ctx.progress.as_ref(), ctx.cancellation.as_ref()).await?;
+        ctx.transitions.push(transition);
+        Ok(Transition::Complete(CompletionResult { project_id: ctx.project_id.to_string() }))
+    }
+}
+
+/// Drive a project through all five SPARC phases in order, starting from
+/// `Specification`, appending a `CoordinatedPhaseTransition` at each edge.
+/// Each `.run()` call can only return the single `TransitionTo`-legal next
+/// phase -- skipping from `Specification` straight to `Completion`, for
+/// instance, isn't an illegal runtime request here, it's code that doesn't
+/// exist to write.
+pub async fn run_phase_machine(
+    engine: &mut CoordinationEngine,
+    project_id: &str,
+    retry_policy: RetryPolicy,
+    gate: GateCheck,
+    progress: Option<ProgressReporter>,
+    cancellation: Option<CancellationToken>,
+) -> Result<(Vec<CoordinatedPhaseTransition>, CompletionResult)> {
+    let mut ctx = PhaseRunContext { engine, project_id, retry_policy, gate, progress, cancellation, transitions: Vec::new() };
+
+    let Transition::Next(pseudocode) = Specification.run(&mut ctx).await? else {
+        unreachable!("Specification only ever transitions to Pseudocode");
+    };
+    let Transition::Next(architecture) = pseudocode.run(&mut ctx).await? else {
+        unreachable!("Pseudocode only ever transitions to Architecture");
+    };
+    let Transition::Next(refinement) = architecture.run(&mut ctx).await? else {
+        unreachable!("Architecture only ever transitions to Refinement");
+    };
+
+    match refinement.run(&mut ctx).await? {
+        Transition::Complete(result) => Ok((ctx.transitions, result)),
+        Transition::Next(_) => unreachable!("Refinement only ever completes the machine"),
+    }
+}
+
+/// Subscribes to every durable `CoordinationEvent` a `CoordinationEngine`
+/// appends, so dashboards and other teams can follow phase transitions,
+/// handoffs, and quality-gate outcomes without polling the engine. `report_batch` has a default that calls
+/// `report` per event; a backend that can send a batch in one round trip
+/// (Kafka, say) should override it.
+pub trait CoordinationReporter: Send + Sync {
+    fn report<'a>(&'a self, event: &'a CoordinationEvent) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+    fn report_batch<'a>(&'a self, events: &'a [CoordinationEvent]) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            for event in events {
+                self.report(event).await;
+            }
+        })
+    }
+}
+
+/// The default reporter: does nothing. `CoordinationEngine::with_store`
+/// registers this so existing behavior is unchanged when no reporter (or
+/// the `kafka-reporter` feature) is configured.
+pub struct NoopReporter;
+
+impl CoordinationReporter for NoopReporter {
+    fn report<'a>(&'a self, _event: &'a CoordinationEvent) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async {})
+    }
+}
+
+/// Wraps another reporter with batching: events are buffered in memory and
+/// handed to the inner reporter's `report_batch` either once `batch_size`
+/// is reached or on every `flush_interval` tick, whichever comes first, so
+/// a high-volume coordination engine doesn't make one round trip per event.
+pub struct BatchingReporter<R> {
+    inner: Arc<R>,
+    buffer: Arc<tokio::sync::Mutex<Vec<CoordinationEvent>>>,
+    batch_size: usize,
+}
+
+impl<R: CoordinationReporter + 'static> BatchingReporter<R> {
+    pub fn new(inner: R, batch_size: usize, flush_interval: Duration) -> Self {
+        let inner = Arc::new(inner);
+        let buffer = Arc::new(tokio::sync::Mutex::new(Vec::new()));
+
+        let background_inner = inner.clone();
+        let background_buffer = buffer.clone();
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(flush_interval);
+            loop {
+                ticker.tick().await;
+                let batch = {
+                    let mut guard = background_buffer.lock().await;
+                    if guard.is_empty() {
+                        continue;
+                    }
+                    std::mem::take(&mut *guard)
+                };
+                background_inner.report_batch(&batch).await;
+            }
+        });
+
+        Self { inner, buffer, batch_size }
+    }
+}
+
+impl<R: CoordinationReporter + 'static> CoordinationReporter for BatchingReporter<R> {
+    fn report<'a>(&'a self, event: &'a CoordinationEvent) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let batch = {
+                let mut guard = self.buffer.lock().await;
+                guard.push(event.clone());
+                if guard.len() < self.batch_size {
+                    return;
+                }
+                std::mem::take(&mut *guard)
+            };
+            self.inner.report_batch(&batch).await;
+        })
+    }
+}
+
+/// Publishes coordination events to a Kafka topic, one JSON message per
+/// event. Behind the `kafka-reporter` feature so pulling in `rdkafka`
+/// (and its native `librdkafka` dependency) is opt-in -- every other
+/// reporter in this module has no such cost.
+#[cfg(feature = "kafka-reporter")]
+pub struct KafkaReporter {
+    producer: rdkafka::producer::FutureProducer,
+    topic: String,
+}
+
+#[cfg(feature = "kafka-reporter")]
+impl KafkaReporter {
+    pub fn new(brokers: &str, topic: &str) -> Result<Self> {
+        let producer = rdkafka::config::ClientConfig::new()
+            .set("bootstrap.servers", brokers)
+            .create()
+            .map_err(|err| anyhow!("Failed to create Kafka producer: {}", err))?;
+        Ok(Self { producer, topic: topic.to_string() })
+    }
+}
+
+#[cfg(feature = "kafka-reporter")]
+impl CoordinationReporter for KafkaReporter {
+    fn report<'a>(&'a self, event: &'a CoordinationEvent) -> Pin<Box<dyn Future<Output = ()> + Send + 'a>> {
+        Box::pin(async move {
+            let payload = match serde_json::to_vec(event) {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    tracing::warn!("Failed to serialize coordination event for Kafka: {}", err);
+                    return;
+                }
+            };
+
+            let record = rdkafka::producer::FutureRecord::to(&self.topic)
+                .payload(&payload)
+                .key(self.topic.as_str());
+
+            if let Err((err, _)) = self.producer.send(record, Duration::from_secs(5)).await {
+                tracing::warn!("Failed to publish coordination event to Kafka topic {}: {}", self.topic, err);
+            }
+        })
+    }
+}
+
+/// Which worker is handling a project's phase work for a sticky window.
+struct StickyAssignment {
+    worker_id: String,
+    assigned_at: std::time::Instant,
+}
+
+/// Routes a project's successive phase-execution work to the same worker
+/// that handled its previous phase, instead of spreading every phase
+/// across a team's shared pool -- the point being to keep cache locality
+/// (a warmed analysis cache for `/tmp/auth-system`-style paths) across
+/// handoffs. A project "sticks" to its worker until `sticky_timeout`
+/// elapses since its last assignment, then falls back to the team's
+/// shared round-robin queue like any other project would.
+pub struct StickyWorkQueue {
+    sticky_timeout: Duration,
+    shared_queues: HashMap<String, VecDeque<String>>,
+    sticky_assignments: HashMap<String, StickyAssignment>,
+}
+
+impl StickyWorkQueue {
+    pub fn new(sticky_timeout: Duration) -> Self {
+        Self {
+            sticky_timeout,
+            shared_queues: HashMap::new(),
+            sticky_assignments: HashMap::new(),
+        }
+    }
+
+    /// Register `worker_id` as available to take work for `team_id`. A
+    /// worker can be registered for more than one team.
+    pub fn register_worker(&mut self, team_id: &str, worker_id: &str) {
+        self.shared_queues.entry(team_id.to_string()).or_insert_with(VecDeque::new).push_back(worker_id.to_string());
+    }
+
+    /// Pick the worker that should run `project_id`'s next phase for
+    /// `team_id`: its previous worker, if one was assigned within the
+    /// sticky timeout, otherwise the next worker from the team's shared
+    /// queue (rotated to the back so assignments round-robin over time).
+    pub fn assign(&mut self, team_id: &str, project_id: &str) -> Result<String> {
+        if let Some(existing) = self.sticky_assignments.get(project_id) {
+            if existing.assigned_at.elapsed() < self.sticky_timeout {
+                return Ok(existing.worker_id.clone());
+            }
+        }
+
+        let queue = self
+            .shared_queues
+            .get_mut(team_id)
+            .ok_or_else(|| anyhow!("No workers registered for team {}", team_id))?;
+        let worker_id = queue.pop_front().ok_or_else(|| anyhow!("No available workers for team {}", team_id))?;
+        queue.push_back(worker_id.clone());
+
+        self.sticky_assignments.insert(
+            project_id.to_string(),
+            StickyAssignment { worker_id: worker_id.clone(), assigned_at: std::time::Instant::now() },
+        );
+        Ok(worker_id)
+    }
+
+    /// Refresh a project's sticky window after its assigned worker picks
+    /// up another phase, so the timeout measures idle time between phases
+    /// rather than time since the very first assignment.
+    pub fn touch(&mut self, project_id: &str) {
+        if let Some(assignment) = self.sticky_assignments.get_mut(project_id) {
+            assignment.assigned_at = std::time::Instant::now();
+        }
+    }
+
+    /// Drop a project's sticky assignment immediately, e.g. once it
+    /// reaches `Completion`, freeing its worker for other projects
+    /// without waiting out the timeout.
+    pub fn release(&mut self, project_id: &str) {
+        self.sticky_assignments.remove(project_id);
+    }
+
+    /// A snapshot of `team_id`'s queue, reflecting which projects are
+    /// currently stuck to which worker and for how much longer -- the
+    /// basis for a `get_team_kanban_status` to show sticky assignments
+    /// instead of just a plain work queue.
+    pub fn team_status(&self, team_id: &str) -> TeamQueueStatus {
+        let shared_workers: Vec<String> = self.shared_queues.get(team_id).cloned().unwrap_or_default().into();
+        let sticky_assignments = self
+            .sticky_assignments
+            .iter()
+            .filter(|(_, assignment)| shared_workers.contains(&assignment.worker_id))
+            .map(|(project_id, assignment)| StickyAssignmentView {
+                project_id: project_id.clone(),
+                worker_id: assignment.worker_id.clone(),
+                remaining: self.sticky_timeout.saturating_sub(assignment.assigned_at.elapsed()),
+            })
+            .collect();
+
+        TeamQueueStatus { team_id: team_id.to_string(), shared_workers, sticky_assignments }
+    }
+}
+
+/// A team's work queue, with its sticky assignments resolved into a
+/// displayable form.
+#[derive(Debug, Clone)]
+pub struct TeamQueueStatus {
+    pub team_id: String,
+    pub shared_workers: Vec<String>,
+    pub sticky_assignments: Vec<StickyAssignmentView>,
+}
+
+/// One project's sticky worker assignment and how much longer it holds.
+#[derive(Debug, Clone)]
+pub struct StickyAssignmentView {
+    pub project_id: String,
+    pub worker_id: String,
+    pub remaining: Duration,
+}
@@ -0,0 +1,212 @@
+//! Declarative `TestSuite` loading from JSON/YAML fixture files.
+//!
+//! The inline `TestSuite`/`TestCase` literals sprinkled through
+//! `testing_framework`'s own tests show how verbose constructing a suite
+//! programmatically is. This lets a user keep a test corpus as data files
+//! on disk and register it directly instead of recompiling, the format
+//! detected from the file extension (`.json` vs `.yaml`/`.yml`).
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use tokio::fs;
+
+use crate::testing_framework::{ComprehensiveTestingFramework, TestSuite};
+
+/// Deserialize a single `TestSuite` fixture, picking JSON or YAML based on
+/// `path`'s extension. Any other/missing extension is rejected rather than
+/// guessed at.
+async fn load_suite_file(path: &Path) -> Result<TestSuite> {
+    let content = fs::read_to_string(path).await
+        .with_context(|| format!("Failed to read suite fixture: {}", path.display()))?;
+
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("json") => serde_json::from_str(&content)
+            .with_context(|| format!("Failed to parse JSON suite fixture: {}", path.display())),
+        Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+            .with_context(|| format!("Failed to parse YAML suite fixture: {}", path.display())),
+        other => Err(anyhow::anyhow!(
+            "Unrecognized suite fixture extension {:?} for {}: expected .json, .yaml, or .yml",
+            other,
+            path.display()
+        )),
+    }
+}
+
+impl ComprehensiveTestingFramework {
+    /// Load a `TestSuite` from a JSON or YAML fixture file and register it,
+    /// the same as if it had been constructed programmatically and passed to
+    /// `register_test_suite`.
+    pub async fn load_suite_from_path(&self, path: &Path) -> Result<()> {
+        let suite = load_suite_file(path).await?;
+        self.register_test_suite(suite).await
+    }
+
+    /// Load and register every suite fixture directly under `dir` whose file
+    /// name matches `glob_pattern` (e.g. `"*.suite.json"`), optionally
+    /// keeping only suites whose `name` contains `name_filter`. Returns the
+    /// name of every suite registered, in directory-listing order.
+    pub async fn load_suites_from_directory(
+        &self,
+        dir: &Path,
+        glob_pattern: &str,
+        name_filter: Option<&str>,
+    ) -> Result<Vec<String>> {
+        let pattern = glob::Pattern::new(glob_pattern)
+            .with_context(|| format!("Invalid glob pattern: {glob_pattern}"))?;
+
+        let mut entries = fs::read_dir(dir).await
+            .with_context(|| format!("Failed to read fixture directory: {}", dir.display()))?;
+
+        let mut registered = Vec::new();
+        while let Some(entry) = entries.next_entry().await
+            .with_context(|| format!("Failed to iterate fixture directory: {}", dir.display()))?
+        {
+            let path = entry.path();
+            let matches_pattern = path.file_name()
+                .and_then(|name| name.to_str())
+                .map(|name| pattern.matches(name))
+                .unwrap_or(false);
+            if !matches_pattern {
+                continue;
+            }
+
+            let suite = load_suite_file(&path).await?;
+            if let Some(name_filter) = name_filter {
+                if !suite.name.contains(name_filter) {
+                    continue;
+                }
+            }
+
+            let suite_name = suite.name.clone();
+            self.register_test_suite(suite).await?;
+            registered.push(suite_name);
+        }
+
+        Ok(registered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    const FIXTURE_JSON: &str = r#"{
+        "name": "fixture_suite",
+        "description": "loaded from a fixture",
+        "test_cases": [
+            {
+                "name": "fixture_case",
+                "description": "a fixture case",
+                "test_type": "Unit",
+                "input_data": {
+                    "source_code": "fn main() {}",
+                    "file_path": null,
+                    "project_configuration": null,
+                    "analysis_parameters": null,
+                    "mock_data": null
+                },
+                "expected_output": {
+                    "success": true,
+                    "error_message": null,
+                    "metrics": null,
+                    "analysis_results": null,
+                    "performance_thresholds": null
+                },
+                "preconditions": [],
+                "postconditions": [],
+                "tags": []
+            }
+        ],
+        "setup_hooks": [],
+        "teardown_hooks": [],
+        "timeout_milliseconds": 30000,
+        "parallel_execution": false
+    }"#;
+
+    const FIXTURE_YAML: &str = r#"
+name: fixture_suite_yaml
+description: loaded from a YAML fixture
+test_cases:
+  - name: fixture_case
+    description: a fixture case
+    test_type: Unit
+    input_data:
+      source_code: "fn main() {}"
+      file_path: null
+      project_configuration: null
+      analysis_parameters: null
+      mock_data: null
+    expected_output:
+      success: true
+      error_message: null
+      metrics: null
+      analysis_results: null
+      performance_thresholds: null
+    preconditions: []
+    postconditions: []
+    tags: []
+setup_hooks: []
+teardown_hooks: []
+timeout_milliseconds: 30000
+parallel_execution: false
+"#;
+
+    #[tokio::test]
+    async fn load_suite_from_path_registers_a_json_fixture() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("suite.json");
+        fs::write(&path, FIXTURE_JSON).await.unwrap();
+
+        let framework = ComprehensiveTestingFramework::new().unwrap();
+        framework.load_suite_from_path(&path).await.unwrap();
+
+        let suites = framework.test_suites_snapshot().unwrap();
+        assert_eq!(suites.len(), 1);
+        assert_eq!(suites[0].0, "fixture_suite");
+    }
+
+    #[tokio::test]
+    async fn load_suite_from_path_registers_a_yaml_fixture() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("suite.yaml");
+        fs::write(&path, FIXTURE_YAML).await.unwrap();
+
+        let framework = ComprehensiveTestingFramework::new().unwrap();
+        framework.load_suite_from_path(&path).await.unwrap();
+
+        let suites = framework.test_suites_snapshot().unwrap();
+        assert_eq!(suites[0].0, "fixture_suite_yaml");
+    }
+
+    #[tokio::test]
+    async fn load_suite_file_rejects_an_unrecognized_extension() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("suite.txt");
+        fs::write(&path, FIXTURE_JSON).await.unwrap();
+
+        assert!(load_suite_file(&path).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn load_suites_from_directory_applies_the_glob_and_name_filter() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.suite.json"), FIXTURE_JSON).await.unwrap();
+        fs::write(
+            dir.path().join("b.suite.json"),
+            FIXTURE_JSON.replace("fixture_suite", "other_suite"),
+        )
+        .await
+        .unwrap();
+        fs::write(dir.path().join("ignored.txt"), "not a fixture").await.unwrap();
+
+        let framework = ComprehensiveTestingFramework::new().unwrap();
+        let registered = framework
+            .load_suites_from_directory(dir.path(), "*.suite.json", Some("fixture"))
+            .await
+            .unwrap();
+
+        assert_eq!(registered, vec!["fixture_suite".to_string()]);
+    }
+}
@@ -377,3 +377,330 @@ pub enum ValidationRule {
     Optional(String),
     Conditional(String, String), // rule, condition
 }
+
+/// BM25 term-frequency saturation constant (standard default).
+const BM25_K1: f32 = 1.2;
+/// BM25 document-length normalization constant (standard default).
+const BM25_B: f32 = 0.75;
+/// Reciprocal Rank Fusion constant; larger values flatten the influence of
+/// rank position, so lower-ranked-but-present results still contribute.
+const RRF_K: f32 = 60.0;
+
+fn tokenize(text: &str) -> Vec<String> {
+    text.split_whitespace().map(|token| token.to_lowercase()).collect()
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.is_empty() || b.is_empty() || a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+/// A document `RagRetriever` can retrieve: raw content for BM25 scoring, a
+/// pre-computed embedding for vector scoring, and the access level a caller
+/// needs to see it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrievalDocument {
+    pub id: String,
+    pub content: String,
+    pub embedding: Vec<f32>,
+    pub access_level: AccessLevel,
+}
+
+/// One fused, access-filtered result from `RagRetriever::retrieve`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RetrievedSnippet {
+    pub id: String,
+    pub content: String,
+    pub fused_score: f32,
+}
+
+/// Executable RAG retrieval engine: indexes `RetrievalDocument`s and answers
+/// `KnowledgeType::RAG` queries by combining a BM25 lexical ranking with a
+/// cosine-similarity vector ranking via Reciprocal Rank Fusion, the way
+/// modern hybrid search engines do. Access control is enforced before
+/// scoring so a caller never sees a document its `AccessLevel` can't reach.
+pub struct RagRetriever {
+    documents: Vec<RetrievalDocument>,
+}
+
+impl RagRetriever {
+    pub fn new() -> Self {
+        Self { documents: Vec::new() }
+    }
+
+    /// Index (or re-index, replacing any prior document with the same id) a
+    /// document for retrieval.
+    pub fn index(&mut self, document: RetrievalDocument) {
+        self.documents.retain(|existing| existing.id != document.id);
+        self.documents.push(document);
+    }
+
+    /// Run a hybrid BM25 + vector RAG query against every indexed document
+    /// `user_level` can reach, fuse the two rankings with Reciprocal Rank
+    /// Fusion, drop anything below `similarity_threshold`, and return the
+    /// top `max_results` snippets ordered by fused score.
+    ///
+    /// `_database` is accepted (but unused today) so a real persisted
+    /// document store can back this retriever later without changing the
+    /// call signature callers depend on.
+    pub fn retrieve(
+        &self,
+        _database: &DatabaseManager,
+        query: &str,
+        query_embedding: &[f32],
+        similarity_threshold: f32,
+        max_results: usize,
+        user_level: &AccessLevel,
+    ) -> Result<Vec<RetrievedSnippet>> {
+        let accessible: Vec<&RetrievalDocument> = self
+            .documents
+            .iter()
+            .filter(|doc| doc.access_level.can_access(user_level))
+            .collect();
+
+        if accessible.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let query_terms = tokenize(query);
+        let bm25_ranked = Self::bm25_rank(&accessible, &query_terms);
+        let vector_ranked = Self::vector_rank(&accessible, query_embedding);
+
+        let cosine_by_id: HashMap<&str, f32> =
+            vector_ranked.iter().map(|(id, score)| (id.as_str(), *score)).collect();
+
+        let mut fused_scores: HashMap<&str, f32> = HashMap::new();
+        for (rank, (id, _)) in bm25_ranked.iter().enumerate() {
+            *fused_scores.entry(id.as_str()).or_insert(0.0) += 1.0 / (RRF_K + rank as f32 + 1.0);
+        }
+        for (rank, (id, _)) in vector_ranked.iter().enumerate() {
+            *fused_scores.entry(id.as_str()).or_insert(0.0) += 1.0 / (RRF_K + rank as f32 + 1.0);
+        }
+
+        let mut results: Vec<RetrievedSnippet> = accessible
+            .into_iter()
+            .filter(|doc| cosine_by_id.get(doc.id.as_str()).copied().unwrap_or(0.0) >= similarity_threshold)
+            .filter_map(|doc| {
+                let fused_score = *fused_scores.get(doc.id.as_str())?;
+                Some(RetrievedSnippet {
+                    id: doc.id.clone(),
+                    content: doc.content.clone(),
+                    fused_score,
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.fused_score.partial_cmp(&a.fused_score).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(max_results);
+
+        Ok(results)
+    }
+
+    /// Rank `docs` by BM25 score over `query_terms` (k1≈1.2, b≈0.75),
+    /// returning `(document_id, score)` pairs sorted best-first.
+    fn bm25_rank(docs: &[&RetrievalDocument], query_terms: &[String]) -> Vec<(String, f32)> {
+        let doc_count = docs.len() as f32;
+        let tokenized_docs: Vec<Vec<String>> = docs.iter().map(|doc| tokenize(&doc.content)).collect();
+        let avg_doc_len = tokenized_docs.iter().map(|tokens| tokens.len() as f32).sum::<f32>()
+            / doc_count.max(1.0);
+
+        let doc_freq: HashMap<&String, usize> = query_terms
+            .iter()
+            .map(|term| {
+                let df = tokenized_docs.iter().filter(|tokens| tokens.contains(term)).count();
+                (term, df)
+            })
+            .collect();
+
+        let mut scored: Vec<(String, f32)> = docs
+            .iter()
+            .zip(&tokenized_docs)
+            .map(|(doc, tokens)| {
+                let doc_len = tokens.len() as f32;
+                let score: f32 = query_terms
+                    .iter()
+                    .map(|term| {
+                        let df = *doc_freq.get(term).unwrap_or(&0) as f32;
+                        if df == 0.0 {
+                            return 0.0;
+                        }
+                        let idf = ((doc_count - df + 0.5) / (df + 0.5) + 1.0).ln();
+                        let term_freq = tokens.iter().filter(|token| *token == term).count() as f32;
+                        let length_norm = 1.0 - BM25_B + BM25_B * doc_len / avg_doc_len.max(1.0);
+                        idf * (term_freq * (BM25_K1 + 1.0)) / (term_freq + BM25_K1 * length_norm)
+                    })
+                    .sum();
+                (doc.id.clone(), score)
+            })
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored
+    }
+
+    /// Rank `docs` by cosine similarity against `query_embedding`, returning
+    /// `(document_id, score)` pairs sorted best-first.
+    fn vector_rank(docs: &[&RetrievalDocument], query_embedding: &[f32]) -> Vec<(String, f32)> {
+        let mut scored: Vec<(String, f32)> = docs
+            .iter()
+            .map(|doc| (doc.id.clone(), cosine_similarity(query_embedding, &doc.embedding)))
+            .collect();
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        scored
+    }
+}
+
+impl Default for RagRetriever {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A stored `FactType::SecurityVulnerability` fact: the fields a CVE/
+/// advisory lookup needs (`vulnerability_id`, `affected_versions`) plus the
+/// verification bookkeeping `CveVerifier::verify` reconciles.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecurityVulnerabilityFact {
+    pub vulnerability_id: String,
+    pub affected_versions: String, // e.g. "<1.2.3" or a semver range
+    pub severity: String,
+    pub verification_level: VerificationLevel,
+    pub source_authority: SourceAuthority,
+    pub last_verified: u64,
+}
+
+/// One advisory record as returned by an `AdvisorySource` lookup.
+#[derive(Debug, Clone)]
+pub struct AdvisoryRecord {
+    pub affected_versions: String,
+    pub severity: String,
+    pub withdrawn: bool,
+}
+
+/// Pluggable CVE/advisory backend -- RustSec, OSV, NVD, etc. -- that
+/// `CveVerifier` reconciles stored `SecurityVulnerabilityFact`s against.
+pub trait AdvisorySource {
+    /// Look up `vulnerability_id`, returning `None` if the source has no
+    /// record of it.
+    fn lookup(&self, vulnerability_id: &str) -> Option<AdvisoryRecord>;
+}
+
+/// Outcome of `CveVerifier::verify`: the fact's updated verification fields,
+/// plus a diagnostic describing what was checked when the advisory
+/// disagreed with what was stored (or automation didn't run at all).
+#[derive(Debug, Clone)]
+pub struct CveVerificationOutcome {
+    pub verification_level: VerificationLevel,
+    pub last_verified: u64,
+    pub trust_score: f32,
+    pub diagnostic: Option<String>,
+}
+
+/// Reconciles stored `SecurityVulnerabilityFact`s against a pluggable
+/// `AdvisorySource`: promotes `VerificationLevel` to `AutomatedVerified`
+/// when the advisory confirms the id and the affected-version ranges
+/// overlap, and downgrades to `Unverified` when the advisory contradicts
+/// the stored severity or has been withdrawn.
+pub struct CveVerifier<S: AdvisorySource> {
+    source: S,
+}
+
+impl<S: AdvisorySource> CveVerifier<S> {
+    pub fn new(source: S) -> Self {
+        Self { source }
+    }
+
+    /// Reconcile `fact` against the advisory source. Only automated fact
+    /// types (`VerificationLevel::can_automate`) are checked; anything else
+    /// is returned unchanged with a diagnostic explaining why.
+    pub fn verify(&self, fact: &SecurityVulnerabilityFact) -> CveVerificationOutcome {
+        if !fact.verification_level.can_automate() {
+            return CveVerificationOutcome {
+                verification_level: fact.verification_level.clone(),
+                last_verified: fact.last_verified,
+                trust_score: Self::trust_score(&fact.verification_level, &fact.source_authority),
+                diagnostic: Some(
+                    "verification level does not support automation; left unchanged".to_string(),
+                ),
+            };
+        }
+
+        let Some(advisory) = self.source.lookup(&fact.vulnerability_id) else {
+            return CveVerificationOutcome {
+                verification_level: VerificationLevel::Unverified,
+                last_verified: fact.last_verified,
+                trust_score: Self::trust_score(&VerificationLevel::Unverified, &fact.source_authority),
+                diagnostic: Some(format!("no advisory found for {}", fact.vulnerability_id)),
+            };
+        };
+
+        if advisory.withdrawn {
+            return CveVerificationOutcome {
+                verification_level: VerificationLevel::Unverified,
+                last_verified: fact.last_verified,
+                trust_score: Self::trust_score(&VerificationLevel::Unverified, &fact.source_authority),
+                diagnostic: Some(format!("advisory for {} was withdrawn", fact.vulnerability_id)),
+            };
+        }
+
+        if advisory.severity != fact.severity {
+            return CveVerificationOutcome {
+                verification_level: VerificationLevel::Unverified,
+                last_verified: fact.last_verified,
+                trust_score: Self::trust_score(&VerificationLevel::Unverified, &fact.source_authority),
+                diagnostic: Some(format!(
+                    "advisory severity ({}) contradicts stored severity ({})",
+                    advisory.severity, fact.severity
+                )),
+            };
+        }
+
+        if !version_ranges_overlap(&advisory.affected_versions, &fact.affected_versions) {
+            return CveVerificationOutcome {
+                verification_level: VerificationLevel::Unverified,
+                last_verified: fact.last_verified,
+                trust_score: Self::trust_score(&VerificationLevel::Unverified, &fact.source_authority),
+                diagnostic: Some(format!(
+                    "advisory affected_versions ({}) does not overlap stored range ({})",
+                    advisory.affected_versions, fact.affected_versions
+                )),
+            };
+        }
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("System time is before the UNIX epoch")
+            .as_secs();
+
+        CveVerificationOutcome {
+            verification_level: VerificationLevel::AutomatedVerified,
+            last_verified: now,
+            trust_score: Self::trust_score(&VerificationLevel::AutomatedVerified, &fact.source_authority),
+            diagnostic: None,
+        }
+    }
+
+    /// Combined trust score: verification confidence weighted equally
+    /// against the authority of whoever supplied the fact.
+    fn trust_score(level: &VerificationLevel, authority: &SourceAuthority) -> f32 {
+        (level.confidence_score() + authority.authority_score()) / 2.0
+    }
+}
+
+/// Whether two affected-version range strings plausibly overlap. This is a
+/// conservative heuristic -- exact match or substring containment -- rather
+/// than a full semver-range parser, since this crate has no semver
+/// dependency to parse `"<1.2.3"`-style ranges with.
+fn version_ranges_overlap(a: &str, b: &str) -> bool {
+    let (a, b) = (a.trim(), b.trim());
+    a == b || a.contains(b) || b.contains(a)
+}
@@ -63,14 +63,17 @@ pub struct AnalysisResult {
     pub timestamp: chrono::DateTime<chrono::Utc>,
 }
 
+pub mod admin_api;
 pub mod analysis;
 pub mod ast_analysis;
 pub mod config;
 pub mod context;
+pub mod coordination;
 pub mod data_persistence;
 pub mod database;
 pub mod dependencies;
 pub mod enterprise_types;
+pub mod fixtures;
 pub mod knowledge_integration;
 pub mod machine_learning;
 pub mod memory_integration;
@@ -79,6 +82,7 @@ pub mod ml;
 pub mod ml_patterns;
 pub mod parser;
 pub mod patterns;
+pub mod performance_baseline;
 pub mod project_context;
 pub mod schema_validation;
 pub mod sparc_integration;
@@ -87,8 +91,10 @@ pub mod testing_framework;
 pub mod tree;
 pub mod tsos_integration;
 pub mod types;
+pub mod watch;
 
 // Re-export main types for easy access
+pub use admin_api::{admin_router, SharedDataManager};
 pub use analysis::FileAnalyzer;
 pub use ast_analysis::{AstAnalyzer, FileAnalysisResult, AnalysisMetrics};
 pub use config::{ConfigManager, SecurityConfig, SparcConfig};
@@ -0,0 +1,262 @@
+//! Git-tagged performance baselines and regression detection.
+//!
+//! `PerformanceSummary` results are otherwise discarded after each run, so a
+//! gradual slowdown across commits has nothing to compare against. This
+//! module persists each performance-test run's timing stats to a
+//! `MetricsReport` tagged with the git revision that produced it, and flags
+//! a regression when a new run's mean drifts too far from the most recent
+//! baseline for that test name.
+
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+/// One performance run's timing stats, tagged with the git state it ran
+/// against. The `git_*` fields degrade to empty strings outside a git repo
+/// or when `git` isn't on `PATH`, rather than erroring -- benchmarking
+/// shouldn't fail just because it ran from an extracted tarball.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MetricsReport {
+    pub test_name: String,
+    pub git_revision: String,
+    pub git_describe: String,
+    pub commit_date: String,
+    pub recorded_at_unix_seconds: u64,
+    pub mean_milliseconds: f64,
+    pub std_dev_milliseconds: f64,
+    pub min_milliseconds: f64,
+    pub max_milliseconds: f64,
+}
+
+impl MetricsReport {
+    /// Build a report for `test_name` from its raw per-iteration samples.
+    pub fn capture(test_name: &str, samples_ms: &[f64]) -> Self {
+        let mean = mean(samples_ms);
+        let min = samples_ms.iter().cloned().fold(f64::INFINITY, f64::min);
+        let max = samples_ms.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+        Self::from_stats(
+            test_name,
+            mean,
+            std_dev(samples_ms, mean),
+            if min.is_finite() { min } else { 0.0 },
+            if max.is_finite() { max } else { 0.0 },
+        )
+    }
+
+    /// Build a report for `test_name` from already-computed stats (e.g. a
+    /// [`BenchmarkSummary`](crate::testing_framework::BenchmarkSummary)),
+    /// tagging it with the current git state.
+    pub fn from_stats(test_name: &str, mean_milliseconds: f64, std_dev_milliseconds: f64, min_milliseconds: f64, max_milliseconds: f64) -> Self {
+        Self {
+            test_name: test_name.to_string(),
+            git_revision: git_output(&["rev-parse", "HEAD"]),
+            git_describe: git_output(&["describe", "--dirty", "--always"]),
+            commit_date: git_output(&["log", "-1", "--format=%cI"]),
+            recorded_at_unix_seconds: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_secs())
+                .unwrap_or(0),
+            mean_milliseconds,
+            std_dev_milliseconds,
+            min_milliseconds,
+            max_milliseconds,
+        }
+    }
+}
+
+fn git_output(args: &[&str]) -> String {
+    Command::new("git")
+        .args(args)
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+        .unwrap_or_default()
+}
+
+fn mean(samples: &[f64]) -> f64 {
+    if samples.is_empty() {
+        0.0
+    } else {
+        samples.iter().sum::<f64>() / samples.len() as f64
+    }
+}
+
+fn std_dev(samples: &[f64], mean: f64) -> f64 {
+    if samples.len() < 2 {
+        return 0.0;
+    }
+    let variance = samples.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (samples.len() - 1) as f64;
+    variance.sqrt()
+}
+
+/// Thresholds controlling when a new run is flagged as a regression against
+/// its baseline.
+#[derive(Debug, Clone)]
+pub struct RegressionThresholds {
+    /// Flag when `new_mean > baseline_mean + k * baseline_std_dev`.
+    pub std_dev_multiplier: f64,
+    /// Additionally flag when the percentage increase over `baseline_mean`
+    /// exceeds this, if set.
+    pub max_percent_increase: Option<f64>,
+}
+
+impl Default for RegressionThresholds {
+    fn default() -> Self {
+        Self {
+            std_dev_multiplier: 3.0,
+            max_percent_increase: None,
+        }
+    }
+}
+
+/// A detected slowdown between a baseline run and a new one.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PerformanceRegression {
+    pub test_name: String,
+    pub baseline_mean_milliseconds: f64,
+    pub new_mean_milliseconds: f64,
+    pub threshold_milliseconds: f64,
+    pub percent_increase: f64,
+}
+
+/// Compare `new_mean_milliseconds` against `baseline`, returning `Some` when
+/// either the std-dev-multiplier or percentage threshold is exceeded.
+pub fn detect_regression(
+    baseline: &MetricsReport,
+    new_mean_milliseconds: f64,
+    thresholds: &RegressionThresholds,
+) -> Option<PerformanceRegression> {
+    let threshold_milliseconds =
+        baseline.mean_milliseconds + thresholds.std_dev_multiplier * baseline.std_dev_milliseconds;
+    let percent_increase = if baseline.mean_milliseconds > 0.0 {
+        ((new_mean_milliseconds - baseline.mean_milliseconds) / baseline.mean_milliseconds) * 100.0
+    } else {
+        0.0
+    };
+
+    let exceeds_std_dev = new_mean_milliseconds > threshold_milliseconds;
+    let exceeds_percent = thresholds.max_percent_increase.map(|max| percent_increase > max).unwrap_or(false);
+
+    if exceeds_std_dev || exceeds_percent {
+        Some(PerformanceRegression {
+            test_name: baseline.test_name.clone(),
+            baseline_mean_milliseconds: baseline.mean_milliseconds,
+            new_mean_milliseconds,
+            threshold_milliseconds,
+            percent_increase,
+        })
+    } else {
+        None
+    }
+}
+
+/// Append-only JSON-lines store of [`MetricsReport`]s, one line per run.
+pub struct PerformanceBaselineStore {
+    path: PathBuf,
+}
+
+impl PerformanceBaselineStore {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Default location for a project that hasn't configured one explicitly.
+    pub fn default_at(root: &Path) -> Self {
+        Self::new(root.join(".performance-baselines").join("metrics.jsonl"))
+    }
+
+    pub async fn record(&self, report: &MetricsReport) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent).await.ok();
+        }
+        let mut content = if self.path.exists() {
+            fs::read_to_string(&self.path).await
+                .with_context(|| format!("Failed to read {}", self.path.display()))?
+        } else {
+            String::new()
+        };
+        content.push_str(&serde_json::to_string(report)?);
+        content.push('\n');
+        fs::write(&self.path, content).await
+            .with_context(|| format!("Failed to write {}", self.path.display()))
+    }
+
+    /// The most recently recorded report for `test_name`, if any.
+    pub async fn most_recent_baseline(&self, test_name: &str) -> Result<Option<MetricsReport>> {
+        if !self.path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(&self.path).await
+            .with_context(|| format!("Failed to read {}", self.path.display()))?;
+        Ok(content
+            .lines()
+            .filter_map(|line| serde_json::from_str::<MetricsReport>(line).ok())
+            .filter(|report| report.test_name == test_name)
+            .max_by_key(|report| report.recorded_at_unix_seconds))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn capture_computes_mean_and_std_dev() {
+        let report = MetricsReport::capture("bench_a", &[10.0, 10.0, 10.0, 10.0]);
+        assert_eq!(report.mean_milliseconds, 10.0);
+        assert_eq!(report.std_dev_milliseconds, 0.0);
+        assert_eq!(report.min_milliseconds, 10.0);
+        assert_eq!(report.max_milliseconds, 10.0);
+    }
+
+    #[test]
+    fn detect_regression_flags_a_mean_far_past_k_std_devs() {
+        let baseline = MetricsReport::capture("bench_a", &[10.0, 10.0, 10.0, 10.0]);
+        let regression = detect_regression(&baseline, 100.0, &RegressionThresholds::default());
+        assert!(regression.is_some());
+    }
+
+    #[test]
+    fn detect_regression_ignores_noise_within_threshold() {
+        let baseline = MetricsReport::capture("bench_a", &[9.0, 10.0, 11.0, 10.0]);
+        let regression = detect_regression(&baseline, 10.5, &RegressionThresholds::default());
+        assert!(regression.is_none());
+    }
+
+    #[test]
+    fn detect_regression_honors_percent_threshold_even_under_std_dev_bound() {
+        // Std-dev bound is 10 + 100 * 0.816 ≈ 91.6, so only the 10% rise trips it.
+        let baseline = MetricsReport::capture("bench_a", &[9.0, 10.0, 11.0, 10.0]);
+        let thresholds = RegressionThresholds { std_dev_multiplier: 100.0, max_percent_increase: Some(5.0) };
+        let regression = detect_regression(&baseline, 11.0, &thresholds);
+        assert!(regression.is_some());
+    }
+
+    #[tokio::test]
+    async fn store_round_trips_and_returns_the_most_recent_baseline() {
+        let dir = tempdir().unwrap();
+        let store = PerformanceBaselineStore::default_at(dir.path());
+
+        let first = MetricsReport::capture("bench_a", &[10.0, 10.0]);
+        store.record(&first).await.unwrap();
+        let mut second = MetricsReport::capture("bench_a", &[20.0, 20.0]);
+        second.recorded_at_unix_seconds = first.recorded_at_unix_seconds + 1;
+        store.record(&second).await.unwrap();
+
+        let baseline = store.most_recent_baseline("bench_a").await.unwrap().unwrap();
+        assert_eq!(baseline.mean_milliseconds, 20.0);
+    }
+
+    #[tokio::test]
+    async fn most_recent_baseline_is_none_for_an_unknown_test() {
+        let dir = tempdir().unwrap();
+        let store = PerformanceBaselineStore::default_at(dir.path());
+        assert!(store.most_recent_baseline("never_run").await.unwrap().is_none());
+    }
+}
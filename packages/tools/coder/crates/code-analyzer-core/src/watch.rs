@@ -0,0 +1,256 @@
+//! Watch mode: re-run only the test suites affected by a source change.
+//!
+//! Builds a lightweight module dependency graph by scanning `use crate::...`
+//! and `mod ...;` declarations in each `.rs` file under a root directory --
+//! this crate has no working parser module to drive a real import graph from
+//! (`crate::parser` is declared in `lib.rs` but has no file backing it), so
+//! this is a text-level heuristic rather than a true AST-driven one. On a
+//! filesystem event, the reverse-dependency closure of the changed file is
+//! computed and only the `TestSuite`s whose cases touch an affected file are
+//! re-executed, the way Deno's test runner reruns specifiers whose local
+//! dependency graph roots changed.
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::sync::mpsc;
+
+use crate::testing_framework::{ComprehensiveTestingFramework, TestSuiteReport};
+
+/// Forward (`file -> files it imports`) and reverse (`file -> files that
+/// import it`) edges of the heuristic module dependency graph.
+#[derive(Debug, Default)]
+pub struct DependencyGraph {
+    dependents: HashMap<PathBuf, HashSet<PathBuf>>,
+}
+
+impl DependencyGraph {
+    /// Walk every `.rs` file under `root` and record an edge from each file
+    /// to the sibling/crate-relative files its `use`/`mod` lines resolve to.
+    /// An import that can't be resolved to a file on disk (an external
+    /// crate, a re-export, a glob `use foo::*`) is silently skipped rather
+    /// than guessed at.
+    pub fn build(root: &Path) -> Result<Self> {
+        let mut files = Vec::new();
+        for entry in walkdir::WalkDir::new(root).into_iter().filter_map(|e| e.ok()) {
+            if entry.file_type().is_file() && entry.path().extension().map(|e| e == "rs").unwrap_or(false) {
+                files.push(entry.path().to_path_buf());
+            }
+        }
+
+        let mut dependents: HashMap<PathBuf, HashSet<PathBuf>> = HashMap::new();
+        for file in &files {
+            let source = std::fs::read_to_string(file)
+                .with_context(|| format!("Failed to read {} while building dependency graph", file.display()))?;
+            for imported in Self::resolve_imports(file, &source, &files) {
+                dependents.entry(imported).or_default().insert(file.clone());
+            }
+        }
+
+        Ok(Self { dependents })
+    }
+
+    /// Resolve the modules a file's `use crate::a::b` / `mod a;` lines refer
+    /// to against the set of files actually on disk, by matching path
+    /// components against each candidate file's own path.
+    fn resolve_imports(file: &Path, source: &str, files: &[PathBuf]) -> Vec<PathBuf> {
+        let mut resolved = Vec::new();
+        let parent = file.parent().unwrap_or(Path::new("."));
+
+        for line in source.lines() {
+            let trimmed = line.trim();
+            let module_path = if let Some(rest) = trimmed.strip_prefix("use crate::") {
+                rest.split("::").next()
+            } else if let Some(rest) = trimmed.strip_prefix("mod ") {
+                rest.trim_end_matches(';').split_whitespace().next()
+            } else {
+                None
+            };
+
+            let Some(module) = module_path else { continue };
+            let module = module.trim_end_matches(';');
+            if module.is_empty() {
+                continue;
+            }
+
+            let candidate_file = parent.join(format!("{module}.rs"));
+            let candidate_mod = parent.join(module).join("mod.rs");
+            for candidate in [candidate_file, candidate_mod] {
+                if let Some(found) = files.iter().find(|f| **f == candidate) {
+                    resolved.push(found.clone());
+                }
+            }
+        }
+
+        resolved
+    }
+
+    /// Every file that depends, directly or transitively, on `changed`
+    /// (including `changed` itself).
+    pub fn reverse_dependency_closure(&self, changed: &Path) -> HashSet<PathBuf> {
+        let mut closure = HashSet::new();
+        let mut frontier = vec![changed.to_path_buf()];
+        closure.insert(changed.to_path_buf());
+
+        while let Some(current) = frontier.pop() {
+            if let Some(direct_dependents) = self.dependents.get(&current) {
+                for dependent in direct_dependents {
+                    if closure.insert(dependent.clone()) {
+                        frontier.push(dependent.clone());
+                    }
+                }
+            }
+        }
+
+        closure
+    }
+}
+
+/// Project-level configuration files that, when changed, invalidate every
+/// registered suite rather than just the ones touching a specific source
+/// file -- a `Cargo.toml`/lockfile edit can change how the whole crate
+/// builds and runs, not just whatever file happens to sit next to it.
+const PROJECT_CONFIGURATION_FILENAMES: &[&str] = &["Cargo.toml", "Cargo.lock", "rust-toolchain.toml", "rust-toolchain"];
+
+fn is_project_configuration_path(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| PROJECT_CONFIGURATION_FILENAMES.contains(&name))
+        .unwrap_or(false)
+}
+
+impl ComprehensiveTestingFramework {
+    /// Names of every registered suite that has at least one `TestCase`
+    /// whose `input_data.file_path` is `file` or lies in its reverse
+    /// dependency closure.
+    pub fn suites_affected_by(&self, affected_files: &HashSet<PathBuf>) -> Result<Vec<String>> {
+        let suites = self.test_suites_snapshot()?;
+        Ok(suites
+            .into_iter()
+            .filter(|(_, suite)| {
+                suite.test_cases.iter().any(|case| {
+                    case.input_data
+                        .file_path
+                        .as_ref()
+                        .map(|p| affected_files.contains(p))
+                        .unwrap_or(false)
+                })
+            })
+            .map(|(name, _)| name)
+            .collect())
+    }
+
+    /// Watch `root` for filesystem changes and re-run only the affected
+    /// suites as they land, printing an incremental delta report after each
+    /// run. Runs until the channel closes (the watcher is dropped) or a
+    /// filesystem error occurs; intended for an interactive development loop
+    /// rather than CI, where `execute_all_test_suites` is more appropriate.
+    pub async fn watch(&self, root: &Path) -> Result<()> {
+        let graph = DependencyGraph::build(root)?;
+        let (tx, mut rx) = mpsc::unbounded_channel::<notify::Result<Event>>();
+
+        // The notify callback runs on its own internal thread and is a plain
+        // (non-async) closure, so it can push straight into the unbounded
+        // tokio channel without needing a blocking bridge thread.
+        let mut watcher: RecommendedWatcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })
+        .context("Failed to create filesystem watcher")?;
+        watcher.watch(root, RecursiveMode::Recursive).context("Failed to start watching")?;
+
+        let mut previous_reports: HashMap<String, TestSuiteReport> = HashMap::new();
+
+        while let Some(event) = rx.recv().await {
+            let Ok(event) = event else { continue };
+            if !matches!(event.kind, EventKind::Modify(_) | EventKind::Create(_)) {
+                continue;
+            }
+
+            let mut affected_files = HashSet::new();
+            let mut rerun_all = false;
+            for path in &event.paths {
+                if is_project_configuration_path(path) {
+                    rerun_all = true;
+                }
+                affected_files.extend(graph.reverse_dependency_closure(path));
+            }
+
+            let affected_suites = if rerun_all {
+                self.test_suites_snapshot()?.into_iter().map(|(name, _)| name).collect()
+            } else {
+                self.suites_affected_by(&affected_files)?
+            };
+            for suite_name in affected_suites {
+                let report = self.execute_test_suite(&suite_name).await?;
+                print_delta(previous_reports.get(&suite_name), &report);
+                previous_reports.insert(suite_name, report);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Print an incremental summary comparing `report` against the suite's
+/// previous run, if any.
+fn print_delta(previous: Option<&TestSuiteReport>, report: &TestSuiteReport) {
+    match previous {
+        Some(previous) => {
+            let passed_delta = report.passed_tests as i64 - previous.passed_tests as i64;
+            let failed_delta = report.failed_tests as i64 - previous.failed_tests as i64;
+            println!(
+                "[watch] {}: {} passed ({:+}), {} failed ({:+})",
+                report.suite_name, report.passed_tests, passed_delta, report.failed_tests, failed_delta
+            );
+        }
+        None => {
+            println!(
+                "[watch] {}: {} passed, {} failed (first run)",
+                report.suite_name, report.passed_tests, report.failed_tests
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use tempfile::tempdir;
+
+    #[test]
+    fn reverse_dependency_closure_follows_use_crate_edges() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.rs"), "pub fn a() {}\n").unwrap();
+        fs::write(dir.path().join("b.rs"), "use crate::a;\npub fn b() {}\n").unwrap();
+        fs::write(dir.path().join("c.rs"), "use crate::b;\npub fn c() {}\n").unwrap();
+
+        let graph = DependencyGraph::build(dir.path()).unwrap();
+        let closure = graph.reverse_dependency_closure(&dir.path().join("a.rs"));
+
+        assert!(closure.contains(&dir.path().join("a.rs")));
+        assert!(closure.contains(&dir.path().join("b.rs")));
+        assert!(closure.contains(&dir.path().join("c.rs")));
+    }
+
+    #[test]
+    fn unrelated_files_are_excluded_from_the_closure() {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("a.rs"), "pub fn a() {}\n").unwrap();
+        fs::write(dir.path().join("unrelated.rs"), "pub fn unrelated() {}\n").unwrap();
+
+        let graph = DependencyGraph::build(dir.path()).unwrap();
+        let closure = graph.reverse_dependency_closure(&dir.path().join("a.rs"));
+
+        assert!(!closure.contains(&dir.path().join("unrelated.rs")));
+    }
+
+    #[test]
+    fn is_project_configuration_path_matches_known_config_filenames() {
+        assert!(is_project_configuration_path(Path::new("/repo/Cargo.toml")));
+        assert!(is_project_configuration_path(Path::new("/repo/Cargo.lock")));
+        assert!(!is_project_configuration_path(Path::new("/repo/src/lib.rs")));
+    }
+}
@@ -61,6 +61,19 @@ pub struct SQLiteConfig {
     pub page_size: i32,       // Page size in bytes
     pub auto_vacuum: String,  // NONE, FULL, INCREMENTAL
     pub database_file: String, // Specific database file name
+    pub enable_session_extension: bool, // Track changesets via the session extension
+}
+
+/// Conflict resolution applied when replaying a changeset into a database
+/// whose rows have diverged from the one the changeset was captured against.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum ConflictPolicy {
+    /// Skip the conflicting change and keep the target row as-is.
+    Omit,
+    /// Overwrite the target row with the value from the changeset.
+    Replace,
+    /// Abort applying the changeset entirely on the first conflict.
+    Abort,
 }
 
 /// LanceDB configuration for vector embeddings and ML features (disk-based)
@@ -70,7 +83,9 @@ pub struct LanceDBConfig {
     pub index_type: String,   // IVF, HNSW, FLAT
     pub metric: String,       // L2, COSINE, DOT
     pub num_partitions: usize,
-    pub max_connections: usize,
+    pub max_connections: usize, // HNSW M: max neighbors per node per layer
+    pub ef_construction: usize, // HNSW candidate list size while building the graph
+    pub ef_search: usize,       // HNSW candidate list size while searching the graph
     pub enable_compression: bool,
     pub compression_type: String, // ZSTD, LZ4, SNAPPY
     pub database_directory: String, // Specific database directory name
@@ -212,6 +227,11 @@ impl DatabasePathResolver {
         }
     }
     
+    /// Get data directory for primary database content
+    pub fn get_data_directory(&self) -> &PathBuf {
+        &self.data_directory
+    }
+
     /// Get cache directory for temporary data
     pub fn get_cache_directory(&self) -> &PathBuf {
         &self.cache_directory
@@ -272,6 +292,8 @@ pub struct DatabaseManager {
     config: DatabaseConfig,
     #[cfg(feature = "sqlite")]
     sqlite_connection: Option<rusqlite::Connection>,
+    #[cfg(feature = "sqlite")]
+    sqlite_session: Option<rusqlite::session::Session<'static>>,
     // Future database connections (when crates are available):
     // lancedb_connection: Option<lancedb::Database>,
     // kuzu_connection: Option<kuzu::Database>,
@@ -279,6 +301,7 @@ pub struct DatabaseManager {
     // mysql_connection: Option<mysql_async::Pool>,
     connection_health: HashMap<DatabaseType, bool>,
     performance_metrics: HashMap<String, f64>,
+    vector_index: Option<VectorIndex>,
 }
 
 /// Code analysis record for database storage
@@ -342,15 +365,60 @@ impl DatabaseManager {
         Ok(Self {
             config,
             sqlite_connection: None,
+            #[cfg(feature = "sqlite")]
+            sqlite_session: None,
             lancedb_connection: None,
             kuzu_connection: None,
             postgres_connection: None,
             mysql_connection: None,
             connection_health: HashMap::new(),
             performance_metrics: HashMap::new(),
+            vector_index: None,
         })
     }
-    
+
+    /// Build (or reload) the in-process HNSW vector index from the
+    /// configured `lancedb_config`, resuming a persisted graph under the
+    /// foundation-resolved data directory when one already exists.
+    pub fn build_vector_index(&mut self) -> Result<()> {
+        let lancedb_config = self.config.lancedb_config.clone().ok_or_else(|| FileAwareError::DatabaseError {
+            message: "vector index requires a lancedb_config".to_string(),
+        })?;
+
+        let path_resolver = DatabasePathResolver::new()?;
+        let data_directory = path_resolver.get_data_directory().clone();
+        std::fs::create_dir_all(&data_directory)
+            .map_err(|e| FileAwareError::DatabaseError {
+                message: format!("Failed to create data directory: {}", e),
+            })?;
+
+        self.vector_index = Some(match VectorIndex::load(&data_directory)? {
+            Some(index) => index,
+            None => VectorIndex::new(&lancedb_config),
+        });
+        Ok(())
+    }
+
+    /// Insert a vector into the in-process HNSW index and persist the
+    /// updated graph under the foundation-resolved data directory.
+    pub fn index_vector(&mut self, id: String, vector: Vec<f32>) -> Result<()> {
+        let index = self.vector_index.as_mut().ok_or_else(|| FileAwareError::DatabaseError {
+            message: "vector index not initialized; call build_vector_index first".to_string(),
+        })?;
+        index.insert(id, vector);
+
+        let path_resolver = DatabasePathResolver::new()?;
+        index.save(path_resolver.get_data_directory())
+    }
+
+    /// Approximate nearest-neighbor search over the in-process HNSW index.
+    pub fn search_similar_vectors(&self, query: &[f32], k: usize) -> Result<Vec<(String, f32)>> {
+        let index = self.vector_index.as_ref().ok_or_else(|| FileAwareError::DatabaseError {
+            message: "vector index not initialized; call build_vector_index first".to_string(),
+        })?;
+        Ok(index.search(query, k))
+    }
+
     /// Initialize database connection with foundation-resolved paths
     pub async fn initialize(&mut self) -> Result<()> {
         let path_resolver = DatabasePathResolver::new()?;
@@ -366,8 +434,22 @@ impl DatabaseManager {
     
     /// Initialize SQLite with foundation-resolved path
     async fn initialize_sqlite(&mut self, path_resolver: &DatabasePathResolver) -> Result<()> {
+        // `sqlite_session` borrows `sqlite_connection` under an erased
+        // `'static` lifetime (see `begin_session`'s safety comment).
+        // Replacing the connection here while a session is still alive would
+        // drop the connection out from under that borrow -- a use-after-free
+        // the next time the session is touched -- so refuse instead of
+        // silently clobbering it. Callers must `capture_changeset` (which
+        // ends the session) before re-initializing.
+        #[cfg(feature = "sqlite")]
+        if self.sqlite_session.is_some() {
+            return Err(FileAwareError::DatabaseError {
+                message: "Cannot re-initialize SQLite while a session is active; call capture_changeset first".to_string(),
+            });
+        }
+
         let db_path = path_resolver.resolve_database_path(&self.config.database_type, &self.config);
-        
+
         let conn = rusqlite::Connection::open(&db_path)
             .map_err(|e| FileAwareError::DatabaseError {
                 message: format!("Failed to connect to SQLite at {}: {}", db_path.display(), e),
@@ -400,7 +482,93 @@ impl DatabaseManager {
         self.connection_health.insert(DatabaseType::SQLite, true);
         Ok(())
     }
-    
+
+    /// Start tracking changes to `tables` via SQLite's session extension, so
+    /// they can later be captured as a portable changeset. Pass an empty
+    /// slice to track every table in the database.
+    #[cfg(feature = "sqlite")]
+    pub fn begin_session(&mut self, tables: &[String]) -> Result<()> {
+        let conn = self.sqlite_connection.as_ref().ok_or_else(|| FileAwareError::DatabaseError {
+            message: "SQLite connection not initialized".to_string(),
+        })?;
+
+        let mut session = rusqlite::session::Session::new(conn)
+            .map_err(|e| FileAwareError::DatabaseError {
+                message: format!("Failed to start session: {}", e),
+            })?;
+
+        if tables.is_empty() {
+            session.attach(None).map_err(|e| FileAwareError::DatabaseError {
+                message: format!("Failed to attach session: {}", e),
+            })?;
+        } else {
+            for table in tables {
+                session.attach(Some(table.as_str())).map_err(|e| FileAwareError::DatabaseError {
+                    message: format!("Failed to attach table {}: {}", table, e),
+                })?;
+            }
+        }
+
+        // SAFETY: `session` borrows `self.sqlite_connection`. The connection
+        // lives in this same struct and is only ever replaced by a later
+        // `initialize()` call; `initialize_sqlite` refuses to run while
+        // `sqlite_session` is `Some`, so the connection this session
+        // borrows cannot be dropped out from under it before
+        // `capture_changeset` takes (and drops) the session.
+        let session: rusqlite::session::Session<'static> = unsafe { std::mem::transmute(session) };
+        self.sqlite_session = Some(session);
+        Ok(())
+    }
+
+    /// Serialize every change recorded since `begin_session` into a portable
+    /// changeset, ending the session.
+    #[cfg(feature = "sqlite")]
+    pub fn capture_changeset(&mut self) -> Result<Vec<u8>> {
+        let mut session = self.sqlite_session.take().ok_or_else(|| FileAwareError::DatabaseError {
+            message: "No active session; call begin_session first".to_string(),
+        })?;
+
+        let mut changeset = Vec::new();
+        session.changeset_strm(&mut changeset).map_err(|e| FileAwareError::DatabaseError {
+            message: format!("Failed to capture changeset: {}", e),
+        })?;
+        Ok(changeset)
+    }
+
+    /// Replay a changeset produced by `capture_changeset` into this
+    /// database, resolving conflicting rows according to `policy`.
+    #[cfg(feature = "sqlite")]
+    pub fn apply_changeset(&self, changeset: &[u8], policy: ConflictPolicy) -> Result<()> {
+        let conn = self.sqlite_connection.as_ref().ok_or_else(|| FileAwareError::DatabaseError {
+            message: "SQLite connection not initialized".to_string(),
+        })?;
+
+        conn.apply_strm(
+            &mut &changeset[..],
+            None::<fn(&str) -> bool>,
+            |_conflict_type, _item| match policy {
+                ConflictPolicy::Omit => rusqlite::session::ConflictAction::SQLITE_CHANGESET_OMIT,
+                ConflictPolicy::Replace => rusqlite::session::ConflictAction::SQLITE_CHANGESET_REPLACE,
+                ConflictPolicy::Abort => rusqlite::session::ConflictAction::SQLITE_CHANGESET_ABORT,
+            },
+        )
+        .map_err(|e| FileAwareError::DatabaseError {
+            message: format!("Failed to apply changeset: {}", e),
+        })?;
+
+        Ok(())
+    }
+
+    /// Invert a changeset so it can be applied to undo the changes it describes.
+    #[cfg(feature = "sqlite")]
+    pub fn invert_changeset(changeset: &[u8]) -> Result<Vec<u8>> {
+        let mut inverted = Vec::new();
+        rusqlite::session::invert_strm(&mut &changeset[..], &mut inverted).map_err(|e| FileAwareError::DatabaseError {
+            message: format!("Failed to invert changeset: {}", e),
+        })?;
+        Ok(inverted)
+    }
+
     /// Initialize LanceDB with foundation-resolved path
     async fn initialize_lancedb(&mut self, path_resolver: &DatabasePathResolver) -> Result<()> {
         let db_path = path_resolver.resolve_database_path(&self.config.database_type, &self.config);
@@ -776,6 +944,7 @@ impl Default for DatabaseConfig {
                 page_size: 4096,
                 auto_vacuum: "INCREMENTAL".to_string(),
                 database_file: "code_analysis.db".to_string(),
+                enable_session_extension: true,
             }),
             lancedb_config: Some(LanceDBConfig {
                 vector_dimension: 128,
@@ -783,6 +952,8 @@ impl Default for DatabaseConfig {
                 metric: "L2".to_string(),
                 num_partitions: 10,
                 max_connections: 100,
+                ef_construction: 200,
+                ef_search: 100,
                 enable_compression: true,
                 compression_type: "ZSTD".to_string(),
                 database_directory: "lancedb_code_analysis".to_string(),
@@ -834,6 +1005,589 @@ impl Default for DatabaseManager {
     }
 }
 
+/// Distance metric used when comparing vectors in the HNSW index. Lower is
+/// always "closer", so cosine and inner product are stored as `1 - similarity`
+/// and `-dot_product` respectively.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub enum VectorMetric {
+    Cosine,
+    L2,
+    InnerProduct,
+}
+
+impl VectorMetric {
+    fn from_config_str(metric: &str) -> Self {
+        match metric.to_uppercase().as_str() {
+            "L2" => VectorMetric::L2,
+            "DOT" => VectorMetric::InnerProduct,
+            _ => VectorMetric::Cosine,
+        }
+    }
+
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        match self {
+            VectorMetric::L2 => a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum::<f32>().sqrt(),
+            VectorMetric::InnerProduct => -a.iter().zip(b).map(|(x, y)| x * y).sum::<f32>(),
+            VectorMetric::Cosine => {
+                let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+                let mag_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+                let mag_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+                if mag_a == 0.0 || mag_b == 0.0 {
+                    1.0
+                } else {
+                    1.0 - dot / (mag_a * mag_b)
+                }
+            }
+        }
+    }
+}
+
+/// A single vector stored in the HNSW graph, with its neighbor list per layer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct HnswNode {
+    id: String,
+    vector: Vec<f32>,
+    level: usize,
+    neighbors: Vec<Vec<usize>>, // neighbors[layer] = neighbor node indices
+}
+
+/// A node scored by distance to a query, ordered closest-first so it can be
+/// used in both a min-heap (candidates to explore) and, via `Reverse`, the
+/// complementary max-heap (farthest of the current best results).
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct ScoredNode {
+    index: usize,
+    distance: f32,
+}
+
+impl Eq for ScoredNode {}
+
+impl Ord for ScoredNode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.distance.partial_cmp(&other.distance).unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
+impl PartialOrd for ScoredNode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// In-process Hierarchical Navigable Small World index for approximate
+/// nearest-neighbor search over embeddings stored in `LanceDBConfig`-typed
+/// tables. Built from scratch here since the `lancedb` crate isn't available
+/// in this build; the graph is persisted as JSON under the data directory so
+/// it survives process restarts.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VectorIndex {
+    metric: VectorMetric,
+    max_connections: usize, // M
+    ef_construction: usize,
+    ef_search: usize,
+    level_multiplier: f64, // mL
+    nodes: Vec<HnswNode>,
+    entry_point: Option<usize>,
+    rng_state: u64,
+}
+
+impl VectorIndex {
+    /// Create an empty index configured from the table's LanceDB settings.
+    pub fn new(config: &LanceDBConfig) -> Self {
+        let m = config.max_connections.max(2);
+        Self {
+            metric: VectorMetric::from_config_str(&config.metric),
+            max_connections: m,
+            ef_construction: config.ef_construction.max(m),
+            ef_search: config.ef_search.max(m),
+            level_multiplier: 1.0 / (m as f64).ln(),
+            nodes: Vec::new(),
+            entry_point: None,
+            rng_state: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    /// Deterministic xorshift64* PRNG, so index builds are reproducible
+    /// across runs without pulling in a random number crate.
+    fn next_uniform(&mut self) -> f64 {
+        self.rng_state ^= self.rng_state << 13;
+        self.rng_state ^= self.rng_state >> 7;
+        self.rng_state ^= self.rng_state << 17;
+        ((self.rng_state >> 11) as f64 / (1u64 << 53) as f64).clamp(f64::MIN_POSITIVE, 1.0)
+    }
+
+    /// Sample a node's layer as `floor(-ln(U) * mL)`.
+    fn random_level(&mut self) -> usize {
+        let u = self.next_uniform();
+        (-u.ln() * self.level_multiplier).floor() as usize
+    }
+
+    fn distance(&self, a: &[f32], b: &[f32]) -> f32 {
+        self.metric.distance(a, b)
+    }
+
+    /// Greedily descend from `start`, moving to the closest neighbor at
+    /// `layer` until no neighbor improves on the current node.
+    fn greedy_closest(&self, start: usize, query: &[f32], layer: usize) -> usize {
+        let mut current = start;
+        let mut current_distance = self.distance(&self.nodes[current].vector, query);
+        loop {
+            let mut moved = false;
+            if let Some(neighbors) = self.nodes[current].neighbors.get(layer) {
+                for &neighbor in neighbors {
+                    let distance = self.distance(&self.nodes[neighbor].vector, query);
+                    if distance < current_distance {
+                        current = neighbor;
+                        current_distance = distance;
+                        moved = true;
+                    }
+                }
+            }
+            if !moved {
+                return current;
+            }
+        }
+    }
+
+    /// Best-first search of `layer`, bounded to `ef` results, starting from
+    /// `entry_points`. Returns the visited candidates closest to `query`.
+    fn search_layer(&self, query: &[f32], entry_points: &[usize], ef: usize, layer: usize) -> Vec<usize> {
+        use std::cmp::Reverse;
+        use std::collections::{BinaryHeap, HashSet};
+
+        let mut visited: HashSet<usize> = entry_points.iter().copied().collect();
+        let mut candidates: BinaryHeap<Reverse<ScoredNode>> = BinaryHeap::new();
+        let mut found: BinaryHeap<ScoredNode> = BinaryHeap::new();
+
+        for &entry in entry_points {
+            let distance = self.distance(&self.nodes[entry].vector, query);
+            let scored = ScoredNode { index: entry, distance };
+            candidates.push(Reverse(scored));
+            found.push(scored);
+        }
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            let worst = found.peek().map(|n| n.distance).unwrap_or(f32::INFINITY);
+            if found.len() >= ef && current.distance > worst {
+                break;
+            }
+
+            if let Some(neighbors) = self.nodes[current.index].neighbors.get(layer).cloned() {
+                for neighbor in neighbors {
+                    if !visited.insert(neighbor) {
+                        continue;
+                    }
+                    let distance = self.distance(&self.nodes[neighbor].vector, query);
+                    let worst = found.peek().map(|n| n.distance).unwrap_or(f32::INFINITY);
+                    if found.len() < ef || distance < worst {
+                        let scored = ScoredNode { index: neighbor, distance };
+                        candidates.push(Reverse(scored));
+                        found.push(scored);
+                        if found.len() > ef {
+                            found.pop();
+                        }
+                    }
+                }
+            }
+        }
+
+        found.into_sorted_vec().into_iter().map(|n| n.index).collect()
+    }
+
+    /// Select up to `m` neighbors from `candidates`, pruning a candidate if
+    /// an already-selected neighbor is closer to it than the query is.
+    fn select_neighbors(&self, query: &[f32], candidates: Vec<usize>, m: usize) -> Vec<usize> {
+        let mut ranked: Vec<(usize, f32)> = candidates
+            .into_iter()
+            .map(|index| (index, self.distance(&self.nodes[index].vector, query)))
+            .collect();
+        ranked.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut selected = Vec::new();
+        for (candidate, candidate_distance) in ranked {
+            if selected.len() >= m {
+                break;
+            }
+            let pruned = selected.iter().any(|&already: &usize| {
+                self.distance(&self.nodes[already].vector, &self.nodes[candidate].vector) < candidate_distance
+            });
+            if !pruned {
+                selected.push(candidate);
+            }
+        }
+        selected
+    }
+
+    /// Insert a vector into the graph, assigning it a random level and
+    /// wiring it into the existing layers top-down.
+    pub fn insert(&mut self, id: String, vector: Vec<f32>) {
+        let level = self.random_level();
+        let new_index = self.nodes.len();
+
+        let Some(entry) = self.entry_point else {
+            self.nodes.push(HnswNode { id, vector, level, neighbors: vec![Vec::new(); level + 1] });
+            self.entry_point = Some(new_index);
+            return;
+        };
+
+        let entry_level = self.nodes[entry].level;
+        let mut current = entry;
+        for layer in ((level + 1)..=entry_level).rev() {
+            current = self.greedy_closest(current, &vector, layer);
+        }
+
+        let mut neighbors_by_layer = vec![Vec::new(); level + 1];
+        let mut entry_points = vec![current];
+        for layer in (0..=level.min(entry_level)).rev() {
+            let candidates = self.search_layer(&vector, &entry_points, self.ef_construction, layer);
+            let selected = self.select_neighbors(&vector, candidates, self.max_connections);
+            neighbors_by_layer[layer] = selected.clone();
+            entry_points = selected;
+        }
+
+        self.nodes.push(HnswNode { id, vector, level, neighbors: neighbors_by_layer });
+
+        // Wire the reverse edges and re-prune any neighbor that now exceeds M.
+        for layer in 0..=level.min(entry_level) {
+            let neighbors = self.nodes[new_index].neighbors[layer].clone();
+            for neighbor in neighbors {
+                if let Some(neighbor_layer) = self.nodes[neighbor].neighbors.get_mut(layer) {
+                    neighbor_layer.push(new_index);
+                    if neighbor_layer.len() > self.max_connections {
+                        let neighbor_vector = self.nodes[neighbor].vector.clone();
+                        let candidates = self.nodes[neighbor].neighbors[layer].clone();
+                        let pruned = self.select_neighbors(&neighbor_vector, candidates, self.max_connections);
+                        self.nodes[neighbor].neighbors[layer] = pruned;
+                    }
+                }
+            }
+        }
+
+        if level > entry_level {
+            self.entry_point = Some(new_index);
+        }
+    }
+
+    /// Approximate nearest-neighbor search: descend to layer 0 then run a
+    /// best-first search bounded by `ef_search`, returning up to `k` results
+    /// ordered by ascending distance.
+    pub fn search(&self, query: &[f32], k: usize) -> Vec<(String, f32)> {
+        let Some(entry) = self.entry_point else {
+            return Vec::new();
+        };
+
+        let entry_level = self.nodes[entry].level;
+        let mut current = entry;
+        for layer in (1..=entry_level).rev() {
+            current = self.greedy_closest(current, query, layer);
+        }
+
+        let ef = self.ef_search.max(k);
+        let candidates = self.search_layer(query, &[current], ef, 0);
+
+        let mut results: Vec<(usize, f32)> = candidates
+            .into_iter()
+            .map(|index| (index, self.distance(&self.nodes[index].vector, query)))
+            .collect();
+        results.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+        results.truncate(k);
+
+        results.into_iter().map(|(index, distance)| (self.nodes[index].id.clone(), distance)).collect()
+    }
+
+    /// Persist the graph as JSON under the foundation-resolved data directory.
+    pub fn save(&self, data_directory: &std::path::Path) -> Result<()> {
+        let path = data_directory.join("vector_index.json");
+        let json = serde_json::to_string(self)?;
+        std::fs::write(path, json)?;
+        Ok(())
+    }
+
+    /// Reload a previously persisted graph, if one exists under the
+    /// foundation-resolved data directory.
+    pub fn load(data_directory: &std::path::Path) -> Result<Option<Self>> {
+        let path = data_directory.join("vector_index.json");
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = std::fs::read_to_string(path)?;
+        Ok(Some(serde_json::from_str(&json)?))
+    }
+}
+
+/// Declarative benchmark harness for comparing `DatabaseManager` backends
+/// (SQLite, LanceDB, Kuzu, ...) under a reproducible, replayable workload.
+pub mod bench {
+    use super::*;
+    use std::time::Instant;
+
+    /// Min/max byte-length range a workload samples key/value sizes from.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct SizeDistribution {
+        pub min_bytes: usize,
+        pub max_bytes: usize,
+    }
+
+    impl SizeDistribution {
+        fn sample(&self, rng: &mut Xorshift64Star) -> usize {
+            if self.max_bytes <= self.min_bytes {
+                return self.min_bytes;
+            }
+            self.min_bytes + (rng.next_u64() as usize % (self.max_bytes - self.min_bytes + 1))
+        }
+    }
+
+    /// Relative weights of each operation kind a workload exercises.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct OperationMix {
+        pub insert: u32,
+        pub get: u32,
+        pub update: u32,
+        pub delete: u32,
+        pub vector_search: u32,
+        pub graph_traversal: u32,
+    }
+
+    /// A declarative benchmark workload, serializable to/from JSON so it can
+    /// be generated once and replayed across backends.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct Workload {
+        pub operation_count: usize,
+        pub key_size: SizeDistribution,
+        pub value_size: SizeDistribution,
+        pub mix: OperationMix,
+        pub seed: u64,
+    }
+
+    impl Workload {
+        pub fn from_json(json: &str) -> Result<Self> {
+            serde_json::from_str(json).map_err(FileAwareError::from)
+        }
+
+        pub fn to_json(&self) -> Result<String> {
+            serde_json::to_string_pretty(self).map_err(FileAwareError::from)
+        }
+    }
+
+    #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+    enum BenchOperation {
+        Insert,
+        Get,
+        Update,
+        Delete,
+        VectorSearch,
+        GraphTraversal,
+    }
+
+    /// Deterministic xorshift64* PRNG so benchmark replays are reproducible
+    /// from a seed alone.
+    struct Xorshift64Star {
+        state: u64,
+    }
+
+    impl Xorshift64Star {
+        fn new(seed: u64) -> Self {
+            Self { state: seed.max(1) }
+        }
+
+        fn next_u64(&mut self) -> u64 {
+            self.state ^= self.state << 13;
+            self.state ^= self.state >> 7;
+            self.state ^= self.state << 17;
+            self.state.wrapping_mul(0x2545_F491_4F6C_DD1D)
+        }
+
+        fn choose(&mut self, weights: &[(BenchOperation, u32)]) -> BenchOperation {
+            let total: u32 = weights.iter().map(|(_, w)| *w).sum();
+            if total == 0 {
+                return BenchOperation::Get;
+            }
+            let mut pick = (self.next_u64() % total as u64) as u32;
+            for (op, weight) in weights {
+                if pick < *weight {
+                    return *op;
+                }
+                pick -= weight;
+            }
+            weights.last().map(|(op, _)| *op).unwrap_or(BenchOperation::Get)
+        }
+    }
+
+    /// Latency percentiles, throughput, and error count for one operation kind.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct OperationStats {
+        pub count: usize,
+        pub errors: usize,
+        pub p50_ms: f64,
+        pub p95_ms: f64,
+        pub p99_ms: f64,
+        pub throughput_ops_per_sec: f64,
+    }
+
+    /// Machine-readable benchmark summary, plus a latency CDF export
+    /// (`(latency_ms, cumulative_fraction)` pairs, ascending) for plotting.
+    #[derive(Debug, Clone, Serialize, Deserialize)]
+    pub struct BenchReport {
+        pub total_duration_ms: f64,
+        pub operations: HashMap<String, OperationStats>,
+        pub latency_cdf: Vec<(f64, f64)>,
+    }
+
+    impl BenchReport {
+        pub fn to_json(&self) -> Result<String> {
+            serde_json::to_string_pretty(self).map_err(FileAwareError::from)
+        }
+    }
+
+    fn percentile(sorted_ms: &[f64], p: f64) -> f64 {
+        if sorted_ms.is_empty() {
+            return 0.0;
+        }
+        let rank = ((p / 100.0) * (sorted_ms.len() - 1) as f64).round() as usize;
+        sorted_ms[rank.min(sorted_ms.len() - 1)]
+    }
+
+    /// Run `workload` against `manager`, recording per-operation latencies,
+    /// then summarize them into percentiles, throughput, and a CDF.
+    pub async fn run(manager: &mut DatabaseManager, workload: &Workload) -> Result<BenchReport> {
+        let mut rng = Xorshift64Star::new(workload.seed);
+        let weights = [
+            (BenchOperation::Insert, workload.mix.insert),
+            (BenchOperation::Get, workload.mix.get),
+            (BenchOperation::Update, workload.mix.update),
+            (BenchOperation::Delete, workload.mix.delete),
+            (BenchOperation::VectorSearch, workload.mix.vector_search),
+            (BenchOperation::GraphTraversal, workload.mix.graph_traversal),
+        ];
+
+        let mut latencies: HashMap<BenchOperation, Vec<f64>> = HashMap::new();
+        let mut errors: HashMap<BenchOperation, usize> = HashMap::new();
+        let mut inserted_ids: Vec<String> = Vec::new();
+
+        let start = Instant::now();
+        for i in 0..workload.operation_count {
+            let op = rng.choose(&weights);
+            let key_size = workload.key_size.sample(&mut rng);
+            let value_size = workload.value_size.sample(&mut rng);
+            let id = format!("bench-{}-{}", i, key_size);
+            let content = "x".repeat(value_size.max(1));
+
+            let op_start = Instant::now();
+            let result: Result<()> = match op {
+                BenchOperation::Insert => manager.store_analysis(bench_record(&id, &content)).await.map(|_| ()),
+                BenchOperation::Get => {
+                    let query = CodeAnalysisQuery {
+                        file_path_pattern: inserted_ids.last().cloned(),
+                        language_filter: None,
+                        quality_threshold: None,
+                        complexity_range: None,
+                        date_range: None,
+                        limit: Some(1),
+                        offset: None,
+                    };
+                    manager.query_analysis(query).await.map(|_| ())
+                }
+                BenchOperation::Update => match inserted_ids.last() {
+                    Some(target) => {
+                        let mut updates = HashMap::new();
+                        updates.insert("quality_score".to_string(), serde_json::json!(1.0));
+                        manager.update_analysis(target, updates).await.map(|_| ())
+                    }
+                    None => Ok(()),
+                },
+                BenchOperation::Delete => match inserted_ids.pop() {
+                    Some(target) => manager.delete_analysis(&target).await.map(|_| ()),
+                    None => Ok(()),
+                },
+                BenchOperation::VectorSearch => {
+                    let query_vector = vec![0.1_f32; 8];
+                    manager.search_similar_vectors(&query_vector, 10).map(|_| ())
+                }
+                BenchOperation::GraphTraversal => Err(FileAwareError::DatabaseError {
+                    message: "graph traversal benchmarking requires a Kuzu connection, which is not wired in this build".to_string(),
+                }),
+            };
+            let elapsed_ms = op_start.elapsed().as_secs_f64() * 1000.0;
+
+            if result.is_ok() && matches!(op, BenchOperation::Insert) {
+                inserted_ids.push(id);
+            }
+            if result.is_err() {
+                *errors.entry(op).or_insert(0) += 1;
+            }
+            latencies.entry(op).or_default().push(elapsed_ms);
+        }
+        let total_duration = start.elapsed();
+
+        let mut operations = HashMap::new();
+        let mut all_latencies: Vec<f64> = Vec::new();
+        for (op, mut samples) in latencies {
+            samples.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            all_latencies.extend(samples.iter().copied());
+
+            let total_ms: f64 = samples.iter().sum();
+            let throughput = if total_ms > 0.0 { samples.len() as f64 / (total_ms / 1000.0) } else { 0.0 };
+
+            operations.insert(
+                format!("{:?}", op),
+                OperationStats {
+                    count: samples.len(),
+                    errors: errors.get(&op).copied().unwrap_or(0),
+                    p50_ms: percentile(&samples, 50.0),
+                    p95_ms: percentile(&samples, 95.0),
+                    p99_ms: percentile(&samples, 99.0),
+                    throughput_ops_per_sec: throughput,
+                },
+            );
+        }
+
+        all_latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let total = all_latencies.len().max(1);
+        let latency_cdf = all_latencies
+            .iter()
+            .enumerate()
+            .map(|(i, &latency)| (latency, (i + 1) as f64 / total as f64))
+            .collect();
+
+        Ok(BenchReport {
+            total_duration_ms: total_duration.as_secs_f64() * 1000.0,
+            operations,
+            latency_cdf,
+        })
+    }
+
+    fn bench_record(id: &str, content: &str) -> CodeAnalysisRecord {
+        CodeAnalysisRecord {
+            id: id.to_string(),
+            file_path: format!("{}.bench", id),
+            language: "bench".to_string(),
+            complexity_score: 0.0,
+            quality_score: 0.0,
+            ai_mistake_score: 0.0,
+            littering_score: 0.0,
+            analysis_timestamp: 0,
+            features: CodeFeatures {
+                cyclomatic_complexity: 0.0,
+                maintainability_index: 0.0,
+                lines_of_code: content.len(),
+                function_count: 0,
+                import_count: 0,
+                nesting_depth: 0,
+                halstead_metrics: HalsteadMetrics {
+                    vocabulary: 0.0,
+                    length: 0.0,
+                    volume: 0.0,
+                    difficulty: 0.0,
+                    effort: 0.0,
+                    time: 0.0,
+                    bugs: 0.0,
+                },
+            },
+            suggestions: Vec::new(),
+        }
+    }
+}
+
 // Error types for database operations
 impl From<serde_json::Error> for FileAwareError {
     fn from(error: serde_json::Error) -> Self {
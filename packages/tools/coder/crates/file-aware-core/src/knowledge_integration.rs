@@ -12,6 +12,7 @@
 use crate::{Result, FileAwareError, FileAnalyzer, DatabaseManager};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 
 // TODO: Add knowledge type validation
 // TODO: Implement knowledge type optimization
@@ -38,13 +39,39 @@ pub enum KnowledgeType {
     },
 }
 
+// TODO: Add knowledge query validation
+// TODO: Implement knowledge query batching
+// TODO: Add knowledge query performance monitoring
+// TODO: Consider implementing knowledge query templates
+
+/// A request to one of the two knowledge systems (RAG or FACT); which one
+/// is determined by `knowledge_type`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgeQuery {
+    pub knowledge_type: KnowledgeType,
+}
+
+// TODO: Add knowledge result validation
+// TODO: Implement knowledge result ranking
+// TODO: Add knowledge result performance monitoring
+// TODO: Consider implementing knowledge result caching
+
+/// A knowledge-system response: retrieved/verified content plus a
+/// confidence signal and the sources it was drawn from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgeResult {
+    pub content: String,
+    pub confidence: f32,
+    pub sources: Vec<String>,
+}
+
 // TODO: Add fact type validation
 // TODO: Implement fact type optimization
 // TODO: Add fact type performance monitoring
 // TODO: Consider implementing fact type templates
 
 /// Types of facts for FACT system
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FactType {
     /// Code patterns and best practices
     CodePattern,
@@ -131,12 +158,188 @@ pub struct KnowledgeIntegration {
     
     // Brain system integration
     brain_integration: BrainIntegration,
+
+    /// Query observability pipeline, present only when built via
+    /// `with_telemetry`; `query_knowledge` is a plain passthrough
+    /// otherwise. A `Mutex` rather than `&mut self` so instrumentation
+    /// doesn't change the method's existing shared-reference signature.
+    telemetry: Option<std::sync::Mutex<TelemetryCollector>>,
     // TODO: Add more integration fields:
     // knowledge_cache: KnowledgeCache,
     // performance_monitor: PerformanceMonitor,
     // access_controller: AccessController,
 }
 
+// TODO: Add telemetry sink validation
+// TODO: Implement telemetry sink retries
+// TODO: Add telemetry sink performance monitoring
+// TODO: Consider implementing telemetry sink batching formats
+
+/// Where `KnowledgeIntegration`'s query telemetry goes once it's flushed
+/// out of the in-memory buffer.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum TelemetrySink {
+    /// Print each event as a JSON line.
+    Stdout,
+    /// Append JSON lines to a file.
+    File(PathBuf),
+    /// POST a JSON batch to an external collector.
+    // TODO: Actually POST this once an HTTP client dependency is
+    // available to this crate; for now the events are just dropped.
+    Push(String),
+}
+
+// TODO: Add telemetry config validation
+// TODO: Implement telemetry config hot reload
+// TODO: Add telemetry config performance monitoring
+// TODO: Consider implementing telemetry config templates
+
+/// Telemetry configuration for `KnowledgeIntegration::with_telemetry`:
+/// where events go, and how many to buffer before flushing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TelemetryConfig {
+    pub sinks: Vec<TelemetrySink>,
+    pub flush_threshold: usize,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            sinks: vec![TelemetrySink::Stdout],
+            flush_threshold: 100,
+        }
+    }
+}
+
+// TODO: Add knowledge query event validation
+// TODO: Implement knowledge query event batching
+// TODO: Add knowledge query event performance monitoring
+// TODO: Consider implementing knowledge query event sampling
+
+/// One instrumented `query_knowledge` call.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnowledgeQueryEvent {
+    pub query_type: String,
+    pub latency_ms: u64,
+    pub vectors_scanned: usize,
+    pub cache_hit: bool,
+    pub similarity_scores: Vec<f32>,
+    pub verification_level: Option<VerificationLevel>,
+}
+
+// TODO: Add metrics snapshot validation
+// TODO: Implement metrics snapshot history
+// TODO: Add metrics snapshot performance monitoring
+// TODO: Consider implementing metrics snapshot export formats
+
+/// Aggregated view of buffered `KnowledgeQueryEvent`s, returned by
+/// `KnowledgeIntegration::drain_metrics`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricsSnapshot {
+    pub query_count: usize,
+    pub p50_latency_ms: u64,
+    pub p95_latency_ms: u64,
+    pub per_query_type_hit_rate: HashMap<String, f32>,
+}
+
+// TODO: Add telemetry collector validation
+// TODO: Implement telemetry collector backpressure
+// TODO: Add telemetry collector performance monitoring
+// TODO: Consider implementing telemetry collector sharding
+
+/// Buffers `KnowledgeQueryEvent`s and aggregates them into a
+/// `MetricsSnapshot` on demand, flushing raw events to `sinks` once the
+/// buffer passes `flush_threshold` -- an agent-style collector that can
+/// run inline at low query volume or batch at high volume without
+/// changing `query_knowledge`'s call sites.
+pub struct TelemetryCollector {
+    config: TelemetryConfig,
+    events: Vec<KnowledgeQueryEvent>,
+}
+
+impl TelemetryCollector {
+    fn new(config: TelemetryConfig) -> Self {
+        Self {
+            config,
+            events: Vec::new(),
+        }
+    }
+
+    fn record(&mut self, event: KnowledgeQueryEvent) {
+        self.events.push(event);
+        if self.events.len() >= self.config.flush_threshold {
+            self.flush();
+        }
+    }
+
+    fn flush(&mut self) {
+        for sink in &self.config.sinks {
+            match sink {
+                TelemetrySink::Stdout => {
+                    for event in &self.events {
+                        if let Ok(line) = serde_json::to_string(event) {
+                            println!("{line}");
+                        }
+                    }
+                }
+                TelemetrySink::File(path) => {
+                    // TODO: Reuse one open file handle instead of
+                    // re-opening it on every flush.
+                    if let Ok(mut file) = std::fs::OpenOptions::new().create(true).append(true).open(path) {
+                        use std::io::Write;
+                        for event in &self.events {
+                            if let Ok(line) = serde_json::to_string(event) {
+                                let _ = writeln!(file, "{line}");
+                            }
+                        }
+                    }
+                }
+                TelemetrySink::Push(_url) => {}
+            }
+        }
+        self.events.clear();
+    }
+
+    /// Aggregate whatever events are currently buffered into a snapshot,
+    /// without flushing them to `sinks` or clearing the buffer.
+    fn snapshot(&self) -> MetricsSnapshot {
+        if self.events.is_empty() {
+            return MetricsSnapshot::default();
+        }
+
+        let mut latencies: Vec<u64> = self.events.iter().map(|e| e.latency_ms).collect();
+        latencies.sort_unstable();
+
+        let mut hits: HashMap<String, (usize, usize)> = HashMap::new();
+        for event in &self.events {
+            let entry = hits.entry(event.query_type.clone()).or_insert((0, 0));
+            entry.1 += 1;
+            if event.cache_hit {
+                entry.0 += 1;
+            }
+        }
+        let per_query_type_hit_rate = hits
+            .into_iter()
+            .map(|(query_type, (hit, total))| (query_type, hit as f32 / total as f32))
+            .collect();
+
+        MetricsSnapshot {
+            query_count: self.events.len(),
+            p50_latency_ms: percentile(&latencies, 50),
+            p95_latency_ms: percentile(&latencies, 95),
+            per_query_type_hit_rate,
+        }
+    }
+}
+
+fn percentile(sorted: &[u64], pct: usize) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let index = (sorted.len() * pct / 100).min(sorted.len() - 1);
+    sorted[index]
+}
+
 // TODO: Add RAG system validation
 // TODO: Implement RAG system optimization
 // TODO: Add RAG system performance monitoring
@@ -195,12 +398,155 @@ pub struct VectorStore {
     backend: String,
     dimensions: usize,
     similarity_metric: String,
+
+    /// Per-document bookkeeping (file hash, index version, owned vector
+    /// ids), keyed by source file path. Lets `reindex_changed` tell an
+    /// unseen file from one that just needs its vectors replaced.
+    documents: HashMap<PathBuf, IndexedDocument>,
+
+    /// Embedded chunks, keyed by a vector id unique within `documents`.
+    vectors: HashMap<String, StoredVector>,
     // TODO: Add more vector store fields:
     // index_type: IndexType,
     // compression: Compression,
     // sharding: Sharding,
 }
 
+impl Default for VectorStore {
+    fn default() -> Self {
+        Self {
+            backend: "in-memory".to_string(),
+            dimensions: 0,
+            similarity_metric: "cosine".to_string(),
+            documents: HashMap::new(),
+            vectors: HashMap::new(),
+        }
+    }
+}
+
+impl VectorStore {
+    /// Replace `path`'s vectors with freshly embedded `chunks`, bumping its
+    /// `index_version` so any previously-returned vector ids for this
+    /// document are now stale. Returns `(vectors_added, vectors_updated)`,
+    /// i.e. whether this was a brand-new document or a re-index.
+    fn upsert_document(&mut self, path: &Path, file_hash: String, chunks: Vec<Vec<f32>>) -> (usize, usize) {
+        let is_new = !self.documents.contains_key(path);
+        let previous_version = self.documents.get(path).map(|doc| doc.index_version).unwrap_or(0);
+        self.remove_document(path);
+
+        let index_version = previous_version + 1;
+        let mut vector_ids = Vec::with_capacity(chunks.len());
+        for (chunk_index, embedding) in chunks.into_iter().enumerate() {
+            let vector_id = format!("{}#{}#{}", path.display(), index_version, chunk_index);
+            self.vectors.insert(
+                vector_id.clone(),
+                StoredVector {
+                    document: path.to_path_buf(),
+                    index_version,
+                    embedding,
+                },
+            );
+            vector_ids.push(vector_id);
+        }
+        let vector_count = vector_ids.len();
+
+        self.documents.insert(
+            path.to_path_buf(),
+            IndexedDocument {
+                file_hash,
+                index_version,
+                vector_ids,
+            },
+        );
+
+        if is_new {
+            (vector_count, 0)
+        } else {
+            (0, vector_count)
+        }
+    }
+
+    /// Drop every vector belonging to `path`. Returns how many were removed.
+    fn remove_document(&mut self, path: &Path) -> usize {
+        let Some(doc) = self.documents.remove(path) else {
+            return 0;
+        };
+        for vector_id in &doc.vector_ids {
+            self.vectors.remove(vector_id);
+        }
+        doc.vector_ids.len()
+    }
+
+    /// Whether `vector_id`'s source document has since been re-indexed to a
+    /// newer version -- i.e. this vector predates the file's current
+    /// on-disk content and `query_rag_system` should skip it.
+    fn is_stale(&self, vector_id: &str) -> bool {
+        let Some(vector) = self.vectors.get(vector_id) else {
+            return true;
+        };
+        self.documents
+            .get(&vector.document)
+            .map(|doc| doc.index_version != vector.index_version)
+            .unwrap_or(true)
+    }
+}
+
+// TODO: Add indexed document validation
+// TODO: Implement indexed document compaction
+// TODO: Add indexed document performance monitoring
+// TODO: Consider implementing indexed document versioning
+
+/// Bookkeeping for one indexed file: the hash it was last embedded from,
+/// a version bumped on every re-index, and the vector ids it currently owns.
+#[derive(Debug, Clone)]
+struct IndexedDocument {
+    file_hash: String,
+    index_version: u64,
+    vector_ids: Vec<String>,
+}
+
+// TODO: Add stored vector validation
+// TODO: Implement stored vector compression
+// TODO: Add stored vector performance monitoring
+// TODO: Consider implementing stored vector clustering
+
+/// One embedded chunk, plus enough provenance to tell whether it's still
+/// current for its source document.
+#[derive(Debug, Clone)]
+struct StoredVector {
+    document: PathBuf,
+    index_version: u64,
+    embedding: Vec<f32>,
+}
+
+// TODO: Add file change event validation
+// TODO: Implement file change event batching
+// TODO: Add file change event performance monitoring
+// TODO: Consider implementing file change event debouncing
+
+/// One file-change notification from a `FileAnalyzer` change stream,
+/// mirroring the add/modify/remove shape the `notify`-based watcher
+/// elsewhere in this workspace already uses.
+#[derive(Debug, Clone)]
+pub enum FileChangeEvent {
+    Added(PathBuf),
+    Modified(PathBuf),
+    Removed(PathBuf),
+}
+
+// TODO: Add reindex report validation
+// TODO: Implement reindex report aggregation
+// TODO: Add reindex report performance monitoring
+// TODO: Consider implementing reindex report history
+
+/// Counts returned by `KnowledgeIntegration::reindex_changed`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct ReindexReport {
+    pub vectors_added: usize,
+    pub vectors_updated: usize,
+    pub vectors_removed: usize,
+}
+
 // TODO: Add embedding service validation
 // TODO: Implement embedding service optimization
 // TODO: Add embedding service performance monitoring
@@ -217,6 +563,63 @@ pub struct EmbeddingService {
     // cache_enabled: bool,
 }
 
+impl Default for EmbeddingService {
+    fn default() -> Self {
+        Self {
+            model: "placeholder".to_string(),
+            dimensions: 32,
+            batch_size: 256,
+        }
+    }
+}
+
+impl EmbeddingService {
+    /// Split `content` into `batch_size`-byte chunks and produce one
+    /// embedding per chunk.
+    // TODO: Replace this byte-folding placeholder with a real embedding
+    // model call once one is wired into this crate; it exists so the
+    // incremental re-indexing pipeline (add/update/remove bookkeeping,
+    // staleness detection) can be exercised end to end today.
+    fn embed_chunks(&self, content: &str) -> Vec<Vec<f32>> {
+        if content.is_empty() {
+            return Vec::new();
+        }
+        content
+            .as_bytes()
+            .chunks(self.batch_size.max(1))
+            .map(|chunk| {
+                let mut vector = vec![0.0f32; self.dimensions.max(1)];
+                for (i, byte) in chunk.iter().enumerate() {
+                    vector[i % vector.len()] += *byte as f32 / 255.0;
+                }
+                vector
+            })
+            .collect()
+    }
+}
+
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+    if a.is_empty() || b.is_empty() || a.len() != b.len() {
+        return 0.0;
+    }
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+    let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm_a == 0.0 || norm_b == 0.0 {
+        0.0
+    } else {
+        dot / (norm_a * norm_b)
+    }
+}
+
+pub(crate) fn content_hash(content: &str) -> String {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let mut hasher = DefaultHasher::new();
+    content.hash(&mut hasher);
+    format!("{:x}", hasher.finish())
+}
+
 // TODO: Add semantic index validation
 // TODO: Implement semantic index optimization
 // TODO: Add semantic index performance monitoring
@@ -243,12 +646,324 @@ pub struct FactDatabase {
     backend: String,
     schema: FactSchema,
     indexing: FactIndexing,
+
+    /// RocksDB handle for the fact index: keys encode `FactKey`, values are
+    /// serialized `FactMetadata`. `None` when `indexing` has never been
+    /// opened against a real store (e.g. a database built with `new()`
+    /// rather than `open()`).
+    index_db: Option<rocksdb::DB>,
+
+    /// Root directory for the filesystem blob store, used when `indexing`
+    /// is `FactIndexing::FilesystemBlobStore`: payloads live under
+    /// `blob_root/domain/content_hash`.
+    blob_root: PathBuf,
+
+    /// Serializes `next_index`'s seek against `store_fact`'s write so two
+    /// concurrent callers can't both read the same "last" key and assign the
+    /// same `monotonic_index`, silently overwriting each other's entry.
+    write_lock: std::sync::Mutex<()>,
     // TODO: Add more fact database fields:
     // version: String,
     // backup_strategy: BackupStrategy,
     // replication: Replication,
 }
 
+// TODO: Add fact schema validation
+// TODO: Implement fact schema migrations
+// TODO: Add fact schema performance monitoring
+// TODO: Consider implementing fact schema versioning
+
+/// Column naming for the fact index: one column family for the index
+/// itself, one for the per-`(domain, fact_type)` monotonic index counters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactSchema {
+    pub index_cf: String,
+    pub counter_cf: String,
+}
+
+impl Default for FactSchema {
+    fn default() -> Self {
+        Self {
+            index_cf: "fact_index".to_string(),
+            counter_cf: "fact_counters".to_string(),
+        }
+    }
+}
+
+// TODO: Add fact indexing validation
+// TODO: Implement fact indexing optimization
+// TODO: Add fact indexing performance monitoring
+// TODO: Consider implementing fact indexing clustering
+
+/// Where large fact payloads (code snippets, doc text, advisory bodies)
+/// are stored, kept separate from the index so range scans over
+/// `FactKey`s stay cheap.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum FactIndexing {
+    /// Blobs live in a BlobDB-style RocksDB column family.
+    // TODO: Wire this variant up to a real BlobDB column family; for now
+    // it falls back to the filesystem layout.
+    BlobColumnFamily,
+    /// Blobs live on disk under `blob_root/domain/content_hash`.
+    FilesystemBlobStore,
+}
+
+// TODO: Add fact key validation
+// TODO: Implement fact key compaction
+// TODO: Add fact key performance monitoring
+// TODO: Consider implementing fact key range sharding
+
+/// Composite key identifying one verified fact: `(domain, fact_type,
+/// monotonic_index)`. Encoded as `domain\0fact_type\0index` with the index
+/// in big-endian bytes, so a RocksDB prefix scan over `domain`/`fact_type`
+/// returns facts in insertion order without a separate secondary index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FactKey {
+    pub domain: String,
+    pub fact_type: FactType,
+    pub monotonic_index: u64,
+}
+
+impl FactKey {
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = Self::prefix(&self.domain, &self.fact_type);
+        bytes.extend_from_slice(&self.monotonic_index.to_be_bytes());
+        bytes
+    }
+
+    fn prefix(domain: &str, fact_type: &FactType) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(domain.len() + 16);
+        bytes.extend_from_slice(domain.as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(fact_type_key_segment(fact_type).as_bytes());
+        bytes.push(0);
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let mut parts = bytes.splitn(3, |b| *b == 0);
+        let domain = std::str::from_utf8(parts.next()?).ok()?.to_string();
+        let fact_type_segment = std::str::from_utf8(parts.next()?).ok()?;
+        let index_bytes = parts.next()?;
+        let monotonic_index = u64::from_be_bytes(index_bytes.try_into().ok()?);
+        Some(Self {
+            domain,
+            fact_type: fact_type_from_key_segment(fact_type_segment)?,
+            monotonic_index,
+        })
+    }
+}
+
+fn fact_type_key_segment(fact_type: &FactType) -> &'static str {
+    match fact_type {
+        FactType::CodePattern => "code_pattern",
+        FactType::ArchitecturalDecision => "architectural_decision",
+        FactType::SecurityVulnerability => "security_vulnerability",
+        FactType::PerformanceOptimization => "performance_optimization",
+        FactType::DependencyInfo => "dependency_info",
+        FactType::APIDocumentation => "api_documentation",
+        FactType::TestingStrategy => "testing_strategy",
+        FactType::DeploymentInfo => "deployment_info",
+    }
+}
+
+fn fact_type_from_key_segment(segment: &str) -> Option<FactType> {
+    Some(match segment {
+        "code_pattern" => FactType::CodePattern,
+        "architectural_decision" => FactType::ArchitecturalDecision,
+        "security_vulnerability" => FactType::SecurityVulnerability,
+        "performance_optimization" => FactType::PerformanceOptimization,
+        "dependency_info" => FactType::DependencyInfo,
+        "api_documentation" => FactType::APIDocumentation,
+        "testing_strategy" => FactType::TestingStrategy,
+        "deployment_info" => FactType::DeploymentInfo,
+        _ => return None,
+    })
+}
+
+// TODO: Add fact metadata validation
+// TODO: Implement fact metadata compaction
+// TODO: Add fact metadata performance monitoring
+// TODO: Consider implementing fact metadata versioning
+
+/// Compact metadata stored in the fact index. The full payload (code
+/// snippet, doc text, advisory body) lives in the blob store instead, so
+/// `query_fact_range` can scan a whole domain without paging it in.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FactMetadata {
+    pub verification_level: VerificationLevel,
+    pub source_authority: SourceAuthority,
+    pub trust_score: f32,
+    pub content_hash: String,
+}
+
+// TODO: Add blob validation
+// TODO: Implement blob compression
+// TODO: Add blob performance monitoring
+// TODO: Consider implementing blob deduplication
+
+/// A large fact payload, addressed by `content_hash` and stored separately
+/// from the index.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Blob {
+    pub content_hash: String,
+    pub content: Vec<u8>,
+}
+
+impl FactDatabase {
+    /// Open (creating if missing) the RocksDB-backed fact index at
+    /// `index_path`, with blob payloads written under `blob_root`.
+    pub fn open(index_path: &Path, blob_root: PathBuf) -> Result<Self> {
+        let mut opts = rocksdb::Options::default();
+        opts.create_if_missing(true);
+        let index_db = rocksdb::DB::open(&opts, index_path).map_err(|e| FileAwareError::Io {
+            message: format!("Failed to open fact index at {index_path:?}: {e}"),
+        })?;
+        std::fs::create_dir_all(&blob_root).map_err(|e| FileAwareError::Io {
+            message: format!("Failed to create blob store at {blob_root:?}: {e}"),
+        })?;
+
+        Ok(Self {
+            backend: "rocksdb".to_string(),
+            schema: FactSchema::default(),
+            indexing: FactIndexing::FilesystemBlobStore,
+            index_db: Some(index_db),
+            blob_root,
+            write_lock: std::sync::Mutex::new(()),
+        })
+    }
+
+    fn db(&self) -> Result<&rocksdb::DB> {
+        self.index_db.as_ref().ok_or_else(|| FileAwareError::Analysis {
+            message: "Fact database was not opened against a RocksDB store".to_string(),
+        })
+    }
+
+    /// Next `monotonic_index` for `(domain, fact_type)`, derived from the
+    /// highest key currently in that prefix rather than a separate counter
+    /// column family -- simpler, at the cost of an extra seek per store.
+    // TODO: Move this to the `counter_cf` named in `FactSchema` once the
+    // column-family plumbing is wired up, to avoid the seek.
+    fn next_index(&self, domain: &str, fact_type: &FactType) -> Result<u64> {
+        let prefix = FactKey::prefix(domain, fact_type);
+        let db = self.db()?;
+        let mut iter = db.prefix_iterator(&prefix);
+        let last = iter.next_back();
+        match last {
+            Some(Ok((key, _))) => Ok(FactKey::decode(&key).map(|k| k.monotonic_index + 1).unwrap_or(0)),
+            _ => Ok(0),
+        }
+    }
+
+    /// Persist a verified fact: the compact `metadata` goes into the
+    /// RocksDB index under a freshly assigned `FactKey`, the (possibly
+    /// large) `payload` goes into the blob store keyed by its content hash.
+    pub fn store_fact(
+        &self,
+        domain: &str,
+        fact_type: FactType,
+        metadata: FactMetadata,
+        payload: &[u8],
+    ) -> Result<FactKey> {
+        // Hold the lock across the seek-for-next-index and the put that
+        // claims it, so two concurrent `store_fact` calls for the same
+        // `(domain, fact_type)` can't both observe the same "last" key and
+        // assign the same `monotonic_index`.
+        let _guard = self.write_lock.lock().map_err(|_| FileAwareError::Analysis {
+            message: "Fact database write lock was poisoned by a panicked writer".to_string(),
+        })?;
+
+        let monotonic_index = self.next_index(domain, &fact_type)?;
+        let key = FactKey {
+            domain: domain.to_string(),
+            fact_type,
+            monotonic_index,
+        };
+
+        self.write_blob(domain, &metadata.content_hash, payload)?;
+
+        let value = serde_json::to_vec(&metadata).map_err(|e| FileAwareError::Analysis {
+            message: format!("Failed to serialize fact metadata: {e}"),
+        })?;
+        self.db()?.put(key.encode(), value).map_err(|e| FileAwareError::Io {
+            message: format!("Failed to write fact index entry: {e}"),
+        })?;
+
+        Ok(key)
+    }
+
+    /// Range/prefix scan over every fact in `domain`/`fact_type` whose
+    /// `monotonic_index` falls in `index_range`, e.g. "all security
+    /// advisories for domain X added since index N". Blob bodies are only
+    /// loaded when `load_blobs` is set, so a caller that just wants to know
+    /// what changed doesn't pay for reading every payload.
+    pub fn query_fact_range(
+        &self,
+        domain: &str,
+        fact_type: FactType,
+        index_range: std::ops::Range<u64>,
+        load_blobs: bool,
+    ) -> Result<Vec<(FactKey, Option<Blob>)>> {
+        let prefix = FactKey::prefix(domain, &fact_type);
+        let db = self.db()?;
+        let mut results = Vec::new();
+
+        for entry in db.prefix_iterator(&prefix) {
+            let (key_bytes, value_bytes) = entry.map_err(|e| FileAwareError::Io {
+                message: format!("Failed to scan fact index: {e}"),
+            })?;
+            let Some(key) = FactKey::decode(&key_bytes) else {
+                continue;
+            };
+            if !index_range.contains(&key.monotonic_index) {
+                continue;
+            }
+
+            let blob = if load_blobs {
+                let metadata: FactMetadata = serde_json::from_slice(&value_bytes).map_err(|e| {
+                    FileAwareError::Analysis {
+                        message: format!("Failed to parse fact metadata: {e}"),
+                    }
+                })?;
+                Some(self.load_blob(domain, &metadata.content_hash)?)
+            } else {
+                None
+            };
+
+            results.push((key, blob));
+        }
+
+        Ok(results)
+    }
+
+    fn blob_path(&self, domain: &str, content_hash: &str) -> PathBuf {
+        self.blob_root.join(domain).join(content_hash)
+    }
+
+    fn write_blob(&self, domain: &str, content_hash: &str, payload: &[u8]) -> Result<()> {
+        let path = self.blob_path(domain, content_hash);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| FileAwareError::Io {
+                message: format!("Failed to create blob directory {parent:?}: {e}"),
+            })?;
+        }
+        std::fs::write(&path, payload).map_err(|e| FileAwareError::Io {
+            message: format!("Failed to write blob {path:?}: {e}"),
+        })
+    }
+
+    fn load_blob(&self, domain: &str, content_hash: &str) -> Result<Blob> {
+        let path = self.blob_path(domain, content_hash);
+        let content = std::fs::read(&path).map_err(|e| FileAwareError::Io {
+            message: format!("Failed to read blob {path:?}: {e}"),
+        })?;
+        Ok(Blob {
+            content_hash: content_hash.to_string(),
+            content,
+        })
+    }
+}
+
 // TODO: Add verification engine validation
 // TODO: Implement verification engine optimization
 // TODO: Add verification engine performance monitoring
@@ -265,6 +980,160 @@ pub struct VerificationEngine {
     // performance_optimizer: PerformanceOptimizer,
 }
 
+// TODO: Add verification rule validation
+// TODO: Implement verification rule compilation
+// TODO: Add verification rule performance monitoring
+// TODO: Consider implementing verification rule templates
+
+/// One check `verify_fact`'s rule-evaluation stage runs against a fact.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum VerificationRule {
+    /// Source's aggregate trust score must be at least this.
+    MinTrustScore(f32),
+    /// Source must carry at least this authority level.
+    RequireAuthority(SourceAuthority),
+    /// Source must not appear on `SourceValidator`'s blacklist.
+    NotBlacklisted,
+}
+
+// TODO: Add review process validation
+// TODO: Implement review process automation
+// TODO: Add review process performance monitoring
+// TODO: Consider implementing review process escalation
+
+/// Manual-escalation policy for facts the automated stages can't
+/// confidently verify or reject on their own.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewProcess {
+    /// Facts scoring at or above this (but below `confidence_threshold`)
+    /// are escalated instead of auto-rejected.
+    pub escalation_threshold: f32,
+    pub reviewers: Vec<String>,
+}
+
+// TODO: Add stage name validation
+// TODO: Implement stage name sequencing
+// TODO: Add stage name performance monitoring
+// TODO: Consider implementing stage name parallelization
+
+/// One stage of `verify_fact`'s pipeline, in run order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StageName {
+    SourceValidation,
+    RuleEvaluation,
+    ConfidenceScoring,
+    ReviewEscalation,
+}
+
+// TODO: Add verification context validation
+// TODO: Implement verification context caching
+// TODO: Add verification context performance monitoring
+// TODO: Consider implementing verification context sharing
+
+/// The source-side facts `verify_fact`'s stages need about whoever
+/// supplied a fact, reused across all four stages of one verification run
+/// instead of re-deriving it per stage.
+#[derive(Debug, Clone)]
+pub struct VerificationContext {
+    pub source_id: String,
+    pub authority: SourceAuthority,
+}
+
+/// The result of running a fact through `VerificationEngine::verify_fact`:
+/// the assigned level, which stage (if any) rejected it, and a per-stage
+/// timing breakdown for profiling slow stages.
+#[derive(Debug, Clone)]
+pub struct VerificationOutcome {
+    pub verified: bool,
+    pub level: VerificationLevel,
+    pub failed_stage: Option<StageName>,
+    pub stage_timings: Vec<(StageName, std::time::Duration)>,
+}
+
+impl VerificationEngine {
+    /// Run `fact` through source validation, rule evaluation, confidence
+    /// scoring, and (if still undecided) review escalation, in that order.
+    /// A hard failure at any stage short-circuits the rest -- there's no
+    /// point confidence-scoring a blacklisted source -- but every stage
+    /// that did run still has its elapsed time recorded in the outcome.
+    pub fn verify_fact(
+        &self,
+        fact: &FactMetadata,
+        ctx: &VerificationContext,
+        source_validator: &SourceValidator,
+    ) -> VerificationOutcome {
+        let mut stage_timings = Vec::with_capacity(4);
+
+        let (source_ok, elapsed) = Self::timed(|| source_validator.validate(&ctx.source_id, &ctx.authority));
+        stage_timings.push((StageName::SourceValidation, elapsed));
+        if !source_ok {
+            return VerificationOutcome {
+                verified: false,
+                level: VerificationLevel::Unverified,
+                failed_stage: Some(StageName::SourceValidation),
+                stage_timings,
+            };
+        }
+
+        let (rules_ok, elapsed) = Self::timed(|| self.evaluate_rules(fact, ctx, source_validator));
+        stage_timings.push((StageName::RuleEvaluation, elapsed));
+        if !rules_ok {
+            return VerificationOutcome {
+                verified: false,
+                level: VerificationLevel::Unverified,
+                failed_stage: Some(StageName::RuleEvaluation),
+                stage_timings,
+            };
+        }
+
+        let (confidence, elapsed) = Self::timed(|| fact.trust_score);
+        stage_timings.push((StageName::ConfidenceScoring, elapsed));
+        if confidence >= self.confidence_threshold {
+            return VerificationOutcome {
+                verified: true,
+                level: fact.verification_level.clone(),
+                failed_stage: None,
+                stage_timings,
+            };
+        }
+
+        let (escalated, elapsed) = Self::timed(|| confidence >= self.review_process.escalation_threshold);
+        stage_timings.push((StageName::ReviewEscalation, elapsed));
+
+        VerificationOutcome {
+            verified: escalated,
+            level: if escalated {
+                VerificationLevel::CommunityVerified
+            } else {
+                VerificationLevel::Unverified
+            },
+            failed_stage: if escalated { None } else { Some(StageName::ReviewEscalation) },
+            stage_timings,
+        }
+    }
+
+    fn evaluate_rules(
+        &self,
+        fact: &FactMetadata,
+        ctx: &VerificationContext,
+        source_validator: &SourceValidator,
+    ) -> bool {
+        self.verification_rules.iter().all(|rule| match rule {
+            VerificationRule::MinTrustScore(min) => fact.trust_score >= *min,
+            VerificationRule::RequireAuthority(required) => {
+                std::mem::discriminant(&ctx.authority) == std::mem::discriminant(required)
+            }
+            VerificationRule::NotBlacklisted => !source_validator.blacklist.contains(&ctx.source_id),
+        })
+    }
+
+    fn timed<T>(f: impl FnOnce() -> T) -> (T, std::time::Duration) {
+        let start = std::time::Instant::now();
+        let result = f();
+        (result, start.elapsed())
+    }
+}
+
 // TODO: Add source validator validation
 // TODO: Implement source validator optimization
 // TODO: Add source validator performance monitoring
@@ -281,6 +1150,25 @@ pub struct SourceValidator {
     // risk_assessor: RiskAssessor,
 }
 
+impl SourceValidator {
+    /// Authority lookup, trust-score check, and blacklist check in one
+    /// pass -- the first stage of `VerificationEngine::verify_fact`. An
+    /// unknown source (no recorded authority or trust score) is allowed
+    /// through rather than rejected outright; `RequireAuthority` rules
+    /// still catch it downstream if the caller's claimed authority matters.
+    fn validate(&self, source_id: &str, authority: &SourceAuthority) -> bool {
+        if self.blacklist.contains(&source_id.to_string()) {
+            return false;
+        }
+        if let Some(known_authority) = self.authority_levels.get(source_id) {
+            if std::mem::discriminant(known_authority) != std::mem::discriminant(authority) {
+                return false;
+            }
+        }
+        self.trust_scores.get(source_id).copied().unwrap_or(1.0) > 0.0
+    }
+}
+
 // TODO: Add neural coordinator validation
 // TODO: Implement neural coordinator optimization
 // TODO: Add neural coordinator performance monitoring
@@ -343,31 +1231,263 @@ impl KnowledgeIntegration {
             rag_system: RAGSystem::new(),
             fact_system: FACTSystem::new(),
             brain_integration: BrainIntegration::new(),
+            telemetry: None,
         })
     }
-    
+
+    /// Create a knowledge integration with query observability enabled:
+    /// every `query_knowledge` call is timed and buffered, and flushed to
+    /// `config.sinks` once `config.flush_threshold` events accumulate.
+    pub fn with_telemetry(config: TelemetryConfig) -> Result<Self> {
+        let mut integration = Self::new()?;
+        integration.telemetry = Some(std::sync::Mutex::new(TelemetryCollector::new(config)));
+        Ok(integration)
+    }
+
+    /// Aggregate whatever query events are currently buffered into a
+    /// `MetricsSnapshot`, without flushing them to `sinks`. Returns an
+    /// empty snapshot when telemetry isn't enabled.
+    pub fn drain_metrics(&self) -> MetricsSnapshot {
+        self.telemetry
+            .as_ref()
+            .and_then(|collector| collector.lock().ok())
+            .map(|collector| collector.snapshot())
+            .unwrap_or_default()
+    }
+
     /// Query knowledge system (RAG or FACT)
     pub async fn query_knowledge(&self, query: KnowledgeQuery) -> Result<KnowledgeResult> {
         // TODO: Cache results in MemoryIntegration for repeated queries.
         // TODO: Add query validation
         // TODO: Implement query optimization
-        // TODO: Add query performance monitoring
         // TODO: Consider implementing query caching
-        
-        match query.knowledge_type {
+
+        let start = std::time::Instant::now();
+        let query_type = match &query.knowledge_type {
+            KnowledgeType::RAG { .. } => "rag",
+            KnowledgeType::FACT { .. } => "fact",
+        };
+
+        let result = match query.knowledge_type {
             KnowledgeType::RAG { .. } => self.query_rag_system(&query).await,
             KnowledgeType::FACT { .. } => self.query_fact_system(&query).await,
+        };
+
+        if let Some(telemetry) = &self.telemetry {
+            let event = KnowledgeQueryEvent {
+                query_type: query_type.to_string(),
+                latency_ms: start.elapsed().as_millis() as u64,
+                // TODO: Thread the actual per-query scan count through
+                // instead of approximating it with the store's total size.
+                vectors_scanned: self.rag_system.vector_store.vectors.len(),
+                cache_hit: false,
+                similarity_scores: result.as_ref().map(|r| vec![r.confidence]).unwrap_or_default(),
+                verification_level: None,
+            };
+            if let Ok(mut collector) = telemetry.lock() {
+                collector.record(event);
+            }
         }
+
+        result
     }
-    
+
+    /// Answer a `KnowledgeType::RAG` query against `VectorStore`: embed the
+    /// query text the same way indexed chunks are embedded, rank stored
+    /// vectors by cosine similarity, and skip anything `is_stale` -- a
+    /// vector whose source file has since been re-indexed to a newer
+    /// version -- so retrieval never serves embeddings for deleted or
+    /// rewritten code.
+    async fn query_rag_system(&self, query: &KnowledgeQuery) -> Result<KnowledgeResult> {
+        let KnowledgeType::RAG {
+            query: text,
+            similarity_threshold,
+            max_results,
+            ..
+        } = &query.knowledge_type
+        else {
+            return Err(FileAwareError::Analysis {
+                message: "query_rag_system called with a non-RAG query".to_string(),
+            });
+        };
+
+        let query_vector = self
+            .rag_system
+            .embedding_service
+            .embed_chunks(text)
+            .into_iter()
+            .next()
+            .unwrap_or_default();
+
+        let mut scored: Vec<(f32, &StoredVector)> = self
+            .rag_system
+            .vector_store
+            .vectors
+            .iter()
+            .filter(|(id, _)| !self.rag_system.vector_store.is_stale(id))
+            .map(|(_, vector)| (cosine_similarity(&query_vector, &vector.embedding), vector))
+            .filter(|(score, _)| *score >= *similarity_threshold)
+            .collect();
+
+        scored.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+        scored.truncate(*max_results);
+
+        let confidence = scored.first().map(|(score, _)| *score).unwrap_or(0.0);
+        let sources = scored
+            .iter()
+            .map(|(_, vector)| vector.document.display().to_string())
+            .collect();
+
+        Ok(KnowledgeResult {
+            content: format!("{} relevant chunk(s) found for: {text}", scored.len()),
+            confidence,
+            sources,
+        })
+    }
+
+    /// Handle a batch of file-change events from a `FileAnalyzer` change
+    /// stream: re-embed added/modified files via `EmbeddingService` and
+    /// upsert the resulting vectors into `VectorStore`, or drop a removed
+    /// file's vectors entirely. Each touched document's `index_version` is
+    /// bumped so `query_rag_system` can detect and skip anything that
+    /// predates the file's current on-disk content.
+    pub async fn reindex_changed(&mut self, changes: &[FileChangeEvent]) -> Result<ReindexReport> {
+        let mut report = ReindexReport::default();
+
+        for change in changes {
+            match change {
+                FileChangeEvent::Removed(path) => {
+                    report.vectors_removed += self.rag_system.vector_store.remove_document(path);
+                }
+                FileChangeEvent::Added(path) | FileChangeEvent::Modified(path) => {
+                    let content = std::fs::read_to_string(path).map_err(|e| FileAwareError::Io {
+                        message: format!("Failed to read {path:?} for re-indexing: {e}"),
+                    })?;
+                    let file_hash = content_hash(&content);
+                    let chunks = self.rag_system.embedding_service.embed_chunks(&content);
+                    let (added, updated) = self
+                        .rag_system
+                        .vector_store
+                        .upsert_document(path, file_hash, chunks);
+                    report.vectors_added += added;
+                    report.vectors_updated += updated;
+                }
+            }
+        }
+
+        Ok(report)
+    }
+
     // TODO: Add more knowledge integration methods:
     // pub async fn validate_knowledge(&self, knowledge: &KnowledgeData) -> Result<ValidationResult>
     // pub async fn optimize_knowledge(&self, knowledge: &KnowledgeData) -> Result<OptimizationResult>
-    // pub async fn monitor_knowledge_performance(&self) -> Result<PerformanceReport>
+    // monitor_knowledge_performance is now `with_telemetry` + `drain_metrics` above.
     // pub async fn backup_knowledge(&self) -> Result<BackupResult>
-    
+
     // TODO: Add knowledge optimization
-    // TODO: Implement knowledge performance monitoring
     // TODO: Add knowledge backup and recovery
     // TODO: Consider implementing knowledge automation
 }
+
+#[cfg(test)]
+mod fact_database_tests {
+    use super::*;
+
+    fn open_temp_db() -> (FactDatabase, tempfile::TempDir) {
+        let dir = tempfile::TempDir::new().expect("create temp dir");
+        let db = FactDatabase::open(&dir.path().join("index"), dir.path().join("blobs"))
+            .expect("open fact database");
+        (db, dir)
+    }
+
+    fn sample_metadata(content: &str) -> FactMetadata {
+        FactMetadata {
+            verification_level: VerificationLevel::Unverified,
+            source_authority: SourceAuthority::Community,
+            trust_score: 0.0,
+            content_hash: content_hash(content),
+        }
+    }
+
+    #[test]
+    fn store_and_query_fact_round_trips() {
+        let (db, _dir) = open_temp_db();
+
+        let key = db
+            .store_fact(
+                "zenflow-core",
+                FactType::CodePattern,
+                sample_metadata("println!(\"hi\")"),
+                b"println!(\"hi\")",
+            )
+            .expect("store fact");
+        assert_eq!(key.monotonic_index, 0);
+
+        let results = db
+            .query_fact_range("zenflow-core", FactType::CodePattern, 0..10, true)
+            .expect("query fact range");
+        assert_eq!(results.len(), 1);
+        let (found_key, blob) = &results[0];
+        assert_eq!(found_key, &key);
+        assert_eq!(blob.as_ref().unwrap().content, b"println!(\"hi\")");
+    }
+
+    #[test]
+    fn monotonic_index_increments_per_domain_and_type() {
+        let (db, _dir) = open_temp_db();
+
+        for i in 0..5 {
+            let key = db
+                .store_fact(
+                    "zenflow-core",
+                    FactType::CodePattern,
+                    sample_metadata(&format!("pattern {i}")),
+                    format!("pattern {i}").as_bytes(),
+                )
+                .expect("store fact");
+            assert_eq!(key.monotonic_index, i);
+        }
+
+        // A different fact_type in the same domain starts its own sequence.
+        let other_key = db
+            .store_fact(
+                "zenflow-core",
+                FactType::SecurityVulnerability,
+                sample_metadata("CVE-0000-0000"),
+                b"CVE-0000-0000",
+            )
+            .expect("store fact");
+        assert_eq!(other_key.monotonic_index, 0);
+    }
+
+    #[test]
+    fn concurrent_store_fact_assigns_distinct_indices() {
+        use std::sync::Arc;
+        use std::thread;
+
+        let (db, _dir) = open_temp_db();
+        let db = Arc::new(db);
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let db = Arc::clone(&db);
+                thread::spawn(move || {
+                    db.store_fact(
+                        "zenflow-core",
+                        FactType::CodePattern,
+                        sample_metadata(&format!("concurrent {i}")),
+                        format!("concurrent {i}").as_bytes(),
+                    )
+                    .expect("store fact")
+                })
+            })
+            .collect();
+
+        let mut indices: Vec<u64> = handles
+            .into_iter()
+            .map(|h| h.join().unwrap().monotonic_index)
+            .collect();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..8).collect::<Vec<u64>>(), "every writer must get a distinct index");
+    }
+}
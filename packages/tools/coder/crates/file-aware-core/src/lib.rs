@@ -12,6 +12,7 @@ use serde::{Deserialize, Serialize};
 pub mod analysis;
 pub mod context;
 pub mod dependencies;
+pub mod knowledge_integration;
 pub mod parser;
 pub mod patterns;
 pub mod symbols;
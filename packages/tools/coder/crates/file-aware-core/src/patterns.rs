@@ -3,6 +3,11 @@
 use serde::{Deserialize, Serialize};
 use regex::Regex;
 
+use crate::knowledge_integration::{
+    content_hash, FactDatabase, FactMetadata, FactType, SourceAuthority, VerificationLevel,
+};
+use crate::Result;
+
 /// Code pattern detector
 #[derive(Debug)]
 pub struct PatternDetector {
@@ -70,6 +75,43 @@ impl PatternDetector {
 
         matches
     }
+
+    /// Run [`detect_patterns`](Self::detect_patterns) and persist every
+    /// `Warning`/`Error` match into `db` as an unverified `CodePattern` fact
+    /// under `domain`, so later review can promote/downgrade them the same
+    /// way [`crate::knowledge_integration::CveVerifier`]-style checks do for
+    /// other fact types. `Info` matches (e.g. stray `TODO`s) are too noisy to
+    /// warrant a durable fact and are returned but not recorded.
+    pub fn detect_and_record(
+        &self,
+        content: &str,
+        language: Option<&str>,
+        db: &FactDatabase,
+        domain: &str,
+    ) -> Result<Vec<PatternMatch>> {
+        let matches = self.detect_patterns(content, language);
+
+        for m in &matches {
+            if matches!(m.severity, PatternSeverity::Info) {
+                continue;
+            }
+
+            let metadata = FactMetadata {
+                verification_level: VerificationLevel::Unverified,
+                source_authority: SourceAuthority::Community,
+                trust_score: 0.0,
+                content_hash: content_hash(&m.matched_text),
+            };
+            db.store_fact(
+                domain,
+                FactType::CodePattern,
+                metadata,
+                m.matched_text.as_bytes(),
+            )?;
+        }
+
+        Ok(matches)
+    }
 }
 
 impl Default for PatternDetector {
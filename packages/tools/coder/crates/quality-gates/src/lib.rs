@@ -11,6 +11,9 @@ use regex::Regex;
 use std::fs;
 use tracing::{info, warn, error};
 
+pub mod progress;
+pub use progress::{CancellationToken, ProgressEvent, ProgressOutcome, ProgressReporter};
+
 /// Configuration for quality gate enforcement
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QualityGateConfig {
@@ -82,6 +85,10 @@ pub enum QualityGateStatus {
     Failed,
     Warning,
     Skipped,
+    /// The run was aborted partway through by `CancellationToken::cancel`
+    /// or a dropped progress receiver -- `run_all_gates_with_progress`
+    /// only ever returns this between steps, never mid-step.
+    Cancelled,
 }
 
 /// Individual quality issue
@@ -262,10 +269,47 @@ impl QualityGateEngine {
         ];
     }
 
-    /// Run all quality gates on a project
+    /// Run all quality gates on a project.
     pub async fn run_all_gates(&self, project_path: &str) -> Result<QualityGateResult> {
+        self.run_all_gates_with_progress(project_path, None, None).await
+    }
+
+    /// Same as `run_all_gates`, but emits a `ProgressEvent` to `progress`
+    /// around each gate step (Oxlint, ESLint, custom patterns, AI
+    /// patterns) and checks `cancellation` between them. Cancelling (or
+    /// dropping the paired receiver) stops the run before its next step
+    /// rather than mid-step, returning a `QualityGateStatus::Cancelled`
+    /// result instead of an error.
+    pub async fn run_all_gates_with_progress(
+        &self,
+        project_path: &str,
+        progress: Option<&ProgressReporter>,
+        cancellation: Option<&CancellationToken>,
+    ) -> Result<QualityGateResult> {
+        const TOTAL_STEPS: u64 = 4;
+        if let Some(reporter) = progress {
+            reporter.begin("Running quality gates", Some(TOTAL_STEPS));
+        }
+
+        let is_cancelled = |step_done: u64, step_name: &str| -> bool {
+            let cancelled = cancellation.map(|token| token.is_cancelled()).unwrap_or(false)
+                || progress.map(|reporter| !reporter.is_connected()).unwrap_or(false);
+            if cancelled {
+                if let Some(reporter) = progress {
+                    reporter.report(step_done, Some(TOTAL_STEPS), format!("Cancelled before {step_name}"));
+                    reporter.end(ProgressOutcome::Cancelled);
+                }
+            }
+            cancelled
+        };
+
         let mut all_issues = Vec::new();
         let mut total_score = 100.0;
+        let mut steps_done = 0u64;
+
+        if is_cancelled(steps_done, "Oxlint") {
+            return Ok(Self::cancelled_result(all_issues.len()));
+        }
 
         // Run Oxlint if enabled
         if self.config.oxlint_enabled {
@@ -287,6 +331,13 @@ impl QualityGateEngine {
                 }
             }
         }
+        steps_done += 1;
+        if let Some(reporter) = progress {
+            reporter.report(steps_done, Some(TOTAL_STEPS), "Oxlint complete");
+        }
+        if is_cancelled(steps_done, "ESLint") {
+            return Ok(Self::cancelled_result(all_issues.len()));
+        }
 
         // Run ESLint if enabled
         if self.config.eslint_enabled {
@@ -308,16 +359,34 @@ impl QualityGateEngine {
                 }
             }
         }
+        steps_done += 1;
+        if let Some(reporter) = progress {
+            reporter.report(steps_done, Some(TOTAL_STEPS), "ESLint complete");
+        }
+        if is_cancelled(steps_done, "custom pattern detection") {
+            return Ok(Self::cancelled_result(all_issues.len()));
+        }
 
         // Run custom pattern detection
         let custom_issues = self.run_custom_pattern_detection(project_path).await?;
         all_issues.extend(custom_issues);
+        steps_done += 1;
+        if let Some(reporter) = progress {
+            reporter.report(steps_done, Some(TOTAL_STEPS), "Custom pattern detection complete");
+        }
+        if is_cancelled(steps_done, "AI pattern detection") {
+            return Ok(Self::cancelled_result(all_issues.len()));
+        }
 
         // Run AI pattern detection
         if self.config.ai_pattern_detection {
             let ai_issues = self.run_ai_pattern_detection(project_path).await?;
             all_issues.extend(ai_issues);
         }
+        steps_done += 1;
+        if let Some(reporter) = progress {
+            reporter.report(steps_done, Some(TOTAL_STEPS), "AI pattern detection complete");
+        }
 
         // Calculate score and determine status
         let errors: Vec<_> = all_issues.iter()
@@ -358,6 +427,10 @@ impl QualityGateEngine {
             QualityGateStatus::Passed
         };
 
+        if let Some(reporter) = progress {
+            reporter.end(ProgressOutcome::Completed);
+        }
+
         Ok(QualityGateResult {
             status,
             score: total_score,
@@ -370,6 +443,21 @@ impl QualityGateEngine {
         })
     }
 
+    /// An empty result recording that the run was cancelled before
+    /// finishing rather than actually evaluating any gates.
+    fn cancelled_result(issues_so_far: usize) -> QualityGateResult {
+        QualityGateResult {
+            status: QualityGateStatus::Cancelled,
+            score: 0.0,
+            total_issues: issues_so_far,
+            errors: Vec::new(),
+            warnings: Vec::new(),
+            info: Vec::new(),
+            ai_pattern_issues: Vec::new(),
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
     /// Run Oxlint for Rust code analysis
     async fn run_oxlint(&self, project_path: &str) -> Result<Vec<QualityIssue>> {
         let output = Command::new("oxlint")
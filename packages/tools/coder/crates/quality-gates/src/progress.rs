@@ -0,0 +1,89 @@
+//! Streaming progress reporting for long-running quality gate runs.
+//!
+//! `run_all_gates` walks several independent steps (Oxlint, ESLint, custom
+//! pattern detection, AI pattern detection) that can each take a while on a
+//! large repo, but it only ever returns once every step is done. The types
+//! here let a caller pass an optional channel-backed sink and get
+//! `ProgressEvent`s as each step starts/advances/finishes, and an optional
+//! cancellation token so a long scan can be aborted between steps instead
+//! of always running to completion.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+
+/// One message in a `ProgressReporter` stream: exactly one `Begin`, any
+/// number of `Report`s, then exactly one `End`.
+#[derive(Debug, Clone)]
+pub enum ProgressEvent {
+    Begin { title: String, total: Option<u64> },
+    Report { processed: u64, total: Option<u64>, message: String },
+    End { outcome: ProgressOutcome },
+}
+
+/// How a progress-tracked operation finished.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressOutcome {
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+/// A channel-backed sink for `ProgressEvent`s. Sends are best-effort: if
+/// the paired receiver has been dropped, `send` is silently a no-op rather
+/// than an error, since a caller that stopped listening has implicitly
+/// asked to cancel, not crash the gate run.
+#[derive(Clone)]
+pub struct ProgressReporter {
+    sender: UnboundedSender<ProgressEvent>,
+}
+
+impl ProgressReporter {
+    /// Create a connected reporter/receiver pair.
+    pub fn channel() -> (Self, UnboundedReceiver<ProgressEvent>) {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        (Self { sender }, receiver)
+    }
+
+    pub fn begin(&self, title: impl Into<String>, total: Option<u64>) {
+        let _ = self.sender.send(ProgressEvent::Begin { title: title.into(), total });
+    }
+
+    pub fn report(&self, processed: u64, total: Option<u64>, message: impl Into<String>) {
+        let _ = self.sender.send(ProgressEvent::Report { processed, total, message: message.into() });
+    }
+
+    pub fn end(&self, outcome: ProgressOutcome) {
+        let _ = self.sender.send(ProgressEvent::End { outcome });
+    }
+
+    /// `false` once the receiving end has been dropped -- treated as an
+    /// implicit cancellation alongside `CancellationToken::is_cancelled`.
+    pub fn is_connected(&self) -> bool {
+        !self.sender.is_closed()
+    }
+}
+
+/// Cooperative cancellation flag, checked between gate steps. Cloning
+/// shares the same underlying flag, so a caller can hold one clone and
+/// cancel it from elsewhere (a UI "stop" button, a request timeout) while
+/// another clone is threaded into the gate run.
+#[derive(Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::SeqCst);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+}
@@ -2,48 +2,868 @@
 
 use crate::Result;
 use polars::prelude::*;
-use rand::Rng;
+use rand::{Rng, RngCore, SeedableRng};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
 
-/// Noise injection augmentation
+/// How `NoiseAugmentation`'s strength is specified.
+#[derive(Debug, Clone, Copy)]
+pub enum NoiseStrength {
+    /// A fixed standard deviation, independent of each column's own scale.
+    Absolute(f64),
+    /// A target signal-to-noise ratio in dB. The standard deviation is
+    /// derived per column as `sigma = sqrt(P_s / 10^(snr_db/10))`, where
+    /// `P_s = mean(x^2)` is that column's signal power.
+    TargetSnrDb(f64),
+}
+
+/// Shape of the noise `NoiseAugmentation` injects.
+#[derive(Debug, Clone, Copy)]
+pub enum NoiseKind {
+    /// `x + N(0, sigma^2)`.
+    Additive,
+    /// `x * (1 + N(0, sigma^2))`.
+    Multiplicative,
+    /// Replaces a random `fraction` of points with `x + sign * spike_magnitude * sigma`
+    /// (sign chosen at random), simulating sensor glitches/outliers rather
+    /// than ambient noise.
+    Spike { fraction: f64, spike_magnitude: f64 },
+}
+
+/// Noise injection augmentation: perturbs the numeric columns of a
+/// `DataFrame` with additive, multiplicative, or spike/outlier noise whose
+/// strength is either an absolute standard deviation or a target SNR.
+/// Non-float/time/index columns are passed through unchanged.
 pub struct NoiseAugmentation {
     pub noise_level: f64,
+    strength: NoiseStrength,
+    kind: NoiseKind,
 }
 
 impl Default for NoiseAugmentation {
     fn default() -> Self {
-        Self { noise_level: 0.01 }
+        Self::new(0.01)
     }
 }
 
 impl NoiseAugmentation {
+    /// Additive Gaussian noise with an absolute standard deviation.
     pub fn new(noise_level: f64) -> Self {
-        Self { noise_level }
+        Self { noise_level, strength: NoiseStrength::Absolute(noise_level), kind: NoiseKind::Additive }
+    }
+
+    /// Noise of the given `kind`, with `strength` either an absolute
+    /// standard deviation or a target SNR in dB.
+    pub fn with_kind(strength: NoiseStrength, kind: NoiseKind) -> Self {
+        let noise_level = match strength {
+            NoiseStrength::Absolute(level) => level,
+            NoiseStrength::TargetSnrDb(_) => 0.0,
+        };
+        Self { noise_level, strength, kind }
+    }
+
+    pub fn augment<R: Rng + ?Sized>(&self, data: &DataFrame, rng: &mut R) -> Result<DataFrame> {
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let values: Vec<Option<f64>> = numeric_series.into_iter().collect();
+                    let sigma = self.column_sigma(&values);
+
+                    let noisy: Vec<Option<f64>> = match self.kind {
+                        NoiseKind::Additive => values
+                            .iter()
+                            .map(|v| v.map(|x| x + sample_standard_normal(rng) * sigma))
+                            .collect(),
+                        NoiseKind::Multiplicative => values
+                            .iter()
+                            .map(|v| v.map(|x| x * (1.0 + sample_standard_normal(rng) * sigma)))
+                            .collect(),
+                        NoiseKind::Spike { fraction, spike_magnitude } => values
+                            .iter()
+                            .map(|v| {
+                                v.map(|x| {
+                                    if rng.gen::<f64>() < fraction {
+                                        let sign = if rng.gen::<bool>() { 1.0 } else { -1.0 };
+                                        x + sign * spike_magnitude * sigma
+                                    } else {
+                                        x
+                                    }
+                                })
+                            })
+                            .collect(),
+                    };
+
+                    let transformed = Series::new(col_name.clone(), noisy).into_column();
+                    result = result.hstack(&[transformed])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
     }
-    
-    pub fn augment<R: Rng>(&self, data: &DataFrame, _rng: &mut R) -> Result<DataFrame> {
-        // Placeholder implementation
-        Ok(data.clone())
+
+    /// Derives the noise standard deviation for one column's values per
+    /// `self.strength`, excluding null values from the signal-power
+    /// estimate used by `NoiseStrength::TargetSnrDb`.
+    fn column_sigma(&self, values: &[Option<f64>]) -> f64 {
+        match self.strength {
+            NoiseStrength::Absolute(level) => level,
+            NoiseStrength::TargetSnrDb(snr_db) => {
+                let present: Vec<f64> = values.iter().filter_map(|v| *v).collect();
+                if present.is_empty() {
+                    return 0.0;
+                }
+
+                let signal_power = present.iter().map(|v| v * v).sum::<f64>() / present.len() as f64;
+                (signal_power / 10f64.powf(snr_db / 10.0)).sqrt()
+            }
+        }
     }
 }
 
-/// Time warping augmentation
+/// Samples one value from the standard normal distribution via the
+/// Box-Muller transform. This crate doesn't depend on `rand_distr`, so
+/// Gaussian sampling is done directly against `rand::Rng`.
+fn sample_standard_normal<R: Rng + ?Sized>(rng: &mut R) -> f64 {
+    let u1: f64 = rng.gen::<f64>().max(f64::MIN_POSITIVE);
+    let u2: f64 = rng.gen::<f64>();
+    (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+}
+
+/// Windowing function applied to the sinc kernel in `TimeWarpAugmentation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SincWindow {
+    Hann,
+    Blackman,
+}
+
+/// Time warping augmentation: rescales the time axis with a smooth random
+/// warp (a renormalized cumulative sum of random per-step speed factors
+/// bounded by `warp_factor`), then resamples each value column at the
+/// warped positions via windowed-sinc interpolation rather than naive
+/// linear interpolation, which would alias high-frequency content. The
+/// output keeps the original row count.
 pub struct TimeWarpAugmentation {
     pub warp_factor: f64,
+    pub kernel_half_width: usize,
+    pub window: SincWindow,
 }
 
 impl Default for TimeWarpAugmentation {
     fn default() -> Self {
-        Self { warp_factor: 0.1 }
+        Self { warp_factor: 0.1, kernel_half_width: 8, window: SincWindow::Hann }
     }
 }
 
 impl TimeWarpAugmentation {
     pub fn new(warp_factor: f64) -> Self {
-        Self { warp_factor }
+        Self { warp_factor, ..Self::default() }
+    }
+
+    /// Construct with an explicit sinc kernel half-width (taps on each side
+    /// of the interpolation point) and window choice.
+    pub fn with_kernel(warp_factor: f64, kernel_half_width: usize, window: SincWindow) -> Self {
+        Self { warp_factor, kernel_half_width, window }
+    }
+
+    pub fn augment<R: Rng + ?Sized>(&self, data: &DataFrame, rng: &mut R) -> Result<DataFrame> {
+        let n = data.height();
+        if n == 0 {
+            return Ok(data.clone());
+        }
+
+        let warped_positions = self.generate_warp(n, rng);
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let values: Vec<f64> = (0..n).map(|i| numeric_series.get(i).unwrap_or(f64::NAN)).collect();
+
+                    let resampled: Vec<f64> =
+                        warped_positions.iter().map(|&t| self.resample_at(&values, t)).collect();
+
+                    let transformed = Series::new(col_name.clone(), resampled).into_column();
+                    result = result.hstack(&[transformed])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Generates a smooth monotonic warp of the `n`-sample time axis: a
+    /// cumulative sum of random per-step speed factors in `[1 -
+    /// warp_factor, 1 + warp_factor]`, renormalized so the warped axis
+    /// still spans `[0, n-1]` -- this is what keeps the output row count
+    /// equal to the input's despite the local speed-up/slow-down.
+    fn generate_warp<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Vec<f64> {
+        if n == 1 {
+            return vec![0.0];
+        }
+
+        let mut cumulative = Vec::with_capacity(n);
+        let mut acc = 0.0;
+        cumulative.push(acc);
+        for _ in 1..n {
+            let speed = 1.0 + rng.gen_range(-self.warp_factor..=self.warp_factor);
+            acc += speed.max(0.01);
+            cumulative.push(acc);
+        }
+
+        let span = cumulative[n - 1];
+        cumulative.iter().map(|&c| c / span * (n - 1) as f64).collect()
+    }
+
+    /// Evaluates `values` at fractional position `t` via windowed-sinc
+    /// interpolation over `2 * kernel_half_width` neighboring samples,
+    /// zero-padding past the series' edges.
+    fn resample_at(&self, values: &[f64], t: f64) -> f64 {
+        let n = values.len() as isize;
+        let half = self.kernel_half_width as isize;
+        let center = t.floor() as isize;
+
+        let mut sum = 0.0;
+        for k in (center - half + 1)..=(center + half) {
+            let sample = if k >= 0 && k < n { values[k as usize] } else { 0.0 };
+            let x = t - k as f64;
+            sum += sample * sinc(x) * self.window_weight(x, half as f64);
+        }
+
+        sum
+    }
+
+    /// Window weight for offset `x` from the interpolation point; zero
+    /// outside `[-half_width, half_width]`.
+    fn window_weight(&self, x: f64, half_width: f64) -> f64 {
+        if x.abs() > half_width {
+            return 0.0;
+        }
+
+        let ratio = (x + half_width) / (2.0 * half_width);
+        match self.window {
+            SincWindow::Hann => 0.5 - 0.5 * (2.0 * std::f64::consts::PI * ratio).cos(),
+            SincWindow::Blackman => {
+                0.42 - 0.5 * (2.0 * std::f64::consts::PI * ratio).cos()
+                    + 0.08 * (4.0 * std::f64::consts::PI * ratio).cos()
+            }
+        }
+    }
+}
+
+/// Normalized sinc: `sin(pi*x) / (pi*x)`, with the removable singularity at
+/// `x == 0` handled explicitly.
+fn sinc(x: f64) -> f64 {
+    if x.abs() < 1e-12 {
+        1.0
+    } else {
+        (std::f64::consts::PI * x).sin() / (std::f64::consts::PI * x)
+    }
+}
+
+/// Seedable 1-D gradient ("Perlin-style") noise source: precomputes a
+/// permutation table from `seed` via a Fisher-Yates shuffle, then evaluates
+/// smooth correlated noise at any position by interpolating between
+/// lattice-point gradients with a quintic smoothstep fade. Two calls with
+/// the same seed always produce the same table, and therefore the same
+/// noise curve.
+struct GradientNoise1D {
+    permutation: [u8; 512],
+}
+
+impl GradientNoise1D {
+    fn new(seed: u64) -> Self {
+        let mut table: Vec<u8> = (0..=255u16).map(|v| v as u8).collect();
+        let mut rng = StdRng::seed_from_u64(seed);
+        for i in (1..table.len()).rev() {
+            let j = rng.gen_range(0..=i);
+            table.swap(i, j);
+        }
+
+        let mut permutation = [0u8; 512];
+        for (i, slot) in permutation.iter_mut().enumerate() {
+            *slot = table[i % 256];
+        }
+
+        Self { permutation }
+    }
+
+    /// Gradient at lattice point `lattice_point`: +1 or -1, chosen by the
+    /// permutation table's parity. In one dimension the sign is all a
+    /// gradient can meaningfully carry.
+    fn gradient(&self, lattice_point: i64) -> f64 {
+        let hash = self.permutation[(lattice_point.rem_euclid(256)) as usize];
+        if hash & 1 == 0 {
+            1.0
+        } else {
+            -1.0
+        }
+    }
+
+    /// Evaluates the noise function at position `t`, roughly in `[-1, 1]`.
+    fn sample(&self, t: f64) -> f64 {
+        let i0 = t.floor() as i64;
+        let i1 = i0 + 1;
+        let frac = t - i0 as f64;
+
+        let g0 = self.gradient(i0);
+        let g1 = self.gradient(i1);
+
+        let dot0 = g0 * frac;
+        let dot1 = g1 * (frac - 1.0);
+
+        let fade = smoothstep(frac);
+        dot0 + fade * (dot1 - dot0)
+    }
+}
+
+/// Quintic smoothstep fade: `6t^5 - 15t^4 + 10t^3`. Used instead of linear
+/// interpolation between lattice gradients since its first and second
+/// derivatives vanish at `t = 0` and `t = 1`, avoiding visible seams at
+/// lattice boundaries.
+fn smoothstep(t: f64) -> f64 {
+    t * t * t * (t * (t * 6.0 - 15.0) + 10.0)
+}
+
+/// Sums several octaves of `GradientNoise1D` -- each at `lacunarity` times
+/// the previous octave's frequency and `persistence` times its amplitude --
+/// and normalizes by the total amplitude so the result stays within
+/// roughly `[-1, 1]` regardless of octave count.
+struct FractalNoise1D {
+    noise: GradientNoise1D,
+    octaves: u32,
+    lacunarity: f64,
+    persistence: f64,
+}
+
+impl FractalNoise1D {
+    fn new(seed: u64, octaves: u32, lacunarity: f64, persistence: f64) -> Self {
+        Self { noise: GradientNoise1D::new(seed), octaves: octaves.max(1), lacunarity, persistence }
+    }
+
+    fn sample(&self, t: f64) -> f64 {
+        let mut total = 0.0;
+        let mut amplitude = 1.0;
+        let mut frequency = 1.0;
+        let mut amplitude_sum = 0.0;
+
+        for _ in 0..self.octaves {
+            total += self.noise.sample(t * frequency) * amplitude;
+            amplitude_sum += amplitude;
+            amplitude *= self.persistence;
+            frequency *= self.lacunarity;
+        }
+
+        if amplitude_sum > 0.0 {
+            total / amplitude_sum
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Coherent (spatially-correlated) noise augmentation: adds smooth drift
+/// generated by a seedable fractal gradient-noise source instead of
+/// independent per-sample noise, to simulate slow sensor drift and
+/// low-frequency disturbances that `NoiseAugmentation`'s white noise would
+/// destroy rather than preserve.
+pub struct CoherentNoiseAugmentation {
+    pub seed: u64,
+    pub frequency: f64,
+    pub amplitude: f64,
+    pub octaves: u32,
+    pub lacunarity: f64,
+    pub persistence: f64,
+}
+
+impl Default for CoherentNoiseAugmentation {
+    fn default() -> Self {
+        Self { seed: 0, frequency: 0.05, amplitude: 0.1, octaves: 4, lacunarity: 2.0, persistence: 0.5 }
+    }
+}
+
+impl CoherentNoiseAugmentation {
+    pub fn new(seed: u64, amplitude: f64) -> Self {
+        Self { seed, amplitude, ..Self::default() }
+    }
+
+    /// Construct with explicit fractal-stacking parameters: how fast the
+    /// sample position advances (`frequency`), how many octaves to sum,
+    /// and each octave's frequency/amplitude multiplier (`lacunarity`,
+    /// `persistence`).
+    pub fn with_octaves(
+        seed: u64,
+        frequency: f64,
+        amplitude: f64,
+        octaves: u32,
+        lacunarity: f64,
+        persistence: f64,
+    ) -> Self {
+        Self { seed, frequency, amplitude, octaves, lacunarity, persistence }
+    }
+
+    /// Adds the generated coherent series, scaled by `self.amplitude`, onto
+    /// each numeric column. The drift is entirely determined by `self.seed`
+    /// rather than caller-supplied randomness, so augmenting the same
+    /// `DataFrame` twice with the same settings reproduces the same drift.
+    pub fn augment(&self, data: &DataFrame) -> Result<DataFrame> {
+        let n = data.height();
+        let noise = FractalNoise1D::new(self.seed, self.octaves, self.lacunarity, self.persistence);
+        let drift: Vec<f64> = (0..n).map(|i| noise.sample(i as f64 * self.frequency) * self.amplitude).collect();
+
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let drifted: Vec<Option<f64>> = numeric_series
+                        .into_iter()
+                        .zip(&drift)
+                        .map(|(v, d)| v.map(|x| x + d))
+                        .collect();
+
+                    let transformed = Series::new(col_name.clone(), drifted).into_column();
+                    result = result.hstack(&[transformed])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+/// Common interface for every augmenter in this module, letting them be
+/// chained and randomized uniformly via `Sequential`/`OneOf`/`Probability`
+/// instead of calling each struct by name. Takes `rng: &mut dyn RngCore`
+/// rather than a generic `R: Rng` so it can be boxed as `Box<dyn Augment>`
+/// -- trait objects can't have generic methods, but `dyn RngCore` still
+/// gets every `Rng` method via rand's blanket impl.
+pub trait Augment {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame>;
+}
+
+impl Augment for NoiseAugmentation {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data, rng)
+    }
+}
+
+impl Augment for TimeWarpAugmentation {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data, rng)
+    }
+}
+
+impl Augment for CoherentNoiseAugmentation {
+    fn augment(&self, data: &DataFrame, _rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data)
+    }
+}
+
+/// Runs a list of augmenters in order, piping each one's output into the
+/// next's input, all driven by the same `rng` so an entire pipeline (e.g.
+/// jitter -> time-warp -> scale) is reproducible from a single seed.
+pub struct Sequential(pub Vec<Box<dyn Augment>>);
+
+impl Augment for Sequential {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        let mut current = data.clone();
+        for step in &self.0 {
+            current = step.augment(&current, rng)?;
+        }
+        Ok(current)
+    }
+}
+
+/// Picks exactly one augmenter per call, weighted by each entry's weight
+/// (weights need not sum to 1; they're normalized against their total).
+pub struct OneOf {
+    augmenters: Vec<Box<dyn Augment>>,
+    weights: Vec<f64>,
+}
+
+impl OneOf {
+    pub fn new(weighted: Vec<(Box<dyn Augment>, f64)>) -> Self {
+        let (augmenters, weights) = weighted.into_iter().unzip();
+        Self { augmenters, weights }
+    }
+}
+
+impl Augment for OneOf {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        let Some(last) = self.augmenters.last() else {
+            return Ok(data.clone());
+        };
+
+        let total: f64 = self.weights.iter().sum();
+        let mut pick = rng.gen::<f64>() * total;
+
+        for (augmenter, weight) in self.augmenters.iter().zip(&self.weights) {
+            if pick < *weight {
+                return augmenter.augment(data, rng);
+            }
+            pick -= weight;
+        }
+
+        // Floating-point rounding can leave `pick` just past the last
+        // weight's boundary; fall back to the last augmenter rather than
+        // silently skipping the draw.
+        last.augment(data, rng)
+    }
+}
+
+/// Applies `inner` with probability `p` (clamped to `[0, 1]`), otherwise
+/// passes the input through unchanged.
+pub struct Probability {
+    pub probability: f64,
+    pub inner: Box<dyn Augment>,
+}
+
+impl Probability {
+    pub fn new(probability: f64, inner: Box<dyn Augment>) -> Self {
+        Self { probability: probability.clamp(0.0, 1.0), inner }
+    }
+}
+
+impl Augment for Probability {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        if rng.gen::<f64>() < self.probability {
+            self.inner.augment(data, rng)
+        } else {
+            Ok(data.clone())
+        }
+    }
+}
+
+/// Crops a random contiguous sub-window covering a `ratio` fraction of the
+/// original length, then resamples it back to the original row count via
+/// linear interpolation, so the output keeps the same shape as the input.
+pub struct WindowSlice {
+    pub ratio: f64,
+}
+
+impl Default for WindowSlice {
+    fn default() -> Self {
+        Self { ratio: 0.9 }
+    }
+}
+
+impl WindowSlice {
+    pub fn new(ratio: f64) -> Self {
+        Self { ratio: ratio.clamp(0.0, 1.0) }
+    }
+
+    pub fn augment<R: Rng + ?Sized>(&self, data: &DataFrame, rng: &mut R) -> Result<DataFrame> {
+        let n = data.height();
+        if n < 2 {
+            return Ok(data.clone());
+        }
+
+        let window_len = (((n as f64) * self.ratio).round() as usize).clamp(2, n);
+        let max_start = n - window_len;
+        let start = if max_start == 0 { 0 } else { rng.gen_range(0..=max_start) };
+
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let window: Vec<f64> =
+                        (start..start + window_len).map(|i| numeric_series.get(i).unwrap_or(f64::NAN)).collect();
+
+                    let resampled: Vec<f64> = (0..n)
+                        .map(|i| {
+                            let position = if n == 1 {
+                                0.0
+                            } else {
+                                i as f64 * (window_len - 1) as f64 / (n - 1) as f64
+                            };
+                            linear_interpolate(&window, position)
+                        })
+                        .collect();
+
+                    let transformed = Series::new(col_name.clone(), resampled).into_column();
+                    result = result.hstack(&[transformed])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
     }
-    
-    pub fn augment<R: Rng>(&self, data: &DataFrame, _rng: &mut R) -> Result<DataFrame> {
-        // Placeholder implementation
-        Ok(data.clone())
+}
+
+/// Linearly interpolates `values` at fractional index `position`, clamping
+/// to the slice's bounds.
+fn linear_interpolate(values: &[f64], position: f64) -> f64 {
+    let n = values.len();
+    if n == 0 {
+        return f64::NAN;
+    }
+    if n == 1 {
+        return values[0];
+    }
+
+    let lower = (position.floor().max(0.0) as usize).min(n - 1);
+    let upper = (lower + 1).min(n - 1);
+    let frac = position - lower as f64;
+
+    values[lower] * (1.0 - frac) + values[upper] * frac
+}
+
+impl Augment for WindowSlice {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data, rng)
+    }
+}
+
+/// A natural cubic spline (zero second derivative at both ends) through a
+/// handful of `(x, y)` knots, fit via the standard tridiagonal
+/// Thomas-algorithm recurrence. Used by `MagnitudeWarp` to build a smooth
+/// gain curve from a few random knot values.
+struct CubicSpline {
+    xs: Vec<f64>,
+    ys: Vec<f64>,
+    second_derivatives: Vec<f64>,
+}
+
+impl CubicSpline {
+    fn fit(xs: Vec<f64>, ys: Vec<f64>) -> Self {
+        let n = xs.len();
+        let mut second_derivatives = vec![0.0; n];
+
+        if n >= 3 {
+            let mut alpha = vec![0.0; n];
+            for i in 1..n - 1 {
+                alpha[i] = (3.0 / (xs[i + 1] - xs[i])) * (ys[i + 1] - ys[i])
+                    - (3.0 / (xs[i] - xs[i - 1])) * (ys[i] - ys[i - 1]);
+            }
+
+            let mut l = vec![1.0; n];
+            let mut mu = vec![0.0; n];
+            let mut z = vec![0.0; n];
+
+            for i in 1..n - 1 {
+                l[i] = 2.0 * (xs[i + 1] - xs[i - 1]) - (xs[i] - xs[i - 1]) * mu[i - 1];
+                mu[i] = (xs[i + 1] - xs[i]) / l[i];
+                z[i] = (alpha[i] - (xs[i] - xs[i - 1]) * z[i - 1]) / l[i];
+            }
+
+            for i in (0..n - 1).rev() {
+                second_derivatives[i] = z[i] - mu[i] * second_derivatives[i + 1];
+            }
+        }
+
+        Self { xs, ys, second_derivatives }
+    }
+
+    /// Evaluates the spline at `x`, clamped to the knot range.
+    fn evaluate(&self, x: f64) -> f64 {
+        let n = self.xs.len();
+        if n == 0 {
+            return 1.0;
+        }
+        if n == 1 {
+            return self.ys[0];
+        }
+
+        let x = x.clamp(self.xs[0], self.xs[n - 1]);
+        let segment = self.xs.windows(2).position(|w| x >= w[0] && x <= w[1]).unwrap_or(n - 2);
+
+        let (x0, x1) = (self.xs[segment], self.xs[segment + 1]);
+        let (y0, y1) = (self.ys[segment], self.ys[segment + 1]);
+        let (z0, z1) = (self.second_derivatives[segment], self.second_derivatives[segment + 1]);
+        let h = x1 - x0;
+
+        let a = (x1 - x) / h;
+        let b = (x - x0) / h;
+
+        a * y0 + b * y1 + ((a.powi(3) - a) * z0 + (b.powi(3) - b) * z1) * (h * h) / 6.0
+    }
+}
+
+/// Multiplies the series by a smooth per-point gain curve: a natural cubic
+/// spline through `num_knots` evenly-spaced knots whose values are sampled
+/// `N(1, sigma^2)`, evaluated at every row.
+pub struct MagnitudeWarp {
+    pub num_knots: usize,
+    pub sigma: f64,
+}
+
+impl Default for MagnitudeWarp {
+    fn default() -> Self {
+        Self { num_knots: 4, sigma: 0.2 }
+    }
+}
+
+impl MagnitudeWarp {
+    pub fn new(num_knots: usize, sigma: f64) -> Self {
+        Self { num_knots: num_knots.max(2), sigma }
+    }
+
+    pub fn augment<R: Rng + ?Sized>(&self, data: &DataFrame, rng: &mut R) -> Result<DataFrame> {
+        let n = data.height();
+        if n == 0 {
+            return Ok(data.clone());
+        }
+
+        let last_index = (n - 1).max(1) as f64;
+        let last_knot = (self.num_knots - 1).max(1) as f64;
+        let knot_xs: Vec<f64> = (0..self.num_knots).map(|i| i as f64 * last_index / last_knot).collect();
+        let knot_ys: Vec<f64> =
+            (0..self.num_knots).map(|_| 1.0 + sample_standard_normal(rng) * self.sigma).collect();
+
+        let spline = CubicSpline::fit(knot_xs, knot_ys);
+        let gain: Vec<f64> = (0..n).map(|i| spline.evaluate(i as f64)).collect();
+
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let warped: Vec<Option<f64>> =
+                        numeric_series.into_iter().zip(&gain).map(|(v, g)| v.map(|x| x * g)).collect();
+
+                    let transformed = Series::new(col_name.clone(), warped).into_column();
+                    result = result.hstack(&[transformed])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Augment for MagnitudeWarp {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data, rng)
+    }
+}
+
+/// Multiplies every numeric column by a single random factor sampled
+/// uniformly from `[min_factor, max_factor]` -- the same factor for every
+/// column, so the series' relative shape is preserved.
+pub struct Scaling {
+    pub min_factor: f64,
+    pub max_factor: f64,
+}
+
+impl Default for Scaling {
+    fn default() -> Self {
+        Self { min_factor: 0.9, max_factor: 1.1 }
+    }
+}
+
+impl Scaling {
+    pub fn new(min_factor: f64, max_factor: f64) -> Self {
+        Self { min_factor, max_factor }
+    }
+
+    pub fn augment<R: Rng + ?Sized>(&self, data: &DataFrame, rng: &mut R) -> Result<DataFrame> {
+        let factor = rng.gen_range(self.min_factor..=self.max_factor);
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let scaled =
+                        numeric_series.apply(|val| val.map(|v| v * factor)).with_name(col_name.clone());
+                    result = result.hstack(&[scaled.into_column()])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Augment for Scaling {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data, rng)
+    }
+}
+
+/// Splits the series into `num_segments` equal-length contiguous segments
+/// (any remainder rows stay appended to the last segment) and shuffles the
+/// segment order. Every row's value and the overall row count (and
+/// therefore the series' mean) are preserved -- only the temporal order is
+/// destroyed.
+pub struct Permutation {
+    pub num_segments: usize,
+}
+
+impl Default for Permutation {
+    fn default() -> Self {
+        Self { num_segments: 4 }
+    }
+}
+
+impl Permutation {
+    pub fn new(num_segments: usize) -> Self {
+        Self { num_segments: num_segments.max(1) }
+    }
+
+    pub fn augment<R: Rng + ?Sized>(&self, data: &DataFrame, rng: &mut R) -> Result<DataFrame> {
+        let n = data.height();
+        let num_segments = self.num_segments.min(n.max(1));
+        if num_segments <= 1 || n == 0 {
+            return Ok(data.clone());
+        }
+
+        let segment_len = n / num_segments;
+        let mut bounds: Vec<(usize, usize)> = (0..num_segments)
+            .map(|i| {
+                let start = i * segment_len;
+                let end = if i == num_segments - 1 { n } else { start + segment_len };
+                (start, end)
+            })
+            .collect();
+        bounds.shuffle(rng);
+
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let values: Vec<f64> = (0..n).map(|i| numeric_series.get(i).unwrap_or(f64::NAN)).collect();
+
+                    let permuted: Vec<f64> =
+                        bounds.iter().flat_map(|&(start, end)| values[start..end].iter().copied()).collect();
+
+                    let transformed = Series::new(col_name.clone(), permuted).into_column();
+                    result = result.hstack(&[transformed])?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+impl Augment for Permutation {
+    fn augment(&self, data: &DataFrame, rng: &mut dyn RngCore) -> Result<DataFrame> {
+        self.augment(data, rng)
     }
 }
\ No newline at end of file
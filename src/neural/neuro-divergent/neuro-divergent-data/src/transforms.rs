@@ -2,57 +2,366 @@
 
 use crate::Result;
 use polars::prelude::*;
+use std::collections::HashMap;
 
-/// Log transformation
+/// Log transformation: `y = ln(x + c) / ln(base)`, with a configurable
+/// offset `c` applied before taking the log so columns containing zero
+/// (or other small values) don't blow up. `inverse_transform` reverses it
+/// as `base^y - c`.
 pub struct LogTransform {
     pub base: f64,
+    pub offset: f64,
 }
 
 impl Default for LogTransform {
     fn default() -> Self {
-        Self { base: std::f64::consts::E }
+        Self { base: std::f64::consts::E, offset: 0.0 }
     }
 }
 
 impl LogTransform {
     pub fn new(base: f64) -> Self {
-        Self { base }
+        Self { base, offset: 0.0 }
     }
-    
+
+    /// Construct with an explicit offset `c` applied as `ln(x + c)`.
+    pub fn with_offset(base: f64, offset: f64) -> Self {
+        Self { base, offset }
+    }
+
     pub fn transform(&self, data: &DataFrame) -> Result<DataFrame> {
-        // Placeholder implementation
-        Ok(data.clone())
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+
+                    for value in numeric_series.into_iter().flatten() {
+                        if value + self.offset <= 0.0 {
+                            return Err(crate::DataPipelineError::ComputationError {
+                                message: format!(
+                                    "LogTransform: value {value} + offset {} is non-positive in column '{col_name}'",
+                                    self.offset,
+                                ),
+                            });
+                        }
+                    }
+
+                    let transformed = numeric_series
+                        .apply(|val| val.map(|v| (v + self.offset).ln() / self.base.ln()))
+                        .with_name(col_name.clone());
+
+                    result.with_column(transformed.into_column())?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
     }
-    
+
     pub fn inverse_transform(&self, data: &DataFrame) -> Result<DataFrame> {
-        // Placeholder implementation
-        Ok(data.clone())
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let transformed = numeric_series
+                        .apply(|val| val.map(|v| self.base.powf(v) - self.offset))
+                        .with_name(col_name.clone());
+
+                    result.with_column(transformed.into_column())?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
     }
 }
 
-/// Difference transformation
+/// Difference transformation: `d[t] = x[t] - x[t-periods]`, producing
+/// `n - periods` rows. The first `periods` original values of each numeric
+/// column are kept as state so `inverse_transform` can reconstruct the
+/// original series by cumulative summation from those anchors.
 pub struct DifferenceTransform {
     pub periods: usize,
+    anchors: HashMap<String, Vec<f64>>,
 }
 
 impl Default for DifferenceTransform {
     fn default() -> Self {
-        Self { periods: 1 }
+        Self { periods: 1, anchors: HashMap::new() }
     }
 }
 
 impl DifferenceTransform {
     pub fn new(periods: usize) -> Self {
-        Self { periods }
+        Self { periods, anchors: HashMap::new() }
+    }
+
+    pub fn transform(&mut self, data: &DataFrame) -> Result<DataFrame> {
+        let n = data.height();
+        if n <= self.periods {
+            return Err(crate::DataPipelineError::ComputationError {
+                message: format!(
+                    "DifferenceTransform: need more than {} rows to difference, got {n}",
+                    self.periods,
+                ),
+            });
+        }
+
+        let mut result_columns = Vec::with_capacity(data.width());
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let values: Vec<f64> = (0..n)
+                        .map(|i| numeric_series.get(i).unwrap_or(f64::NAN))
+                        .collect();
+
+                    self.anchors.insert(col_name.to_string(), values[..self.periods].to_vec());
+
+                    let diffs: Vec<f64> = (self.periods..n)
+                        .map(|t| values[t] - values[t - self.periods])
+                        .collect();
+
+                    result_columns.push(Series::new(col_name.clone(), diffs).into_column());
+                }
+                _ => {
+                    let sliced = series.slice(self.periods as i64, n - self.periods);
+                    result_columns.push(sliced.into_column());
+                }
+            }
+        }
+
+        Ok(DataFrame::new(result_columns)?)
+    }
+
+    pub fn inverse_transform(&self, data: &DataFrame) -> Result<DataFrame> {
+        let diff_len = data.height();
+        let total_len = diff_len + self.periods;
+        let mut result_columns = Vec::with_capacity(data.width());
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            let key = col_name.to_string();
+
+            if let Some(anchor) = self.anchors.get(&key) {
+                let numeric_series = series.f64()?;
+                let mut original = vec![0.0; total_len];
+                original[..self.periods].copy_from_slice(anchor);
+
+                for t in self.periods..total_len {
+                    let diff_val = numeric_series.get(t - self.periods).unwrap_or(f64::NAN);
+                    original[t] = original[t - self.periods] + diff_val;
+                }
+
+                result_columns.push(Series::new(col_name.clone(), original).into_column());
+            } else {
+                // No anchor was recorded for this column (e.g. it wasn't
+                // numeric, or `transform` hasn't run yet) -- pass it
+                // through unchanged.
+                result_columns.push(series.clone());
+            }
+        }
+
+        Ok(DataFrame::new(result_columns)?)
+    }
+}
+
+/// Box-Cox transformation: `y = (x^λ - 1) / λ` for `λ != 0`, or `y = ln(x)`
+/// for `λ == 0`. Requires strictly positive input, like `LogTransform`.
+pub struct BoxCoxTransform {
+    pub lambda: f64,
+}
+
+impl Default for BoxCoxTransform {
+    fn default() -> Self {
+        Self { lambda: 1.0 }
+    }
+}
+
+impl BoxCoxTransform {
+    pub fn new(lambda: f64) -> Self {
+        Self { lambda }
     }
-    
+
     pub fn transform(&self, data: &DataFrame) -> Result<DataFrame> {
-        // Placeholder implementation
-        Ok(data.clone())
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+
+                    for value in numeric_series.into_iter().flatten() {
+                        if value <= 0.0 {
+                            return Err(crate::DataPipelineError::ComputationError {
+                                message: format!(
+                                    "BoxCoxTransform: value {value} is non-positive in column '{col_name}'",
+                                ),
+                            });
+                        }
+                    }
+
+                    let lambda = self.lambda;
+                    let transformed = numeric_series
+                        .apply(|val| {
+                            val.map(|v| {
+                                if lambda == 0.0 {
+                                    v.ln()
+                                } else {
+                                    (v.powf(lambda) - 1.0) / lambda
+                                }
+                            })
+                        })
+                        .with_name(col_name.clone());
+
+                    result.with_column(transformed.into_column())?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
     }
-    
+
     pub fn inverse_transform(&self, data: &DataFrame) -> Result<DataFrame> {
-        // Placeholder implementation
-        Ok(data.clone())
+        let mut result = data.clone();
+
+        for col_name in data.get_column_names() {
+            let series = data.column(col_name)?;
+            match series.dtype() {
+                DataType::Float64 | DataType::Float32 | DataType::Int64 | DataType::Int32 => {
+                    let numeric_series = series.f64()?;
+                    let lambda = self.lambda;
+                    let transformed = numeric_series
+                        .apply(|val| {
+                            val.map(|v| {
+                                if lambda == 0.0 {
+                                    v.exp()
+                                } else {
+                                    (lambda * v + 1.0).powf(1.0 / lambda)
+                                }
+                            })
+                        })
+                        .with_name(col_name.clone());
+
+                    result.with_column(transformed.into_column())?;
+                }
+                _ => continue,
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// Estimate `lambda` for `values` by grid search over the Box-Cox
+    /// profile log-likelihood -- there's no closed form, so a coarse
+    /// sweep is the standard way to fit it.
+    pub fn fit(values: &[f64]) -> Result<Self> {
+        if values.is_empty() || values.iter().any(|&v| v <= 0.0) {
+            return Err(crate::DataPipelineError::ComputationError {
+                message: "BoxCoxTransform::fit requires a non-empty slice of strictly positive values".to_string(),
+            });
+        }
+
+        let n = values.len() as f64;
+        let log_sum: f64 = values.iter().map(|v| v.ln()).sum();
+
+        let mut best_lambda = -2.0;
+        let mut best_log_likelihood = f64::NEG_INFINITY;
+
+        let mut lambda = -2.0;
+        while lambda <= 2.0 {
+            let transformed: Vec<f64> = values
+                .iter()
+                .map(|&v| if lambda == 0.0 { v.ln() } else { (v.powf(lambda) - 1.0) / lambda })
+                .collect();
+
+            let mean = transformed.iter().sum::<f64>() / n;
+            let variance = transformed.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+
+            if variance > 0.0 {
+                let log_likelihood = -0.5 * n * variance.ln() + (lambda - 1.0) * log_sum;
+                if log_likelihood > best_log_likelihood {
+                    best_log_likelihood = log_likelihood;
+                    best_lambda = lambda;
+                }
+            }
+
+            lambda += 0.1;
+        }
+
+        Ok(Self::new(best_lambda))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_columns_close(a: &DataFrame, b: &DataFrame, col: &str, tol: f64) {
+        let a = a.column(col).unwrap().f64().unwrap();
+        let b = b.column(col).unwrap().f64().unwrap();
+        for (x, y) in a.into_iter().zip(b.into_iter()) {
+            match (x, y) {
+                (Some(x), Some(y)) => assert!((x - y).abs() < tol, "{x} vs {y}"),
+                (None, None) => {}
+                (x, y) => panic!("mismatched nulls: {x:?} vs {y:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn log_transform_round_trips() {
+        let data = DataFrame::new(vec![
+            Series::new("value".into(), vec![1.0, 2.0, 10.0, 100.0]).into_column(),
+        ])
+        .unwrap();
+
+        let t = LogTransform::new(10.0);
+        let transformed = t.transform(&data).unwrap();
+        assert_eq!(transformed.width(), data.width());
+        let recovered = t.inverse_transform(&transformed).unwrap();
+
+        assert_columns_close(&data, &recovered, "value", 1e-9);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn box_cox_transform_round_trips() {
+        let data = DataFrame::new(vec![
+            Series::new("value".into(), vec![1.0, 2.0, 10.0, 100.0]).into_column(),
+        ])
+        .unwrap();
+
+        let t = BoxCoxTransform::new(0.5);
+        let transformed = t.transform(&data).unwrap();
+        assert_eq!(transformed.width(), data.width());
+        let recovered = t.inverse_transform(&transformed).unwrap();
+
+        assert_columns_close(&data, &recovered, "value", 1e-9);
+    }
+
+    #[test]
+    fn difference_transform_round_trips() {
+        let data = DataFrame::new(vec![
+            Series::new("value".into(), vec![1.0, 3.0, 6.0, 10.0, 15.0]).into_column(),
+        ])
+        .unwrap();
+
+        let mut t = DifferenceTransform::new(1);
+        let transformed = t.transform(&data).unwrap();
+        let recovered = t.inverse_transform(&transformed).unwrap();
+
+        assert_columns_close(&data, &recovered, "value", 1e-9);
+    }
+}
@@ -7,6 +7,8 @@ use serde::{Serialize, Deserialize};
 use std::collections::HashMap;
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
+use regex::Regex;
+use std::collections::{HashSet, VecDeque};
 
 /// Query processing result
 #[derive(Serialize, Deserialize, Debug, Clone)]
@@ -54,6 +56,24 @@ pub struct ProcessorStats {
     pub total_execution_time_ms: f64,
     pub cache_hit_rate: f64,
     pub pattern_matches: u64,
+    /// Running average, over all queries seen, of query length in whitespace
+    /// tokens. Feeds the `avgdl` term of `PatternEngine`'s BM25 scoring.
+    pub average_query_length: f64,
+    /// Number of cache hits seen, the numerator behind `cache_hit_rate`.
+    pub cache_hits: u64,
+    /// Success/failure counts per query type (the matched pattern's id, or
+    /// `"generic_processing"`/`"cached"` when no pattern matched or the
+    /// result came from cache), exposed by `export_prometheus`.
+    #[serde(default)]
+    pub query_type_counts: FxHashMap<String, QueryTypeCounts>,
+    /// Processing-time histogram across all queries, exposed by
+    /// `export_prometheus`.
+    #[serde(default)]
+    pub latency_histogram: LatencyHistogram,
+    /// Bounded per-query history `aggregate` slices by `Dimension`; the
+    /// oldest entry is dropped once `STATS_ROLLUP_CAPACITY` is exceeded.
+    #[serde(default)]
+    records: VecDeque<StatsRecord>,
 }
 
 impl Default for ProcessorStats {
@@ -66,25 +86,261 @@ impl Default for ProcessorStats {
             total_execution_time_ms: 0.0,
             cache_hit_rate: 0.0,
             pattern_matches: 0,
+            average_query_length: 0.0,
+            cache_hits: 0,
+            query_type_counts: FxHashMap::default(),
+            latency_histogram: LatencyHistogram::default(),
+            records: VecDeque::new(),
         }
     }
 }
 
+impl ProcessorStats {
+    /// Appends one processed query's dimension values, evicting the oldest
+    /// record once `STATS_ROLLUP_CAPACITY` is exceeded.
+    fn record(
+        &mut self,
+        query_type: String,
+        intent: String,
+        sentiment: String,
+        timestamp_ms: f64,
+        success: bool,
+        processing_time_ms: f64,
+        cache_hit: bool,
+    ) {
+        if self.records.len() >= STATS_ROLLUP_CAPACITY {
+            self.records.pop_front();
+        }
+        self.records.push_back(StatsRecord {
+            query_type,
+            intent,
+            sentiment,
+            timestamp_ms,
+            success,
+            processing_time_ms,
+            cache_hit,
+        });
+    }
+
+    /// Groups recorded queries by `dims` (in order) and summarizes each group:
+    /// count, success/failure split, total/average processing time, and
+    /// cache-hit ratio. Rows are sorted by `key` for stable output.
+    pub fn aggregate(&self, dims: &[Dimension]) -> Vec<AggregateRow> {
+        let mut groups: FxHashMap<Vec<String>, AggregateAccumulator> = FxHashMap::default();
+
+        for record in &self.records {
+            let key: Vec<String> = dims.iter().map(|dim| dim.value_for(record)).collect();
+            let accumulator = groups.entry(key).or_default();
+            accumulator.count += 1;
+            if record.success {
+                accumulator.success_count += 1;
+            } else {
+                accumulator.failure_count += 1;
+            }
+            accumulator.total_processing_time_ms += record.processing_time_ms;
+            if record.cache_hit {
+                accumulator.cache_hits += 1;
+            }
+        }
+
+        let mut rows: Vec<AggregateRow> = groups
+            .into_iter()
+            .map(|(key, accumulator)| AggregateRow {
+                key,
+                count: accumulator.count,
+                success_count: accumulator.success_count,
+                failure_count: accumulator.failure_count,
+                total_processing_time_ms: accumulator.total_processing_time_ms,
+                average_processing_time_ms: accumulator.total_processing_time_ms / accumulator.count as f64,
+                cache_hit_ratio: accumulator.cache_hits as f64 / accumulator.count as f64,
+            })
+            .collect();
+
+        rows.sort_by(|a, b| a.key.cmp(&b.key));
+        rows
+    }
+}
+
+/// Success/failure counts for one query type, as tracked in
+/// `ProcessorStats::query_type_counts`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct QueryTypeCounts {
+    pub success: u64,
+    pub failure: u64,
+}
+
+/// Time granularity `Dimension::TimeBucket` buckets a record's processing
+/// timestamp into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum TimeGranularity {
+    Hour,
+    Minute,
+}
+
+/// A dimension `ProcessorStats::aggregate` can group recorded queries by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Dimension {
+    QueryType,
+    Intent,
+    Sentiment,
+    TimeBucket(TimeGranularity),
+}
+
+impl Dimension {
+    fn value_for(&self, record: &StatsRecord) -> String {
+        match self {
+            Dimension::QueryType => record.query_type.clone(),
+            Dimension::Intent => record.intent.clone(),
+            Dimension::Sentiment => record.sentiment.clone(),
+            Dimension::TimeBucket(granularity) => time_bucket_label(record.timestamp_ms, *granularity),
+        }
+    }
+}
+
+/// Buckets epoch-millisecond `timestamp_ms` (e.g. from `js_sys::Date::now`)
+/// into the start-of-bucket epoch second for `granularity`, as a string label.
+fn time_bucket_label(timestamp_ms: f64, granularity: TimeGranularity) -> String {
+    let bucket_seconds: i64 = match granularity {
+        TimeGranularity::Hour => 3600,
+        TimeGranularity::Minute => 60,
+    };
+    let total_seconds = (timestamp_ms / 1000.0).floor() as i64;
+    let bucket_start = total_seconds.div_euclid(bucket_seconds) * bucket_seconds;
+    bucket_start.to_string()
+}
+
+/// One processed query's dimension values and outcome, as recorded by
+/// `ProcessorStats::record` and grouped by `ProcessorStats::aggregate`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct StatsRecord {
+    query_type: String,
+    intent: String,
+    sentiment: String,
+    timestamp_ms: f64,
+    success: bool,
+    processing_time_ms: f64,
+    cache_hit: bool,
+}
+
+/// Running totals `ProcessorStats::aggregate` accumulates per dimension
+/// combination before turning them into an `AggregateRow`.
+#[derive(Default)]
+struct AggregateAccumulator {
+    count: u64,
+    success_count: u64,
+    failure_count: u64,
+    total_processing_time_ms: f64,
+    cache_hits: u64,
+}
+
+/// One row of `ProcessorStats::aggregate`'s output: the dimension values this
+/// group was keyed by (parallel to the `dims` argument, in order), and the
+/// resulting counts/timings/cache-hit ratio for that combination.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AggregateRow {
+    pub key: Vec<String>,
+    pub count: u64,
+    pub success_count: u64,
+    pub failure_count: u64,
+    pub total_processing_time_ms: f64,
+    pub average_processing_time_ms: f64,
+    pub cache_hit_ratio: f64,
+}
+
+/// Bounded history size for `ProcessorStats::records`, the raw data
+/// `aggregate` slices -- the oldest entry is dropped once this is exceeded so
+/// the rollup doesn't grow unbounded over a long-lived processor.
+const STATS_ROLLUP_CAPACITY: usize = 2000;
+
+/// Upper bounds (in milliseconds) of the `queryprocessor_processing_time_ms`
+/// histogram buckets; an implicit final `+Inf` bucket always equals `count`.
+const LATENCY_BUCKET_BOUNDS_MS: &[f64] = &[1.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 5000.0];
+
+/// Cumulative processing-time histogram, in the shape Prometheus's
+/// exposition format expects: a running count per bucket boundary plus the
+/// total sum and count.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct LatencyHistogram {
+    /// Cumulative counts parallel to `LATENCY_BUCKET_BOUNDS_MS`.
+    pub bucket_counts: Vec<u64>,
+    pub sum_ms: f64,
+    pub count: u64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self { bucket_counts: vec![0; LATENCY_BUCKET_BOUNDS_MS.len()], sum_ms: 0.0, count: 0 }
+    }
+}
+
+impl LatencyHistogram {
+    fn observe(&mut self, value_ms: f64) {
+        for (bound, bucket_count) in LATENCY_BUCKET_BOUNDS_MS.iter().zip(self.bucket_counts.iter_mut()) {
+            if value_ms <= *bound {
+                *bucket_count += 1;
+            }
+        }
+        self.sum_ms += value_ms;
+        self.count += 1;
+    }
+}
+
 /// Pattern recognition engine
-#[derive(Debug, Clone)]
 pub struct PatternEngine {
     patterns: FxHashMap<String, QueryPattern>,
     match_threshold: f64,
+    /// Optional semantic embedder, e.g. installed from JS via
+    /// `QueryProcessor::set_embedder`. When set, `match_pattern` blends
+    /// keyword overlap with cosine similarity of embeddings; when unset,
+    /// scoring falls back to keyword overlap alone.
+    embed: Option<Box<dyn Fn(&str) -> Vec<f32>>>,
+    /// Weight given to the keyword-overlap score in the hybrid blend; the
+    /// remainder (`1.0 - alpha`) weights the embedding cosine similarity.
+    alpha: f64,
+    /// Number of patterns whose keyword set contains a given term, used as
+    /// BM25's document frequency so rarer keywords dominate ranking.
+    keyword_df: FxHashMap<String, usize>,
+    /// Short names that resolve to an existing pattern id, set up via
+    /// `alias_pattern`.
+    aliases: FxHashMap<String, String>,
+    /// Inverted index from keyword to the ids of patterns that list it,
+    /// so `match_pattern` can gather BM25 candidates without scanning every
+    /// registered pattern.
+    term_index: FxHashMap<String, Vec<String>>,
+}
+
+impl std::fmt::Debug for PatternEngine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PatternEngine")
+            .field("patterns", &self.patterns)
+            .field("match_threshold", &self.match_threshold)
+            .field("embed", &self.embed.as_ref().map(|_| "<embedder fn>"))
+            .field("alpha", &self.alpha)
+            .field("keyword_df", &self.keyword_df)
+            .field("aliases", &self.aliases)
+            .field("term_index", &self.term_index)
+            .finish()
+    }
 }
 
-#[derive(Debug, Clone)]
+/// Okapi BM25 term-frequency saturation constant.
+const BM25_K1: f64 = 1.2;
+/// Okapi BM25 document-length normalization constant.
+const BM25_B: f64 = 0.75;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct QueryPattern {
     pub id: String,
     pub name: String,
     pub keywords: SmallVec<[String; 8]>,
     pub template: String,
     pub confidence: f64,
+    #[serde(default)]
     pub usage_count: u32,
+    /// Semantic embedding for this pattern, filled in lazily the first time
+    /// it's scored against a query (see `PatternEngine::embed`).
+    #[serde(default)]
+    pub embedding: Option<Vec<f32>>,
 }
 
 impl PatternEngine {
@@ -92,33 +348,97 @@ impl PatternEngine {
         let mut engine = Self {
             patterns: FxHashMap::default(),
             match_threshold: 0.7,
+            embed: None,
+            alpha: 0.5,
+            keyword_df: FxHashMap::default(),
+            aliases: FxHashMap::default(),
+            term_index: FxHashMap::default(),
         };
         engine.load_default_patterns();
         engine
     }
 
-    pub fn match_pattern(&mut self, query: &str) -> Option<QueryPattern> {
+    /// Install a semantic embedder. Once set, every subsequent `match_pattern`
+    /// call blends keyword overlap with embedding cosine similarity instead
+    /// of relying on keywords alone.
+    pub fn set_embed<F>(&mut self, embed: F)
+    where
+        F: Fn(&str) -> Vec<f32> + 'static,
+    {
+        self.embed = Some(Box::new(embed));
+    }
+
+    pub fn set_match_threshold(&mut self, threshold: f64) {
+        self.match_threshold = threshold;
+    }
+
+    /// Sets the keyword/embedding blend weight, clamped to `[0.0, 1.0]`.
+    pub fn set_alpha(&mut self, alpha: f64) {
+        self.alpha = alpha.clamp(0.0, 1.0);
+    }
+
+    /// Ranks patterns against `query` by BM25 (blended with embedding cosine
+    /// similarity when an embedder is installed), using `term_index` to
+    /// narrow the candidates to patterns sharing at least one keyword with
+    /// the query, and returns the top scorer if it clears `match_threshold`.
+    pub fn match_pattern(&mut self, query: &str, stats: &mut ProcessorStats) -> Option<QueryPattern> {
         let query_lower = query.to_lowercase();
+        let query_tokens: Vec<&str> = query_lower.split_whitespace().collect();
+
+        stats.average_query_length += (query_tokens.len() as f64 - stats.average_query_length)
+            / stats.total_queries.max(1) as f64;
+        let avgdl = if stats.average_query_length > 0.0 { stats.average_query_length } else { 1.0 };
+
+        let query_embedding = self.embed.as_ref().map(|embed| l2_normalize(embed(&query_lower)));
         let mut best_match: Option<(String, f64)> = None;
 
-        for (pattern_id, pattern) in &self.patterns {
-            let confidence = self.calculate_confidence(&query_lower, pattern);
-            
-            if confidence >= self.match_threshold {
-                if let Some((_, best_confidence)) = &best_match {
-                    if confidence > *best_confidence {
-                        best_match = Some((pattern_id.clone(), confidence));
+        // With an embedder installed a pattern can still match purely on
+        // semantic similarity with zero keyword overlap, so fall back to
+        // scanning every pattern; otherwise the inverted index lets us skip
+        // patterns that share no keyword with the query at all.
+        let pattern_ids: Vec<String> = if self.embed.is_some() {
+            self.patterns.keys().cloned().collect()
+        } else {
+            let mut candidates: std::collections::HashSet<String> = std::collections::HashSet::new();
+            for token in &query_tokens {
+                if let Some(postings) = self.term_index.get(*token) {
+                    candidates.extend(postings.iter().cloned());
+                }
+            }
+            candidates.into_iter().collect()
+        };
+        let pattern_count = self.patterns.len();
+        for pattern_id in pattern_ids {
+            let score = {
+                let pattern = self.patterns.get_mut(&pattern_id).expect("key from self.patterns");
+                let keyword_score = bm25_score(&query_tokens, pattern, &self.keyword_df, pattern_count, avgdl);
+
+                match &query_embedding {
+                    Some(query_embedding) => {
+                        if pattern.embedding.is_none() {
+                            let embed = self.embed.as_ref().expect("query_embedding implies embed is set");
+                            let text = format!("{} {}", pattern.name, pattern.keywords.join(" "));
+                            pattern.embedding = Some(l2_normalize(embed(&text)));
+                        }
+                        let cosine = cosine_similarity(query_embedding, pattern.embedding.as_ref().unwrap());
+                        self.alpha * keyword_score + (1.0 - self.alpha) * cosine
                     }
-                } else {
-                    best_match = Some((pattern_id.clone(), confidence));
+                    None => keyword_score,
+                }
+            };
+
+            if score >= self.match_threshold {
+                let is_better = best_match.as_ref().map_or(true, |(_, best_score)| score > *best_score);
+                if is_better {
+                    best_match = Some((pattern_id, score));
                 }
             }
         }
 
-        if let Some((pattern_id, confidence)) = best_match {
+        if let Some((pattern_id, score)) = best_match {
             if let Some(pattern) = self.patterns.get_mut(&pattern_id) {
                 pattern.usage_count += 1;
-                pattern.confidence = (pattern.confidence + confidence) / 2.0;
+                pattern.confidence = (pattern.confidence + score) / 2.0;
                 return Some(pattern.clone());
             }
         }
@@ -126,23 +446,6 @@ impl PatternEngine {
         None
     }
 
-    fn calculate_confidence(&self, query: &str, pattern: &QueryPattern) -> f64 {
-        let mut matches = 0;
-        let total_keywords = pattern.keywords.len();
-
-        if total_keywords == 0 {
-            return 0.0;
-        }
-
-        for keyword in &pattern.keywords {
-            if query.contains(keyword) {
-                matches += 1;
-            }
-        }
-
-        matches as f64 / total_keywords as f64
-    }
-
     fn load_default_patterns(&mut self) {
         // Data analysis patterns
         self.add_pattern(QueryPattern {
@@ -163,6 +466,7 @@ impl PatternEngine {
             template: "data_analysis_template".to_string(),
             confidence: 0.8,
             usage_count: 0,
+            embedding: None,
         });
         
         // Machine Learning patterns
@@ -184,6 +488,7 @@ impl PatternEngine {
             template: "ml_template".to_string(),
             confidence: 0.85,
             usage_count: 0,
+            embedding: None,
         });
         
         // System Architecture patterns
@@ -205,6 +510,7 @@ impl PatternEngine {
             template: "architecture_template".to_string(),
             confidence: 0.82,
             usage_count: 0,
+            embedding: None,
         });
         
         // API Design patterns
@@ -226,6 +532,7 @@ impl PatternEngine {
             template: "api_design_template".to_string(),
             confidence: 0.83,
             usage_count: 0,
+            embedding: None,
         });
         
         // Performance Optimization patterns
@@ -247,6 +554,7 @@ impl PatternEngine {
             template: "performance_template".to_string(),
             confidence: 0.84,
             usage_count: 0,
+            embedding: None,
         });
 
         // Question answering patterns
@@ -265,6 +573,7 @@ impl PatternEngine {
             template: "qa_template".to_string(),
             confidence: 0.75,
             usage_count: 0,
+            embedding: None,
         });
 
         // Code generation patterns
@@ -283,6 +592,7 @@ impl PatternEngine {
             template: "code_gen_template".to_string(),
             confidence: 0.85,
             usage_count: 0,
+            embedding: None,
         });
 
         // Problem solving patterns
@@ -304,6 +614,7 @@ impl PatternEngine {
             template: "problem_solving_template".to_string(),
             confidence: 0.8,
             usage_count: 0,
+            embedding: None,
         });
         
         // Security Analysis patterns
@@ -325,6 +636,7 @@ impl PatternEngine {
             template: "security_template".to_string(),
             confidence: 0.87,
             usage_count: 0,
+            embedding: None,
         });
         
         // DevOps patterns
@@ -346,6 +658,7 @@ impl PatternEngine {
             template: "devops_template".to_string(),
             confidence: 0.81,
             usage_count: 0,
+            embedding: None,
         });
         
         // Database Design patterns
@@ -367,14 +680,587 @@ impl PatternEngine {
             template: "database_template".to_string(),
             confidence: 0.79,
             usage_count: 0,
+            embedding: None,
         });
     }
 
     fn add_pattern(&mut self, pattern: QueryPattern) {
+        let unique_keywords: std::collections::HashSet<&str> =
+            pattern.keywords.iter().map(|k| k.as_str()).collect();
+        for keyword in unique_keywords {
+            *self.keyword_df.entry(keyword.to_string()).or_insert(0) += 1;
+            self.term_index.entry(keyword.to_string()).or_default().push(pattern.id.clone());
+        }
+
         self.patterns.insert(pattern.id.clone(), pattern);
     }
+
+    /// Removes a pattern (or alias) by id, returning whether anything was removed.
+    pub fn remove_pattern(&mut self, id: &str) -> bool {
+        let id = self.resolve_alias(id).to_string();
+        let Some(pattern) = self.patterns.remove(&id) else {
+            return false;
+        };
+
+        let unique_keywords: std::collections::HashSet<&str> =
+            pattern.keywords.iter().map(|k| k.as_str()).collect();
+        for keyword in unique_keywords {
+            if let Some(count) = self.keyword_df.get_mut(keyword) {
+                *count = count.saturating_sub(1);
+                if *count == 0 {
+                    self.keyword_df.remove(keyword);
+                }
+            }
+
+            if let Some(postings) = self.term_index.get_mut(keyword) {
+                postings.retain(|candidate_id| candidate_id != &id);
+                if postings.is_empty() {
+                    self.term_index.remove(keyword);
+                }
+            }
+        }
+
+        true
+    }
+
+    fn resolve_alias<'a>(&'a self, id: &'a str) -> &'a str {
+        self.aliases.get(id).map(|target| target.as_str()).unwrap_or(id)
+    }
+
+    /// Makes `alias_id` resolve to the existing pattern `target_id`.
+    pub fn alias_pattern(&mut self, alias_id: &str, target_id: &str) -> Result<(), String> {
+        if !self.patterns.contains_key(target_id) {
+            return Err(format!("unknown target pattern: {target_id}"));
+        }
+        self.aliases.insert(alias_id.to_string(), target_id.to_string());
+        Ok(())
+    }
+
+    /// Returns all currently registered patterns.
+    pub fn list_patterns(&self) -> Vec<QueryPattern> {
+        self.patterns.values().cloned().collect()
+    }
+
+    /// Serializes the full learned pattern set -- including updated
+    /// `usage_count` and smoothed `confidence` -- so it can be restored
+    /// later via `import_patterns`.
+    pub fn export_patterns(&self) -> String {
+        serde_json::to_string(&self.list_patterns()).unwrap_or_default()
+    }
+
+    /// Replaces the current pattern set with one previously produced by
+    /// `export_patterns`.
+    pub fn import_patterns(&mut self, json: &str) -> Result<(), String> {
+        let patterns: Vec<QueryPattern> = serde_json::from_str(json).map_err(|err| err.to_string())?;
+
+        self.patterns.clear();
+        self.keyword_df.clear();
+        self.term_index.clear();
+        for pattern in patterns {
+            self.add_pattern(pattern);
+        }
+
+        Ok(())
+    }
+}
+
+/// Okapi BM25 score of `pattern`'s keywords against `query_tokens`, squashed
+/// to `[0, 1)` via `score / (score + 1)` so it can be compared against
+/// `match_threshold` and blended with the embedding cosine similarity.
+/// Rarer keywords (lower document frequency) contribute more than common
+/// ones, unlike a flat matches/total ratio.
+fn bm25_score(
+    query_tokens: &[&str],
+    pattern: &QueryPattern,
+    keyword_df: &FxHashMap<String, usize>,
+    pattern_count: usize,
+    avgdl: f64,
+) -> f64 {
+    let doc_len = query_tokens.len() as f64;
+    let mut score = 0.0;
+
+    for keyword in &pattern.keywords {
+        let f = query_tokens.iter().filter(|token| *token == keyword).count();
+        if f == 0 {
+            continue;
+        }
+
+        let df = keyword_df.get(keyword.as_str()).copied().unwrap_or(0) as f64;
+        let n = pattern_count as f64;
+        let idf = ((n - df + 0.5) / (df + 0.5) + 1.0).ln();
+        let f = f as f64;
+        let tf_component = (f * (BM25_K1 + 1.0)) / (f + BM25_K1 * (1.0 - BM25_B + BM25_B * doc_len / avgdl));
+
+        score += idf * tf_component;
+    }
+
+    score / (score + 1.0)
+}
+
+/// Scales `vector` to unit length; a zero vector is returned unchanged so
+/// that `cosine_similarity` can treat it as orthogonal to everything (`0.0`)
+/// rather than dividing by zero.
+fn l2_normalize(vector: Vec<f32>) -> Vec<f32> {
+    let norm = vector.iter().map(|v| v * v).sum::<f32>().sqrt();
+    if norm == 0.0 {
+        return vector;
+    }
+    vector.into_iter().map(|v| v / norm).collect()
+}
+
+/// Cosine similarity between two (expected L2-normalized) vectors. Mismatched
+/// lengths or zero-norm inputs yield `0.0` instead of panicking or `NaN`.
+fn cosine_similarity(a: &[f32], b: &[f32]) -> f64 {
+    if a.len() != b.len() || a.is_empty() {
+        return 0.0;
+    }
+
+    let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+    dot as f64
+}
+
+/// Lowercases `query`, splits on whitespace, and drops stopwords, yielding
+/// the bag-of-words set used for clustering similarity.
+fn tokenize_for_clustering(query: &str) -> HashSet<String> {
+    query
+        .to_lowercase()
+        .split_whitespace()
+        .map(|token| token.trim_matches(|c: char| !c.is_alphanumeric()).to_string())
+        .filter(|token| !token.is_empty() && !STOPWORDS.contains(&token.as_str()))
+        .collect()
+}
+
+/// Jaccard similarity `|A ∩ B| / |A ∪ B|` between two bag-of-words sets.
+fn jaccard_similarity(a: &HashSet<String>, b: &HashSet<String>) -> f64 {
+    if a.is_empty() && b.is_empty() {
+        return 0.0;
+    }
+
+    let intersection = a.intersection(b).count();
+    let union = a.union(b).count();
+    intersection as f64 / union as f64
+}
+
+/// Single-pass agglomerative clustering of unmatched queries by bag-of-words
+/// Jaccard similarity: a query joins the first existing cluster whose
+/// highest member similarity exceeds `CLUSTER_SIMILARITY_THRESHOLD`,
+/// otherwise it seeds a new cluster.
+fn cluster_unmatched_queries(history: &VecDeque<String>) -> Vec<Vec<HashSet<String>>> {
+    let mut clusters: Vec<Vec<HashSet<String>>> = Vec::new();
+
+    for query in history {
+        let tokens = tokenize_for_clustering(query);
+        if tokens.is_empty() {
+            continue;
+        }
+
+        let best_cluster = clusters.iter().enumerate().max_by(|(_, a), (_, b)| {
+            let sim_a = a.iter().map(|member| jaccard_similarity(member, &tokens)).fold(0.0, f64::max);
+            let sim_b = b.iter().map(|member| jaccard_similarity(member, &tokens)).fold(0.0, f64::max);
+            sim_a.partial_cmp(&sim_b).unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let joined = best_cluster.and_then(|(index, cluster)| {
+            let similarity = cluster.iter().map(|member| jaccard_similarity(member, &tokens)).fold(0.0, f64::max);
+            (similarity > CLUSTER_SIMILARITY_THRESHOLD).then_some(index)
+        });
+
+        match joined {
+            Some(index) => clusters[index].push(tokens),
+            None => clusters.push(vec![tokens]),
+        }
+    }
+
+    clusters
+}
+
+/// Proposes a `QueryPattern` for each cluster with at least `min_cluster_size`
+/// members: candidate keywords are terms appearing in more than half the
+/// cluster's queries, and confidence is the average fraction of members
+/// sharing each candidate keyword.
+fn discover_patterns_from_history(history: &VecDeque<String>, min_cluster_size: usize) -> Vec<QueryPattern> {
+    let clusters = cluster_unmatched_queries(history);
+    let mut proposals = Vec::new();
+
+    for (index, cluster) in clusters.iter().enumerate() {
+        if cluster.len() < min_cluster_size {
+            continue;
+        }
+
+        let mut term_counts: FxHashMap<&str, usize> = FxHashMap::default();
+        for member in cluster {
+            for term in member {
+                *term_counts.entry(term.as_str()).or_insert(0) += 1;
+            }
+        }
+
+        let member_count = cluster.len() as f64;
+        let candidate_terms: Vec<(&str, usize)> = term_counts
+            .into_iter()
+            .filter(|(_, count)| *count as f64 / member_count > 0.5)
+            .collect();
+
+        if candidate_terms.is_empty() {
+            continue;
+        }
+
+        let confidence =
+            candidate_terms.iter().map(|(_, count)| *count as f64 / member_count).sum::<f64>() / candidate_terms.len() as f64;
+        let keywords: SmallVec<[String; 8]> = candidate_terms.iter().map(|(term, _)| term.to_string()).collect();
+        let id = format!("discovered_{index}");
+
+        proposals.push(QueryPattern {
+            id: id.clone(),
+            name: format!("Discovered Pattern {index}"),
+            keywords,
+            template: format!("{id}_template"),
+            confidence,
+            usage_count: 0,
+            embedding: None,
+        });
+    }
+
+    proposals
+}
+
+/// Why `ExecutionInsights` flagged a processed query, surfaced so callers can
+/// explain why a specific query is expensive without re-deriving it themselves.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryProblem {
+    None,
+    /// Processing time exceeded the rolling `mean + SLOW_EXECUTION_STD_DEVS *
+    /// stddev` of all queries seen so far.
+    SlowExecution,
+    /// `calculate_query_complexity` topped `HIGH_COMPLEXITY_THRESHOLD`.
+    HighComplexity,
+    /// This query's fingerprint has missed cache more than
+    /// `FREQUENT_CACHE_MISS_THRESHOLD` times.
+    FrequentCacheMiss,
+}
+
+/// One processed query's fingerprint, outcome, and diagnosed `QueryProblem`,
+/// as recorded by `ExecutionInsights::record`.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Insight {
+    pub fingerprint: u64,
+    pub pattern_id: Option<String>,
+    pub processing_time_ms: f64,
+    pub cache_hit: bool,
+    pub problem: QueryProblem,
+}
+
+/// Z-score multiplier for flagging a query's processing time as
+/// `QueryProblem::SlowExecution` against the rolling Welford mean/stddev.
+const SLOW_EXECUTION_STD_DEVS: f64 = 2.0;
+
+/// `calculate_query_complexity` score (capped at 10.0 -- see its doc comment)
+/// above which a query is flagged `QueryProblem::HighComplexity`.
+const HIGH_COMPLEXITY_THRESHOLD: f64 = 7.0;
+
+/// Cache misses for the same fingerprint beyond this count flag the query as
+/// `QueryProblem::FrequentCacheMiss`.
+const FREQUENT_CACHE_MISS_THRESHOLD: u32 = 3;
+
+/// Bounded history size for `ExecutionInsights`'s ring buffer.
+const EXECUTION_INSIGHTS_CAPACITY: usize = 500;
+
+/// Strips a query down to a stable shape for fingerprinting: lowercased,
+/// quoted string literals and digit runs collapsed to a placeholder, and
+/// whitespace normalized, so `"find user 42"` and `"find user 7"` fingerprint
+/// identically.
+fn normalize_for_fingerprint(query: &str) -> String {
+    let mut normalized = String::with_capacity(query.len());
+    let mut chars = query.chars().peekable();
+    let mut in_string = false;
+    let mut string_quote = '"';
+
+    while let Some(c) = chars.next() {
+        if in_string {
+            if c == string_quote {
+                in_string = false;
+                normalized.push('?');
+            }
+            continue;
+        }
+        if c == '"' || c == '\'' {
+            in_string = true;
+            string_quote = c;
+            continue;
+        }
+        if c.is_ascii_digit() {
+            while chars.peek().is_some_and(|next| next.is_ascii_digit()) {
+                chars.next();
+            }
+            normalized.push('#');
+            continue;
+        }
+        normalized.push(c.to_ascii_lowercase());
+    }
+
+    normalized.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// A stable fingerprint for `query`, insensitive to literal values, used to
+/// recognize the same query shape recurring across `ExecutionInsights`.
+fn fingerprint_query(query: &str) -> u64 {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    normalize_for_fingerprint(query).hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Bounded ring buffer of recently processed queries with anomaly detection,
+/// modeled on an execution-insights table: each entry carries a query
+/// fingerprint, the matched pattern, timing/cache outcome, and a diagnosed
+/// `QueryProblem`. The rolling mean/variance behind `SlowExecution` is kept
+/// with Welford's online algorithm so no full history needs to be retained.
+struct ExecutionInsights {
+    capacity: usize,
+    ring: VecDeque<Insight>,
+    count: u64,
+    mean_ms: f64,
+    m2: f64,
+    /// Cache misses seen so far per fingerprint, feeding `FrequentCacheMiss`.
+    cache_miss_counts: FxHashMap<u64, u32>,
+}
+
+impl ExecutionInsights {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            ring: VecDeque::with_capacity(capacity),
+            count: 0,
+            mean_ms: 0.0,
+            m2: 0.0,
+            cache_miss_counts: FxHashMap::default(),
+        }
+    }
+
+    fn std_dev_ms(&self) -> f64 {
+        if self.count < 2 { 0.0 } else { (self.m2 / self.count as f64).sqrt() }
+    }
+
+    /// Records one processed query, updates the rolling timing stats and
+    /// per-fingerprint miss count, diagnoses a `QueryProblem`, and returns the
+    /// resulting `Insight` after pushing it onto the ring buffer.
+    fn record(
+        &mut self,
+        query: &str,
+        pattern_id: Option<String>,
+        processing_time_ms: f64,
+        cache_hit: bool,
+        complexity: f64,
+    ) -> Insight {
+        let fingerprint = fingerprint_query(query);
+
+        // Welford's online algorithm: update the running mean/variance before
+        // classifying this sample, so a single outlier widens the threshold
+        // for the *next* query rather than excluding itself from the window.
+        self.count += 1;
+        let delta = processing_time_ms - self.mean_ms;
+        self.mean_ms += delta / self.count as f64;
+        self.m2 += delta * (processing_time_ms - self.mean_ms);
+
+        if !cache_hit {
+            *self.cache_miss_counts.entry(fingerprint).or_insert(0) += 1;
+        }
+        let miss_count = self.cache_miss_counts.get(&fingerprint).copied().unwrap_or(0);
+
+        let problem = if self.count >= 2
+            && processing_time_ms > self.mean_ms + SLOW_EXECUTION_STD_DEVS * self.std_dev_ms()
+        {
+            QueryProblem::SlowExecution
+        } else if complexity > HIGH_COMPLEXITY_THRESHOLD {
+            QueryProblem::HighComplexity
+        } else if miss_count > FREQUENT_CACHE_MISS_THRESHOLD {
+            QueryProblem::FrequentCacheMiss
+        } else {
+            QueryProblem::None
+        };
+
+        let insight = Insight { fingerprint, pattern_id, processing_time_ms, cache_hit, problem };
+
+        if self.ring.len() >= self.capacity {
+            self.ring.pop_front();
+        }
+        self.ring.push_back(insight.clone());
+
+        insight
+    }
+
+    fn recent(&self) -> Vec<Insight> {
+        self.ring.iter().cloned().collect()
+    }
+
+    fn for_fingerprint(&self, fingerprint: u64) -> Vec<Insight> {
+        self.ring.iter().filter(|insight| insight.fingerprint == fingerprint).cloned().collect()
+    }
+}
+
+/// Number of features in the vector `IntentClusterer` clusters on: word
+/// count, char count (scaled), `calculate_query_complexity`, entity count,
+/// and a signed sentiment score, in that order.
+const INTENT_FEATURE_DIMS: usize = 5;
+
+/// Default number of centroids `IntentClusterer` learns, absent an explicit
+/// `k` (mirrors a typical small intent taxonomy: question/create/fix/etc).
+const DEFAULT_INTENT_CLUSTERS: usize = 6;
+
+/// Maps `analyze_sentiment`'s label to the signed score `IntentClusterer`
+/// feeds into its feature vector.
+fn sentiment_score(sentiment: &str) -> f64 {
+    match sentiment {
+        "positive" => 1.0,
+        "negative" => -1.0,
+        _ => 0.0,
+    }
+}
+
+/// Builds the raw (pre-normalization) feature vector `IntentClusterer::assign`
+/// clusters `query` on, from signals this file already computes elsewhere.
+fn intent_feature_vector(query: &str, complexity: f64, entity_count: usize, sentiment: &str) -> [f64; INTENT_FEATURE_DIMS] {
+    [
+        query.split_whitespace().count() as f64,
+        query.len() as f64 / 100.0,
+        complexity,
+        entity_count as f64,
+        sentiment_score(sentiment),
+    ]
+}
+
+/// One learned intent cluster's identity, size, centroid, and the keywords
+/// most frequent among the queries assigned to it, as reported by
+/// `IntentClusterer::cluster_summary`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterSummary {
+    pub cluster_id: usize,
+    pub point_count: u64,
+    pub centroid: [f64; INTENT_FEATURE_DIMS],
+    pub dominant_keywords: Vec<String>,
+}
+
+/// Online k-means clusterer learning intent groups from query feature
+/// vectors, replacing a fixed keyword taxonomy. Centroids update
+/// incrementally (`c <- c + (x - c) / n_assigned`) so no per-query history is
+/// retained, and each feature dimension is normalized by a running min/max
+/// before distance is computed so e.g. `word_count` and `sentiment_score`
+/// contribute comparably despite very different raw scales.
+struct IntentClusterer {
+    k: usize,
+    centroids: Vec<[f64; INTENT_FEATURE_DIMS]>,
+    assigned_counts: Vec<u64>,
+    dim_min: [f64; INTENT_FEATURE_DIMS],
+    dim_max: [f64; INTENT_FEATURE_DIMS],
+    /// Distinct query texts already used to seed a centroid, so seeding stops
+    /// once `k` *distinct* queries have been seen rather than just `k` calls.
+    seeded_queries: HashSet<String>,
+    /// Keyword frequency per cluster, for `cluster_summary`'s dominant
+    /// keywords -- diagnostic only, never affects assignment.
+    cluster_keyword_counts: Vec<FxHashMap<String, u32>>,
+}
+
+impl IntentClusterer {
+    fn new(k: usize) -> Self {
+        Self {
+            k,
+            centroids: Vec::with_capacity(k),
+            assigned_counts: vec![0; k],
+            dim_min: [f64::INFINITY; INTENT_FEATURE_DIMS],
+            dim_max: [f64::NEG_INFINITY; INTENT_FEATURE_DIMS],
+            seeded_queries: HashSet::new(),
+            cluster_keyword_counts: vec![FxHashMap::default(); k],
+        }
+    }
+
+    fn update_bounds(&mut self, raw: &[f64; INTENT_FEATURE_DIMS]) {
+        for i in 0..INTENT_FEATURE_DIMS {
+            self.dim_min[i] = self.dim_min[i].min(raw[i]);
+            self.dim_max[i] = self.dim_max[i].max(raw[i]);
+        }
+    }
+
+    fn normalize(&self, raw: &[f64; INTENT_FEATURE_DIMS]) -> [f64; INTENT_FEATURE_DIMS] {
+        let mut normalized = [0.0; INTENT_FEATURE_DIMS];
+        for i in 0..INTENT_FEATURE_DIMS {
+            let (min, max) = (self.dim_min[i], self.dim_max[i]);
+            normalized[i] = if max > min { (raw[i] - min) / (max - min) } else { 0.0 };
+        }
+        normalized
+    }
+
+    fn squared_distance(a: &[f64; INTENT_FEATURE_DIMS], b: &[f64; INTENT_FEATURE_DIMS]) -> f64 {
+        a.iter().zip(b).map(|(x, y)| (x - y).powi(2)).sum()
+    }
+
+    /// Assigns `query` to the nearest centroid by squared Euclidean distance
+    /// (seeding a fresh centroid instead, as long as fewer than `k` distinct
+    /// queries have seeded one so far), updates that centroid and the running
+    /// min/max, and returns the assigned cluster id.
+    fn assign(&mut self, query: &str, raw: [f64; INTENT_FEATURE_DIMS]) -> usize {
+        self.update_bounds(&raw);
+        let normalized = self.normalize(&raw);
+
+        let cluster_id = if self.centroids.len() < self.k && self.seeded_queries.insert(query.to_string()) {
+            self.centroids.push(normalized);
+            self.centroids.len() - 1
+        } else {
+            self.centroids
+                .iter()
+                .enumerate()
+                .min_by(|(_, a), (_, b)| {
+                    Self::squared_distance(a, &normalized)
+                        .partial_cmp(&Self::squared_distance(b, &normalized))
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+                .map(|(index, _)| index)
+                .expect("assign is only reachable once at least one centroid has been seeded")
+        };
+
+        self.assigned_counts[cluster_id] += 1;
+        let n = self.assigned_counts[cluster_id] as f64;
+        let centroid = &mut self.centroids[cluster_id];
+        for i in 0..INTENT_FEATURE_DIMS {
+            centroid[i] += (normalized[i] - centroid[i]) / n;
+        }
+
+        let keyword_counts = &mut self.cluster_keyword_counts[cluster_id];
+        for token in query.split_whitespace() {
+            let token = token.trim_matches(|c: char| !c.is_alphanumeric()).to_lowercase();
+            if !token.is_empty() && !STOPWORDS.contains(&token.as_str()) {
+                *keyword_counts.entry(token).or_insert(0) += 1;
+            }
+        }
+
+        cluster_id
+    }
+
+    /// Every seeded centroid's id, assigned-point count, current position,
+    /// and up to 5 most frequent keywords among its assigned queries.
+    fn cluster_summary(&self) -> Vec<ClusterSummary> {
+        self.centroids
+            .iter()
+            .enumerate()
+            .map(|(cluster_id, centroid)| {
+                let mut keywords: Vec<(String, u32)> =
+                    self.cluster_keyword_counts[cluster_id].iter().map(|(k, v)| (k.clone(), *v)).collect();
+                keywords.sort_by(|a, b| b.1.cmp(&a.1));
+                keywords.truncate(5);
+
+                ClusterSummary {
+                    cluster_id,
+                    point_count: self.assigned_counts[cluster_id],
+                    centroid: *centroid,
+                    dominant_keywords: keywords.into_iter().map(|(keyword, _)| keyword).collect(),
+                }
+            })
+            .collect()
+    }
 }
 
+/// Tool names matching this pattern are considered dangerous and require an
+/// explicit `confirm` flag before `process_internal` will execute them.
+const DANGEROUS_TOOL_PATTERN: &str = r"(?i)^(delete|drop|remove|destroy|shutdown|kill|format|exec)";
+
 /// High-performance query processor
 #[wasm_bindgen]
 pub struct QueryProcessor {
@@ -382,8 +1268,37 @@ pub struct QueryProcessor {
     stats: ProcessorStats,
     cache: Option<crate::FastCache>,
     optimization_level: u8,
+    /// Named tools a matched pattern can dispatch to instead of its static
+    /// `process_*` fallback, installed from JS via `register_tool`.
+    tools: FxHashMap<String, Box<dyn FnMut(&str, &QueryPattern) -> Result<String, String>>>,
+    /// Maps a pattern's `template` to the name of the tool that should
+    /// handle it, populated via `map_template_to_tool`.
+    tool_mapping: FxHashMap<String, String>,
+    dangerous_tool_pattern: Regex,
+    /// Bounded history of queries no pattern matched, fed to `discover_patterns`.
+    unmatched_queries: VecDeque<String>,
+    /// Ring buffer of recent query outcomes with anomaly detection; see
+    /// `recent_insights`/`insights_for_fingerprint`.
+    execution_insights: ExecutionInsights,
+    /// Online k-means over query feature vectors, learning intent groups
+    /// rather than relying solely on `classify_intent`'s fixed keywords.
+    intent_clusterer: IntentClusterer,
 }
 
+/// Maximum number of unmatched queries kept for `discover_patterns`; the
+/// oldest entry is evicted once this is exceeded.
+const UNMATCHED_HISTORY_CAPACITY: usize = 500;
+
+/// Two unmatched queries join the same cluster when their bag-of-words
+/// Jaccard similarity exceeds this.
+const CLUSTER_SIMILARITY_THRESHOLD: f64 = 0.4;
+
+const STOPWORDS: &[&str] = &[
+    "a", "an", "the", "is", "are", "was", "were", "be", "been", "being", "what", "how", "why", "when", "where",
+    "who", "which", "of", "in", "on", "to", "for", "and", "or", "that", "this", "these", "those", "it", "with",
+    "as", "by", "at", "from", "do", "does", "did", "can", "could", "should", "would", "i", "you", "me",
+];
+
 #[wasm_bindgen]
 impl QueryProcessor {
     /// Create a new query processor
@@ -394,6 +1309,12 @@ impl QueryProcessor {
             stats: ProcessorStats::default(),
             cache: Some(crate::FastCache::new()),
             optimization_level: 1,
+            tools: FxHashMap::default(),
+            tool_mapping: FxHashMap::default(),
+            dangerous_tool_pattern: Regex::new(DANGEROUS_TOOL_PATTERN).expect("valid dangerous tool regex"),
+            unmatched_queries: VecDeque::new(),
+            execution_insights: ExecutionInsights::new(EXECUTION_INSIGHTS_CAPACITY),
+            intent_clusterer: IntentClusterer::new(DEFAULT_INTENT_CLUSTERS),
         }
     }
 
@@ -405,35 +1326,216 @@ impl QueryProcessor {
             stats: ProcessorStats::default(),
             cache: Some(crate::FastCache::with_capacity(cache_size)),
             optimization_level: 1,
+            tools: FxHashMap::default(),
+            tool_mapping: FxHashMap::default(),
+            dangerous_tool_pattern: Regex::new(DANGEROUS_TOOL_PATTERN).expect("valid dangerous tool regex"),
+            unmatched_queries: VecDeque::new(),
+            execution_insights: ExecutionInsights::new(EXECUTION_INSIGHTS_CAPACITY),
+            intent_clusterer: IntentClusterer::new(DEFAULT_INTENT_CLUSTERS),
         }
     }
 
+    /// Register a named tool, wrapping a JS callback `(query, pattern_json) -> string`.
+    /// A matched pattern whose `template` is mapped to this name (via
+    /// `map_template_to_tool`) will invoke it instead of its static fallback.
+    #[wasm_bindgen]
+    pub fn register_tool(&mut self, name: &str, func: &js_sys::Function) {
+        let func = func.clone();
+        self.tools.insert(
+            name.to_string(),
+            Box::new(move |query: &str, pattern: &QueryPattern| {
+                let pattern_json = serde_wasm_bindgen::to_value(pattern).unwrap_or(JsValue::NULL);
+                func.call2(&JsValue::NULL, &JsValue::from_str(query), &pattern_json)
+                    .map_err(|err| format!("{:?}", err))
+                    .and_then(|value| {
+                        value.as_string().ok_or_else(|| "tool did not return a string".to_string())
+                    })
+            }),
+        );
+    }
+
+    /// Route pattern matches on `template` to the tool named `tool_name`.
+    #[wasm_bindgen]
+    pub fn map_template_to_tool(&mut self, template: &str, tool_name: &str) {
+        self.tool_mapping.insert(template.to_string(), tool_name.to_string());
+    }
+
+    /// Register a custom pattern at runtime from `{id, name, keywords, template, confidence}`.
+    #[wasm_bindgen]
+    pub fn add_custom_pattern(&mut self, json: &JsValue) -> Result<(), JsValue> {
+        let pattern: QueryPattern =
+            serde_wasm_bindgen::from_value(json.clone()).map_err(|err| JsValue::from_str(&err.to_string()))?;
+        self.pattern_engine.add_pattern(pattern);
+        Ok(())
+    }
+
+    /// Remove a pattern (or alias) by id. Returns whether anything was removed.
+    #[wasm_bindgen]
+    pub fn remove_pattern(&mut self, id: &str) -> bool {
+        self.pattern_engine.remove_pattern(id)
+    }
+
+    /// List all currently registered patterns.
+    #[wasm_bindgen]
+    pub fn list_patterns(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.pattern_engine.list_patterns()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Make `alias_id` resolve to the existing pattern `target_id`.
+    #[wasm_bindgen]
+    pub fn alias_pattern(&mut self, alias_id: &str, target_id: &str) -> Result<(), JsValue> {
+        self.pattern_engine.alias_pattern(alias_id, target_id).map_err(|err| JsValue::from_str(&err))
+    }
+
+    /// Serialize the full learned pattern set (including usage_count and confidence) to JSON.
+    #[wasm_bindgen]
+    pub fn export_patterns(&self) -> String {
+        self.pattern_engine.export_patterns()
+    }
+
+    /// Replace the pattern set with one previously produced by `export_patterns`.
+    #[wasm_bindgen]
+    pub fn import_patterns(&mut self, json: &str) -> Result<(), JsValue> {
+        self.pattern_engine.import_patterns(json).map_err(|err| JsValue::from_str(&err))
+    }
+
+    /// Cluster the unmatched-query history and propose new `QueryPattern`s for
+    /// clusters of at least `min_cluster_size` queries. Proposals are returned
+    /// for the caller to accept via `add_custom_pattern`; nothing is inserted
+    /// automatically.
+    #[wasm_bindgen]
+    pub fn discover_patterns(&self, min_cluster_size: usize) -> JsValue {
+        let proposals = discover_patterns_from_history(&self.unmatched_queries, min_cluster_size);
+        serde_wasm_bindgen::to_value(&proposals).unwrap_or(JsValue::NULL)
+    }
+
     /// Process a query string and return the result
     #[wasm_bindgen]
     pub fn process(&mut self, query: &str) -> String {
-        let result = self.process_query(query);
+        let result = self.process_query(query, false);
         result.result_data
     }
 
     /// Process a query and return detailed results
     #[wasm_bindgen]
     pub fn process_detailed(&mut self, query: &str) -> JsValue {
-        let result = self.process_query(query);
+        let result = self.process_query(query, false);
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
+    /// Process a query, allowing dangerous tools (see `DANGEROUS_TOOL_PATTERN`) to execute
+    #[wasm_bindgen]
+    pub fn process_confirmed(&mut self, query: &str) -> String {
+        let result = self.process_query(query, true);
+        result.result_data
+    }
+
     /// Set optimization level (0-3)
     #[wasm_bindgen]
     pub fn set_optimization_level(&mut self, level: u8) {
         self.optimization_level = level.min(3);
     }
 
+    /// Set the minimum score a pattern must reach to be considered a match
+    #[wasm_bindgen]
+    pub fn set_match_threshold(&mut self, threshold: f64) {
+        self.pattern_engine.set_match_threshold(threshold);
+    }
+
+    /// Set the keyword/embedding blend weight used once an embedder is installed
+    #[wasm_bindgen]
+    pub fn set_alpha(&mut self, alpha: f64) {
+        self.pattern_engine.set_alpha(alpha);
+    }
+
+    /// Install a JS embedding function for hybrid keyword + semantic pattern
+    /// matching. `callback` takes a text string and returns an array of numbers.
+    #[wasm_bindgen]
+    pub fn set_embedder(&mut self, callback: js_sys::Function) {
+        self.pattern_engine.set_embed(move |text: &str| {
+            callback
+                .call1(&JsValue::NULL, &JsValue::from_str(text))
+                .ok()
+                .and_then(|value| serde_wasm_bindgen::from_value::<Vec<f32>>(value).ok())
+                .unwrap_or_default()
+        });
+    }
+
     /// Get processor statistics
     #[wasm_bindgen]
     pub fn get_stats(&self) -> JsValue {
         serde_wasm_bindgen::to_value(&self.stats).unwrap_or(JsValue::NULL)
     }
 
+    /// Every learned intent cluster's id, size, centroid, and dominant
+    /// keywords, from `IntentClusterer`.
+    #[wasm_bindgen]
+    pub fn cluster_summary(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.intent_clusterer.cluster_summary()).unwrap_or(JsValue::NULL)
+    }
+
+    /// The most recent processed queries with their diagnosed `QueryProblem`,
+    /// oldest first -- see `ExecutionInsights`.
+    #[wasm_bindgen]
+    pub fn recent_insights(&self) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.execution_insights.recent()).unwrap_or(JsValue::NULL)
+    }
+
+    /// Insights recorded for queries fingerprinting the same as `fingerprint`.
+    #[wasm_bindgen]
+    pub fn insights_for_fingerprint(&self, fingerprint: u64) -> JsValue {
+        serde_wasm_bindgen::to_value(&self.execution_insights.for_fingerprint(fingerprint)).unwrap_or(JsValue::NULL)
+    }
+
+    /// Group recorded queries by `dims` (a JS array of `Dimension`, e.g.
+    /// `["QueryType", {"TimeBucket": "Hour"}]`) and return per-group
+    /// counts/timings/cache-hit ratio -- see `ProcessorStats::aggregate`.
+    #[wasm_bindgen]
+    pub fn aggregate_stats(&self, dims: &JsValue) -> Result<JsValue, JsValue> {
+        let dims: Vec<Dimension> =
+            serde_wasm_bindgen::from_value(dims.clone()).map_err(|err| JsValue::from_str(&err.to_string()))?;
+        Ok(serde_wasm_bindgen::to_value(&self.stats.aggregate(&dims)).unwrap_or(JsValue::NULL))
+    }
+
+    /// Render `self.stats` as Prometheus text exposition format, suitable for
+    /// serving from a `/metrics` scrape endpoint.
+    #[wasm_bindgen]
+    pub fn export_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP queryprocessor_queries_total Total queries processed, by type and status.\n");
+        out.push_str("# TYPE queryprocessor_queries_total counter\n");
+        let mut types: Vec<&String> = self.stats.query_type_counts.keys().collect();
+        types.sort();
+        for query_type in types {
+            let counts = &self.stats.query_type_counts[query_type];
+            out.push_str(&format!(
+                "queryprocessor_queries_total{{type=\"{query_type}\",status=\"success\"}} {}\n",
+                counts.success
+            ));
+            out.push_str(&format!(
+                "queryprocessor_queries_total{{type=\"{query_type}\",status=\"failure\"}} {}\n",
+                counts.failure
+            ));
+        }
+
+        out.push_str("# HELP queryprocessor_processing_time_ms Query processing time in milliseconds.\n");
+        out.push_str("# TYPE queryprocessor_processing_time_ms histogram\n");
+        let histogram = &self.stats.latency_histogram;
+        for (bound, bucket_count) in LATENCY_BUCKET_BOUNDS_MS.iter().zip(histogram.bucket_counts.iter()) {
+            out.push_str(&format!("queryprocessor_processing_time_ms_bucket{{le=\"{bound}\"}} {bucket_count}\n"));
+        }
+        out.push_str(&format!("queryprocessor_processing_time_ms_bucket{{le=\"+Inf\"}} {}\n", histogram.count));
+        out.push_str(&format!("queryprocessor_processing_time_ms_sum {}\n", histogram.sum_ms));
+        out.push_str(&format!("queryprocessor_processing_time_ms_count {}\n", histogram.count));
+
+        out.push_str("# HELP queryprocessor_cache_hit_rate Fraction of queries served from cache.\n");
+        out.push_str("# TYPE queryprocessor_cache_hit_rate gauge\n");
+        out.push_str(&format!("queryprocessor_cache_hit_rate {}\n", self.stats.cache_hit_rate));
+
+        out
+    }
+
     /// Clear processor cache
     #[wasm_bindgen]
     pub fn clear_cache(&mut self) {
@@ -449,7 +1551,7 @@ impl QueryProcessor {
 
         if let Ok(queries) = serde_wasm_bindgen::from_value::<Vec<String>>(sample_queries.clone()) {
             for query in queries {
-                self.process_query(&query);
+                self.process_query(&query, false);
                 processed_count += 1;
             }
         }
@@ -460,7 +1562,7 @@ impl QueryProcessor {
 
 impl QueryProcessor {
     /// Internal query processing with full result details
-    pub fn process_query(&mut self, query: &str) -> QueryResult {
+    pub fn process_query(&mut self, query: &str, confirm: bool) -> QueryResult {
         let start_time = js_sys::Date::now();
         self.stats.total_queries += 1;
 
@@ -468,15 +1570,19 @@ impl QueryProcessor {
         if let Some(cache) = &mut self.cache {
             if let Some(cached_result) = cache.get(query) {
                 let execution_time = js_sys::Date::now() - start_time;
-                self.update_stats(true, execution_time, true);
-                
+                let intent = self.classify_intent(query);
+                let sentiment = self.analyze_sentiment(query);
+                self.update_stats(true, execution_time, true, "cached", &intent, &sentiment, start_time);
+                let complexity = self.calculate_query_complexity(query);
+                self.execution_insights.record(query, None, execution_time, true, complexity);
+
                 return QueryResult::new(true, execution_time, cached_result)
                     .with_cache_hit(true);
             }
         }
 
         // Process the query
-        let result = self.process_internal(query);
+        let result = self.process_internal(query, confirm);
         let execution_time = js_sys::Date::now() - start_time;
 
         // Cache the result if successful
@@ -486,7 +1592,19 @@ impl QueryProcessor {
             }
         }
 
-        self.update_stats(result.success, execution_time, false);
+        let query_type = result
+            .metadata
+            .get("pattern_id")
+            .and_then(|value| value.as_str())
+            .unwrap_or("generic_processing")
+            .to_string();
+        let intent = self.classify_intent(query);
+        let sentiment = self.analyze_sentiment(query);
+        self.update_stats(result.success, execution_time, false, &query_type, &intent, &sentiment, start_time);
+
+        let pattern_id = result.metadata.get("pattern_id").and_then(|value| value.as_str()).map(str::to_string);
+        let complexity = self.calculate_query_complexity(query);
+        self.execution_insights.record(query, pattern_id, execution_time, false, complexity);
 
         QueryResult::new(result.success, execution_time, result.result_data)
             .with_cache_hit(false)
@@ -494,11 +1612,42 @@ impl QueryProcessor {
                           serde_json::Value::Bool(result.success))
     }
 
-    fn process_internal(&mut self, query: &str) -> QueryResult {
+    fn process_internal(&mut self, query: &str, confirm: bool) -> QueryResult {
         // Pattern matching
-        if let Some(pattern) = self.pattern_engine.match_pattern(query) {
+        if let Some(pattern) = self.pattern_engine.match_pattern(query, &mut self.stats) {
             self.stats.pattern_matches += 1;
-            
+
+            if let Some(tool_name) = self.tool_mapping.get(&pattern.template).cloned() {
+                if self.dangerous_tool_pattern.is_match(&tool_name) && !confirm {
+                    return QueryResult::new(false, 0.0, String::new())
+                        .with_metadata("pattern_id".to_string(), serde_json::Value::String(pattern.id))
+                        .with_metadata("tool".to_string(), serde_json::Value::String(tool_name))
+                        .with_metadata(
+                            "error".to_string(),
+                            serde_json::Value::String("dangerous tool requires confirm=true".to_string()),
+                        );
+                }
+
+                if let Some(tool) = self.tools.get_mut(&tool_name) {
+                    return match tool(query, &pattern) {
+                        Ok(result_data) => QueryResult::new(true, 0.0, result_data)
+                            .with_metadata("pattern_id".to_string(), serde_json::Value::String(pattern.id.clone()))
+                            .with_metadata("tool".to_string(), serde_json::Value::String(tool_name))
+                            .with_metadata(
+                                "confidence".to_string(),
+                                serde_json::Value::Number(
+                                    serde_json::Number::from_f64(pattern.confidence)
+                                        .unwrap_or_else(|| serde_json::Number::from(0)),
+                                ),
+                            ),
+                        Err(error) => QueryResult::new(false, 0.0, String::new())
+                            .with_metadata("pattern_id".to_string(), serde_json::Value::String(pattern.id.clone()))
+                            .with_metadata("tool".to_string(), serde_json::Value::String(tool_name))
+                            .with_metadata("error".to_string(), serde_json::Value::String(error)),
+                    };
+                }
+            }
+
             let result_data = match pattern.template.as_str() {
                 "data_analysis_template" => self.process_data_analysis(query),
                 "qa_template" => self.process_question_answer(query),
@@ -522,6 +1671,10 @@ impl QueryProcessor {
                                   serde_json::Number::from_f64(pattern.confidence).unwrap()
                               ))
         } else {
+            // No pattern reached match_threshold -- keep the query around for
+            // discover_patterns to cluster later.
+            self.record_unmatched_query(query);
+
             // Generic processing
             let result_data = self.process_generic(query);
             QueryResult::new(true, 0.0, result_data)
@@ -814,7 +1967,7 @@ impl QueryProcessor {
         }).to_string()
     }
     
-    fn process_generic(&self, query: &str) -> String {
+    fn process_generic(&mut self, query: &str) -> String {
         serde_json::json!({
             "type": "generic_processing",
             "query": query,
@@ -831,6 +1984,7 @@ impl QueryProcessor {
                 "semantic_analysis": {
                     "entities": self.extract_entities(query),
                     "intent": self.classify_intent(query),
+                    "discovered_intent": self.discovered_intent(query),
                     "sentiment": self.analyze_sentiment(query)
                 }
             },
@@ -873,6 +2027,17 @@ impl QueryProcessor {
         }
     }
     
+    /// Learned intent cluster id for `query`, from online k-means over
+    /// `[word_count, char_count/100, complexity, entity_count, sentiment_score]`
+    /// -- a data-driven complement to `classify_intent`'s fixed keyword rules.
+    fn discovered_intent(&mut self, query: &str) -> usize {
+        let complexity = self.calculate_query_complexity(query);
+        let entity_count = self.extract_entities(query).len();
+        let sentiment = self.analyze_sentiment(query);
+        let raw = intent_feature_vector(query, complexity, entity_count, &sentiment);
+        self.intent_clusterer.assign(query, raw)
+    }
+
     fn analyze_sentiment(&self, query: &str) -> String {
         let query_lower = query.to_lowercase();
         let positive_words = ["good", "great", "excellent", "amazing", "perfect"];
@@ -904,7 +2069,16 @@ impl QueryProcessor {
         complexity.min(10.0).max(0.1)
     }
 
-    fn update_stats(&mut self, success: bool, execution_time: f64, cache_hit: bool) {
+    fn update_stats(
+        &mut self,
+        success: bool,
+        execution_time: f64,
+        cache_hit: bool,
+        query_type: &str,
+        intent: &str,
+        sentiment: &str,
+        timestamp_ms: f64,
+    ) {
         if success {
             self.stats.successful_queries += 1;
         } else {
@@ -912,20 +2086,44 @@ impl QueryProcessor {
         }
 
         self.stats.total_execution_time_ms += execution_time;
-        
+
         if self.stats.total_queries > 0 {
-            self.stats.average_execution_time_ms = 
+            self.stats.average_execution_time_ms =
                 self.stats.total_execution_time_ms / self.stats.total_queries as f64;
         }
 
-        // Update cache hit rate
+        self.stats.latency_histogram.observe(execution_time);
+
+        let counts = self.stats.query_type_counts.entry(query_type.to_string()).or_default();
+        if success {
+            counts.success += 1;
+        } else {
+            counts.failure += 1;
+        }
+
         if cache_hit {
-            let total_cache_requests = self.stats.successful_queries;
-            if total_cache_requests > 0 {
-                // Simplified cache hit rate calculation
-                self.stats.cache_hit_rate = 0.75; // Placeholder
-            }
+            self.stats.cache_hits += 1;
         }
+        self.stats.cache_hit_rate = self.stats.cache_hits as f64 / self.stats.total_queries as f64;
+
+        self.stats.record(
+            query_type.to_string(),
+            intent.to_string(),
+            sentiment.to_string(),
+            timestamp_ms,
+            success,
+            execution_time,
+            cache_hit,
+        );
+    }
+
+    /// Remembers a query no pattern matched, for later clustering by
+    /// `discover_patterns`, evicting the oldest entry once at capacity.
+    fn record_unmatched_query(&mut self, query: &str) {
+        if self.unmatched_queries.len() >= UNMATCHED_HISTORY_CAPACITY {
+            self.unmatched_queries.pop_front();
+        }
+        self.unmatched_queries.push_back(query.to_string());
     }
 }
 
@@ -949,7 +2147,8 @@ mod tests {
     #[test]
     fn test_pattern_matching() {
         let mut engine = PatternEngine::new();
-        let pattern = engine.match_pattern("analyze the data");
+        let mut stats = ProcessorStats::default();
+        let pattern = engine.match_pattern("analyze the data", &mut stats);
         assert!(pattern.is_some());
         
         if let Some(p) = pattern {
@@ -957,25 +2156,296 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_match_pattern_finds_no_candidates_without_keyword_overlap() {
+        let mut engine = PatternEngine::new();
+        let mut stats = ProcessorStats::default();
+        let pattern = engine.match_pattern("xyzzy plugh", &mut stats);
+        assert!(pattern.is_none());
+    }
+
+    #[test]
+    fn test_hybrid_matching_caches_embeddings() {
+        let mut engine = PatternEngine::new();
+        engine.set_embed(|text: &str| {
+            if text.contains("analyze") || text.contains("data") {
+                vec![1.0, 0.0]
+            } else {
+                vec![0.0, 1.0]
+            }
+        });
+
+        let mut stats = ProcessorStats::default();
+        let pattern = engine.match_pattern("analyze the data", &mut stats).expect("should match");
+        assert_eq!(pattern.id, "data_analysis");
+        assert!(pattern.embedding.is_some());
+    }
+
     #[test]
     fn test_query_processing() {
         let mut processor = QueryProcessor::new();
-        let result = processor.process_query("what is the weather?");
+        let result = processor.process_query("what is the weather?", false);
         assert!(result.success);
         assert!(!result.result_data.is_empty());
     }
 
+    #[test]
+    fn test_dangerous_tool_pattern_flags_destructive_names() {
+        let pattern = Regex::new(DANGEROUS_TOOL_PATTERN).unwrap();
+        assert!(pattern.is_match("delete_records"));
+        assert!(pattern.is_match("DROP_TABLE"));
+        assert!(!pattern.is_match("fetch_weather"));
+    }
+
+    #[test]
+    fn test_unregistered_tool_mapping_falls_back_to_static_processing() {
+        let mut processor = QueryProcessor::new();
+        processor.map_template_to_tool("qa_template", "missing_tool");
+        let result = processor.process_query("what is the weather?", false);
+        assert!(result.success);
+    }
+
+    #[test]
+    fn test_pattern_alias_and_remove() {
+        let mut engine = PatternEngine::new();
+        assert!(engine.alias_pattern("analytics", "data_analysis").is_ok());
+        assert!(engine.alias_pattern("missing_alias", "does_not_exist").is_err());
+
+        assert!(engine.remove_pattern("analytics"));
+        assert!(!engine.patterns.contains_key("data_analysis"));
+    }
+
+    #[test]
+    fn test_export_import_patterns_round_trip() {
+        let mut engine = PatternEngine::new();
+        engine.match_pattern("analyze the data", &mut ProcessorStats::default());
+        let exported = engine.export_patterns();
+
+        let mut fresh = PatternEngine::new();
+        fresh.import_patterns(&exported).expect("import should succeed");
+
+        let pattern = fresh.list_patterns().into_iter().find(|p| p.id == "data_analysis").unwrap();
+        assert_eq!(pattern.usage_count, 1);
+    }
+
     #[test]
     fn test_cache_functionality() {
         let mut processor = QueryProcessor::new();
-        
+
         // Process same query twice
         let query = "test query";
-        let result1 = processor.process_query(query);
-        let result2 = processor.process_query(query);
-        
+        let result1 = processor.process_query(query, false);
+        let result2 = processor.process_query(query, false);
+
         assert!(result1.success);
         assert!(result2.success);
         assert!(result2.cache_hit); // Second should be cache hit
     }
+
+    #[test]
+    fn test_discover_patterns_from_history_clusters_similar_unmatched_queries() {
+        let mut history = VecDeque::new();
+        for query in [
+            "how do i reset my flux capacitor",
+            "how do i reset my flux capacitor please",
+            "reset the flux capacitor for me",
+            "what is the weather today",
+        ] {
+            history.push_back(query.to_string());
+        }
+
+        let proposals = discover_patterns_from_history(&history, 2);
+
+        assert_eq!(proposals.len(), 1);
+        let proposal = &proposals[0];
+        assert!(proposal.keywords.iter().any(|k| k == "reset"));
+        assert!(proposal.keywords.iter().any(|k| k == "flux"));
+        assert!(proposal.confidence > 0.5);
+    }
+
+    #[test]
+    fn test_record_unmatched_query_evicts_oldest_at_capacity() {
+        let mut processor = QueryProcessor::new();
+        for i in 0..(UNMATCHED_HISTORY_CAPACITY + 10) {
+            processor.record_unmatched_query(&format!("unmatched query {i}"));
+        }
+
+        assert_eq!(processor.unmatched_queries.len(), UNMATCHED_HISTORY_CAPACITY);
+        assert_eq!(processor.unmatched_queries.front().unwrap(), "unmatched query 10");
+    }
+
+    #[test]
+    fn test_fingerprint_query_ignores_literals() {
+        assert_eq!(fingerprint_query("find user 42"), fingerprint_query("find user 7"));
+        assert_eq!(fingerprint_query(r#"find user "alice""#), fingerprint_query(r#"find user "bob""#));
+        assert_ne!(fingerprint_query("find user 42"), fingerprint_query("delete user 42"));
+    }
+
+    #[test]
+    fn test_execution_insights_flags_slow_execution() {
+        let mut insights = ExecutionInsights::new(10);
+        for _ in 0..10 {
+            insights.record("steady query", None, 10.0, true, 1.0);
+        }
+        let slow = insights.record("steady query", None, 1000.0, true, 1.0);
+        assert_eq!(slow.problem, QueryProblem::SlowExecution);
+    }
+
+    #[test]
+    fn test_execution_insights_flags_high_complexity() {
+        let mut insights = ExecutionInsights::new(10);
+        let insight = insights.record("complex query", None, 10.0, true, 9.5);
+        assert_eq!(insight.problem, QueryProblem::HighComplexity);
+    }
+
+    #[test]
+    fn test_execution_insights_flags_frequent_cache_miss() {
+        let mut insights = ExecutionInsights::new(10);
+        let mut last = insights.record("repeated miss", None, 10.0, false, 1.0);
+        for _ in 0..FREQUENT_CACHE_MISS_THRESHOLD {
+            last = insights.record("repeated miss", None, 10.0, false, 1.0);
+        }
+        assert_eq!(last.problem, QueryProblem::FrequentCacheMiss);
+    }
+
+    #[test]
+    fn test_execution_insights_ring_buffer_evicts_oldest() {
+        let mut insights = ExecutionInsights::new(2);
+        insights.record("a", None, 1.0, true, 1.0);
+        insights.record("b", None, 1.0, true, 1.0);
+        insights.record("c", None, 1.0, true, 1.0);
+
+        let recent = insights.recent();
+        assert_eq!(recent.len(), 2);
+        assert_eq!(recent[0].fingerprint, fingerprint_query("b"));
+        assert_eq!(recent[1].fingerprint, fingerprint_query("c"));
+    }
+
+    #[test]
+    fn test_insights_for_fingerprint_filters_by_query_shape() {
+        let mut insights = ExecutionInsights::new(10);
+        insights.record("find user 1", None, 1.0, true, 1.0);
+        insights.record("find user 2", None, 1.0, true, 1.0);
+        insights.record("unrelated query", None, 1.0, true, 1.0);
+
+        let matches = insights.for_fingerprint(fingerprint_query("find user 99"));
+        assert_eq!(matches.len(), 2);
+    }
+
+    #[test]
+    fn test_process_query_records_execution_insight() {
+        let mut processor = QueryProcessor::new();
+        processor.process_query("analyze the data", false);
+        assert_eq!(processor.execution_insights.recent().len(), 1);
+    }
+
+    #[test]
+    fn test_intent_clusterer_seeds_distinct_centroids_up_to_k() {
+        let mut clusterer = IntentClusterer::new(2);
+        let a = clusterer.assign("first query", [1.0, 0.1, 1.0, 0.0, 0.0]);
+        let b = clusterer.assign("second query", [10.0, 1.0, 5.0, 2.0, 1.0]);
+        assert_ne!(a, b);
+        assert_eq!(clusterer.cluster_summary().len(), 2);
+    }
+
+    #[test]
+    fn test_intent_clusterer_assigns_nearest_centroid_once_seeded() {
+        let mut clusterer = IntentClusterer::new(2);
+        clusterer.assign("low", [1.0, 0.1, 1.0, 0.0, 0.0]);
+        clusterer.assign("high", [10.0, 1.0, 5.0, 2.0, 1.0]);
+
+        let cluster = clusterer.assign("also low", [1.2, 0.1, 1.1, 0.0, 0.0]);
+        assert_eq!(cluster, 0);
+    }
+
+    #[test]
+    fn test_intent_clusterer_tracks_dominant_keywords() {
+        let mut clusterer = IntentClusterer::new(1);
+        clusterer.assign("reset the flux capacitor", [3.0, 0.25, 1.0, 0.0, 0.0]);
+        clusterer.assign("reset the flux capacitor again", [4.0, 0.3, 1.0, 0.0, 0.0]);
+
+        let summary = clusterer.cluster_summary();
+        assert_eq!(summary.len(), 1);
+        assert!(summary[0].dominant_keywords.contains(&"reset".to_string()));
+        assert_eq!(summary[0].point_count, 2);
+    }
+
+    #[test]
+    fn test_discovered_intent_reports_alongside_rule_based_intent() {
+        let mut processor = QueryProcessor::new();
+        let result = processor.process_query("xyzzy plugh foobar", false);
+        let data: serde_json::Value = serde_json::from_str(&result.result_data).unwrap();
+        let semantic = &data["result"]["semantic_analysis"];
+        assert!(semantic.get("intent").is_some());
+        assert!(semantic.get("discovered_intent").is_some());
+    }
+
+    #[test]
+    fn test_time_bucket_label_groups_within_the_same_bucket() {
+        let minute_start = 1_700_000_000_000.0;
+        assert_eq!(
+            time_bucket_label(minute_start, TimeGranularity::Minute),
+            time_bucket_label(minute_start + 30_000.0, TimeGranularity::Minute)
+        );
+        assert_ne!(
+            time_bucket_label(minute_start, TimeGranularity::Minute),
+            time_bucket_label(minute_start + 60_000.0, TimeGranularity::Minute)
+        );
+    }
+
+    #[test]
+    fn test_aggregate_groups_by_query_type() {
+        let mut stats = ProcessorStats::default();
+        stats.record("data_analysis".to_string(), "general".to_string(), "neutral".to_string(), 0.0, true, 10.0, false);
+        stats.record("data_analysis".to_string(), "general".to_string(), "neutral".to_string(), 0.0, false, 20.0, false);
+        stats.record("qa_template".to_string(), "general".to_string(), "neutral".to_string(), 0.0, true, 5.0, true);
+
+        let rows = stats.aggregate(&[Dimension::QueryType]);
+        assert_eq!(rows.len(), 2);
+
+        let data_analysis_row = rows.iter().find(|row| row.key == vec!["data_analysis".to_string()]).unwrap();
+        assert_eq!(data_analysis_row.count, 2);
+        assert_eq!(data_analysis_row.success_count, 1);
+        assert_eq!(data_analysis_row.failure_count, 1);
+        assert_eq!(data_analysis_row.average_processing_time_ms, 15.0);
+        assert_eq!(data_analysis_row.cache_hit_ratio, 0.0);
+
+        let qa_row = rows.iter().find(|row| row.key == vec!["qa_template".to_string()]).unwrap();
+        assert_eq!(qa_row.cache_hit_ratio, 1.0);
+    }
+
+    #[test]
+    fn test_aggregate_composes_multiple_dimensions() {
+        let mut stats = ProcessorStats::default();
+        stats.record("data_analysis".to_string(), "information_seeking".to_string(), "positive".to_string(), 0.0, true, 10.0, false);
+        stats.record("data_analysis".to_string(), "creation".to_string(), "positive".to_string(), 0.0, true, 10.0, false);
+
+        let rows = stats.aggregate(&[Dimension::QueryType, Dimension::Intent]);
+        assert_eq!(rows.len(), 2);
+        assert!(rows.iter().all(|row| row.key[0] == "data_analysis"));
+    }
+
+    #[test]
+    fn test_process_query_feeds_stats_aggregate() {
+        let mut processor = QueryProcessor::new();
+        processor.process_query("analyze the data", false);
+
+        let rows = processor.stats.aggregate(&[Dimension::QueryType]);
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].key, vec!["data_analysis".to_string()]);
+    }
+
+    #[test]
+    fn test_export_prometheus_reports_counts_and_cache_hit_rate() {
+        let mut processor = QueryProcessor::new();
+        processor.process_query("analyze the data", false);
+        processor.process_query("analyze the data", false); // second call is a cache hit
+
+        let exported = processor.export_prometheus();
+
+        assert!(exported.contains("queryprocessor_queries_total{type=\"data_analysis\",status=\"success\"} 1"));
+        assert!(exported.contains("queryprocessor_queries_total{type=\"cached\",status=\"success\"} 1"));
+        assert!(exported.contains("queryprocessor_processing_time_ms_count 2"));
+        assert!(exported.contains("queryprocessor_cache_hit_rate 0.5"));
+    }
 }
\ No newline at end of file
@@ -628,6 +628,182 @@ fn extract_key_points(data: &serde_json::Value) -> Vec<String> {
     ]
 }
 
+/// Per-step timing distribution collected by `benchmark_template`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StepBenchmark {
+    pub step_id: String,
+    pub count: usize,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub mean_ms: f64,
+    pub median_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub std_dev_ms: f64,
+    pub success_rate: f64,
+    pub expected_ms: Option<f64>,
+    pub regression: bool,
+}
+
+/// Aggregated output of `benchmark_template`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct BenchmarkReport {
+    pub template_id: String,
+    pub iterations: u32,
+    pub warmup: u32,
+    pub steps: Vec<StepBenchmark>,
+    pub any_regression: bool,
+}
+
+/// Run a cognitive template repeatedly to measure real per-step performance.
+///
+/// Executes `warmup` discarded passes followed by `iterations` timed passes,
+/// then compares each step's measured mean against
+/// `ProcessingStep::performance_metrics` / `TemplatePattern::expected_execution_time_ms`
+/// (step-level metrics take precedence when present) using `tolerance_pct`
+/// (e.g. `20.0` flags a step that runs 20% slower than expected).
+#[wasm_bindgen]
+pub fn benchmark_template(
+    template_json: &str,
+    context_json: &str,
+    iterations: u32,
+    warmup: u32,
+    tolerance_pct: f64,
+) -> String {
+    let (mut template, context) = match (
+        serde_json::from_str::<CognitiveTemplate>(template_json),
+        serde_json::from_str::<serde_json::Value>(context_json),
+    ) {
+        (Ok(t), Ok(c)) => (t, c),
+        (Err(e), _) => return format!(r#"{{"error": "Invalid template: {}"}}"#, e),
+        (_, Err(e)) => return format!(r#"{{"error": "Invalid context: {}"}}"#, e),
+    };
+
+    for _ in 0..warmup {
+        let _ = apply_template(&template, &context);
+    }
+
+    let mut samples: HashMap<String, Vec<f64>> = HashMap::new();
+    let mut successes: HashMap<String, u64> = HashMap::new();
+
+    for _ in 0..iterations.max(1) {
+        let result = apply_template(&template, &context);
+        if let Some(step_results) = result.get("step_results").and_then(|v| v.as_array()) {
+            for step_result in step_results {
+                let step_id = step_result
+                    .get("step_id")
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("unknown")
+                    .to_string();
+                let time_ms = step_result
+                    .get("execution_time_ms")
+                    .and_then(|v| v.as_f64())
+                    .unwrap_or(0.0);
+                let succeeded = step_result
+                    .get("result")
+                    .map(|r| is_step_successful(r))
+                    .unwrap_or(true);
+
+                samples.entry(step_id.clone()).or_default().push(time_ms);
+                let counter = successes.entry(step_id).or_insert(0);
+                if succeeded {
+                    *counter += 1;
+                }
+            }
+        }
+    }
+
+    let mut steps = Vec::new();
+    let mut any_regression = false;
+    let expected_default = template.pattern.expected_execution_time_ms;
+
+    for step in &mut template.pattern.steps {
+        let Some(times) = samples.get(&step.step_id) else {
+            continue;
+        };
+        let succeeded = successes.get(&step.step_id).copied().unwrap_or(0);
+        let expected_ms = step
+            .performance_metrics
+            .as_ref()
+            .map(|m| m.average_time_ms)
+            .or(expected_default);
+
+        let bench = summarize_step_timings(&step.step_id, times, succeeded, expected_ms, tolerance_pct);
+
+        let metrics = step.performance_metrics.get_or_insert(StepMetrics {
+            execution_count: 0,
+            total_time_ms: 0.0,
+            average_time_ms: 0.0,
+            success_rate: 0.0,
+            error_count: 0,
+        });
+        metrics.execution_count += bench.count as u64;
+        metrics.total_time_ms += times.iter().sum::<f64>();
+        metrics.average_time_ms = bench.mean_ms;
+        metrics.success_rate = bench.success_rate;
+        metrics.error_count += bench.count as u64 - succeeded;
+
+        any_regression = any_regression || bench.regression;
+        steps.push(bench);
+    }
+
+    let report = BenchmarkReport {
+        template_id: template.id.clone(),
+        iterations,
+        warmup,
+        steps,
+        any_regression,
+    };
+
+    serde_json::to_string(&report)
+        .unwrap_or_else(|e| format!(r#"{{"error": "Serialization failed: {}"}}"#, e))
+}
+
+/// Reduce a step's raw per-iteration timings into a `StepBenchmark`, flagging
+/// a regression when the measured mean exceeds `expected_ms` by `tolerance_pct`.
+fn summarize_step_timings(
+    step_id: &str,
+    times: &[f64],
+    succeeded: u64,
+    expected_ms: Option<f64>,
+    tolerance_pct: f64,
+) -> StepBenchmark {
+    let count = times.len();
+    let mut sorted = times.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mean = sorted.iter().sum::<f64>() / count.max(1) as f64;
+    let variance = sorted.iter().map(|t| (t - mean).powi(2)).sum::<f64>() / count.max(1) as f64;
+
+    let percentile = |p: f64| -> f64 {
+        if sorted.is_empty() {
+            return 0.0;
+        }
+        let idx = ((p * (sorted.len() as f64 - 1.0)).round() as usize).min(sorted.len() - 1);
+        sorted[idx]
+    };
+
+    let regression = match expected_ms {
+        Some(expected) if expected > 0.0 => mean > expected * (1.0 + tolerance_pct / 100.0),
+        _ => false,
+    };
+
+    StepBenchmark {
+        step_id: step_id.to_string(),
+        count,
+        min_ms: sorted.first().copied().unwrap_or(0.0),
+        max_ms: sorted.last().copied().unwrap_or(0.0),
+        mean_ms: mean,
+        median_ms: percentile(0.5),
+        p95_ms: percentile(0.95),
+        p99_ms: percentile(0.99),
+        std_dev_ms: variance.sqrt(),
+        success_rate: succeeded as f64 / count.max(1) as f64,
+        expected_ms,
+        regression,
+    }
+}
+
 // External dependency for time operations in WASM
 mod chrono {
     pub struct Utc;
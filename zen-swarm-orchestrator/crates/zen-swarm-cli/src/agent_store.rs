@@ -0,0 +1,355 @@
+//! Pluggable persistence backends for agent records.
+//!
+//! `spawn::register_agent_with_swarm` used to read and rewrite the whole
+//! `agents-<id>.json` file on every call, which is O(n) per spawn and racy
+//! under concurrent spawns. `AgentStore` abstracts the backend so the
+//! default JSON file can be swapped for an embedded SQLite (libSQL) or LMDB
+//! store behind cargo features, mirroring how Garage moved off a single
+//! embedded store to interchangeable `sqlite_adapter`/`lmdb_adapter`
+//! backends.
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+
+use crate::commands::spawn::{Agent, AgentStatus};
+
+/// Persistence backend for the agents belonging to one swarm.
+#[async_trait]
+pub trait AgentStore: Send + Sync {
+    async fn insert(&self, agent: &Agent) -> Result<()>;
+    async fn get(&self, agent_id: &str) -> Result<Option<Agent>>;
+    async fn count(&self) -> Result<usize>;
+    async fn list(&self) -> Result<Vec<Agent>>;
+    async fn update_status(&self, agent_id: &str, status: AgentStatus) -> Result<()>;
+    async fn remove(&self, agent_id: &str) -> Result<()>;
+}
+
+/// Which `AgentStore` implementation to use. `Sqlite`/`Lmdb` are only
+/// constructible when this crate is built with the matching cargo feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StoreBackend {
+    Json,
+    Sqlite,
+    Lmdb,
+}
+
+impl std::str::FromStr for StoreBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "json" => Ok(Self::Json),
+            "sqlite" => Ok(Self::Sqlite),
+            "lmdb" => Ok(Self::Lmdb),
+            other => Err(anyhow::anyhow!(
+                "Unknown agent store backend '{other}': expected json, sqlite, or lmdb"
+            )),
+        }
+    }
+}
+
+/// Open the requested backend for `swarm_id`, rooted under `data_dir`
+/// (typically the `directories::ProjectDirs` data-local dir already used by
+/// `spawn::get_agents_file`).
+pub async fn open_store(
+    data_dir: &std::path::Path,
+    swarm_id: &str,
+    backend: StoreBackend,
+) -> Result<Box<dyn AgentStore>> {
+    match backend {
+        StoreBackend::Json => Ok(Box::new(JsonFileAgentStore::new(
+            data_dir.join(format!("agents-{swarm_id}.json")),
+        ))),
+        #[cfg(feature = "sqlite-store")]
+        StoreBackend::Sqlite => Ok(Box::new(
+            sqlite::SqliteAgentStore::open(data_dir.join(format!("agents-{swarm_id}.sqlite")))
+                .await?,
+        )),
+        #[cfg(not(feature = "sqlite-store"))]
+        StoreBackend::Sqlite => Err(anyhow::anyhow!(
+            "Built without the 'sqlite-store' feature; rebuild with --features sqlite-store"
+        )),
+        #[cfg(feature = "lmdb-store")]
+        StoreBackend::Lmdb => Ok(Box::new(lmdb::LmdbAgentStore::open(
+            data_dir.join(format!("agents-{swarm_id}.lmdb")),
+        )?)),
+        #[cfg(not(feature = "lmdb-store"))]
+        StoreBackend::Lmdb => Err(anyhow::anyhow!(
+            "Built without the 'lmdb-store' feature; rebuild with --features lmdb-store"
+        )),
+    }
+}
+
+/// Today's default: the whole agent list for a swarm as one JSON file,
+/// read and rewritten in full on every mutating call.
+pub struct JsonFileAgentStore {
+    path: std::path::PathBuf,
+}
+
+impl JsonFileAgentStore {
+    pub fn new(path: std::path::PathBuf) -> Self {
+        Self { path }
+    }
+
+    fn read_all(&self) -> Result<Vec<Agent>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read agent store: {}", self.path.display()))?;
+        Ok(serde_json::from_str(&content).unwrap_or_default())
+    }
+
+    fn write_all(&self, agents: &[Agent]) -> Result<()> {
+        let content = serde_json::to_string_pretty(agents)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write agent store: {}", self.path.display()))
+    }
+}
+
+#[async_trait]
+impl AgentStore for JsonFileAgentStore {
+    async fn insert(&self, agent: &Agent) -> Result<()> {
+        let mut agents = self.read_all()?;
+        agents.retain(|a| a.id != agent.id);
+        agents.push(agent.clone());
+        self.write_all(&agents)
+    }
+
+    async fn get(&self, agent_id: &str) -> Result<Option<Agent>> {
+        Ok(self.read_all()?.into_iter().find(|a| a.id == agent_id))
+    }
+
+    async fn count(&self) -> Result<usize> {
+        Ok(self.read_all()?.len())
+    }
+
+    async fn list(&self) -> Result<Vec<Agent>> {
+        self.read_all()
+    }
+
+    async fn update_status(&self, agent_id: &str, status: AgentStatus) -> Result<()> {
+        let mut agents = self.read_all()?;
+        let agent = agents
+            .iter_mut()
+            .find(|a| a.id == agent_id)
+            .with_context(|| format!("Agent '{agent_id}' not found in store"))?;
+        agent.status = status;
+        self.write_all(&agents)
+    }
+
+    async fn remove(&self, agent_id: &str) -> Result<()> {
+        let mut agents = self.read_all()?;
+        agents.retain(|a| a.id != agent_id);
+        self.write_all(&agents)
+    }
+}
+
+/// Migrate every agent from `from` into `to`, leaving `from` untouched.
+/// Returns the number of agents migrated so the caller (`convert` command)
+/// can report progress.
+pub async fn convert(from: &dyn AgentStore, to: &dyn AgentStore) -> Result<usize> {
+    let agents = from.list().await.context("Failed to read source store")?;
+    for agent in &agents {
+        to.insert(agent)
+            .await
+            .with_context(|| format!("Failed to migrate agent '{}'", agent.id))?;
+    }
+    Ok(agents.len())
+}
+
+#[cfg(feature = "sqlite-store")]
+mod sqlite {
+    use super::*;
+    use libsql::{params, Builder, Connection};
+    use tokio::sync::Mutex;
+
+    /// SQLite (via libSQL, matching this workspace's existing libSQL
+    /// persistence backend) agent store: one row per agent, keyed by id.
+    pub struct SqliteAgentStore {
+        conn: Mutex<Connection>,
+    }
+
+    impl SqliteAgentStore {
+        pub async fn open(path: std::path::PathBuf) -> Result<Self> {
+            let db = Builder::new_local(&path)
+                .build()
+                .await
+                .with_context(|| format!("Failed to open SQLite store: {}", path.display()))?;
+            let conn = db.connect().context("Failed to open SQLite connection")?;
+            conn.execute(
+                "CREATE TABLE IF NOT EXISTS agents (id TEXT PRIMARY KEY, data TEXT NOT NULL)",
+                (),
+            )
+            .await
+            .context("Failed to create agents table")?;
+            Ok(Self { conn: Mutex::new(conn) })
+        }
+    }
+
+    #[async_trait]
+    impl AgentStore for SqliteAgentStore {
+        async fn insert(&self, agent: &Agent) -> Result<()> {
+            let data = serde_json::to_string(agent)?;
+            self.conn
+                .lock()
+                .await
+                .execute(
+                    "INSERT INTO agents (id, data) VALUES (?1, ?2)
+                     ON CONFLICT(id) DO UPDATE SET data = excluded.data",
+                    params![agent.id.clone(), data],
+                )
+                .await
+                .context("Failed to upsert agent")?;
+            Ok(())
+        }
+
+        async fn get(&self, agent_id: &str) -> Result<Option<Agent>> {
+            let conn = self.conn.lock().await;
+            let mut rows = conn
+                .query("SELECT data FROM agents WHERE id = ?1", params![agent_id])
+                .await
+                .context("Failed to query agent")?;
+            match rows.next().await.context("Failed to read agent row")? {
+                Some(row) => {
+                    let data: String = row.get(0)?;
+                    Ok(Some(serde_json::from_str(&data)?))
+                }
+                None => Ok(None),
+            }
+        }
+
+        async fn count(&self) -> Result<usize> {
+            let conn = self.conn.lock().await;
+            let mut rows = conn
+                .query("SELECT COUNT(*) FROM agents", ())
+                .await
+                .context("Failed to count agents")?;
+            let row = rows
+                .next()
+                .await
+                .context("Failed to read count row")?
+                .context("COUNT(*) returned no rows")?;
+            let count: i64 = row.get(0)?;
+            Ok(count as usize)
+        }
+
+        async fn list(&self) -> Result<Vec<Agent>> {
+            let conn = self.conn.lock().await;
+            let mut rows = conn
+                .query("SELECT data FROM agents", ())
+                .await
+                .context("Failed to list agents")?;
+            let mut agents = Vec::new();
+            while let Some(row) = rows.next().await.context("Failed to read agent row")? {
+                let data: String = row.get(0)?;
+                agents.push(serde_json::from_str(&data)?);
+            }
+            Ok(agents)
+        }
+
+        async fn update_status(&self, agent_id: &str, status: AgentStatus) -> Result<()> {
+            let mut agent = self
+                .get(agent_id)
+                .await?
+                .with_context(|| format!("Agent '{agent_id}' not found in store"))?;
+            agent.status = status;
+            self.insert(&agent).await
+        }
+
+        async fn remove(&self, agent_id: &str) -> Result<()> {
+            self.conn
+                .lock()
+                .await
+                .execute("DELETE FROM agents WHERE id = ?1", params![agent_id])
+                .await
+                .context("Failed to delete agent")?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "lmdb-store")]
+mod lmdb {
+    use super::*;
+    use heed::types::{Bytes, Str};
+    use heed::{Database, Env, EnvOpenOptions};
+
+    /// LMDB (via `heed`, matching this workspace's FACT LMDB storage)
+    /// agent store: a single memory-mapped database keyed by agent id.
+    pub struct LmdbAgentStore {
+        env: Env,
+        db: Database<Str, Bytes>,
+    }
+
+    impl LmdbAgentStore {
+        pub fn open(dir: std::path::PathBuf) -> Result<Self> {
+            std::fs::create_dir_all(&dir)
+                .with_context(|| format!("Failed to create LMDB directory: {}", dir.display()))?;
+
+            // SAFETY: this process controls the lifetime of its access to
+            // `dir`, and no flags requiring extra caller guarantees are set.
+            let env = unsafe { EnvOpenOptions::new().max_dbs(1).open(&dir) }
+                .with_context(|| format!("Failed to open LMDB environment: {}", dir.display()))?;
+
+            let mut wtxn = env.write_txn()?;
+            let db: Database<Str, Bytes> = env
+                .create_database(&mut wtxn, Some("agents"))
+                .context("Failed to create/open LMDB agents database")?;
+            wtxn.commit()?;
+
+            Ok(Self { env, db })
+        }
+    }
+
+    #[async_trait]
+    impl AgentStore for LmdbAgentStore {
+        async fn insert(&self, agent: &Agent) -> Result<()> {
+            let data = serde_json::to_vec(agent)?;
+            let mut wtxn = self.env.write_txn()?;
+            self.db.put(&mut wtxn, &agent.id, &data)?;
+            wtxn.commit()?;
+            Ok(())
+        }
+
+        async fn get(&self, agent_id: &str) -> Result<Option<Agent>> {
+            let rtxn = self.env.read_txn()?;
+            match self.db.get(&rtxn, agent_id)? {
+                Some(data) => Ok(Some(serde_json::from_slice(data)?)),
+                None => Ok(None),
+            }
+        }
+
+        async fn count(&self) -> Result<usize> {
+            let rtxn = self.env.read_txn()?;
+            Ok(self.db.len(&rtxn)? as usize)
+        }
+
+        async fn list(&self) -> Result<Vec<Agent>> {
+            let rtxn = self.env.read_txn()?;
+            self.db
+                .iter(&rtxn)?
+                .map(|entry| {
+                    let (_, data) = entry?;
+                    Ok(serde_json::from_slice(data)?)
+                })
+                .collect()
+        }
+
+        async fn update_status(&self, agent_id: &str, status: AgentStatus) -> Result<()> {
+            let mut agent = self
+                .get(agent_id)
+                .await?
+                .with_context(|| format!("Agent '{agent_id}' not found in store"))?;
+            agent.status = status;
+            self.insert(&agent).await
+        }
+
+        async fn remove(&self, agent_id: &str) -> Result<()> {
+            let mut wtxn = self.env.write_txn()?;
+            self.db.delete(&mut wtxn, agent_id)?;
+            wtxn.commit()?;
+            Ok(())
+        }
+    }
+}
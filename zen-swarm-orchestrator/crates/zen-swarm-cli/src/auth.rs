@@ -0,0 +1,91 @@
+//! Shared-secret authentication for agent registration.
+//!
+//! `register_agent_with_swarm` used to accept and persist any agent with no
+//! authentication, so anything that could write the data dir could inject
+//! an agent. This resolves an RPC/registration secret the same way this
+//! workspace's FACT storage resolves its encryption key (inline value or
+//! `_file` path, erroring if both are set, as Garage also does for its RPC
+//! secret), then requires an HMAC-SHA256 of the agent record against that
+//! secret before the agent is accepted.
+
+use std::path::Path;
+
+use anyhow::{bail, Context, Result};
+use hmac::{Hmac, Mac};
+use sha2::{Digest, Sha256};
+
+use crate::commands::spawn::Agent;
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Resolve the registration secret from either an inline value or a file
+/// path, erroring if both are set. Returns `Ok(None)` if neither is set.
+pub fn resolve_registration_secret(
+    inline: Option<&str>,
+    file: Option<&Path>,
+) -> Result<Option<String>> {
+    match (inline, file) {
+        (Some(_), Some(path)) => bail!(
+            "rpc_secret is set both inline and via rpc_secret_file ({path:?}); set only one"
+        ),
+        (Some(value), None) => Ok(Some(value.to_string())),
+        (None, Some(path)) => {
+            let contents = std::fs::read_to_string(path)
+                .with_context(|| format!("Failed to read rpc_secret_file: {path:?}"))?;
+            let trimmed = contents.trim();
+            if trimmed.is_empty() {
+                bail!("rpc_secret_file is empty: {path:?}");
+            }
+            Ok(Some(trimmed.to_string()))
+        }
+        (None, None) => Ok(None),
+    }
+}
+
+/// The agent identity fields an HMAC signs -- enough to bind the signature
+/// to a specific agent without including fields (`status`, `metrics`,
+/// `last_heartbeat`) that legitimately change after registration.
+fn canonical_payload(agent: &Agent) -> String {
+    format!(
+        "{}\0{}\0{}\0{}",
+        agent.id,
+        agent.name,
+        agent.agent_type,
+        agent.capabilities.join(",")
+    )
+}
+
+/// HMAC-SHA256 `agent`'s canonical identity fields under `secret`, hex-encoded.
+pub fn sign_agent(secret: &str, agent: &Agent) -> Result<String> {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes())
+        .context("HMAC-SHA256 accepts a key of any length")?;
+    mac.update(canonical_payload(agent).as_bytes());
+    Ok(hex_encode(&mac.finalize().into_bytes()))
+}
+
+/// Verify `signature` against `agent` under `secret`, comparing in
+/// constant time so a registration attempt can't time its way to a
+/// forgery.
+pub fn verify_agent(secret: &str, agent: &Agent, signature: &str) -> Result<bool> {
+    let expected = sign_agent(secret, agent)?;
+    Ok(constant_time_eq(expected.as_bytes(), signature.as_bytes()))
+}
+
+/// A short, non-secret fingerprint of the registration secret, safe to
+/// persist (e.g. in agent metadata, or alongside a swarm's config) for
+/// auditing which secret value was used without being able to recover it.
+pub fn fingerprint(secret: &str) -> String {
+    let digest = Sha256::digest(secret.as_bytes());
+    hex_encode(&digest[..8])
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b.iter()).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
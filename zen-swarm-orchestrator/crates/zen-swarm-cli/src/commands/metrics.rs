@@ -0,0 +1,42 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use anyhow::Result;
+
+use crate::agent_store::{open_store, StoreBackend};
+use crate::config::Config;
+use crate::metrics::{serve_metrics, SwarmMetrics};
+use crate::output::OutputHandler;
+
+/// `ruv-swarm metrics --serve <addr>`: sample this swarm's agents on an
+/// interval and serve the result in Prometheus/OpenMetrics text format.
+pub async fn execute(
+    _config: &Config,
+    output: &OutputHandler,
+    swarm_config: &crate::commands::init::SwarmInit,
+    serve: SocketAddr,
+    sample_interval: std::time::Duration,
+) -> Result<()> {
+    output.section("Starting Metrics Exporter");
+
+    let data_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+        .map(|dirs| dirs.data_local_dir().to_path_buf())
+        .unwrap_or_else(|| std::path::Path::new(".").to_path_buf());
+    let store = open_store(&data_dir, &swarm_config.swarm_id, StoreBackend::Json).await?;
+
+    let metrics = Arc::new(SwarmMetrics::new()?);
+
+    let sampler_metrics = Arc::clone(&metrics);
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(sample_interval);
+        loop {
+            interval.tick().await;
+            if let Err(err) = sampler_metrics.sample(store.as_ref()).await {
+                tracing::warn!("Metrics sample failed: {err:#}");
+            }
+        }
+    });
+
+    output.success(&format!("Serving metrics at http://{serve}/metrics"));
+    serve_metrics(serve, metrics).await
+}
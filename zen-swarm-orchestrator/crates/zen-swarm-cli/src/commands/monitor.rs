@@ -0,0 +1,62 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::agent_store::{open_store, StoreBackend};
+use crate::config::Config;
+use crate::output::OutputHandler;
+use crate::supervisor::{HeartbeatSupervisor, SupervisorConfig};
+
+/// Execute the monitor command: run the heartbeat supervisor in the
+/// foreground, printing a health report after every sweep until
+/// interrupted. This command is what runs the supervisor; `spawn::execute`
+/// does not start one.
+pub async fn execute(
+    config: &Config,
+    output: &OutputHandler,
+    swarm_config: &crate::commands::init::SwarmInit,
+    poll_interval: Duration,
+    missed_intervals: u32,
+) -> Result<()> {
+    output.section("Starting Heartbeat Supervisor");
+
+    let data_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+        .map(|dirs| dirs.data_local_dir().to_path_buf())
+        .unwrap_or_else(|| std::path::Path::new(".").to_path_buf());
+    let store = open_store(&data_dir, &swarm_config.swarm_id, StoreBackend::Json).await?;
+
+    let supervisor = Arc::new(HeartbeatSupervisor::new(
+        Arc::from(store),
+        SupervisorConfig {
+            poll_interval,
+            missed_intervals,
+        },
+    ));
+
+    output.success(&format!(
+        "Monitoring swarm '{}' (poll every {:?}, {} missed interval(s) before declaring an agent dead)",
+        swarm_config.swarm_id, poll_interval, missed_intervals
+    ));
+
+    loop {
+        tokio::time::sleep(poll_interval).await;
+        let newly_dead = supervisor.sweep().await?;
+        for agent_id in &newly_dead {
+            output.error(&format!(
+                "Agent '{}' missed its heartbeat and was marked offline; its tasks were reassigned",
+                agent_id
+            ));
+        }
+
+        let report = supervisor.health_report().await?;
+        if let (Some(total), Some(healthy)) =
+            (report.get("total_agents"), report.get("healthy_agents"))
+        {
+            output.key_value(&[
+                ("Total agents".to_string(), total.clone()),
+                ("Healthy agents".to_string(), healthy.clone()),
+            ]);
+        }
+    }
+}
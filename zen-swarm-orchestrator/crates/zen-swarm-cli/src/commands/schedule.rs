@@ -0,0 +1,88 @@
+use anyhow::Result;
+use uuid::Uuid;
+
+use crate::commands::spawn::Task;
+use crate::config::Config;
+use crate::output::OutputHandler;
+use crate::scheduler::{ScheduleEntry, ScheduleStore, Trigger};
+
+fn schedule_store(swarm_config: &crate::commands::init::SwarmInit) -> Result<ScheduleStore> {
+    let data_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+        .map(|dirs| dirs.data_local_dir().to_path_buf())
+        .unwrap_or_else(|| std::path::Path::new(".").to_path_buf());
+    Ok(ScheduleStore::new(
+        data_dir.join(format!("schedule-{}.json", swarm_config.swarm_id)),
+    ))
+}
+
+/// `ruv-swarm schedule add`: register a new recurring/one-shot entry.
+pub async fn execute_add(
+    _config: &Config,
+    output: &OutputHandler,
+    swarm_config: &crate::commands::init::SwarmInit,
+    task: Task,
+    trigger: Trigger,
+    target_capabilities: Vec<String>,
+) -> Result<()> {
+    let next_run = match &trigger {
+        Trigger::Interval(_) | Trigger::Cron(_) => chrono::Utc::now(),
+        Trigger::Once(at) => *at,
+    };
+
+    let entry = ScheduleEntry {
+        id: Uuid::new_v4().to_string(),
+        task,
+        trigger,
+        target_capabilities,
+        next_run,
+        enabled: true,
+    };
+
+    let store = schedule_store(swarm_config)?;
+    let entry_id = entry.id.clone();
+    store.add(entry)?;
+
+    output.success(&format!("Scheduled entry '{}' added", entry_id));
+    Ok(())
+}
+
+/// `ruv-swarm schedule list`: show every entry for this swarm.
+pub async fn execute_list(
+    _config: &Config,
+    output: &OutputHandler,
+    swarm_config: &crate::commands::init::SwarmInit,
+) -> Result<()> {
+    let store = schedule_store(swarm_config)?;
+    let entries = store.list()?;
+
+    if entries.is_empty() {
+        output.success("No scheduled entries");
+        return Ok(());
+    }
+
+    for entry in entries {
+        output.key_value(&[
+            ("ID".to_string(), entry.id),
+            ("Task".to_string(), entry.task.id),
+            ("Enabled".to_string(), entry.enabled.to_string()),
+            ("Next run".to_string(), entry.next_run.to_rfc3339()),
+        ]);
+    }
+    Ok(())
+}
+
+/// `ruv-swarm schedule remove`: drop an entry by id.
+pub async fn execute_remove(
+    _config: &Config,
+    output: &OutputHandler,
+    swarm_config: &crate::commands::init::SwarmInit,
+    entry_id: &str,
+) -> Result<()> {
+    let store = schedule_store(swarm_config)?;
+    if store.remove(entry_id)? {
+        output.success(&format!("Removed scheduled entry '{}'", entry_id));
+    } else {
+        output.error(&format!("No scheduled entry with id '{}'", entry_id));
+    }
+    Ok(())
+}
@@ -4,11 +4,12 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use uuid::Uuid;
 
+use crate::agent_store::{open_store, StoreBackend};
 use crate::config::Config;
 use crate::output::{OutputHandler, StatusLevel};
 
 /// Agent spawning and management utilities using HashMap for agent metadata
-mod agent_management_utils {
+pub(crate) mod agent_management_utils {
     use super::*;
     
     /// Use HashMap for comprehensive agent metadata and lifecycle tracking
@@ -17,6 +18,7 @@ mod agent_management_utils {
         spawn_time_ms: u64,
         initialization_log: Vec<String>,
         resource_allocation: Vec<(String, f64)>,
+        registration_secret_fingerprint: Option<String>,
     ) -> HashMap<String, String> {
         let mut metadata = HashMap::new();
         
@@ -47,7 +49,14 @@ mod agent_management_utils {
         metadata.insert("performance_score".to_string(), agent.metrics.performance_score.to_string());
         metadata.insert("cpu_usage_percent".to_string(), agent.metrics.cpu_usage_percent.to_string());
         metadata.insert("memory_usage_mb".to_string(), agent.metrics.memory_usage_mb.to_string());
-        
+
+        // Never store the registration secret itself here -- only a
+        // fingerprint of it, so an operator can audit which secret value
+        // registered this agent without being able to recover the secret.
+        if let Some(fingerprint) = registration_secret_fingerprint {
+            metadata.insert("registration_secret_fingerprint".to_string(), fingerprint);
+        }
+
         metadata
     }
     
@@ -204,9 +213,277 @@ mod agent_management_utils {
         for (agent_id, task_count) in task_loads {
             metadata.insert(format!("agent_{}_task_count", agent_id), task_count.to_string());
         }
-        
+
         metadata
     }
+
+    /// Key used for the unmatched-task bucket in `assign_tasks`'s result. No
+    /// real agent id can collide with it, since agent ids are UUIDs.
+    pub(crate) const UNASSIGNED_KEY: &str = "__unassigned__";
+
+    /// Compute a balanced, capability-respecting task assignment with a
+    /// min-cost max-flow solver (the same technique Garage uses to place
+    /// partitions onto nodes).
+    ///
+    /// The flow network is `S -> agent -> task -> T`. Each `task -> T` edge
+    /// has capacity 1 and cost 0. An `agent -> task` edge exists, with
+    /// capacity 1 and cost 0, only when the agent's capabilities are a
+    /// superset of the task's required capabilities. Each `S -> agent` edge
+    /// is split into `tasks.len()` unit-capacity edges with convex costs
+    /// (0, 1, 4, 9, ...) so that the k-th task handed to an agent costs more,
+    /// which makes the min-cost optimum spread work evenly across agents.
+    ///
+    /// Tasks with no capable agent are collected under the
+    /// [`UNASSIGNED_KEY`] entry instead of being silently dropped. The
+    /// result is deterministic for identical input: agents are ordered by
+    /// id before being wired into the network, so ties between equally
+    /// capable agents are always broken in favor of the lower agent id.
+    pub fn assign_tasks(agents: &[Agent], tasks: &[Task]) -> HashMap<String, Vec<String>> {
+        let mut sorted_agents: Vec<&Agent> = agents.iter().collect();
+        sorted_agents.sort_by(|a, b| a.id.cmp(&b.id));
+
+        let num_agents = sorted_agents.len();
+        let num_tasks = tasks.len();
+
+        let mut assignments: HashMap<String, Vec<String>> = HashMap::new();
+        for agent in &sorted_agents {
+            assignments.insert(agent.id.clone(), Vec::new());
+        }
+
+        if num_agents == 0 || num_tasks == 0 {
+            assignments.insert(
+                UNASSIGNED_KEY.to_string(),
+                tasks.iter().map(|t| t.id.clone()).collect(),
+            );
+            return assignments;
+        }
+
+        // Node layout: source = 0, agents = 1..=num_agents,
+        // tasks = num_agents+1..=num_agents+num_tasks, sink = last.
+        let source = 0usize;
+        let agent_node = |i: usize| 1 + i;
+        let task_node = |j: usize| 1 + num_agents + j;
+        let sink = 1 + num_agents + num_tasks;
+
+        let mut solver = min_cost_flow::MinCostMaxFlow::new(sink + 1);
+
+        for i in 0..num_agents {
+            // Convex per-agent costs: the k-th unit handed to an agent costs k^2.
+            for k in 0..num_tasks {
+                let cost = (k as i64) * (k as i64);
+                solver.add_edge(source, agent_node(i), 1, cost);
+            }
+        }
+
+        let mut task_edge = HashMap::new();
+        for (j, task) in tasks.iter().enumerate() {
+            solver.add_edge(task_node(j), sink, 1, 0);
+            for (i, agent) in sorted_agents.iter().enumerate() {
+                let capable = task
+                    .required_capabilities
+                    .iter()
+                    .all(|cap| agent.capabilities.contains(cap));
+                if capable {
+                    let edge_id = solver.add_edge(agent_node(i), task_node(j), 1, 0);
+                    task_edge.insert((i, j), edge_id);
+                }
+            }
+        }
+
+        solver.solve(source, sink);
+
+        let mut unassigned = Vec::new();
+        for (j, task) in tasks.iter().enumerate() {
+            let mut matched = false;
+            for i in 0..num_agents {
+                if let Some(&edge_id) = task_edge.get(&(i, j)) {
+                    if solver.flow_on(edge_id) > 0 {
+                        assignments
+                            .get_mut(&sorted_agents[i].id)
+                            .expect("agent entry was pre-populated")
+                            .push(task.id.clone());
+                        matched = true;
+                        break;
+                    }
+                }
+            }
+            if !matched {
+                unassigned.push(task.id.clone());
+            }
+        }
+
+        assignments.insert(UNASSIGNED_KEY.to_string(), unassigned);
+        assignments
+    }
+
+    /// Run [`create_load_balancing_metadata`] over `assign_tasks`'s output
+    /// (excluding the unassigned bucket) to get the existing
+    /// variance/load_balance_score quality report for an assignment.
+    pub fn assignment_quality_report(
+        agents: &[Agent],
+        assignments: &HashMap<String, Vec<String>>,
+    ) -> HashMap<String, String> {
+        let task_assignments: Vec<(String, Vec<String>)> = assignments
+            .iter()
+            .filter(|(agent_id, _)| agent_id.as_str() != UNASSIGNED_KEY)
+            .map(|(agent_id, task_ids)| (agent_id.clone(), task_ids.clone()))
+            .collect();
+        create_load_balancing_metadata(agents, task_assignments)
+    }
+}
+
+/// A minimal min-cost max-flow solver used to compute capability-aware,
+/// load-balanced task assignments.
+mod min_cost_flow {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    const INF: i64 = i64::MAX / 4;
+
+    #[derive(Clone)]
+    struct Edge {
+        to: usize,
+        cap: i64,
+        cost: i64,
+    }
+
+    pub struct MinCostMaxFlow {
+        graph: Vec<Vec<usize>>,
+        edges: Vec<Edge>,
+    }
+
+    impl MinCostMaxFlow {
+        pub fn new(node_count: usize) -> Self {
+            Self {
+                graph: vec![Vec::new(); node_count],
+                edges: Vec::new(),
+            }
+        }
+
+        /// Adds a directed edge and its residual counterpart, returning the
+        /// forward edge's id.
+        pub fn add_edge(&mut self, from: usize, to: usize, cap: i64, cost: i64) -> usize {
+            let id = self.edges.len();
+            self.graph[from].push(id);
+            self.edges.push(Edge { to, cap, cost });
+            self.graph[to].push(id + 1);
+            self.edges.push(Edge {
+                to: from,
+                cap: 0,
+                cost: -cost,
+            });
+            id
+        }
+
+        /// How much flow ended up on the given forward edge, i.e. how much
+        /// of its original capacity was consumed.
+        pub fn flow_on(&self, edge_id: usize) -> i64 {
+            self.edges[edge_id ^ 1].cap
+        }
+
+        /// Successive shortest augmenting paths: a Bellman-Ford pass seeds
+        /// vertex potentials, then every further augmentation uses Dijkstra
+        /// reweighted with those potentials (Johnson's technique), until no
+        /// augmenting path remains. Returns `(max_flow, min_cost)`.
+        pub fn solve(&mut self, source: usize, sink: usize) -> (i64, i64) {
+            let n = self.graph.len();
+            let mut potential = vec![0i64; n];
+
+            // Bellman-Ford: seeds potentials so the first Dijkstra pass sees
+            // non-negative reduced costs.
+            {
+                let mut dist = vec![INF; n];
+                dist[source] = 0;
+                for _ in 0..n {
+                    let mut updated = false;
+                    for u in 0..n {
+                        if dist[u] == INF {
+                            continue;
+                        }
+                        for &eid in &self.graph[u] {
+                            let e = &self.edges[eid];
+                            if e.cap > 0 && dist[u] + e.cost < dist[e.to] {
+                                dist[e.to] = dist[u] + e.cost;
+                                updated = true;
+                            }
+                        }
+                    }
+                    if !updated {
+                        break;
+                    }
+                }
+                for v in 0..n {
+                    if dist[v] < INF {
+                        potential[v] = dist[v];
+                    }
+                }
+            }
+
+            let mut total_flow = 0i64;
+            let mut total_cost = 0i64;
+
+            loop {
+                let mut dist = vec![INF; n];
+                let mut prev_edge = vec![usize::MAX; n];
+                dist[source] = 0;
+                let mut heap = BinaryHeap::new();
+                heap.push(Reverse((0i64, source)));
+
+                while let Some(Reverse((d, u))) = heap.pop() {
+                    if d > dist[u] {
+                        continue;
+                    }
+                    for &eid in &self.graph[u] {
+                        let e = &self.edges[eid];
+                        if e.cap <= 0 {
+                            continue;
+                        }
+                        let reduced = e.cost + potential[u] - potential[e.to];
+                        if reduced < 0 {
+                            continue;
+                        }
+                        let nd = d + reduced;
+                        if nd < dist[e.to] {
+                            dist[e.to] = nd;
+                            prev_edge[e.to] = eid;
+                            heap.push(Reverse((nd, e.to)));
+                        }
+                    }
+                }
+
+                if dist[sink] == INF {
+                    break;
+                }
+
+                for v in 0..n {
+                    if dist[v] < INF {
+                        potential[v] += dist[v];
+                    }
+                }
+
+                let mut bottleneck = INF;
+                let mut v = sink;
+                while v != source {
+                    let eid = prev_edge[v];
+                    bottleneck = bottleneck.min(self.edges[eid].cap);
+                    v = self.edges[eid ^ 1].to;
+                }
+
+                let mut v = sink;
+                while v != source {
+                    let eid = prev_edge[v];
+                    self.edges[eid].cap -= bottleneck;
+                    self.edges[eid ^ 1].cap += bottleneck;
+                    v = self.edges[eid ^ 1].to;
+                }
+
+                total_flow += bottleneck;
+                total_cost += bottleneck * (potential[sink] - potential[source]);
+            }
+
+            (total_flow, total_cost)
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -221,6 +498,24 @@ pub struct Agent {
     pub created_at: chrono::DateTime<chrono::Utc>,
     pub last_heartbeat: chrono::DateTime<chrono::Utc>,
     pub metrics: AgentMetrics,
+    /// Tasks currently assigned to this agent, used by the heartbeat
+    /// supervisor to find work to re-assign if the agent is declared dead.
+    #[serde(default)]
+    pub assigned_tasks: Vec<Task>,
+    /// Fingerprint of the registration secret that signed this agent's
+    /// record, if the swarm requires one -- never the secret itself, so an
+    /// operator can audit which secret value registered the agent from the
+    /// stored record alone, without needing to have captured the spawn
+    /// command's console output.
+    #[serde(default)]
+    pub registration_secret_fingerprint: Option<String>,
+}
+
+/// A unit of work to hand to a capable agent via `agent_management_utils::assign_tasks`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub id: String,
+    pub required_capabilities: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -328,6 +623,16 @@ pub async fn execute(
         }
     }
 
+    // Resolve the swarm's registration secret before building the agent
+    // record, so its fingerprint can be set on the record itself and
+    // persisted by `register_agent_with_swarm` below, rather than computed
+    // afterward and only ever printed to the console.
+    let registration_secret = crate::auth::resolve_registration_secret(
+        swarm_config.rpc_secret.as_deref(),
+        swarm_config.rpc_secret_file.as_deref().map(std::path::Path::new),
+    )?;
+    let registration_secret_fingerprint = registration_secret.as_deref().map(crate::auth::fingerprint);
+
     // Create the agent
     let agent = Agent {
         id: agent_id.clone(),
@@ -340,6 +645,8 @@ pub async fn execute(
         created_at: Utc::now(),
         last_heartbeat: Utc::now(),
         metrics: AgentMetrics::default(),
+        assigned_tasks: Vec::new(),
+        registration_secret_fingerprint: registration_secret_fingerprint.clone(),
     };
 
     // Display agent details
@@ -364,30 +671,58 @@ pub async fn execute(
 
     // Spawn the agent
     let spinner = output.spinner("Initializing agent...");
+    let spawn_started = std::time::Instant::now();
+    let mut initialization_log = Vec::new();
 
     // Initialize agent runtime
     initialize_agent_runtime(&agent, config).await?;
+    initialization_log.push("runtime initialized".to_string());
 
-    // Register with swarm
-    register_agent_with_swarm(&agent, &swarm_config).await?;
+    // Register with swarm, signing the agent record if this swarm was
+    // initialized with a registration secret
+    let registration_signature = registration_secret
+        .as_deref()
+        .map(|secret| crate::auth::sign_agent(secret, &agent))
+        .transpose()?;
+    register_agent_with_swarm(&agent, &swarm_config, registration_signature.as_deref()).await?;
+    initialization_log.push("registered with swarm".to_string());
 
     // Set up agent connections
     setup_agent_connections(&agent, &swarm_config).await?;
+    initialization_log.push("connections established".to_string());
 
     // Load initial memory if provided
     if let Some(memory_content) = &agent.memory {
         load_agent_memory(&agent, memory_content).await?;
+        initialization_log.push("memory loaded".to_string());
     }
 
     // Start agent heartbeat
     start_agent_heartbeat(&agent, config).await?;
+    initialization_log.push("heartbeat started".to_string());
 
     if let Some(pb) = spinner {
         pb.finish_with_message("Agent spawned successfully");
     }
 
+    // Record spawn lifecycle metadata for console display. The fingerprint
+    // itself was already set on `agent.registration_secret_fingerprint` and
+    // persisted by `register_agent_with_swarm` above -- this is just a
+    // human-readable echo of what was stored, not where it's stored.
+    let metadata = agent_management_utils::create_agent_metadata(
+        &agent,
+        spawn_started.elapsed().as_millis() as u64,
+        initialization_log,
+        Vec::new(),
+        registration_secret_fingerprint,
+    );
+    output.section("Agent Metadata");
+    let mut metadata_entries: Vec<(String, String)> = metadata.into_iter().collect();
+    metadata_entries.sort_by(|a, b| a.0.cmp(&b.0));
+    output.key_value(&metadata_entries);
+
     // Update agent status to ready
-    update_agent_status(&agent.id, AgentStatus::Ready).await?;
+    update_agent_status(&agent.id, AgentStatus::Ready, &swarm_config).await?;
 
     output.success(&format!(
         "Agent '{}' ({}) spawned successfully!",
@@ -502,16 +837,14 @@ async fn load_current_swarm(output: &OutputHandler) -> Result<crate::commands::i
 }
 
 async fn get_agent_count(swarm_config: &crate::commands::init::SwarmInit) -> Result<usize> {
-    // In a real implementation, this would query the persistence backend
-    // For now, we'll simulate by reading from a file
-    let agents_file = get_agents_file(swarm_config)?;
-
-    if agents_file.exists() {
-        let content = std::fs::read_to_string(&agents_file)?;
-        let agents: Vec<Agent> = serde_json::from_str(&content).unwrap_or_default();
-        Ok(agents.len())
-    } else {
+    let store = default_agent_store(swarm_config).await?;
+    let count = store.count().await?;
+    if count == 0 && !get_agents_file(swarm_config)?.exists() {
+        // No store file has ever been written for this swarm yet, so fall
+        // back to the agents the swarm was initialized with.
         Ok(swarm_config.initial_agents.len())
+    } else {
+        Ok(count)
     }
 }
 
@@ -519,23 +852,48 @@ async fn agent_exists(
     agent_id: &str,
     swarm_config: &crate::commands::init::SwarmInit,
 ) -> Result<bool> {
-    let agents_file = get_agents_file(swarm_config)?;
-
-    if agents_file.exists() {
-        let content = std::fs::read_to_string(&agents_file)?;
-        let agents: Vec<Agent> = serde_json::from_str(&content).unwrap_or_default();
-        Ok(agents.iter().any(|a| a.id == agent_id))
-    } else {
-        Ok(false)
-    }
+    let store = default_agent_store(swarm_config).await?;
+    Ok(store.get(agent_id).await?.is_some())
 }
 
 fn get_agents_file(swarm_config: &crate::commands::init::SwarmInit) -> Result<std::path::PathBuf> {
-    let config_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+    Ok(agent_data_dir()?.join(format!("agents-{}.json", swarm_config.swarm_id)))
+}
+
+fn agent_data_dir() -> Result<std::path::PathBuf> {
+    Ok(directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
         .map(|dirs| dirs.data_local_dir().to_path_buf())
-        .unwrap_or_else(|| std::path::Path::new(".").to_path_buf());
+        .unwrap_or_else(|| std::path::Path::new(".").to_path_buf()))
+}
 
-    Ok(config_dir.join(format!("agents-{}.json", swarm_config.swarm_id)))
+/// The JSON-file backend `get_agent_count`/`agent_exists`/`register_agent_with_swarm`
+/// use unless the user picked a different backend via `ruv-swarm convert`.
+async fn default_agent_store(
+    swarm_config: &crate::commands::init::SwarmInit,
+) -> Result<Box<dyn crate::agent_store::AgentStore>> {
+    open_store(&agent_data_dir()?, &swarm_config.swarm_id, StoreBackend::Json).await
+}
+
+/// Migrate `swarm_config`'s agents from one persistence backend to another,
+/// e.g. `ruv-swarm convert --to sqlite` after a swarm has outgrown the
+/// default JSON-file store. Leaves the source backend's data untouched.
+pub async fn execute_convert(
+    output: &OutputHandler,
+    swarm_config: &crate::commands::init::SwarmInit,
+    from: StoreBackend,
+    to: StoreBackend,
+) -> Result<()> {
+    let data_dir = agent_data_dir()?;
+    let from_store = open_store(&data_dir, &swarm_config.swarm_id, from).await?;
+    let to_store = open_store(&data_dir, &swarm_config.swarm_id, to).await?;
+
+    let migrated = crate::agent_store::convert(from_store.as_ref(), to_store.as_ref()).await?;
+
+    output.success(&format!(
+        "Migrated {} agent(s) from {:?} to {:?}",
+        migrated, from, to
+    ));
+    Ok(())
 }
 
 async fn initialize_agent_runtime(agent: &Agent, config: &Config) -> Result<()> {
@@ -544,26 +902,40 @@ async fn initialize_agent_runtime(agent: &Agent, config: &Config) -> Result<()>
     Ok(())
 }
 
+/// Verify `signature` (if the swarm was configured with a registration
+/// secret) before persisting `agent`. Rejects registration outright if the
+/// swarm requires a secret but no signature was given, and errors if a
+/// signature was given for a swarm with no secret configured at all.
 async fn register_agent_with_swarm(
     agent: &Agent,
     swarm_config: &crate::commands::init::SwarmInit,
+    signature: Option<&str>,
 ) -> Result<()> {
-    // Add agent to persistence
-    let agents_file = get_agents_file(swarm_config)?;
-
-    let mut agents: Vec<Agent> = if agents_file.exists() {
-        let content = std::fs::read_to_string(&agents_file)?;
-        serde_json::from_str(&content).unwrap_or_default()
-    } else {
-        Vec::new()
-    };
-
-    agents.push(agent.clone());
-
-    let content = serde_json::to_string_pretty(&agents)?;
-    std::fs::write(&agents_file, content)?;
+    let secret = crate::auth::resolve_registration_secret(
+        swarm_config.rpc_secret.as_deref(),
+        swarm_config.rpc_secret_file.as_deref().map(std::path::Path::new),
+    )?;
+
+    match secret {
+        Some(secret) => {
+            let signature = signature.ok_or_else(|| {
+                anyhow::anyhow!("This swarm requires a registration secret, but no signature was provided")
+            })?;
+            if !crate::auth::verify_agent(&secret, agent, signature)? {
+                return Err(anyhow::anyhow!(
+                    "Registration signature does not match this swarm's configured secret"
+                ));
+            }
+        }
+        None if signature.is_some() => {
+            return Err(anyhow::anyhow!(
+                "A registration signature was provided, but this swarm has no registration secret configured"
+            ));
+        }
+        None => {}
+    }
 
-    Ok(())
+    default_agent_store(swarm_config).await?.insert(agent).await
 }
 
 async fn setup_agent_connections(
@@ -607,8 +979,13 @@ async fn start_agent_heartbeat(agent: &Agent, config: &Config) -> Result<()> {
     Ok(())
 }
 
-async fn update_agent_status(agent_id: &str, status: AgentStatus) -> Result<()> {
-    // Update agent status in persistence
-    // For now, this is a no-op in the simulation
-    Ok(())
+async fn update_agent_status(
+    agent_id: &str,
+    status: AgentStatus,
+    swarm_config: &crate::commands::init::SwarmInit,
+) -> Result<()> {
+    default_agent_store(swarm_config)
+        .await?
+        .update_status(agent_id, status)
+        .await
 }
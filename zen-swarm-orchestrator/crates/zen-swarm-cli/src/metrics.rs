@@ -0,0 +1,196 @@
+//! Live resource metrics collection and Prometheus/OpenMetrics export.
+//!
+//! `AgentMetrics` fields (`cpu_usage_percent`, `memory_usage_mb`, etc.) were
+//! never populated, so `create_agent_health_report`'s averages were always
+//! zero. `SwarmMetrics` samples this process's own CPU/memory via `sysinfo`
+//! on every heartbeat sweep -- every "agent" here runs in-process rather
+//! than as its own OS process, so the sample is attributed to each active
+//! agent rather than looked up per-pid -- and exposes the result as
+//! Prometheus gauges served over HTTP (`ruv-swarm metrics --serve :9100`),
+//! the way Garage added `system_metrics.rs` and disk-space reporting to
+//! `garage stats`.
+
+use std::net::SocketAddr;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use axum::{routing::get, Router};
+use prometheus::{Encoder, Gauge, GaugeVec, IntGauge, Opts, Registry, TextEncoder};
+use sysinfo::System;
+
+use crate::agent_store::AgentStore;
+use crate::commands::spawn::{agent_management_utils, Agent};
+
+/// Swarm-wide and per-agent Prometheus gauges, backed by a single sampled
+/// snapshot of this process's resource usage plus the existing
+/// `create_agent_health_report`/`create_load_balancing_metadata`
+/// aggregations as the data source.
+pub struct SwarmMetrics {
+    registry: Registry,
+    agent_cpu_percent: GaugeVec,
+    agent_memory_mb: GaugeVec,
+    agent_tasks_completed: GaugeVec,
+    agent_performance_score: GaugeVec,
+    healthy_agents: IntGauge,
+    load_variance: Gauge,
+    load_balance_score: Gauge,
+    host_total_memory_mb: IntGauge,
+    host_available_memory_mb: IntGauge,
+    system: Mutex<System>,
+}
+
+impl SwarmMetrics {
+    pub fn new() -> Result<Self> {
+        let registry = Registry::new();
+
+        let agent_cpu_percent = GaugeVec::new(
+            Opts::new("ruv_swarm_agent_cpu_percent", "Per-agent CPU usage percent"),
+            &["agent_id"],
+        )?;
+        let agent_memory_mb = GaugeVec::new(
+            Opts::new("ruv_swarm_agent_memory_mb", "Per-agent memory usage in MB"),
+            &["agent_id"],
+        )?;
+        let agent_tasks_completed = GaugeVec::new(
+            Opts::new("ruv_swarm_agent_tasks_completed", "Per-agent completed task count"),
+            &["agent_id"],
+        )?;
+        let agent_performance_score = GaugeVec::new(
+            Opts::new("ruv_swarm_agent_performance_score", "Per-agent performance score"),
+            &["agent_id"],
+        )?;
+        let healthy_agents = IntGauge::new("ruv_swarm_healthy_agents", "Healthy agent count")?;
+        let load_variance = Gauge::new("ruv_swarm_load_variance", "Task-load variance across agents")?;
+        let load_balance_score = Gauge::new("ruv_swarm_load_balance_score", "Task-load balance score")?;
+        let host_total_memory_mb =
+            IntGauge::new("ruv_swarm_host_total_memory_mb", "Total host memory in MB")?;
+        let host_available_memory_mb = IntGauge::new(
+            "ruv_swarm_host_available_memory_mb",
+            "Available host memory in MB",
+        )?;
+
+        registry.register(Box::new(agent_cpu_percent.clone()))?;
+        registry.register(Box::new(agent_memory_mb.clone()))?;
+        registry.register(Box::new(agent_tasks_completed.clone()))?;
+        registry.register(Box::new(agent_performance_score.clone()))?;
+        registry.register(Box::new(healthy_agents.clone()))?;
+        registry.register(Box::new(load_variance.clone()))?;
+        registry.register(Box::new(load_balance_score.clone()))?;
+        registry.register(Box::new(host_total_memory_mb.clone()))?;
+        registry.register(Box::new(host_available_memory_mb.clone()))?;
+
+        Ok(Self {
+            registry,
+            agent_cpu_percent,
+            agent_memory_mb,
+            agent_tasks_completed,
+            agent_performance_score,
+            healthy_agents,
+            load_variance,
+            load_balance_score,
+            host_total_memory_mb,
+            host_available_memory_mb,
+            system: Mutex::new(System::new_all()),
+        })
+    }
+
+    /// Sample this process's CPU/memory and the host's total/available
+    /// memory, then refresh every gauge from the current agent list via the
+    /// existing health/load-balancing aggregations.
+    pub async fn sample(&self, agent_store: &dyn AgentStore) -> Result<()> {
+        let (process_cpu, process_memory_mb, host_total_mb, host_available_mb) = {
+            let mut system = self.system.lock().unwrap();
+            system.refresh_all();
+            let pid = sysinfo::get_current_pid().ok();
+            let process = pid.and_then(|pid| system.process(pid));
+            let cpu = process.map(|p| p.cpu_usage() as f64).unwrap_or(0.0);
+            let memory_mb = process.map(|p| p.memory() as f64 / (1024.0 * 1024.0)).unwrap_or(0.0);
+            (
+                cpu,
+                memory_mb,
+                (system.total_memory() / (1024 * 1024)) as i64,
+                (system.available_memory() / (1024 * 1024)) as i64,
+            )
+        };
+
+        self.host_total_memory_mb.set(host_total_mb);
+        self.host_available_memory_mb.set(host_available_mb);
+
+        let agents = agent_store.list().await.context("Failed to list agents for metrics")?;
+        for agent in &agents {
+            self.agent_cpu_percent
+                .with_label_values(&[&agent.id])
+                .set(process_cpu);
+            self.agent_memory_mb
+                .with_label_values(&[&agent.id])
+                .set(process_memory_mb);
+            self.agent_tasks_completed
+                .with_label_values(&[&agent.id])
+                .set(agent.metrics.tasks_completed as f64);
+            self.agent_performance_score
+                .with_label_values(&[&agent.id])
+                .set(agent.metrics.performance_score);
+        }
+
+        self.refresh_swarm_gauges(&agents);
+        Ok(())
+    }
+
+    /// Reuse `create_agent_health_report`/`create_load_balancing_metadata`
+    /// to fill in the swarm-level gauges from the current agent list.
+    fn refresh_swarm_gauges(&self, agents: &[Agent]) {
+        let health_check_results = agents
+            .iter()
+            .map(|a| (a.id.clone(), !matches!(a.status, crate::commands::spawn::AgentStatus::Offline | crate::commands::spawn::AgentStatus::Error(_)), String::new()))
+            .collect();
+        let health_report = agent_management_utils::create_agent_health_report(agents, health_check_results);
+        if let Some(healthy) = health_report.get("healthy_agents").and_then(|v| v.parse::<i64>().ok()) {
+            self.healthy_agents.set(healthy);
+        }
+
+        let task_assignments: Vec<(String, Vec<String>)> = agents
+            .iter()
+            .map(|a| (a.id.clone(), a.assigned_tasks.iter().map(|t| t.id.clone()).collect()))
+            .collect();
+        let load_report = agent_management_utils::create_load_balancing_metadata(agents, task_assignments);
+        if let Some(variance) = load_report.get("load_variance").and_then(|v| v.parse::<f64>().ok()) {
+            self.load_variance.set(variance);
+        }
+        if let Some(score) = load_report.get("load_balance_score").and_then(|v| v.parse::<f64>().ok()) {
+            self.load_balance_score.set(score);
+        }
+    }
+
+    /// Render every registered metric in Prometheus/OpenMetrics text format.
+    pub fn encode(&self) -> Result<String> {
+        let encoder = TextEncoder::new();
+        let metric_families = self.registry.gather();
+        let mut buffer = Vec::new();
+        encoder.encode(&metric_families, &mut buffer)?;
+        String::from_utf8(buffer).context("Prometheus output was not valid UTF-8")
+    }
+}
+
+/// Serve `metrics` at `GET /metrics` until the process exits, the backing
+/// data source for `ruv-swarm metrics --serve`.
+pub async fn serve_metrics(addr: SocketAddr, metrics: std::sync::Arc<SwarmMetrics>) -> Result<()> {
+    let app = Router::new().route(
+        "/metrics",
+        get(move || {
+            let metrics = std::sync::Arc::clone(&metrics);
+            async move {
+                match metrics.encode() {
+                    Ok(body) => body,
+                    Err(e) => format!("# error encoding metrics: {e}\n"),
+                }
+            }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind(addr)
+        .await
+        .with_context(|| format!("Failed to bind metrics endpoint: {addr}"))?;
+    tracing::info!("Serving Prometheus metrics at http://{addr}/metrics");
+    axum::serve(listener, app).await.context("Metrics server failed")?;
+    Ok(())
+}
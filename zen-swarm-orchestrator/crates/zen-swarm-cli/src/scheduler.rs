@@ -0,0 +1,232 @@
+//! Recurring/scheduled task entries with cron-like triggers.
+//!
+//! The crate can spawn agents and assign tasks, but there was previously no
+//! way to say "run this orchestration every N minutes" or "at a fixed
+//! time". This is modeled on u_lib's `scheduler/entry.rs`: a
+//! [`ScheduleEntry`] persisted alongside agents, and a [`Scheduler`] loop
+//! that wakes at the earliest `next_run`, picks a capable idle agent via
+//! [`agent_management_utils::assign_tasks`], dispatches the task, and
+//! recomputes `next_run`.
+
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::agent_store::AgentStore;
+use crate::commands::spawn::{agent_management_utils, Agent, AgentStatus, Task};
+
+/// When a [`ScheduleEntry`] should run next.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Trigger {
+    /// Re-fire every `Duration` after the previous run.
+    Interval(std::time::Duration),
+    /// A cron expression as parsed by the `cron` crate: six or seven
+    /// fields, starting with seconds.
+    Cron(String),
+    /// Fire exactly once, at the given time.
+    Once(DateTime<Utc>),
+}
+
+/// A recurring or one-shot piece of work the scheduler dispatches once a
+/// capable idle agent is available.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleEntry {
+    pub id: String,
+    pub task: Task,
+    pub trigger: Trigger,
+    pub target_capabilities: Vec<String>,
+    pub next_run: DateTime<Utc>,
+    /// A disabled entry is skipped by the scheduler loop and its clock
+    /// (`next_run`) is never advanced while disabled.
+    pub enabled: bool,
+}
+
+/// JSON-file persistence for a swarm's schedule entries, mirroring
+/// `agent_store::JsonFileAgentStore`'s one-file-per-swarm layout.
+pub struct ScheduleStore {
+    path: PathBuf,
+}
+
+impl ScheduleStore {
+    pub fn new(path: PathBuf) -> Self {
+        Self { path }
+    }
+
+    pub fn load(&self) -> Result<Vec<ScheduleEntry>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read schedule store: {}", self.path.display()))?;
+        Ok(serde_json::from_str(&content).unwrap_or_default())
+    }
+
+    pub fn save(&self, entries: &[ScheduleEntry]) -> Result<()> {
+        let content = serde_json::to_string_pretty(entries)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write schedule store: {}", self.path.display()))
+    }
+
+    pub fn add(&self, entry: ScheduleEntry) -> Result<()> {
+        let mut entries = self.load()?;
+        entries.retain(|e| e.id != entry.id);
+        entries.push(entry);
+        self.save(&entries)
+    }
+
+    /// Returns whether an entry with that id was actually removed.
+    pub fn remove(&self, id: &str) -> Result<bool> {
+        let mut entries = self.load()?;
+        let before = entries.len();
+        entries.retain(|e| e.id != id);
+        let removed = entries.len() != before;
+        self.save(&entries)?;
+        Ok(removed)
+    }
+
+    pub fn list(&self) -> Result<Vec<ScheduleEntry>> {
+        self.load()
+    }
+}
+
+/// Wakes at the earliest enabled entry's `next_run`, dispatches it to a
+/// capable idle agent if one is available, and recomputes `next_run`.
+pub struct Scheduler {
+    schedule_store: ScheduleStore,
+    agent_store: Arc<dyn AgentStore>,
+}
+
+impl Scheduler {
+    pub fn new(schedule_store: ScheduleStore, agent_store: Arc<dyn AgentStore>) -> Self {
+        Self {
+            schedule_store,
+            agent_store,
+        }
+    }
+
+    /// Run forever. Each iteration sleeps until the earliest enabled
+    /// `next_run` (or polls periodically if the schedule is empty so a
+    /// concurrently-added entry is picked up), then dispatches every due
+    /// entry it can find a capable idle agent for.
+    pub async fn run(&self) -> Result<()> {
+        loop {
+            let entries = self.schedule_store.load()?;
+            let earliest = entries
+                .iter()
+                .filter(|e| e.enabled)
+                .map(|e| e.next_run)
+                .min();
+
+            let now = Utc::now();
+            let sleep_for = match earliest {
+                Some(next) if next > now => (next - now)
+                    .to_std()
+                    .unwrap_or(std::time::Duration::ZERO),
+                Some(_) => std::time::Duration::ZERO,
+                None => std::time::Duration::from_secs(60),
+            };
+            tokio::time::sleep(sleep_for).await;
+
+            self.tick().await?;
+        }
+    }
+
+    /// One pass over every due, enabled entry. A run that can't find a
+    /// capable idle agent is left with its `next_run` untouched, so it stays
+    /// queued and is retried on the next tick instead of being silently
+    /// dropped. Returns the ids dispatched this tick.
+    pub async fn tick(&self) -> Result<Vec<String>> {
+        let mut entries = self.schedule_store.load()?;
+        let now = Utc::now();
+        let mut dispatched = Vec::new();
+        let mut changed = false;
+
+        for entry in entries.iter_mut() {
+            if !entry.enabled || entry.next_run > now {
+                continue;
+            }
+
+            if self.try_dispatch(entry).await? {
+                entry.next_run = Self::advance(entry, now)?;
+                dispatched.push(entry.id.clone());
+                changed = true;
+            }
+        }
+
+        if changed {
+            self.schedule_store.save(&entries)?;
+        }
+
+        Ok(dispatched)
+    }
+
+    /// Attempt to hand `entry`'s task to a capable idle agent. Returns
+    /// whether a dispatch happened.
+    async fn try_dispatch(&self, entry: &ScheduleEntry) -> Result<bool> {
+        let agents = self.agent_store.list().await?;
+        let idle_capable: Vec<Agent> = agents
+            .into_iter()
+            .filter(|a| matches!(a.status, AgentStatus::Ready | AgentStatus::Idle))
+            .filter(|a| {
+                entry
+                    .target_capabilities
+                    .iter()
+                    .all(|cap| a.capabilities.contains(cap))
+            })
+            .collect();
+
+        if idle_capable.is_empty() {
+            return Ok(false);
+        }
+
+        let assignment =
+            agent_management_utils::assign_tasks(&idle_capable, std::slice::from_ref(&entry.task));
+        let chosen_id = assignment
+            .iter()
+            .filter(|(agent_id, _)| agent_id.as_str() != agent_management_utils::UNASSIGNED_KEY)
+            .find(|(_, task_ids)| task_ids.contains(&entry.task.id))
+            .map(|(agent_id, _)| agent_id.clone());
+
+        let Some(chosen_id) = chosen_id else {
+            return Ok(false);
+        };
+
+        if let Some(mut agent) = self.agent_store.get(&chosen_id).await? {
+            agent.assigned_tasks.push(entry.task.clone());
+            agent.status = AgentStatus::Busy;
+            self.agent_store.insert(&agent).await?;
+        }
+
+        Ok(true)
+    }
+
+    /// Compute the next `next_run` for `entry` after firing at `fired_at`.
+    fn advance(entry: &ScheduleEntry, fired_at: DateTime<Utc>) -> Result<DateTime<Utc>> {
+        match &entry.trigger {
+            Trigger::Interval(duration) => {
+                let step = chrono::Duration::from_std(*duration)
+                    .unwrap_or_else(|_| chrono::Duration::zero());
+                Ok(entry.next_run + step)
+            }
+            Trigger::Cron(expr) => {
+                let schedule: cron::Schedule = expr
+                    .parse()
+                    .with_context(|| format!("Invalid cron expression: {expr}"))?;
+                schedule
+                    .upcoming(Utc)
+                    .next()
+                    .context("Cron schedule has no future occurrences")
+            }
+            Trigger::Once(_) => {
+                // Fired exactly once; mark it effectively retired instead of
+                // re-queuing by pushing next_run to the max representable
+                // instant (the caller can also flip `enabled` off).
+                let _ = fired_at;
+                Ok(DateTime::<Utc>::MAX_UTC)
+            }
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//! Background heartbeat supervision.
+//!
+//! `spawn::start_agent_heartbeat`/`update_agent_status` record an agent's
+//! liveness, but nothing previously watched for an agent going quiet: a
+//! dead agent stayed `Ready` forever and its task assignments were orphaned.
+//! `HeartbeatSupervisor` is a background tokio task (run by the standalone
+//! `ruv-swarm monitor` command; `spawn::execute` does not start one) that
+//! polls every agent's `last_heartbeat` and declares it `Offline` once it
+//! misses a configurable number of polls, then re-feeds its outstanding
+//! tasks through `assign_tasks` so surviving capable agents pick them up.
+//! This mirrors the agent/job-runner loop in u_lib, where a runner
+//! continuously polls assigned work and reacts to liveness.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+use anyhow::Result;
+use chrono::Utc;
+
+use crate::agent_store::AgentStore;
+use crate::commands::spawn::{agent_management_utils, Agent, AgentStatus, Task};
+
+/// How often the supervisor polls, and how many consecutive misses before
+/// an agent is declared dead.
+#[derive(Debug, Clone, Copy)]
+pub struct SupervisorConfig {
+    pub poll_interval: StdDuration,
+    pub missed_intervals: u32,
+}
+
+impl Default for SupervisorConfig {
+    fn default() -> Self {
+        Self {
+            poll_interval: StdDuration::from_secs(5),
+            missed_intervals: 3,
+        }
+    }
+}
+
+/// Watches agent liveness and re-feeds a dead agent's outstanding tasks
+/// through `agent_management_utils::assign_tasks`.
+pub struct HeartbeatSupervisor {
+    store: Arc<dyn AgentStore>,
+    config: SupervisorConfig,
+    /// Sampled on every sweep when set, so `AgentMetrics` stays live instead
+    /// of reading zero forever.
+    metrics: Option<Arc<crate::metrics::SwarmMetrics>>,
+}
+
+impl HeartbeatSupervisor {
+    pub fn new(store: Arc<dyn AgentStore>, config: SupervisorConfig) -> Self {
+        Self {
+            store,
+            config,
+            metrics: None,
+        }
+    }
+
+    /// Attach a `SwarmMetrics` collector so every sweep also samples
+    /// resource usage for the current agent list.
+    pub fn with_metrics(mut self, metrics: Arc<crate::metrics::SwarmMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    /// Spawn the supervisor loop as a background tokio task. The returned
+    /// handle can be aborted to stop supervision, e.g. on shutdown.
+    pub fn start(self: Arc<Self>) -> tokio::task::JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(self.config.poll_interval);
+            loop {
+                interval.tick().await;
+                if let Err(err) = self.sweep().await {
+                    tracing::warn!("Heartbeat supervisor sweep failed: {err:#}");
+                }
+            }
+        })
+    }
+
+    /// One pass: find agents that have missed `missed_intervals` consecutive
+    /// polls, mark them `Offline`, and reassign their outstanding tasks to
+    /// surviving capable agents. Returns the ids newly declared dead this
+    /// sweep.
+    pub async fn sweep(&self) -> Result<Vec<String>> {
+        if let Some(metrics) = &self.metrics {
+            metrics.sample(self.store.as_ref()).await?;
+        }
+
+        let agents = self.store.list().await?;
+        let timeout = chrono::Duration::from_std(self.config.poll_interval)
+            .unwrap_or_else(|_| chrono::Duration::zero())
+            * self.config.missed_intervals as i32;
+        let now = Utc::now();
+
+        let mut newly_dead = Vec::new();
+        for agent in &agents {
+            if matches!(agent.status, AgentStatus::Offline) {
+                continue;
+            }
+            if now.signed_duration_since(agent.last_heartbeat) >= timeout {
+                self.handle_dead_agent(agent, &agents).await?;
+                newly_dead.push(agent.id.clone());
+            }
+        }
+
+        Ok(newly_dead)
+    }
+
+    /// Mark `dead` offline and hand its outstanding tasks back through
+    /// `assign_tasks`, persisting the new owner for each task that found
+    /// one.
+    async fn handle_dead_agent(&self, dead: &Agent, all_agents: &[Agent]) -> Result<()> {
+        self.store.update_status(&dead.id, AgentStatus::Offline).await?;
+
+        if dead.assigned_tasks.is_empty() {
+            return Ok(());
+        }
+
+        let survivors: Vec<Agent> = all_agents
+            .iter()
+            .filter(|a| a.id != dead.id && !matches!(a.status, AgentStatus::Offline))
+            .cloned()
+            .collect();
+
+        let reassignment = agent_management_utils::assign_tasks(&survivors, &dead.assigned_tasks);
+
+        for survivor in &survivors {
+            let Some(new_task_ids) = reassignment.get(&survivor.id) else {
+                continue;
+            };
+            if new_task_ids.is_empty() {
+                continue;
+            }
+
+            let picked_up: Vec<Task> = dead
+                .assigned_tasks
+                .iter()
+                .filter(|t| new_task_ids.contains(&t.id))
+                .cloned()
+                .collect();
+
+            let mut updated = survivor.clone();
+            updated.assigned_tasks.extend(picked_up);
+            self.store.insert(&updated).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Build the existing health report over the current agent list, so a
+    /// caller can see an `Offline` transition reflected right after a sweep.
+    pub async fn health_report(&self) -> Result<HashMap<String, String>> {
+        let agents = self.store.list().await?;
+        let health_check_results = agents
+            .iter()
+            .map(|agent| {
+                let is_healthy = !matches!(agent.status, AgentStatus::Offline | AgentStatus::Error(_));
+                let reason = match &agent.status {
+                    AgentStatus::Offline => "missed heartbeat".to_string(),
+                    AgentStatus::Error(msg) => msg.clone(),
+                    _ => "ok".to_string(),
+                };
+                (agent.id.clone(), is_healthy, reason)
+            })
+            .collect();
+        Ok(agent_management_utils::create_agent_health_report(
+            &agents,
+            health_check_results,
+        ))
+    }
+}
@@ -1,45 +1,320 @@
 //! Embedding generation (minimal implementation)
 
 use crate::{types::Vector, error::VectorError, config::EmbeddingConfig};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::sleep;
 
-/// Simple embedding engine for initial implementation  
+/// Inputs longer than this are truncated before being enqueued, so a single
+/// oversized item can't blow out the token budget for an entire batch.
+const MAX_INPUT_CHARS: usize = 8192;
+
+/// Token budget a pending batch is allowed to accumulate before it is flushed.
+const DEFAULT_TOKEN_BUDGET: usize = 2048;
+
+/// How long a batch is allowed to sit idle before it is flushed regardless of
+/// whether the token budget has been reached.
+const DEBOUNCE: Duration = Duration::from_millis(10);
+
+/// Maximum number of retries when the backend reports a rate limit.
+const MAX_BACKEND_RETRIES: u32 = 3;
+
+/// Rough token estimate used purely for batch sizing, not a real tokenizer.
+fn estimate_tokens(text: &str) -> usize {
+    (text.len() / 4).max(1)
+}
+
+/// Content-addressed cache key: the text combined with a model/config
+/// fingerprint, so changing the embedding model invalidates stale entries.
+fn cache_key(text: &str, config: &EmbeddingConfig) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    text.hash(&mut hasher);
+    config.model_name.hash(&mut hasher);
+    config.dimensions.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Local content-addressed embedding cache with hit/miss counters.
+#[derive(Default)]
+struct EmbeddingCache {
+    entries: HashMap<u64, Vector>,
+    hits: u64,
+    misses: u64,
+}
+
+impl EmbeddingCache {
+    fn get(&mut self, key: u64) -> Option<Vector> {
+        match self.entries.get(&key) {
+            Some(vector) => {
+                self.hits += 1;
+                Some(vector.clone())
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn insert(&mut self, key: u64, vector: Vector) {
+        self.entries.insert(key, vector);
+    }
+}
+
+/// Accumulates pending texts (and their cache keys) until either the token
+/// budget or the debounce timer is reached, so each backend call carries an
+/// optimal batch instead of one text at a time.
+#[derive(Default)]
+struct EmbeddingsQueue {
+    pending: Vec<(u64, String)>,
+    pending_tokens: usize,
+}
+
+impl EmbeddingsQueue {
+    fn push(&mut self, key: u64, text: String) {
+        self.pending_tokens += estimate_tokens(&text);
+        self.pending.push((key, text));
+    }
+
+    fn should_flush(&self, token_budget: usize) -> bool {
+        !self.pending.is_empty() && self.pending_tokens >= token_budget
+    }
+
+    fn take(&mut self) -> Vec<(u64, String)> {
+        self.pending_tokens = 0;
+        std::mem::take(&mut self.pending)
+    }
+}
+
+/// Health snapshot for the embedding engine, including local cache effectiveness.
+#[derive(Debug, Clone)]
+pub struct EmbeddingHealth {
+    pub healthy: bool,
+    pub cache_hits: u64,
+    pub cache_misses: u64,
+}
+
+/// Simple embedding engine for initial implementation
 pub struct EmbeddingEngine {
     config: EmbeddingConfig,
+    cache: Mutex<EmbeddingCache>,
+    queue: Mutex<EmbeddingsQueue>,
 }
 
 impl EmbeddingEngine {
     pub async fn default() -> Result<Self, VectorError> {
         Ok(Self {
             config: EmbeddingConfig::default(),
+            cache: Mutex::new(EmbeddingCache::default()),
+            queue: Mutex::new(EmbeddingsQueue::default()),
         })
     }
-    
+
     pub async fn from_config(config: &EmbeddingConfig) -> Result<Self, VectorError> {
         Ok(Self {
             config: config.clone(),
+            cache: Mutex::new(EmbeddingCache::default()),
+            queue: Mutex::new(EmbeddingsQueue::default()),
         })
     }
-    
+
     pub async fn embed_text(&self, text: &str) -> Result<Vector, VectorError> {
+        Ok(self.embed_batch(&[text.to_string()]).await?.remove(0))
+    }
+
+    /// Embed many texts at once, serving cached entries directly and routing
+    /// only cache misses through the token-aware batching queue.
+    pub async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vector>, VectorError> {
+        let mut results: Vec<Option<Vector>> = vec![None; texts.len()];
+        let mut miss_indices = Vec::new();
+
+        {
+            let mut cache = self.cache.lock().await;
+            for (i, text) in texts.iter().enumerate() {
+                let truncated = truncate_input(text);
+                let key = cache_key(&truncated, &self.config);
+                match cache.get(key) {
+                    Some(vector) => results[i] = Some(vector),
+                    None => miss_indices.push((i, key, truncated)),
+                }
+            }
+        }
+
+        if miss_indices.is_empty() {
+            return Ok(results.into_iter().map(|v| v.unwrap()).collect());
+        }
+
+        {
+            let mut queue = self.queue.lock().await;
+            for (_, key, truncated) in &miss_indices {
+                queue.push(*key, truncated.clone());
+            }
+        }
+
+        // Give the queue a chance to pick up a fuller batch from concurrent
+        // callers before flushing, unless the token budget is already met.
+        let ready = {
+            let queue = self.queue.lock().await;
+            queue.should_flush(DEFAULT_TOKEN_BUDGET)
+        };
+        if !ready {
+            sleep(DEBOUNCE).await;
+        }
+
+        let flushed = {
+            let mut queue = self.queue.lock().await;
+            queue.take()
+        };
+
+        if !flushed.is_empty() {
+            let texts_to_embed: Vec<String> = flushed.iter().map(|(_, text)| text.clone()).collect();
+            let embedded = self.embed_with_retry(&texts_to_embed).await?;
+
+            let mut cache = self.cache.lock().await;
+            for ((key, _), vector) in flushed.into_iter().zip(embedded) {
+                cache.insert(key, vector);
+            }
+        }
+
+        // A concurrent caller's `take()` may have raced ahead of this call's
+        // own `take()` above and flushed (and cached) some or all of the
+        // keys this call pushed -- `flushed` only holds what *this* call
+        // took, so re-check the cache rather than assuming it covers every
+        // key from `miss_indices`.
+        let mut still_missing = Vec::new();
+        {
+            let mut cache = self.cache.lock().await;
+            for (i, key, truncated) in miss_indices {
+                match cache.get(key) {
+                    Some(vector) => results[i] = Some(vector),
+                    None => still_missing.push((i, key, truncated)),
+                }
+            }
+        }
+
+        if !still_missing.is_empty() {
+            // Nobody else embedded these either (e.g. they were pushed after
+            // every concurrent `take()` already ran this round) -- embed
+            // them directly instead of waiting on another debounce cycle.
+            let texts_to_embed: Vec<String> =
+                still_missing.iter().map(|(_, _, text)| text.clone()).collect();
+            let embedded = self.embed_with_retry(&texts_to_embed).await?;
+
+            let mut cache = self.cache.lock().await;
+            for ((i, key, _), vector) in still_missing.into_iter().zip(embedded) {
+                cache.insert(key, vector.clone());
+                results[i] = Some(vector);
+            }
+        }
+
+        Ok(results
+            .into_iter()
+            .map(|v| v.expect("every miss is filled by the flushed batch, cache, or a direct embed"))
+            .collect())
+    }
+
+    /// Call the backend for a batch, retrying with exponential backoff when
+    /// it reports a rate limit instead of failing the whole batch outright.
+    async fn embed_with_retry(&self, texts: &[String]) -> Result<Vec<Vector>, VectorError> {
+        let mut attempt = 0;
+        loop {
+            match self.embed_backend(texts) {
+                Ok(vectors) => return Ok(vectors),
+                Err(err) if attempt < MAX_BACKEND_RETRIES => {
+                    let delay = retry_after(&err)
+                        .unwrap_or_else(|| Duration::from_millis(50 * 2u64.pow(attempt)));
+                    sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
+
+    /// Backend call for a single batch. This is the simple hash-based
+    /// embedding used for the initial implementation.
+    fn embed_backend(&self, texts: &[String]) -> Result<Vec<Vector>, VectorError> {
+        Ok(texts.iter().map(|text| self.generate_embedding(text)).collect())
+    }
+
+    fn generate_embedding(&self, text: &str) -> Vector {
         // Simple hash-based embedding for initial implementation
         let mut dims = vec![0.0; self.config.dimensions];
         let bytes = text.as_bytes();
         for (i, &byte) in bytes.iter().enumerate() {
             dims[i % self.config.dimensions] += byte as f32 / 255.0;
         }
-        
-        // Normalize
-        let magnitude: f32 = dims.iter().map(|x| x * x).sum::<f32>().sqrt();
-        if magnitude > 0.0 {
-            for dim in &mut dims {
-                *dim /= magnitude;
-            }
-        }
-        
-        Ok(Vector::normalized(dims))
+
+        Vector::normalized(dims)
+    }
+
+    pub async fn health_check(&self) -> Result<EmbeddingHealth, VectorError> {
+        let cache = self.cache.lock().await;
+        Ok(EmbeddingHealth {
+            healthy: true,
+            cache_hits: cache.hits,
+            cache_misses: cache.misses,
+        })
     }
-    
-    pub async fn health_check(&self) -> Result<bool, VectorError> {
-        Ok(true)
+}
+
+/// Truncate an over-long input before it enters the cache or the queue, so a
+/// single pathological item can't poison a batch.
+fn truncate_input(text: &str) -> String {
+    if text.len() <= MAX_INPUT_CHARS {
+        text.to_string()
+    } else {
+        text.chars().take(MAX_INPUT_CHARS).collect()
+    }
+}
+
+/// Parse a server-provided retry delay out of a rate-limit error, if the
+/// backend encoded one (as `"rate_limited:retry_after_ms=<n>"`).
+fn retry_after(err: &VectorError) -> Option<Duration> {
+    if let VectorError::Embedding(message) = err {
+        let marker = "retry_after_ms=";
+        let start = message.find(marker)? + marker.len();
+        let digits: String = message[start..].chars().take_while(|c| c.is_ascii_digit()).collect();
+        digits.parse::<u64>().ok().map(Duration::from_millis)
+    } else {
+        None
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn embed_batch_single_caller_fills_every_result() {
+        let engine = EmbeddingEngine::default().await.unwrap();
+        let texts = vec!["alpha".to_string(), "beta".to_string(), "gamma".to_string()];
+        let results = engine.embed_batch(&texts).await.unwrap();
+        assert_eq!(results.len(), texts.len());
+    }
+
+    /// Two concurrent `embed_batch` calls sharing overlapping miss keys must
+    /// not panic: whichever call's `take()` wins the race, the other call
+    /// has to notice its keys were already embedded (cache hit) or embed
+    /// them itself, rather than unwrapping a `None`.
+    #[tokio::test]
+    async fn concurrent_embed_batch_does_not_panic_on_shared_misses() {
+        let engine = Arc::new(EmbeddingEngine::default().await.unwrap());
+
+        let a = Arc::clone(&engine);
+        let texts_a = vec!["shared-1".to_string(), "only-a".to_string()];
+        let call_a = tokio::spawn(async move { a.embed_batch(&texts_a).await });
+
+        let b = Arc::clone(&engine);
+        let texts_b = vec!["shared-1".to_string(), "only-b".to_string()];
+        let call_b = tokio::spawn(async move { b.embed_batch(&texts_b).await });
+
+        let (result_a, result_b) = tokio::join!(call_a, call_b);
+        assert_eq!(result_a.unwrap().unwrap().len(), 2);
+        assert_eq!(result_b.unwrap().unwrap().len(), 2);
+    }
+}
@@ -122,15 +122,15 @@ impl VectorDatabase {
     /// Health check for all components
     pub async fn health_check(&self) -> Result<HealthStatus, VectorError> {
         let store_healthy = self.store.health_check().await?;
-        let embedder_healthy = {
+        let embedder_health = {
             let embedder = self.embedder.read().await;
             embedder.health_check().await?
         };
-        
+
         Ok(HealthStatus {
             store_healthy,
-            embedder_healthy,
-            overall_healthy: store_healthy && embedder_healthy,
+            embedder_healthy: embedder_health.healthy,
+            overall_healthy: store_healthy && embedder_health.healthy,
         })
     }
 }
@@ -0,0 +1,30 @@
+//! `ruv-swarm cache`: inspect or clear the `JobCache` subtask-result cache.
+
+use anyhow::Result;
+
+use crate::commands::orchestrate::tasks_dir;
+use crate::job_cache::JobCache;
+use crate::output::OutputHandler;
+
+/// `ruv-swarm cache stats`: print how many subtask results are cached and
+/// how many of those are still within their TTL.
+pub async fn execute_stats(output: &OutputHandler) -> Result<()> {
+    let cache = JobCache::with_default_ttl(&tasks_dir()?);
+    let stats = cache.stats();
+
+    output.key_value(&[
+        ("Total Entries".to_string(), stats.total.to_string()),
+        ("Fresh".to_string(), stats.fresh.to_string()),
+        ("Expired".to_string(), stats.expired.to_string()),
+    ]);
+
+    Ok(())
+}
+
+/// `ruv-swarm cache clear`: drop every cached subtask result.
+pub async fn execute_clear(output: &OutputHandler) -> Result<()> {
+    let cache = JobCache::with_default_ttl(&tasks_dir()?);
+    cache.clear()?;
+    output.success("Job cache cleared");
+    Ok(())
+}
@@ -1,14 +1,21 @@
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use chrono::Utc;
 use indicatif::ProgressBar;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::path::Path;
 use std::time::Duration;
 use uuid::Uuid;
 
 use crate::config::Config;
 use crate::output::{OutputHandler, StatusLevel};
+use crate::job_cache::JobCache;
+use crate::lua::{AggregationConfig, LuaScript};
+use crate::notifier::{self, NotificationEvent, NotifierConfig};
+use crate::repository::{self, Repository};
+use crate::worker_manager::{WorkerControl, WorkerManager, WorkerStatus};
+use tokio::sync::mpsc;
 
 /// Task orchestration utilities using HashMap for coordination metadata
 mod orchestration_utils {
@@ -20,6 +27,8 @@ mod orchestration_utils {
         execution_time_ms: u64,
         resource_usage: Vec<(String, f64)>,
         agent_assignments: Vec<String>,
+        scheduled_at: Option<chrono::DateTime<Utc>>,
+        next_run_at: Option<chrono::DateTime<Utc>>,
     ) -> HashMap<String, String> {
         let mut metadata = HashMap::new();
         
@@ -46,7 +55,26 @@ mod orchestration_utils {
             .filter(|st| matches!(st.status, TaskStatus::Completed))
             .count();
         metadata.insert("subtasks_completed".to_string(), completed_subtasks.to_string());
-        
+
+        // A subtask's first attempt isn't a retry, so only attempts beyond
+        // the first count toward the total.
+        let retries_total: u32 = task
+            .subtasks
+            .iter()
+            .map(|st| st.attempts.saturating_sub(1))
+            .sum();
+        metadata.insert("retries_total".to_string(), retries_total.to_string());
+
+        // Scheduling: when this run was queued (for a `--at`/`--every`/
+        // `--cron` launch) and, for a recurring entry, when it next fires.
+        // Both are absent for an immediate, non-scheduled run.
+        if let Some(scheduled_at) = scheduled_at {
+            metadata.insert("scheduled_at".to_string(), scheduled_at.to_rfc3339());
+        }
+        if let Some(next_run_at) = next_run_at {
+            metadata.insert("next_run_at".to_string(), next_run_at.to_rfc3339());
+        }
+
         metadata
     }
     
@@ -93,20 +121,30 @@ mod orchestration_utils {
         active_agents: Vec<&str>,
         pending_operations: Vec<String>,
         completed_phases: Vec<String>,
+        reserved_agents: Vec<&str>,
+        queued_subtasks: Vec<String>,
     ) -> HashMap<String, String> {
         let mut state = HashMap::new();
-        
+
         state.insert("task_id".to_string(), task_id.to_string());
         state.insert("coordination_timestamp".to_string(), Utc::now().to_rfc3339());
         state.insert("active_agents".to_string(), active_agents.join(","));
         state.insert("active_agent_count".to_string(), active_agents.len().to_string());
-        
+
         // Operation tracking
         state.insert("pending_operations".to_string(), pending_operations.join(";"));
         state.insert("pending_count".to_string(), pending_operations.len().to_string());
         state.insert("completed_phases".to_string(), completed_phases.join(";"));
         state.insert("completed_phases_count".to_string(), completed_phases.len().to_string());
-        
+
+        // Task-first scheduling: agents currently reserved for a running
+        // subtask, and subtasks that are ready to run but found every
+        // compatible agent already reserved.
+        state.insert("reserved_agents".to_string(), reserved_agents.join(","));
+        state.insert("reserved_agent_count".to_string(), reserved_agents.len().to_string());
+        state.insert("queued_subtasks".to_string(), queued_subtasks.join(","));
+        state.insert("queued_subtask_count".to_string(), queued_subtasks.len().to_string());
+
         // Coordination health indicators
         let health_score = if active_agents.is_empty() {
             0.0
@@ -115,7 +153,7 @@ mod orchestration_utils {
             completion_ratio * 100.0
         };
         state.insert("coordination_health_score".to_string(), format!("{:.1}", health_score));
-        
+
         state
     }
     
@@ -153,7 +191,55 @@ mod orchestration_utils {
         let completed_tasks = tasks.iter().filter(|t| matches!(t.status, TaskStatus::Completed)).count();
         let success_rate = if tasks.is_empty() { 0.0 } else { (completed_tasks as f64 / tasks.len() as f64) * 100.0 };
         analytics.insert("success_rate_percent".to_string(), format!("{:.1}", success_rate));
-        
+
+        // Longest dependency chain across all tasks' subtask DAGs -- the
+        // minimum wall-clock any of them could finish in even with
+        // unlimited agents.
+        let critical_path_length = tasks
+            .iter()
+            .map(|t| critical_path_length(&t.subtasks))
+            .max()
+            .unwrap_or(0);
+        analytics.insert("critical_path_length".to_string(), critical_path_length.to_string());
+
+        // Queue depth and agent utilization for the task-first scheduler:
+        // how many subtasks are still waiting for a compatible free agent
+        // versus how many currently have one reserved and running.
+        let pending_subtasks = tasks
+            .iter()
+            .flat_map(|t| &t.subtasks)
+            .filter(|st| matches!(st.status, TaskStatus::Pending))
+            .count();
+        let running_subtasks = tasks
+            .iter()
+            .flat_map(|t| &t.subtasks)
+            .filter(|st| matches!(st.status, TaskStatus::Running))
+            .count();
+        analytics.insert("subtasks_pending_gauge".to_string(), pending_subtasks.to_string());
+        analytics.insert("subtasks_running_gauge".to_string(), running_subtasks.to_string());
+        analytics.insert("queue_depth".to_string(), pending_subtasks.to_string());
+
+        let reserved_agents: std::collections::HashSet<&str> = tasks
+            .iter()
+            .flat_map(|t| &t.subtasks)
+            .filter(|st| matches!(st.status, TaskStatus::Running))
+            .map(|st| st.assigned_agent.as_str())
+            .collect();
+        let known_agents: std::collections::HashSet<&str> = tasks
+            .iter()
+            .flat_map(|t| &t.assigned_agents)
+            .map(|id| id.as_str())
+            .collect();
+        let agent_utilization_percent = if known_agents.is_empty() {
+            0.0
+        } else {
+            reserved_agents.len() as f64 / known_agents.len() as f64 * 100.0
+        };
+        analytics.insert(
+            "agent_utilization_percent".to_string(),
+            format!("{:.1}", agent_utilization_percent),
+        );
+
         analytics
     }
 }
@@ -172,6 +258,38 @@ pub struct Task {
     pub started_at: Option<chrono::DateTime<chrono::Utc>>,
     pub completed_at: Option<chrono::DateTime<chrono::Utc>>,
     pub timeout_seconds: Option<u64>,
+    /// Max attempts per subtask before it's marked permanently `Failed`.
+    /// Defaults to a strategy-specific value, overridable with `--max-retries`.
+    pub max_retries: u32,
+    /// Minimum agreement ratio `OrchestrationStrategy::Consensus` needs
+    /// among agent outputs to accept a winner; below this the task is
+    /// `Failed` instead of accepting a minority result. Ignored by every
+    /// other strategy. Overridable with `--quorum`.
+    #[serde(default = "default_quorum")]
+    pub quorum: f64,
+    /// How `build_consensus` picks a winner among the candidate output
+    /// buckets. Overridable with `--consensus-rule`.
+    #[serde(default)]
+    pub consensus_rule: ConsensusRule,
+}
+
+fn default_quorum() -> f64 {
+    0.5
+}
+
+/// How `build_consensus` selects a winning output bucket for
+/// `OrchestrationStrategy::Consensus`.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub enum ConsensusRule {
+    /// The bucket with the most agents, one vote per agent.
+    #[default]
+    Majority,
+    /// The bucket with the highest summed agent weight (derived from each
+    /// agent's historical completed/failed task ratio).
+    Weighted,
+    /// Only accept a result if every agent agrees; any disagreement fails
+    /// quorum regardless of the `quorum` fraction.
+    Unanimous,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -199,6 +317,37 @@ pub struct SubTask {
     pub assigned_agent: String,
     pub status: TaskStatus,
     pub result: Option<TaskResult>,
+    /// How many times `execute_subtask` has been attempted for this subtask,
+    /// including the (possibly still in-flight) current attempt.
+    #[serde(default)]
+    pub attempts: u32,
+    /// The error from the most recent failed attempt, kept even after a
+    /// later attempt succeeds so the retry history is visible in results.
+    #[serde(default)]
+    pub last_error: Option<String>,
+    /// Ids of subtasks that must be `Completed` before this one can run,
+    /// turning the flat subtask list into a Ballista-style execution DAG.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+    /// Outputs from every completed dependency, fed into this subtask's
+    /// execution as input once all of `depends_on` has completed.
+    #[serde(default)]
+    pub inputs: Vec<serde_json::Value>,
+    /// Agent type the scheduler should prefer when binding this subtask to
+    /// a free agent, e.g. so a "coder" subtask doesn't land on a
+    /// "researcher". `None` means any agent type is acceptable.
+    #[serde(default)]
+    pub required_agent_type: Option<String>,
+    /// Capability tags the scheduler scores candidate agents against when
+    /// `required_agent_type` alone doesn't pick a clear winner.
+    #[serde(default)]
+    pub required_capabilities: Vec<String>,
+    /// Position in the priority order `run_dag` prefers when several ready
+    /// subtasks are competing for the same free agents -- lower runs first.
+    /// Assigned sequentially at decomposition time and renumbered by
+    /// `reprioritize_subtasks` whenever an operator moves one.
+    #[serde(default)]
+    pub order: usize,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -209,6 +358,11 @@ pub struct TaskResult {
     pub output: serde_json::Value,
     pub execution_time_ms: u64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
+    /// Set when this result was served from `JobCache` instead of a fresh
+    /// agent dispatch, so `display_task_results` can report cache hits
+    /// separately from the success-rate math.
+    #[serde(default)]
+    pub cached: bool,
 }
 
 /// Execute the orchestrate command
@@ -221,6 +375,12 @@ pub async fn execute(
     timeout: Option<u64>,
     priority: u8,
     watch: bool,
+    max_retries: Option<u32>,
+    at: Option<String>,
+    every: Option<String>,
+    cron: Option<String>,
+    quorum: Option<f64>,
+    consensus_rule: Option<String>,
 ) -> Result<()> {
     output.section("Orchestrating Task");
 
@@ -239,6 +399,21 @@ pub async fn execute(
         }
     };
 
+    // Parse --consensus-rule (ignored outside OrchestrationStrategy::Consensus)
+    let consensus_rule_enum = match consensus_rule.as_deref().map(|r| r.to_lowercase()) {
+        None => ConsensusRule::default(),
+        Some(ref r) if r == "majority" => ConsensusRule::Majority,
+        Some(ref r) if r == "weighted" => ConsensusRule::Weighted,
+        Some(ref r) if r == "unanimous" => ConsensusRule::Unanimous,
+        Some(other) => {
+            output.error(&format!(
+                "Invalid consensus rule '{other}'. Valid options: majority, weighted, unanimous"
+            ));
+            return Err(anyhow::anyhow!("Invalid consensus rule"));
+        }
+    };
+    let quorum = quorum.unwrap_or(default_quorum()).clamp(0.0, 1.0);
+
     // Load task description (from file or direct input)
     let task_description = if Path::new(&task).exists() {
         output.info(&format!("Loading task from file: {}", task));
@@ -253,9 +428,52 @@ pub async fn execute(
     }
     let priority = priority.clamp(1, 10);
 
+    // `--at`/`--every`/`--cron` launch persists a scheduled entry instead of
+    // running immediately; the tick loop fires it later through the same
+    // decomposition/execution path via `scheduler::run_to_completion`.
+    if at.is_some() || every.is_some() || cron.is_some() {
+        let trigger = match (at, every, cron) {
+            (Some(_), _, Some(_)) | (_, Some(_), Some(_)) | (Some(_), Some(_), _) => {
+                bail!("Specify only one of --at, --every, or --cron");
+            }
+            (Some(at), None, None) => crate::scheduler::Trigger::Once(crate::scheduler::parse_at(&at)?),
+            (None, Some(every), None) => {
+                crate::scheduler::Trigger::Interval(crate::scheduler::parse_every(&every)?)
+            }
+            (None, None, Some(cron)) => crate::scheduler::Trigger::Cron(cron),
+            (None, None, None) => unreachable!("checked above"),
+        };
+
+        let scheduler = crate::scheduler::Scheduler::new(tasks_dir()?);
+        let entry = scheduler.schedule(
+            task_description,
+            strategy_enum,
+            max_agents,
+            timeout,
+            priority,
+            max_retries,
+            quorum,
+            consensus_rule_enum,
+            trigger,
+        )?;
+
+        output.success(&format!(
+            "Scheduled run '{}': next fire at {}",
+            entry.id,
+            entry.next_run.to_rfc3339()
+        ));
+        return Ok(());
+    }
+
+    // Every task this invocation touches -- the new one created below, and
+    // (via `execute_with_monitoring`/`execute_background`) its eventual
+    // results -- goes through the repository rather than hand-rolled JSON
+    // file writes.
+    let repo = repository::open_default(repository::RepositoryBackend::default(), &tasks_dir()?).await?;
+
     // Load current swarm and available agents
-    let swarm_config = load_current_swarm(output).await?;
-    let available_agents = get_available_agents(&swarm_config).await?;
+    let swarm_config = require_current_swarm(repo.as_ref(), output).await?;
+    let available_agents = repo.list_available_agents(&swarm_config).await?;
 
     if available_agents.is_empty() {
         output
@@ -284,6 +502,9 @@ pub async fn execute(
         started_at: None,
         completed_at: None,
         timeout_seconds: timeout,
+        max_retries: max_retries.unwrap_or_else(|| default_retry_attempts(&strategy_enum)),
+        quorum,
+        consensus_rule: consensus_rule_enum,
     };
 
     // Display task configuration
@@ -321,26 +542,51 @@ pub async fn execute(
         pb.finish_with_message(format!("Created {} subtasks", subtasks.len()));
     }
 
-    // Show subtask breakdown
+    // Show subtask breakdown. Agents aren't bound positionally anymore --
+    // the task-first scheduler reserves one once the subtask is ready --
+    // so before execution this can only show what each subtask requires.
     output.section("Task Breakdown");
     for (i, subtask) in subtasks.iter().enumerate() {
-        output.info(&format!(
-            "{}. {} → {}",
-            i + 1,
-            subtask.description,
-            agents_to_use
-                .iter()
-                .find(|a| a.id == subtask.assigned_agent)
-                .map(|a| &a.name)
-                .unwrap_or(&"Unknown".to_string())
-        ));
+        let target = agents_to_use
+            .iter()
+            .find(|a| a.id == subtask.assigned_agent)
+            .map(|a| a.name.clone())
+            .or_else(|| subtask.required_agent_type.clone().map(|t| format!("any {t} agent")))
+            .unwrap_or_else(|| "any agent".to_string());
+        output.info(&format!("{}. {} → {}", i + 1, subtask.description, target));
     }
 
     // Execute task
     if watch {
-        execute_with_monitoring(task_obj, subtasks, agents_to_use, config, output).await
+        execute_with_monitoring(task_obj, subtasks, agents_to_use, config, output, repo.as_ref()).await
     } else {
-        execute_background(task_obj, subtasks, agents_to_use, config, output).await
+        execute_background(task_obj, subtasks, agents_to_use, config, output, repo.as_ref()).await
+    }
+}
+
+/// Load the current swarm, surfacing the same "run init first" guidance
+/// `load_current_swarm` used to print inline before the `Repository` split.
+async fn require_current_swarm(
+    repo: &dyn Repository,
+    output: &OutputHandler,
+) -> Result<crate::commands::init::SwarmInit> {
+    match repo.get_current_swarm().await {
+        Ok(swarm) => Ok(swarm),
+        Err(err) => {
+            output.error("No active swarm found. Run 'ruv-swarm init' first.");
+            Err(err)
+        }
+    }
+}
+
+/// The `--max-retries`-overridable retry budget per subtask when a
+/// strategy doesn't specify one. Parallel/Consensus subtasks are
+/// independent and cheap to redo, so they get a larger budget than
+/// Sequential, where a retry also delays every subtask after it.
+pub(crate) fn default_retry_attempts(strategy: &OrchestrationStrategy) -> u32 {
+    match strategy {
+        OrchestrationStrategy::Parallel | OrchestrationStrategy::Consensus => 3,
+        OrchestrationStrategy::Sequential | OrchestrationStrategy::Adaptive => 2,
     }
 }
 
@@ -351,228 +597,539 @@ async fn decompose_task(
 ) -> Result<Vec<SubTask>> {
     let mut subtasks = Vec::new();
 
-    // Simulate task decomposition based on strategy
+    // Simulate task decomposition based on strategy, building an execution
+    // DAG via each SubTask's `depends_on` rather than relying on Vec order.
     match strategy {
         OrchestrationStrategy::Parallel => {
-            // Divide task among all agents
+            // Divide task among all agents; no dependencies between them.
+            // Assignment itself is left to the task-first scheduler in
+            // run_dag, which binds a free agent when the subtask is ready
+            // rather than pre-binding it here -- pre-binding let several
+            // subtasks claim the same agent and run it concurrently.
             for (i, agent) in agents.iter().enumerate() {
                 subtasks.push(SubTask {
                     id: Uuid::new_v4().to_string(),
                     description: format!("Parallel subtask {} for {}", i + 1, task.description),
-                    assigned_agent: agent.id.clone(),
+                    assigned_agent: String::new(),
                     status: TaskStatus::Pending,
                     result: None,
+                    attempts: 0,
+                    last_error: None,
+                    depends_on: Vec::new(),
+                    inputs: Vec::new(),
+                    required_agent_type: Some(agent.agent_type.clone()),
+                    required_capabilities: agent.capabilities.clone(),
+                    order: 0,
                 });
             }
         }
         OrchestrationStrategy::Sequential => {
-            // Create a chain of subtasks
+            // A true staged DAG chain: each step depends on the one before it.
+            let mut previous_id: Option<String> = None;
             for (i, agent) in agents.iter().enumerate() {
+                let id = Uuid::new_v4().to_string();
                 subtasks.push(SubTask {
-                    id: Uuid::new_v4().to_string(),
+                    id: id.clone(),
                     description: format!("Step {} of {}", i + 1, task.description),
-                    assigned_agent: agent.id.clone(),
+                    assigned_agent: String::new(),
                     status: TaskStatus::Pending,
                     result: None,
+                    attempts: 0,
+                    last_error: None,
+                    depends_on: previous_id.into_iter().collect(),
+                    inputs: Vec::new(),
+                    required_agent_type: Some(agent.agent_type.clone()),
+                    required_capabilities: agent.capabilities.clone(),
+                    order: 0,
                 });
+                previous_id = Some(id);
             }
         }
         OrchestrationStrategy::Adaptive => {
-            // Start with exploration phase
+            // Explore several approaches independently, then converge into
+            // a single aggregation node that depends on every explorer.
             let explorers = agents.iter().take(3).collect::<Vec<_>>();
+            let mut explorer_ids = Vec::new();
             for (i, agent) in explorers.iter().enumerate() {
+                let id = Uuid::new_v4().to_string();
+                explorer_ids.push(id.clone());
                 subtasks.push(SubTask {
-                    id: Uuid::new_v4().to_string(),
+                    id,
                     description: format!("Explore approach {} for {}", i + 1, task.description),
-                    assigned_agent: agent.id.clone(),
+                    assigned_agent: String::new(),
                     status: TaskStatus::Pending,
                     result: None,
+                    attempts: 0,
+                    last_error: None,
+                    depends_on: Vec::new(),
+                    inputs: Vec::new(),
+                    required_agent_type: Some(agent.agent_type.clone()),
+                    required_capabilities: agent.capabilities.clone(),
+                    order: 0,
+                });
+            }
+
+            if let Some(aggregator) = agents.first() {
+                subtasks.push(SubTask {
+                    id: Uuid::new_v4().to_string(),
+                    description: format!("Converge on best approach for {}", task.description),
+                    assigned_agent: String::new(),
+                    status: TaskStatus::Pending,
+                    result: None,
+                    attempts: 0,
+                    last_error: None,
+                    depends_on: explorer_ids,
+                    inputs: Vec::new(),
+                    required_agent_type: Some(aggregator.agent_type.clone()),
+                    required_capabilities: aggregator.capabilities.clone(),
+                    order: 0,
                 });
             }
         }
         OrchestrationStrategy::Consensus => {
-            // All agents work on the same task
+            // All agents work on the same task independently; consensus is
+            // built from their results afterward, not as a DAG edge.
             for agent in agents {
                 subtasks.push(SubTask {
                     id: Uuid::new_v4().to_string(),
                     description: format!("Provide solution for: {}", task.description),
-                    assigned_agent: agent.id.clone(),
+                    assigned_agent: String::new(),
                     status: TaskStatus::Pending,
                     result: None,
+                    attempts: 0,
+                    last_error: None,
+                    depends_on: Vec::new(),
+                    inputs: Vec::new(),
+                    required_agent_type: Some(agent.agent_type.clone()),
+                    required_capabilities: agent.capabilities.clone(),
+                    order: 0,
                 });
             }
         }
     }
 
+    topological_order(&subtasks).context("Invalid subtask graph")?;
+
+    // Priority order starts out matching decomposition order; an operator
+    // can move entries around later via `reprioritize_subtasks`.
+    for (i, subtask) in subtasks.iter_mut().enumerate() {
+        subtask.order = i;
+    }
+
     Ok(subtasks)
 }
 
-async fn execute_with_monitoring(
-    mut task: Task,
-    mut subtasks: Vec<SubTask>,
-    agents: Vec<crate::commands::spawn::Agent>,
-    config: &Config,
-    output: &OutputHandler,
+/// Where `reprioritize_subtasks` places a subtask relative to another.
+#[derive(Debug, Clone)]
+pub enum PriorityPlacement {
+    Before(String),
+    After(String),
+}
+
+/// Move `subtask_id` to sit immediately before/after another subtask in
+/// priority order, then renumber every subtask's `order` field 0..n to
+/// match its new position. Applied to a full in-memory copy of the list and
+/// only written back on success, so a bad reference (`subtask_id` or the
+/// relative id not found) leaves the original order untouched rather than
+/// partially renumbering it.
+pub(crate) fn reprioritize_subtasks(
+    subtasks: &mut [SubTask],
+    subtask_id: &str,
+    placement: PriorityPlacement,
 ) -> Result<()> {
-    output.section("Executing Task");
+    let mut ordered: Vec<SubTask> = subtasks.to_vec();
+    ordered.sort_by_key(|st| st.order);
 
-    task.started_at = Some(Utc::now());
-    task.status = TaskStatus::Running;
-    task.subtasks = subtasks.clone();
+    let current_index = ordered
+        .iter()
+        .position(|st| st.id == subtask_id)
+        .with_context(|| format!("No subtask with id '{subtask_id}' in this task"))?;
+    let moved = ordered.remove(current_index);
 
-    // Create progress bar
-    let progress = output.progress_bar(subtasks.len() as u64, "Executing subtasks");
+    let (relative_id, offset) = match &placement {
+        PriorityPlacement::Before(id) => (id, 0),
+        PriorityPlacement::After(id) => (id, 1),
+    };
+    let relative_index = ordered
+        .iter()
+        .position(|st| &st.id == relative_id)
+        .with_context(|| format!("No subtask with id '{relative_id}' in this task"))?;
+    ordered.insert(relative_index + offset, moved);
 
-    // Execute based on strategy
-    match &task.strategy {
-        OrchestrationStrategy::Parallel => {
-            // Execute all subtasks in parallel
-            let mut handles = Vec::new();
+    for (i, subtask) in ordered.iter_mut().enumerate() {
+        subtask.order = i;
+    }
 
-            for subtask in &mut subtasks {
-                let subtask_clone = subtask.clone();
-                let agent = agents
-                    .iter()
-                    .find(|a| a.id == subtask.assigned_agent)
-                    .cloned();
+    for subtask in subtasks.iter_mut() {
+        if let Some(updated) = ordered.iter().find(|st| st.id == subtask.id) {
+            subtask.order = updated.order;
+        }
+    }
 
-                if let Some(agent) = agent {
-                    let handle =
-                        tokio::spawn(async move { execute_subtask(subtask_clone, agent).await });
-                    handles.push((subtask.id.clone(), handle));
+    Ok(())
+}
+
+/// Kahn's algorithm over `depends_on` edges, both to detect cycles (an
+/// error at decomposition time rather than a scheduler that hangs forever)
+/// and to give the DAG scheduler a valid execution order.
+fn topological_order(subtasks: &[SubTask]) -> Result<Vec<String>> {
+    let mut in_degree: HashMap<&str, usize> = subtasks.iter().map(|st| (st.id.as_str(), 0)).collect();
+    for subtask in subtasks {
+        for dep in &subtask.depends_on {
+            if let Some(count) = in_degree.get_mut(subtask.id.as_str()) {
+                if subtasks.iter().any(|st| &st.id == dep) {
+                    *count += 1;
                 }
             }
+        }
+    }
 
-            // Wait for all to complete
-            for (subtask_id, handle) in handles {
-                match handle.await {
-                    Ok(Ok(result)) => {
-                        if let Some(subtask) = subtasks.iter_mut().find(|s| s.id == subtask_id) {
-                            subtask.status = TaskStatus::Completed;
-                            subtask.result = Some(result.clone());
-                            task.results.push(result);
-                        }
-                    }
-                    _ => {
-                        if let Some(subtask) = subtasks.iter_mut().find(|s| s.id == subtask_id) {
-                            subtask.status = TaskStatus::Failed("Execution error".to_string());
-                        }
+    let mut ready: VecDeque<&str> = in_degree
+        .iter()
+        .filter(|(_, degree)| **degree == 0)
+        .map(|(id, _)| *id)
+        .collect();
+    let mut order = Vec::with_capacity(subtasks.len());
+
+    while let Some(id) = ready.pop_front() {
+        order.push(id.to_string());
+        for subtask in subtasks {
+            if subtask.depends_on.iter().any(|dep| dep == id) {
+                if let Some(count) = in_degree.get_mut(subtask.id.as_str()) {
+                    *count -= 1;
+                    if *count == 0 {
+                        ready.push_back(subtask.id.as_str());
                     }
                 }
-
-                if let Some(pb) = &progress {
-                    pb.inc(1);
-                }
             }
         }
-        OrchestrationStrategy::Sequential => {
-            // Execute subtasks one by one
-            for subtask in &mut subtasks {
-                let agent = agents
-                    .iter()
-                    .find(|a| a.id == subtask.assigned_agent)
-                    .cloned();
-
-                if let Some(agent) = agent {
-                    match execute_subtask(subtask.clone(), agent).await {
-                        Ok(result) => {
-                            subtask.status = TaskStatus::Completed;
-                            subtask.result = Some(result.clone());
-                            task.results.push(result);
-                        }
-                        Err(e) => {
-                            subtask.status = TaskStatus::Failed(e.to_string());
-                            // Stop on first failure in sequential mode
-                            break;
-                        }
-                    }
-                }
+    }
 
-                if let Some(pb) = &progress {
-                    pb.inc(1);
-                }
-            }
+    if order.len() != subtasks.len() {
+        bail!("Subtask dependency graph contains a cycle");
+    }
+
+    Ok(order)
+}
+
+/// Longest chain of dependency edges in the subtask DAG -- the critical
+/// path length an otherwise-idle scheduler still has to wait out.
+fn critical_path_length(subtasks: &[SubTask]) -> usize {
+    fn depth<'a>(id: &'a str, subtasks: &'a [SubTask], memo: &mut HashMap<&'a str, usize>) -> usize {
+        if let Some(&cached) = memo.get(id) {
+            return cached;
         }
-        OrchestrationStrategy::Adaptive => {
-            // Execute exploration phase first
-            let exploration_count = 3.min(subtasks.len());
-            let mut best_approach = None;
+        let Some(subtask) = subtasks.iter().find(|st| st.id == id) else {
+            return 0;
+        };
+        let depth_here = 1 + subtask
+            .depends_on
+            .iter()
+            .map(|dep| depth(dep, subtasks, memo))
+            .max()
+            .unwrap_or(0);
+        memo.insert(id, depth_here);
+        depth_here
+    }
 
-            for subtask in subtasks.iter_mut().take(exploration_count) {
-                let agent = agents
-                    .iter()
-                    .find(|a| a.id == subtask.assigned_agent)
-                    .cloned();
+    let mut memo = HashMap::new();
+    subtasks
+        .iter()
+        .map(|st| depth(&st.id, subtasks, &mut memo))
+        .max()
+        .unwrap_or(0)
+}
 
-                if let Some(agent) = agent {
-                    if let Ok(result) = execute_subtask(subtask.clone(), agent).await {
-                        subtask.status = TaskStatus::Completed;
-                        subtask.result = Some(result.clone());
-                        task.results.push(result);
+/// Score every agent not already reserved by this pass against a subtask's
+/// requirements and return the best match. An exact `required_agent_type`
+/// match always outranks capability overlap alone, but a subtask without a
+/// compatible type still gets the agent sharing the most capability tags
+/// rather than starving -- Ballista's task-first scheduling still has to
+/// place a task somewhere when no executor is a perfect fit.
+pub(crate) fn select_agent<'a>(
+    subtask: &SubTask,
+    agents: &'a [crate::commands::spawn::Agent],
+    reserved: &std::collections::HashSet<String>,
+) -> Option<&'a crate::commands::spawn::Agent> {
+    agents
+        .iter()
+        .filter(|agent| !reserved.contains(&agent.id))
+        .max_by_key(|agent| {
+            let type_match = subtask.required_agent_type.as_deref() == Some(agent.agent_type.as_str());
+            let capability_overlap = subtask
+                .required_capabilities
+                .iter()
+                .filter(|cap| agent.capabilities.contains(cap))
+                .count();
+            (type_match, capability_overlap)
+        })
+}
 
-                        // Determine best approach (simplified)
-                        if best_approach.is_none() {
-                            best_approach = Some(subtask.id.clone());
-                        }
-                    }
-                }
+/// Drive `subtasks` to completion as a Ballista-style staged execution
+/// graph: each pass launches every `Pending` subtask whose `depends_on` are
+/// all `Completed`, running that ready set concurrently via `tokio::spawn`,
+/// then waits for the batch before computing the next ready set. A subtask
+/// downstream of a dependency that will never complete (`Failed`,
+/// `Timeout`, `Cancelled`) is marked `Failed` without ever running.
+///
+/// Agent binding is task-first rather than positional: a ready subtask
+/// without an `assigned_agent` yet reserves the best-matching free agent
+/// for `select_agent`, and a subtask that finds every compatible agent
+/// already reserved by an earlier subtask in the same ready batch is left
+/// queued (still `Pending`) for the next pass instead of oversubscribing
+/// that agent.
+async fn run_dag(
+    task: &mut Task,
+    subtasks: &mut [SubTask],
+    agents: &[crate::commands::spawn::Agent],
+    retry_config: &RetryConfig,
+    progress: Option<&ProgressBar>,
+    workers: &WorkerManager,
+    cache: &JobCache,
+) {
+    let mut reserved: std::collections::HashSet<String> = subtasks
+        .iter()
+        .filter(|st| matches!(st.status, TaskStatus::Running))
+        .map(|st| st.assigned_agent.clone())
+        .collect();
 
-                if let Some(pb) = &progress {
+    loop {
+        workers.apply_pending_controls().await;
+        let statuses: HashMap<String, TaskStatus> = subtasks
+            .iter()
+            .map(|st| (st.id.clone(), st.status.clone()))
+            .collect();
+
+        // Sorted by priority `order` so a subtask promoted ahead of the
+        // backlog claims a free agent before lower-priority ready subtasks
+        // in the same pass, while still never blocking on one that isn't
+        // ready yet.
+        let mut ready: Vec<&SubTask> = subtasks
+            .iter()
+            .filter(|st| matches!(st.status, TaskStatus::Pending))
+            .filter(|st| {
+                st.depends_on
+                    .iter()
+                    .all(|dep| matches!(statuses.get(dep), Some(TaskStatus::Completed)))
+            })
+            .collect();
+        ready.sort_by_key(|st| st.order);
+        let ready_ids: Vec<String> = ready.into_iter().map(|st| st.id.clone()).collect();
+
+        let mut blocked_any = false;
+        for subtask in subtasks.iter_mut() {
+            if !matches!(subtask.status, TaskStatus::Pending) {
+                continue;
+            }
+            let blocked = subtask.depends_on.iter().any(|dep| {
+                matches!(
+                    statuses.get(dep),
+                    Some(TaskStatus::Failed(_)) | Some(TaskStatus::Timeout) | Some(TaskStatus::Cancelled)
+                )
+            });
+            if blocked {
+                subtask.status = TaskStatus::Failed("Blocked by failed dependency".to_string());
+                blocked_any = true;
+                if let Some(pb) = progress {
                     pb.inc(1);
                 }
             }
+        }
 
-            // Execute remaining tasks with best approach
-            // (Simplified - in real implementation would adapt based on results)
-            for subtask in subtasks.iter_mut().skip(exploration_count) {
-                let agent = agents
-                    .iter()
-                    .find(|a| a.id == subtask.assigned_agent)
-                    .cloned();
+        if ready_ids.is_empty() {
+            if blocked_any {
+                continue;
+            }
+            break;
+        }
 
-                if let Some(agent) = agent {
-                    if let Ok(result) = execute_subtask(subtask.clone(), agent).await {
-                        subtask.status = TaskStatus::Completed;
-                        subtask.result = Some(result.clone());
-                        task.results.push(result);
-                    }
+        // Feed each completed dependency's output into the dependent
+        // subtask's input payload, then reserve a matching free agent
+        // before launching it. A subtask that finds no compatible agent
+        // free this pass is left Pending and queued for the next one.
+        let mut handles = Vec::new();
+        for id in &ready_ids {
+            let inputs: Vec<serde_json::Value> = subtasks
+                .iter()
+                .find(|st| &st.id == id)
+                .into_iter()
+                .flat_map(|st| st.depends_on.iter())
+                .filter_map(|dep| subtasks.iter().find(|st| &st.id == dep))
+                .filter_map(|st| st.result.as_ref().map(|r| r.output.clone()))
+                .collect();
+
+            if let Some(mut cached) = subtasks
+                .iter()
+                .find(|st| &st.id == id)
+                .and_then(|st| cache.lookup(st, &inputs))
+            {
+                cached.cached = true;
+                if let Some(subtask) = subtasks.iter_mut().find(|st| &st.id == id) {
+                    subtask.inputs = inputs;
+                    subtask.status = TaskStatus::Completed;
+                    subtask.result = Some(cached.clone());
                 }
-
-                if let Some(pb) = &progress {
+                task.results.push(cached);
+                if let Some(pb) = progress {
                     pb.inc(1);
                 }
+                continue;
             }
+
+            let agent = match subtasks.iter().find(|st| &st.id == id) {
+                Some(st) if !st.assigned_agent.is_empty() => {
+                    agents.iter().find(|a| a.id == st.assigned_agent).cloned()
+                }
+                Some(st) => select_agent(st, agents, &reserved).cloned(),
+                None => None,
+            };
+
+            let Some(agent) = agent else {
+                // No compatible agent currently free; leave this subtask
+                // queued (still Pending) for a later pass.
+                continue;
+            };
+            reserved.insert(agent.id.clone());
+
+            let Some(subtask) = subtasks.iter_mut().find(|st| &st.id == id) else {
+                continue;
+            };
+            subtask.inputs = inputs;
+            subtask.assigned_agent = agent.id.clone();
+            subtask.status = TaskStatus::Running;
+
+            let mut subtask_clone = subtask.clone();
+            let retry_config = *retry_config;
+            let agent_id = agent.id.clone();
+            let (worker_id, mut control_rx) = workers.register(&task.id, id, &agent_id).await;
+            let handle = tokio::spawn(async move {
+                let result = tokio::select! {
+                    result = execute_subtask_with_retry(&mut subtask_clone, &agent, &retry_config) => result,
+                    _ = wait_for_cancel(&mut control_rx) => {
+                        Err(anyhow::anyhow!("Worker cancelled"))
+                    }
+                };
+                (subtask_clone, result)
+            });
+            handles.push((id.clone(), agent_id, worker_id, handle));
         }
-        OrchestrationStrategy::Consensus => {
-            // Execute all subtasks and build consensus
-            let mut all_results = Vec::new();
 
-            for subtask in &mut subtasks {
-                let agent = agents
-                    .iter()
-                    .find(|a| a.id == subtask.assigned_agent)
-                    .cloned();
+        if handles.is_empty() {
+            // Every ready subtask is missing its agent right now; nothing
+            // more can be done in this run.
+            break;
+        }
 
-                if let Some(agent) = agent {
-                    if let Ok(result) = execute_subtask(subtask.clone(), agent).await {
+        for (id, agent_id, worker_id, handle) in handles {
+            match handle.await {
+                Ok((retried, Ok(result))) => {
+                    let _ = cache.store(&retried, &retried.inputs, &result);
+                    if let Some(subtask) = subtasks.iter_mut().find(|st| st.id == id) {
+                        subtask.attempts = retried.attempts;
+                        subtask.last_error = retried.last_error;
+                        subtask.inputs = retried.inputs;
                         subtask.status = TaskStatus::Completed;
                         subtask.result = Some(result.clone());
-                        all_results.push(result);
+                        task.results.push(result);
                     }
+                    workers.report(&worker_id, WorkerStatus::Idle, None).await;
                 }
-
-                if let Some(pb) = &progress {
-                    pb.inc(1);
+                Ok((retried, Err(err))) => {
+                    if let Some(subtask) = subtasks.iter_mut().find(|st| st.id == id) {
+                        subtask.attempts = retried.attempts;
+                        subtask.last_error = retried.last_error;
+                        subtask.inputs = retried.inputs;
+                        subtask.status = TaskStatus::Failed(err.to_string());
+                    }
+                    workers.report(&worker_id, WorkerStatus::Dead, Some(err.to_string())).await;
+                }
+                Err(_join_err) => {
+                    if let Some(subtask) = subtasks.iter_mut().find(|st| st.id == id) {
+                        subtask.status = TaskStatus::Failed("Execution error".to_string());
+                    }
+                    workers
+                        .report(&worker_id, WorkerStatus::Dead, Some("Execution error".to_string()))
+                        .await;
                 }
             }
 
-            // Build consensus result
-            if !all_results.is_empty() {
-                let consensus_result = build_consensus(&all_results);
-                task.results.push(consensus_result);
+            // Release the reservation now that execute_subtask_with_retry
+            // has returned, so a later pass can bind this agent again.
+            reserved.remove(&agent_id);
+
+            if let Some(pb) = progress {
+                pb.inc(1);
             }
         }
     }
+}
+
+/// Wait for a `Cancel` control message, ignoring `Start`/`Pause` (there's no
+/// mid-retry pause hook in `execute_subtask_with_retry`, so `Pause` only
+/// prevents a *future* pass from picking this subtask back up once it's
+/// re-queued). Returns when the channel is closed too, so a worker whose
+/// manager was dropped doesn't wait forever.
+async fn wait_for_cancel(rx: &mut mpsc::Receiver<WorkerControl>) {
+    loop {
+        match rx.recv().await {
+            Some(WorkerControl::Cancel) => return,
+            Some(_) => continue,
+            None => return,
+        }
+    }
+}
+
+async fn execute_with_monitoring(
+    mut task: Task,
+    mut subtasks: Vec<SubTask>,
+    agents: Vec<crate::commands::spawn::Agent>,
+    config: &Config,
+    output: &OutputHandler,
+    repo: &dyn Repository,
+) -> Result<()> {
+    output.section("Executing Task");
+
+    task.started_at = Some(Utc::now());
+    task.status = TaskStatus::Running;
+    task.subtasks = subtasks.clone();
+
+    let retry_config = RetryConfig::from_max_attempts(task.max_retries);
+
+    // Create progress bar
+    let progress = output.progress_bar(subtasks.len() as u64, "Executing subtasks");
+
+    // Tracks every subtask's worker for the lifetime of this run; see
+    // `ruv-swarm workers` for listing/pause/cancel against its snapshot.
+    let workers = WorkerManager::new(&tasks_dir()?);
+    let cache = JobCache::with_default_ttl(&tasks_dir()?);
+
+    // Run the topological scheduler: every pass launches all subtasks whose
+    // dependencies are Completed, concurrently, and waits for that batch
+    // before looking for the next ready set. This replaces the old
+    // strategy-specific linear loops with one DAG-aware executor that
+    // handles Parallel (no edges -> everything ready at once), Sequential
+    // (a straight chain), and Adaptive (explorers converge into one
+    // dependent aggregation node) uniformly.
+    run_dag(
+        &mut task,
+        &mut subtasks,
+        &agents,
+        &retry_config,
+        progress.as_ref(),
+        &workers,
+        &cache,
+    )
+    .await;
+
+    let mut quorum_failed = false;
+    if matches!(task.strategy, OrchestrationStrategy::Consensus) {
+        let all_results: Vec<TaskResult> = subtasks.iter().filter_map(|s| s.result.clone()).collect();
+        if !all_results.is_empty() {
+            let consensus_result = build_consensus_or_script(&all_results, &agents, task.consensus_rule, task.quorum);
+            quorum_failed = !consensus_result.success;
+            task.results.push(consensus_result);
+        }
+    }
 
     if let Some(pb) = progress {
         pb.finish_with_message("Task execution complete");
@@ -580,7 +1137,9 @@ async fn execute_with_monitoring(
 
     // Update task status
     task.completed_at = Some(Utc::now());
-    task.status = if subtasks
+    task.status = if quorum_failed {
+        TaskStatus::Failed("Consensus quorum not met".to_string())
+    } else if subtasks
         .iter()
         .any(|s| matches!(s.status, TaskStatus::Failed(_)))
     {
@@ -590,7 +1149,10 @@ async fn execute_with_monitoring(
     };
 
     // Save task results
-    save_task_results(&task, output).await?;
+    repo.save_results(&task).await?;
+    output.info(&format!("Results saved for task {}", task.id));
+
+    fire_notifications(&task, &subtasks, output).await;
 
     // Display results
     display_task_results(&task, &subtasks, output);
@@ -598,19 +1160,75 @@ async fn execute_with_monitoring(
     Ok(())
 }
 
+/// Run `task` through the same decompose → DAG-execute → consensus path as
+/// `execute_with_monitoring`, without any CLI progress output, then persist
+/// it. Used by `scheduler::Scheduler`'s tick loop to fire a due scheduled or
+/// recurring orchestration the same way an interactive `orchestrate run`
+/// would.
+pub(crate) async fn run_to_completion(
+    mut task: Task,
+    agents: &[crate::commands::spawn::Agent],
+    repo: &dyn Repository,
+) -> Result<Task> {
+    let mut subtasks = decompose_task(&task, agents, &task.strategy.clone()).await?;
+
+    task.started_at = Some(Utc::now());
+    task.status = TaskStatus::Running;
+    task.subtasks = subtasks.clone();
+
+    let retry_config = RetryConfig::from_max_attempts(task.max_retries);
+    let workers = WorkerManager::new(&tasks_dir()?);
+    let cache = JobCache::with_default_ttl(&tasks_dir()?);
+    run_dag(&mut task, &mut subtasks, agents, &retry_config, None, &workers, &cache).await;
+
+    let mut quorum_failed = false;
+    if matches!(task.strategy, OrchestrationStrategy::Consensus) {
+        let all_results: Vec<TaskResult> = subtasks.iter().filter_map(|s| s.result.clone()).collect();
+        if !all_results.is_empty() {
+            let consensus_result = build_consensus_or_script(&all_results, agents, task.consensus_rule, task.quorum);
+            quorum_failed = !consensus_result.success;
+            task.results.push(consensus_result);
+        }
+    }
+
+    task.subtasks = subtasks.clone();
+    task.completed_at = Some(Utc::now());
+    task.status = if quorum_failed {
+        TaskStatus::Failed("Consensus quorum not met".to_string())
+    } else if subtasks
+        .iter()
+        .any(|s| matches!(s.status, TaskStatus::Failed(_)))
+    {
+        TaskStatus::Failed("Some subtasks failed".to_string())
+    } else {
+        TaskStatus::Completed
+    };
+
+    // No `OutputHandler` on this path (it's driven by `Scheduler::tick_scheduled`,
+    // not an interactive command), so delivery warnings have nowhere to go --
+    // dropped the same way a webhook failure shouldn't fail an already-finished run.
+    let config = NotifierConfig::load(&notifier_config_dir()).unwrap_or_default();
+    let event = build_notification_event(&task, &subtasks);
+    let _ = notifier::notify_task_completed(&config, &event).await;
+
+    repo.update_task_status(&task).await?;
+    Ok(task)
+}
+
 async fn execute_background(
     mut task: Task,
     subtasks: Vec<SubTask>,
     agents: Vec<crate::commands::spawn::Agent>,
     config: &Config,
     output: &OutputHandler,
+    repo: &dyn Repository,
 ) -> Result<()> {
     task.started_at = Some(Utc::now());
     task.status = TaskStatus::Running;
     task.subtasks = subtasks;
 
     // Save task for background execution
-    save_task(&task).await?;
+    repo.insert_task(&task).await?;
 
     output.success(&format!(
         "Task '{}' submitted for background execution",
@@ -622,6 +1240,7 @@ async fn execute_background(
         &[
             format!("ruv-swarm monitor --filter task:{}", task.id),
             format!("ruv-swarm status --detailed"),
+            format!("ruv-swarm orchestrate resume {}", task.id),
         ],
         false,
     );
@@ -629,6 +1248,195 @@ async fn execute_background(
     Ok(())
 }
 
+/// `ruv-swarm orchestrate resume <task_id>`: the durable counterpart to
+/// `execute_background` for a process that exited mid-run. Reloads the
+/// saved task and hands it to `scheduler::Scheduler`, which re-dispatches
+/// every subtask that isn't `Completed` and parks any whose agent isn't
+/// currently available instead of failing it outright.
+pub async fn execute_resume(output: &OutputHandler, task_id: &str) -> Result<()> {
+    output.section("Resuming Task");
+
+    let repo = repository::open_default(repository::RepositoryBackend::default(), &tasks_dir()?).await?;
+    let task = repo.get_task(task_id).await?;
+    let swarm_config = require_current_swarm(repo.as_ref(), output).await?;
+    let agents = repo.list_available_agents(&swarm_config).await?;
+
+    let scheduler = crate::scheduler::Scheduler::new(tasks_dir()?);
+    let outcome = scheduler.resume_task(task, &agents).await?;
+
+    output.success(&format!(
+        "Task '{}' resumed: {} completed, {} failed, {} still waiting for an agent",
+        task_id, outcome.completed, outcome.failed, outcome.pending_rebind
+    ));
+
+    Ok(())
+}
+
+/// `ruv-swarm orchestrate cancel <id>`: remove a scheduled or recurring run
+/// before it fires. Has no effect on a `Task` that's already running or
+/// completed -- those are cancelled the same way any in-flight task would
+/// be, not through this.
+pub async fn execute_cancel_scheduled(output: &OutputHandler, id: &str) -> Result<()> {
+    let scheduler = crate::scheduler::Scheduler::new(tasks_dir()?);
+    if scheduler.cancel_scheduled(id)? {
+        output.success(&format!("Cancelled scheduled run '{id}'"));
+    } else {
+        output.warning(&format!("No scheduled run found with id '{id}'"));
+    }
+    Ok(())
+}
+
+/// `ruv-swarm orchestrate schedule list`: print every scheduled or
+/// recurring run, due or not.
+pub async fn execute_list_scheduled(output: &OutputHandler) -> Result<()> {
+    let scheduler = crate::scheduler::Scheduler::new(tasks_dir()?);
+    let entries = scheduler.list_scheduled()?;
+
+    if entries.is_empty() {
+        output.info("No scheduled runs.");
+        return Ok(());
+    }
+
+    output.section("Scheduled Runs");
+    for entry in &entries {
+        output.key_value(&[
+            ("ID".to_string(), entry.id.clone()),
+            ("Description".to_string(), entry.description.clone()),
+            ("Trigger".to_string(), format!("{:?}", entry.trigger)),
+            ("Enabled".to_string(), entry.enabled.to_string()),
+            ("Next Run".to_string(), entry.next_run.to_rfc3339()),
+            (
+                "Last Run".to_string(),
+                entry
+                    .last_run
+                    .map(|t| t.to_rfc3339())
+                    .unwrap_or_else(|| "never".to_string()),
+            ),
+        ]);
+    }
+
+    Ok(())
+}
+
+/// `ruv-swarm orchestrate schedule enable/disable <id>`: toggle a scheduled
+/// run without losing its trigger/template.
+pub async fn execute_set_scheduled_enabled(output: &OutputHandler, id: &str, enabled: bool) -> Result<()> {
+    let scheduler = crate::scheduler::Scheduler::new(tasks_dir()?);
+    if scheduler.set_scheduled_enabled(id, enabled)? {
+        let verb = if enabled { "Enabled" } else { "Disabled" };
+        output.success(&format!("{verb} scheduled run '{id}'"));
+    } else {
+        output.warning(&format!("No scheduled run found with id '{id}'"));
+    }
+    Ok(())
+}
+
+/// `ruv-swarm orchestrate reprioritize <task> <subtask> --before/--after <other>`:
+/// move a still-queued subtask relative to another within the same task and
+/// persist the renumbered order. Only affects subtasks that haven't been
+/// dispatched yet -- `run_dag` already holds its own in-memory copy of a
+/// `Running` subtask's order for the pass it's mid-execution in.
+pub async fn execute_reprioritize(
+    output: &OutputHandler,
+    task_id: &str,
+    subtask_id: &str,
+    placement: PriorityPlacement,
+) -> Result<()> {
+    let repo = repository::open_default(repository::RepositoryBackend::default(), &tasks_dir()?).await?;
+    let mut task = repo.get_task(task_id).await?;
+    reprioritize_subtasks(&mut task.subtasks, subtask_id, placement)?;
+    repo.update_task_status(&task).await?;
+    output.success(&format!("Reprioritized subtask '{subtask_id}' in task '{task_id}'"));
+    Ok(())
+}
+
+/// Scan every saved task on startup and resume the ones left `Running` or
+/// `Pending` by a process that exited mid-execution.
+pub async fn recover_interrupted_tasks(output: &OutputHandler) -> Result<Vec<String>> {
+    let repo = repository::open_default(repository::RepositoryBackend::default(), &tasks_dir()?).await?;
+    let swarm_config = require_current_swarm(repo.as_ref(), output).await?;
+    let agents = repo.list_available_agents(&swarm_config).await?;
+
+    let scheduler = crate::scheduler::Scheduler::new(tasks_dir()?);
+    scheduler.recover_interrupted(&agents).await
+}
+
+/// Bounds the retry loop around `execute_subtask`, modeled on Ballista's
+/// task-level retry: each attempt after the first waits
+/// `base_delay * 2^attempt`, capped at `max_delay`, with optional jitter so
+/// retrying subtasks don't all wake up in lockstep.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RetryConfig {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+    jitter: bool,
+}
+
+impl RetryConfig {
+    pub(crate) fn from_max_attempts(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+        }
+    }
+
+    /// Delay before the attempt numbered `attempt` (0-based: the delay
+    /// before the *second* attempt overall is `delay_for(0)`).
+    fn delay_for(&self, attempt: u32) -> Duration {
+        let exp_delay = self
+            .base_delay
+            .checked_mul(1u32.checked_shl(attempt).unwrap_or(u32::MAX))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        if self.jitter {
+            let jitter_ms = (exp_delay.as_millis() as u64 / 4).max(1);
+            exp_delay + Duration::from_millis(rand::thread_rng().gen_range(0..jitter_ms))
+        } else {
+            exp_delay
+        }
+    }
+}
+
+/// Transient failures (timeouts, agent-side hiccups) are worth retrying;
+/// anything else is a permanent failure that short-circuits the retry loop.
+fn is_retriable(err: &anyhow::Error) -> bool {
+    let message = err.to_string().to_lowercase();
+    message.contains("timeout") || message.contains("transient") || message.contains("temporarily")
+}
+
+/// Run `execute_subtask` in a loop bounded by `retry.max_attempts`, sleeping
+/// with exponential backoff between tries. Updates `subtask.attempts` and
+/// `subtask.last_error` on every attempt, and only returns `Err` once every
+/// attempt is exhausted or a permanent error is hit.
+pub(crate) async fn execute_subtask_with_retry(
+    subtask: &mut SubTask,
+    agent: &crate::commands::spawn::Agent,
+    retry: &RetryConfig,
+) -> Result<TaskResult> {
+    let mut attempt = 0u32;
+    loop {
+        attempt += 1;
+        subtask.attempts = attempt;
+
+        match execute_subtask(subtask.clone(), agent.clone()).await {
+            Ok(result) => return Ok(result),
+            Err(err) => {
+                subtask.last_error = Some(err.to_string());
+
+                if attempt >= retry.max_attempts || !is_retriable(&err) {
+                    return Err(err);
+                }
+
+                tokio::time::sleep(retry.delay_for(attempt - 1)).await;
+            }
+        }
+    }
+}
+
 async fn execute_subtask(
     subtask: SubTask,
     agent: crate::commands::spawn::Agent,
@@ -656,58 +1464,209 @@ async fn execute_subtask(
             "description": subtask.description,
             "agent_type": agent.agent_type,
             "execution_time_ms": execution_time,
-            "result": "Simulated result"
+            "result": "Simulated result",
+            "inputs": subtask.inputs,
         }),
         execution_time_ms: execution_time,
         timestamp: Utc::now(),
+        cached: false,
     })
 }
 
-fn build_consensus(results: &[TaskResult]) -> TaskResult {
-    // Simplified consensus building
+/// Build a consensus `TaskResult` from every agent's subtask output:
+/// group structurally-equal outputs into candidate buckets, pick a winner
+/// per `rule`, and record the vote distribution, agreement ratio, and
+/// dissenting agents alongside the chosen output. `success` is `false`
+/// (quorum not met) when the winning bucket's agreement ratio falls below
+/// `quorum` -- the caller marks the task `Failed` in that case instead of
+/// silently accepting a minority result.
+fn build_consensus(
+    results: &[TaskResult],
+    agents: &[crate::commands::spawn::Agent],
+    rule: ConsensusRule,
+    quorum: f64,
+) -> TaskResult {
+    let mut buckets: Vec<(serde_json::Value, Vec<&TaskResult>)> = Vec::new();
+    for result in results {
+        match buckets.iter_mut().find(|(output, _)| output == &result.output) {
+            Some((_, members)) => members.push(result),
+            None => buckets.push((result.output.clone(), vec![result])),
+        }
+    }
+
+    let agent_weight = |agent_id: &str| -> f64 {
+        agents
+            .iter()
+            .find(|a| a.id == agent_id)
+            .map(|a| {
+                let total = a.metrics.tasks_completed + a.metrics.tasks_failed;
+                if total == 0 {
+                    1.0
+                } else {
+                    a.metrics.tasks_completed as f64 / total as f64
+                }
+            })
+            .unwrap_or(1.0)
+    };
+
+    let winner_index = match rule {
+        ConsensusRule::Majority | ConsensusRule::Unanimous => buckets
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (_, members))| members.len())
+            .map(|(i, _)| i),
+        ConsensusRule::Weighted => buckets
+            .iter()
+            .enumerate()
+            .max_by(|(_, (_, a)), (_, (_, b))| {
+                let weight = |members: &[&TaskResult]| -> f64 {
+                    members.iter().map(|r| agent_weight(&r.agent_id)).sum()
+                };
+                weight(a).total_cmp(&weight(b))
+            })
+            .map(|(i, _)| i),
+    };
+
+    let Some(winner_index) = winner_index else {
+        // `results` is always non-empty at every call site, but fall back
+        // to an honest empty-consensus result rather than panicking.
+        return TaskResult {
+            agent_id: "consensus".to_string(),
+            subtask_id: None,
+            success: false,
+            output: serde_json::json!({"error": "No agent results to build consensus from"}),
+            execution_time_ms: 0,
+            timestamp: Utc::now(),
+            cached: false,
+        };
+    };
+
+    let (winning_output, winning_members) = &buckets[winner_index];
+    let agreement_ratio = winning_members.len() as f64 / results.len() as f64;
+    let quorum_met = match rule {
+        ConsensusRule::Unanimous => buckets.len() == 1,
+        ConsensusRule::Majority | ConsensusRule::Weighted => agreement_ratio >= quorum,
+    };
+
+    let vote_distribution: Vec<serde_json::Value> = buckets
+        .iter()
+        .map(|(output, members)| {
+            serde_json::json!({
+                "output": output,
+                "votes": members.len(),
+                "agents": members.iter().map(|r| r.agent_id.clone()).collect::<Vec<_>>(),
+            })
+        })
+        .collect();
+
+    let dissenting_agents: Vec<String> = results
+        .iter()
+        .filter(|r| r.output != *winning_output)
+        .map(|r| r.agent_id.clone())
+        .collect();
+
     TaskResult {
         agent_id: "consensus".to_string(),
         subtask_id: None,
-        success: true,
+        success: quorum_met,
         output: serde_json::json!({
-            "consensus": "Combined result from all agents",
+            "consensus": winning_output,
+            "rule": format!("{rule:?}"),
             "agent_count": results.len(),
-            "agreement_level": 0.85
+            "agreement_ratio": agreement_ratio,
+            "quorum": quorum,
+            "quorum_met": quorum_met,
+            "vote_distribution": vote_distribution,
+            "dissenting_agents": dissenting_agents,
         }),
         execution_time_ms: results.iter().map(|r| r.execution_time_ms).sum::<u64>()
             / results.len() as u64,
         timestamp: Utc::now(),
+        cached: false,
+    }
+}
+
+/// Build the consensus result for a `Consensus`-strategy task: try the
+/// operator's Lua aggregation script (`notifier_config_dir()/aggregation.json`)
+/// first, falling back to the built-in `build_consensus` rule engine when no
+/// script is configured or the script fails to load/run. A script error is
+/// not fatal to the task -- it just means this run used the built-in rules
+/// instead of the custom ones.
+fn build_consensus_or_script(
+    results: &[TaskResult],
+    agents: &[crate::commands::spawn::Agent],
+    rule: ConsensusRule,
+    quorum: f64,
+) -> TaskResult {
+    let config = AggregationConfig::load(&notifier_config_dir()).unwrap_or_default();
+    if let Ok(Some(script)) = LuaScript::load(&config) {
+        if let Ok(result) = script.aggregate(results, agents) {
+            return result;
+        }
     }
+    build_consensus(results, agents, rule, quorum)
 }
 
-async fn save_task(task: &Task) -> Result<()> {
-    let tasks_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+/// Directory saved `Task` records live in -- the same directory
+/// `scheduler::Scheduler` scans on startup to find interrupted tasks.
+pub(crate) fn tasks_dir() -> Result<std::path::PathBuf> {
+    let dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
         .map(|dirs| dirs.data_local_dir().join("tasks"))
         .unwrap_or_else(|| Path::new("./tasks").to_path_buf());
-
-    std::fs::create_dir_all(&tasks_dir)?;
-
-    let task_file = tasks_dir.join(format!("{}.json", task.id));
-    let content = serde_json::to_string_pretty(task)?;
-    std::fs::write(task_file, content)?;
-
-    Ok(())
+    std::fs::create_dir_all(&dir)?;
+    Ok(dir)
 }
 
-async fn save_task_results(task: &Task, output: &OutputHandler) -> Result<()> {
-    let results_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
-        .map(|dirs| dirs.data_local_dir().join("results"))
-        .unwrap_or_else(|| Path::new("./results").to_path_buf());
+/// Where `notifiers.json` lives -- next to `current-swarm.json`, not under
+/// `tasks_dir()` (which is a subdirectory of this).
+fn notifier_config_dir() -> std::path::PathBuf {
+    directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+        .map(|dirs| dirs.data_local_dir().to_path_buf())
+        .unwrap_or_else(|| Path::new(".").to_path_buf())
+}
 
-    std::fs::create_dir_all(&results_dir)?;
+fn build_notification_event(task: &Task, subtasks: &[SubTask]) -> NotificationEvent {
+    let duration_seconds = task
+        .completed_at
+        .zip(task.started_at)
+        .map(|(end, start)| (end - start).num_seconds());
+    let success_rate_percent = if task.results.is_empty() {
+        0.0
+    } else {
+        task.results.iter().filter(|r| r.success).count() as f32 / task.results.len() as f32 * 100.0
+    };
 
-    let result_file = results_dir.join(format!("{}.json", task.id));
-    let content = serde_json::to_string_pretty(task)?;
-    std::fs::write(&result_file, content)?;
+    NotificationEvent {
+        task_id: task.id.clone(),
+        status: format!("{:?}", task.status),
+        success: matches!(task.status, TaskStatus::Completed),
+        duration_seconds,
+        success_rate_percent,
+        subtasks_total: subtasks.len(),
+        subtasks_completed: subtasks
+            .iter()
+            .filter(|s| matches!(s.status, TaskStatus::Completed))
+            .count(),
+        timestamp: Utc::now(),
+    }
+}
 
-    output.info(&format!("Results saved to {:?}", result_file));
+/// Fire `notifier`'s configured backends for `task`'s outcome. Best-effort:
+/// a delivery failure is surfaced via `output.warning` rather than failing
+/// the orchestration that already completed.
+async fn fire_notifications(task: &Task, subtasks: &[SubTask], output: &OutputHandler) {
+    let config = match NotifierConfig::load(&notifier_config_dir()) {
+        Ok(config) => config,
+        Err(err) => {
+            output.warning(&format!("Failed to load notifier config: {err:#}"));
+            return;
+        }
+    };
 
-    Ok(())
+    let event = build_notification_event(task, subtasks);
+    for warning in notifier::notify_task_completed(&config, &event).await {
+        output.warning(&warning);
+    }
 }
 
 fn display_task_results(task: &Task, subtasks: &[SubTask], output: &OutputHandler) {
@@ -733,24 +1692,31 @@ fn display_task_results(task: &Task, subtasks: &[SubTask], output: &OutputHandle
                 .count()
                 .to_string(),
         ),
+        (
+            "Cache Hits".to_string(),
+            task.results.iter().filter(|r| r.cached).count().to_string(),
+        ),
     ]);
 
     if !task.results.is_empty() {
         output.section("Execution Summary");
 
-        let avg_time = task
-            .results
-            .iter()
-            .map(|r| r.execution_time_ms)
-            .sum::<u64>()
-            / task.results.len() as u64;
+        // Cache hits skipped the agent round-trip entirely; folding their
+        // (near-zero) execution time into the average would understate how
+        // long a fresh dispatch actually takes.
+        let fresh_results: Vec<&TaskResult> = task.results.iter().filter(|r| !r.cached).collect();
+        let avg_time = if fresh_results.is_empty() {
+            0
+        } else {
+            fresh_results.iter().map(|r| r.execution_time_ms).sum::<u64>() / fresh_results.len() as u64
+        };
         let success_rate = task.results.iter().filter(|r| r.success).count() as f32
             / task.results.len() as f32
             * 100.0;
 
         output.key_value(&[
             (
-                "Average Execution Time".to_string(),
+                "Average Execution Time (fresh)".to_string(),
                 format!("{}ms", avg_time),
             ),
             ("Success Rate".to_string(), format!("{:.1}%", success_rate)),
@@ -758,52 +1724,3 @@ fn display_task_results(task: &Task, subtasks: &[SubTask], output: &OutputHandle
     }
 }
 
-async fn load_current_swarm(output: &OutputHandler) -> Result<crate::commands::init::SwarmInit> {
-    let config_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
-        .map(|dirs| dirs.data_local_dir().to_path_buf())
-        .unwrap_or_else(|| Path::new(".").to_path_buf());
-
-    let current_file = config_dir.join("current-swarm.json");
-
-    if !current_file.exists() {
-        output.error("No active swarm found. Run 'ruv-swarm init' first.");
-        return Err(anyhow::anyhow!("No active swarm"));
-    }
-
-    let content = std::fs::read_to_string(current_file)?;
-    serde_json::from_str(&content).context("Failed to parse swarm configuration")
-}
-
-async fn get_available_agents(
-    swarm_config: &crate::commands::init::SwarmInit,
-) -> Result<Vec<crate::commands::spawn::Agent>> {
-    let agents_file = get_agents_file(swarm_config)?;
-
-    if agents_file.exists() {
-        let content = std::fs::read_to_string(&agents_file)?;
-        let agents: Vec<crate::commands::spawn::Agent> =
-            serde_json::from_str(&content).unwrap_or_default();
-
-        // Filter for available agents (Ready or Idle status)
-        Ok(agents
-            .into_iter()
-            .filter(|a| {
-                matches!(
-                    a.status,
-                    crate::commands::spawn::AgentStatus::Ready
-                        | crate::commands::spawn::AgentStatus::Idle
-                )
-            })
-            .collect())
-    } else {
-        Ok(Vec::new())
-    }
-}
-
-fn get_agents_file(swarm_config: &crate::commands::init::SwarmInit) -> Result<std::path::PathBuf> {
-    let config_dir = directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
-        .map(|dirs| dirs.data_local_dir().to_path_buf())
-        .unwrap_or_else(|| Path::new(".").to_path_buf());
-
-    Ok(config_dir.join(format!("agents-{}.json", swarm_config.swarm_id)))
-}
@@ -0,0 +1,63 @@
+//! `ruv-swarm workers`: list the in-flight (or last-snapshotted) subtask
+//! workers tracked by `worker_manager::WorkerManager`, and let an operator
+//! pause or cancel one by id.
+
+use anyhow::Result;
+
+use crate::commands::orchestrate::tasks_dir;
+use crate::output::OutputHandler;
+use crate::worker_manager::WorkerManager;
+
+/// `ruv-swarm workers`: print every tracked worker's status and last error.
+pub async fn execute(output: &OutputHandler) -> Result<()> {
+    let manager = WorkerManager::new(&tasks_dir()?);
+    let snapshot = manager.load_snapshot()?;
+
+    if snapshot.is_empty() {
+        output.info("No workers tracked yet.");
+        return Ok(());
+    }
+
+    output.section("Workers");
+    for worker in &snapshot {
+        output.key_value(&[
+            ("Worker".to_string(), worker.id.clone()),
+            ("Task".to_string(), worker.task_id.clone()),
+            ("Subtask".to_string(), worker.subtask_id.clone()),
+            ("Agent".to_string(), worker.agent_id.clone()),
+            ("Status".to_string(), format!("{:?}", worker.status)),
+            (
+                "Last Error".to_string(),
+                worker.last_error.clone().unwrap_or_else(|| "-".to_string()),
+            ),
+        ]);
+    }
+
+    Ok(())
+}
+
+/// `ruv-swarm workers pause <id>`: request that a tracked worker pause.
+/// Delivered in-process if this invocation's process is the one running it,
+/// otherwise left as a pending-control file for the owning process to pick
+/// up on its next `run_dag` pass.
+pub async fn execute_pause(output: &OutputHandler, worker_id: &str) -> Result<()> {
+    let manager = WorkerManager::new(&tasks_dir()?);
+    if manager.pause(worker_id).await? {
+        output.success(&format!("Pause requested for worker '{worker_id}'"));
+    } else {
+        output.warning(&format!("No worker found with id '{worker_id}'"));
+    }
+    Ok(())
+}
+
+/// `ruv-swarm workers cancel <id>`: request that a tracked worker cancel,
+/// same delivery rules as `execute_pause`.
+pub async fn execute_cancel(output: &OutputHandler, worker_id: &str) -> Result<()> {
+    let manager = WorkerManager::new(&tasks_dir()?);
+    if manager.cancel(worker_id).await? {
+        output.success(&format!("Cancel requested for worker '{worker_id}'"));
+    } else {
+        output.warning(&format!("No worker found with id '{worker_id}'"));
+    }
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Content-addressed cache for subtask results.
+//!
+//! `run_dag` used to re-run a subtask's full agent round-trip every time,
+//! even when an earlier task executed the exact same prompt/inputs/agent
+//! type combination. `JobCache` keys on a hash of that definition and, on a
+//! fresh hit, hands back the prior `TaskResult` (marked `cached`) instead of
+//! dispatching an agent at all -- the same "skip identical deterministic
+//! work" trade-off `ScheduledRunStore` makes for recurring runs, applied per
+//! subtask instead of per task. Entries live in one JSON file under
+//! `ProjectDirs`' data dir, pruned lazily of anything past its TTL on the
+//! next `store`.
+
+use std::collections::HashMap;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::orchestrate::{SubTask, TaskResult};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CacheEntry {
+    result: TaskResult,
+    cached_at: DateTime<Utc>,
+}
+
+/// Counts returned by `ruv-swarm cache stats`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CacheStats {
+    pub total: usize,
+    pub fresh: usize,
+    pub expired: usize,
+}
+
+/// A subtask-result cache backed by one JSON file, keyed by a hash of the
+/// subtask's description, required agent type/capabilities, and resolved
+/// inputs -- the same fields that determine what an agent would actually
+/// be asked to do.
+pub struct JobCache {
+    path: PathBuf,
+    ttl: chrono::Duration,
+}
+
+impl JobCache {
+    pub fn new(data_dir: &Path, ttl: std::time::Duration) -> Self {
+        Self {
+            path: data_dir.join("job_cache.json"),
+            ttl: chrono::Duration::from_std(ttl).unwrap_or_else(|_| chrono::Duration::zero()),
+        }
+    }
+
+    /// One hour, a reasonable default for re-running the same maintenance
+    /// or monitoring task within a single work session. Override with
+    /// `new` for a different policy.
+    pub fn with_default_ttl(data_dir: &Path) -> Self {
+        Self::new(data_dir, std::time::Duration::from_secs(3600))
+    }
+
+    fn key_for(subtask: &SubTask, inputs: &[serde_json::Value]) -> String {
+        let mut hasher = DefaultHasher::new();
+        subtask.description.hash(&mut hasher);
+        subtask.required_agent_type.hash(&mut hasher);
+        subtask.required_capabilities.hash(&mut hasher);
+        for input in inputs {
+            input.to_string().hash(&mut hasher);
+        }
+        format!("{:x}", hasher.finish())
+    }
+
+    fn load(&self) -> HashMap<String, CacheEntry> {
+        let Ok(content) = std::fs::read_to_string(&self.path) else {
+            return HashMap::new();
+        };
+        serde_json::from_str(&content).unwrap_or_default()
+    }
+
+    fn save(&self, entries: &HashMap<String, CacheEntry>) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let content = serde_json::to_string_pretty(entries)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write job cache: {}", self.path.display()))
+    }
+
+    fn is_fresh(&self, entry: &CacheEntry) -> bool {
+        Utc::now() - entry.cached_at <= self.ttl
+    }
+
+    /// Look up a fresh cached result for `subtask` given its resolved
+    /// `inputs`. An expired entry is treated as a miss.
+    pub fn lookup(&self, subtask: &SubTask, inputs: &[serde_json::Value]) -> Option<TaskResult> {
+        let key = Self::key_for(subtask, inputs);
+        let entry = self.load().remove(&key)?;
+        self.is_fresh(&entry).then_some(entry.result)
+    }
+
+    /// Record a freshly-executed result so a later identical subtask can
+    /// skip re-running it. Expired entries are pruned at the same time.
+    pub fn store(&self, subtask: &SubTask, inputs: &[serde_json::Value], result: &TaskResult) -> Result<()> {
+        let key = Self::key_for(subtask, inputs);
+        let mut entries = self.load();
+        entries.retain(|_, entry| self.is_fresh(entry));
+        entries.insert(
+            key,
+            CacheEntry {
+                result: result.clone(),
+                cached_at: Utc::now(),
+            },
+        );
+        self.save(&entries)
+    }
+
+    /// `ruv-swarm cache clear`: drop every cached entry.
+    pub fn clear(&self) -> Result<()> {
+        if self.path.exists() {
+            std::fs::remove_file(&self.path)
+                .with_context(|| format!("Failed to remove job cache: {}", self.path.display()))?;
+        }
+        Ok(())
+    }
+
+    /// `ruv-swarm cache stats`: how many entries are cached and how many of
+    /// those are still within their TTL.
+    pub fn stats(&self) -> CacheStats {
+        let entries = self.load();
+        let fresh = entries.values().filter(|entry| self.is_fresh(entry)).count();
+        CacheStats {
+            total: entries.len(),
+            fresh,
+            expired: entries.len() - fresh,
+        }
+    }
+}
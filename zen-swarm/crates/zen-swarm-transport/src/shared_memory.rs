@@ -6,25 +6,159 @@ use crate::{
     HealthScore, HealthStatus, RealTimeMetrics, OptimizationRecommendation,
     OptimizationCategory, Priority, ImpactLevel, PerformanceWindow, PerformanceTier,
     SharedMemoryRealTimeMetrics, RingBufferAnalysis, MemoryAnalysis, MemoryTrend,
-    SharedMemoryPerformanceAnalysis,
+    SharedMemoryPerformanceAnalysis, RateLimiterConfig,
 };
 use async_trait::async_trait;
-use crossbeam::channel::{bounded, unbounded, Receiver, Sender};
-use dashmap::DashMap;
+use crossbeam::channel::{bounded, unbounded, Receiver, Select, Sender};
+use crossbeam::queue::SegQueue;
+use dashmap::{DashMap, DashSet};
 use std::{
-    mem::size_of,
+    cell::UnsafeCell,
     sync::{
-        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        atomic::{fence, AtomicBool, AtomicI64, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
+    time::{Duration, Instant},
 };
 use tokio::sync::{mpsc, RwLock};
 use tracing::{error, info};
+#[cfg(unix)]
+use std::os::unix::io::{AsRawFd, FromRawFd, OwnedFd, RawFd};
+#[cfg(unix)]
+use std::os::unix::net::UnixDatagram;
 
 /// Performance metrics using AtomicU64 for lock-free counters
 mod performance_metrics {
     use super::*;
-    
+
+    /// Smoothing factor for the latency EWMA; ~0.1 tracks a rolling window of
+    /// roughly the last 10 samples without keeping any sample history around.
+    const EWMA_ALPHA: f64 = 0.1;
+    /// Decay rate for the exponentially-weighted percentile reservoir, in 1/ms.
+    const HISTOGRAM_LAMBDA: f64 = 0.001;
+    /// Reservoir is rescaled once the oldest live weight would otherwise risk overflow.
+    const RESCALE_AFTER_MS: f64 = 60_000.0;
+    const MAX_RESERVOIR_SAMPLES: usize = 512;
+
+    /// EWMA current-latency estimate plus a bounded, time-decayed weighted
+    /// reservoir of samples so p50/p90/p99 can be read without unbounded memory.
+    pub struct LatencyTracker {
+        ewma_ms: parking_lot::Mutex<f64>,
+        reservoir: parking_lot::Mutex<Vec<(f64, f64)>>, // (value_ms, weight)
+        t0: Instant,
+        last_rescale_ms: AtomicU64,
+        has_sample: AtomicBool,
+    }
+
+    impl LatencyTracker {
+        pub fn new() -> Self {
+            Self {
+                ewma_ms: parking_lot::Mutex::new(0.0),
+                reservoir: parking_lot::Mutex::new(Vec::with_capacity(MAX_RESERVOIR_SAMPLES)),
+                t0: Instant::now(),
+                last_rescale_ms: AtomicU64::new(0),
+                has_sample: AtomicBool::new(false),
+            }
+        }
+
+        pub fn record(&self, sample_ms: f64) {
+            {
+                let mut ewma = self.ewma_ms.lock();
+                *ewma = if self.has_sample.swap(true, Ordering::AcqRel) {
+                    EWMA_ALPHA * sample_ms + (1.0 - EWMA_ALPHA) * *ewma
+                } else {
+                    sample_ms
+                };
+            }
+
+            let now_ms = self.t0.elapsed().as_secs_f64() * 1000.0;
+            let mut reservoir = self.reservoir.lock();
+
+            if now_ms - self.last_rescale_ms.load(Ordering::Relaxed) as f64 > RESCALE_AFTER_MS {
+                let shift = (HISTOGRAM_LAMBDA * now_ms).exp().recip();
+                for (_, weight) in reservoir.iter_mut() {
+                    *weight *= shift;
+                }
+                self.last_rescale_ms.store(now_ms as u64, Ordering::Relaxed);
+            }
+
+            let weight = (HISTOGRAM_LAMBDA * now_ms).exp();
+            if reservoir.len() >= MAX_RESERVOIR_SAMPLES {
+                // Evict the lowest-weight (oldest) sample to bound memory.
+                if let Some((idx, _)) = reservoir
+                    .iter()
+                    .enumerate()
+                    .min_by(|(_, a), (_, b)| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                {
+                    reservoir.swap_remove(idx);
+                }
+            }
+            reservoir.push((sample_ms, weight));
+        }
+
+        pub fn ewma_ms(&self) -> f64 {
+            *self.ewma_ms.lock()
+        }
+
+        pub fn percentile(&self, p: f64) -> f64 {
+            let reservoir = self.reservoir.lock();
+            if reservoir.is_empty() {
+                return 0.0;
+            }
+            let mut sorted = reservoir.clone();
+            sorted.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+            let idx = ((p * (sorted.len() as f64 - 1.0)).round() as usize).min(sorted.len() - 1);
+            sorted[idx].0
+        }
+    }
+
+    impl Default for LatencyTracker {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    /// One-second-resolution sliding window: each bucket holds the total for a
+    /// single wall-clock second, so `rate_over(window_secs)` reports a real
+    /// moving-average throughput instead of lifetime-total / assumed-duration.
+    pub struct SlidingWindowCounter {
+        buckets: parking_lot::Mutex<Vec<(u64, u64)>>, // (epoch_second, total)
+        capacity_secs: u64,
+        start: Instant,
+    }
+
+    impl SlidingWindowCounter {
+        pub fn new(capacity_secs: u64) -> Self {
+            Self {
+                buckets: parking_lot::Mutex::new(vec![(0, 0); capacity_secs.max(1) as usize]),
+                capacity_secs: capacity_secs.max(1),
+                start: Instant::now(),
+            }
+        }
+
+        pub fn record(&self, amount: u64) {
+            let second = self.start.elapsed().as_secs();
+            let idx = (second % self.capacity_secs) as usize;
+            let mut buckets = self.buckets.lock();
+            if buckets[idx].0 != second {
+                buckets[idx] = (second, 0);
+            }
+            buckets[idx].1 += amount;
+        }
+
+        pub fn rate_over(&self, window_secs: u64) -> f64 {
+            let now = self.start.elapsed().as_secs();
+            let window = window_secs.clamp(1, self.capacity_secs);
+            let buckets = self.buckets.lock();
+            let total: u64 = buckets
+                .iter()
+                .filter(|(second, _)| *second != 0 && now.saturating_sub(*second) < window)
+                .map(|(_, value)| value)
+                .sum();
+            total as f64 / window as f64
+        }
+    }
+
     /// Use AtomicU64 for high-performance shared memory metrics tracking
     pub struct SharedMemoryMetrics {
         pub messages_sent: AtomicU64,
@@ -39,8 +173,22 @@ mod performance_metrics {
         pub shared_memory_segments: AtomicU64,
         pub memory_allocation_bytes: AtomicU64,
         pub lock_contention_count: AtomicU64,
+        pub latency: LatencyTracker,
+        pub blocked_on_backpressure: AtomicU64,
+        pub rate_limited_events: AtomicU64,
+        /// Sliding-window accounting, kept separate for inbound vs outbound traffic
+        /// since a peer can be a bandwidth hog in one direction but not the other.
+        pub incoming_bandwidth_window: SlidingWindowCounter,
+        pub outgoing_bandwidth_window: SlidingWindowCounter,
+        /// Microsecond-resolution timing accumulators for ring buffer operations,
+        /// consumed by the periodic stats reporter to derive average op latency.
+        pub write_time_us_total: AtomicU64,
+        pub read_time_us_total: AtomicU64,
+        /// Records rejected by the `RingBuffer::read_with_latency` header/accounting
+        /// check, i.e. a peer writing a bogus length or a torn record.
+        pub corrupt_frames: AtomicU64,
     }
-    
+
     impl SharedMemoryMetrics {
         pub fn new() -> Self {
             Self {
@@ -56,18 +204,82 @@ mod performance_metrics {
                 shared_memory_segments: AtomicU64::new(0),
                 memory_allocation_bytes: AtomicU64::new(0),
                 lock_contention_count: AtomicU64::new(0),
+                latency: LatencyTracker::new(),
+                blocked_on_backpressure: AtomicU64::new(0),
+                rate_limited_events: AtomicU64::new(0),
+                incoming_bandwidth_window: SlidingWindowCounter::new(300),
+                outgoing_bandwidth_window: SlidingWindowCounter::new(300),
+                write_time_us_total: AtomicU64::new(0),
+                read_time_us_total: AtomicU64::new(0),
+                corrupt_frames: AtomicU64::new(0),
             }
         }
+
+        pub fn record_corrupt_frame(&self) {
+            self.corrupt_frames.fetch_add(1, Ordering::Relaxed);
+        }
+
+        pub fn record_write_timing(&self, elapsed: Duration) {
+            self.write_time_us_total.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+        }
+
+        pub fn record_read_timing(&self, elapsed: Duration) {
+            self.read_time_us_total.fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+        }
+
+        pub fn average_write_time_us(&self) -> f64 {
+            let writes = self.ring_buffer_writes.load(Ordering::Relaxed);
+            if writes == 0 {
+                0.0
+            } else {
+                self.write_time_us_total.load(Ordering::Relaxed) as f64 / writes as f64
+            }
+        }
+
+        pub fn average_read_time_us(&self) -> f64 {
+            let reads = self.ring_buffer_reads.load(Ordering::Relaxed);
+            if reads == 0 {
+                0.0
+            } else {
+                self.read_time_us_total.load(Ordering::Relaxed) as f64 / reads as f64
+            }
+        }
+
+        /// Record an observed enqueue-to-dequeue round trip for a ring buffer message.
+        pub fn record_latency_sample(&self, sample: Duration) {
+            self.latency.record(sample.as_secs_f64() * 1000.0);
+        }
+
+        /// Record that a send had to wait for the peer's credit window to free up.
+        pub fn record_backpressure_block(&self) {
+            self.blocked_on_backpressure.fetch_add(1, Ordering::Relaxed);
+        }
+
+        /// Record that a send was throttled by the configured token-bucket rate limiter.
+        pub fn record_rate_limited(&self) {
+            self.rate_limited_events.fetch_add(1, Ordering::Relaxed);
+        }
         
         /// Use AtomicU64 for thread-safe message tracking
         pub fn record_message_sent(&self, message_size: usize) {
             self.messages_sent.fetch_add(1, Ordering::Relaxed);
             self.bytes_transmitted.fetch_add(message_size as u64, Ordering::Relaxed);
+            self.outgoing_bandwidth_window.record(message_size as u64);
         }
-        
+
         pub fn record_message_received(&self, message_size: usize) {
             self.messages_received.fetch_add(1, Ordering::Relaxed);
             self.bytes_received.fetch_add(message_size as u64, Ordering::Relaxed);
+            self.incoming_bandwidth_window.record(message_size as u64);
+        }
+
+        /// Real sliding-window incoming/outgoing throughput, independent of the
+        /// lifetime-average `calculate_throughput_metrics` figures.
+        pub fn windowed_bandwidth(&self, window_secs: u64) -> (f64, f64) {
+            (
+                self.incoming_bandwidth_window.rate_over(window_secs),
+                self.outgoing_bandwidth_window.rate_over(window_secs),
+            )
         }
         
         /// Use AtomicU64 for ring buffer operation tracking
@@ -117,21 +329,22 @@ mod performance_metrics {
         
         /// Use AtomicU64 for throughput calculations  
         pub fn calculate_throughput_metrics(&self, duration_secs: u64) -> (f64, f64, f64) {
-            let messages = self.messages_sent.load(Ordering::Relaxed) 
-                         + self.messages_received.load(Ordering::Relaxed);
-            let bytes = self.bytes_transmitted.load(Ordering::Relaxed) 
-                      + self.bytes_received.load(Ordering::Relaxed);
-            let operations = self.ring_buffer_writes.load(Ordering::Relaxed) 
-                           + self.ring_buffer_reads.load(Ordering::Relaxed);
-            
             if duration_secs == 0 {
                 return (0.0, 0.0, 0.0);
             }
-            
+
+            let messages = self.messages_sent.load(Ordering::Relaxed)
+                         + self.messages_received.load(Ordering::Relaxed);
+            let operations = self.ring_buffer_writes.load(Ordering::Relaxed)
+                           + self.ring_buffer_reads.load(Ordering::Relaxed);
+
+            // Messages/ops counters are lifetime totals (no window tracked for them),
+            // but bandwidth now comes from the real sliding-window accounting tables.
             let messages_per_sec = messages as f64 / duration_secs as f64;
-            let bytes_per_sec = bytes as f64 / duration_secs as f64;
+            let (incoming_rate, outgoing_rate) = self.windowed_bandwidth(duration_secs);
+            let bytes_per_sec = incoming_rate + outgoing_rate;
             let operations_per_sec = operations as f64 / duration_secs as f64;
-            
+
             (messages_per_sec, bytes_per_sec, operations_per_sec)
         }
         
@@ -223,6 +436,10 @@ mod performance_metrics {
                 memory_efficiency: self.calculate_memory_efficiency(),
                 peer_health_score: peer_health,
                 lock_contention_rate: self.calculate_lock_contention_rate(),
+                latency_ewma_ms: self.latency.ewma_ms(),
+                latency_p50_ms: self.latency.percentile(0.5),
+                latency_p90_ms: self.latency.percentile(0.9),
+                latency_p99_ms: self.latency.percentile(0.99),
             }
         }
         
@@ -285,18 +502,21 @@ mod performance_metrics {
             }
         }
         
-        /// Estimate shared memory latency based on ring buffer performance
+        /// Estimate shared memory latency from observed enqueue-to-dequeue timings,
+        /// falling back to the contention/utilization heuristic before any sample lands.
         pub fn estimate_shared_memory_latency(&self) -> f64 {
+            let observed = self.latency.ewma_ms();
+            if observed > 0.0 {
+                return observed;
+            }
+
             let lock_contention_rate = self.calculate_lock_contention_rate();
             let buffer_utilization = self.calculate_buffer_utilization();
-            
-            // Base latency for shared memory (very low)
+
             let mut base_latency = 0.1; // 0.1ms base latency
-            
-            // Add latency based on contention and utilization
             base_latency += lock_contention_rate * 0.01; // Up to 1ms for high contention
             base_latency += (buffer_utilization / 100.0) * 0.5; // Up to 0.5ms for high utilization
-            
+
             base_latency
         }
         
@@ -318,13 +538,34 @@ mod performance_metrics {
                 });
             }
             
-            if overflow_rate > 1.0 {
+            let backpressure_blocks = self.blocked_on_backpressure.load(Ordering::Relaxed);
+            if overflow_rate > 1.0 && backpressure_blocks == 0 {
+                // Overflows without any backpressure waits mean capacity is genuinely
+                // too small, not just a transient stall absorbed by the credit window.
                 recommendations.push(OptimizationRecommendation {
                     category: OptimizationCategory::Capacity,
                     priority: if overflow_rate > 10.0 { Priority::Critical } else { Priority::High },
-                    description: format!("High buffer overflow rate: {:.2}/sec. Consider increasing ring buffer sizes or implementing backpressure.", overflow_rate),
+                    description: format!("High buffer overflow rate: {:.2}/sec with no backpressure waits observed. Increase ring buffer sizes.", overflow_rate),
                     estimated_impact: ImpactLevel::High,
                 });
+            } else if backpressure_blocks > 0 {
+                recommendations.push(OptimizationRecommendation {
+                    category: OptimizationCategory::Capacity,
+                    priority: Priority::Medium,
+                    description: format!("{} sends stalled on peer credit backpressure. Consider a larger send_window if the consumer is simply slower, not stuck.", backpressure_blocks),
+                    estimated_impact: ImpactLevel::Medium,
+                });
+            }
+
+            let rate_limited = self.rate_limited_events.load(Ordering::Relaxed);
+            if rate_limited > 0 {
+                // Deliberately capped via RateLimiterConfig, not an organic overflow/stall.
+                recommendations.push(OptimizationRecommendation {
+                    category: OptimizationCategory::Efficiency,
+                    priority: Priority::Low,
+                    description: format!("{} sends were throttled by the configured rate limiter; this is a deliberate cap, not a capacity problem.", rate_limited),
+                    estimated_impact: ImpactLevel::Low,
+                });
             }
             
             if contention_rate > 5.0 {
@@ -481,6 +722,92 @@ mod performance_metrics {
 #[cfg(not(target_arch = "wasm32"))]
 use shared_memory::{Shmem, ShmemConf};
 
+/// Single token bucket: refills continuously at `rate` tokens/sec up to `capacity`,
+/// so a quiet peer can burst before being smoothed back down to the steady rate.
+struct TokenBucket {
+    rate_per_sec: f64,
+    capacity: f64,
+    tokens: parking_lot::Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    fn new(rate_per_sec: f64, capacity: f64) -> Self {
+        Self {
+            rate_per_sec,
+            capacity,
+            tokens: parking_lot::Mutex::new((capacity, Instant::now())),
+        }
+    }
+
+    fn refill(&self, tokens: &mut (f64, Instant)) {
+        let now = Instant::now();
+        let elapsed = now.duration_since(tokens.1).as_secs_f64();
+        tokens.0 = (tokens.0 + elapsed * self.rate_per_sec).min(self.capacity);
+        tokens.1 = now;
+    }
+
+    /// Try to take `amount` tokens now; returns `Ok(())` on success or `Err(wait)`
+    /// with how long the caller would need to wait for enough tokens to refill.
+    fn try_consume(&self, amount: f64) -> Result<(), Duration> {
+        let mut tokens = self.tokens.lock();
+        self.refill(&mut tokens);
+        if tokens.0 >= amount {
+            tokens.0 -= amount;
+            Ok(())
+        } else {
+            let deficit = amount - tokens.0;
+            Err(Duration::from_secs_f64(deficit / self.rate_per_sec))
+        }
+    }
+}
+
+/// Per-peer token-bucket rate limiter covering both an operations/sec and a
+/// bytes/sec bucket; a write must satisfy both to proceed.
+struct PeerRateLimiter {
+    ops: TokenBucket,
+    bytes: TokenBucket,
+    wait_on_throttle: bool,
+}
+
+impl PeerRateLimiter {
+    fn new(config: &RateLimiterConfig) -> Self {
+        Self {
+            ops: TokenBucket::new(config.ops_per_sec, config.ops_burst),
+            bytes: TokenBucket::new(config.bytes_per_sec, config.bytes_burst),
+            wait_on_throttle: config.wait_on_throttle,
+        }
+    }
+
+    /// Consume one op-token plus `bytes` byte-tokens, waiting for refill when
+    /// configured to, or failing fast with `TransportError::RateLimited`.
+    async fn acquire(&self, bytes: usize) -> Result<(), TransportError> {
+        loop {
+            let ops_result = self.ops.try_consume(1.0);
+            let bytes_result = self.bytes.try_consume(bytes as f64);
+
+            if let (Ok(()), Ok(())) = (&ops_result, &bytes_result) {
+                return Ok(());
+            }
+
+            // Refund whichever bucket we did manage to take from so a miss on the
+            // other bucket doesn't silently drain tokens we can't use this round.
+            if ops_result.is_ok() {
+                let _ = self.ops.try_consume(-1.0);
+            }
+            if bytes_result.is_ok() {
+                let _ = self.bytes.try_consume(-(bytes as f64));
+            }
+
+            if !self.wait_on_throttle {
+                return Err(TransportError::RateLimited("token bucket exhausted".to_string()));
+            }
+
+            let wait = ops_result.err().into_iter().chain(bytes_result.err()).max().unwrap_or_default();
+            tokio::time::sleep(wait.max(Duration::from_millis(1))).await;
+        }
+    }
+}
+
 /// Shared memory segment info
 #[derive(Debug, Clone)]
 pub struct SharedMemoryInfo {
@@ -489,123 +816,412 @@ pub struct SharedMemoryInfo {
     pub ring_buffer_size: usize,
 }
 
-/// Lock-free ring buffer for message passing
+/// Record header: `[i32 length][i32 type]`, length written last (release) so a
+/// reader never observes a partially written record (length 0 = not ready yet).
+const RECORD_HEADER_LEN: usize = 8;
+/// Records are padded to an 8-byte boundary so header fields stay naturally aligned.
+const RECORD_ALIGNMENT: usize = 8;
+/// Marks a record that only exists to pad out to the physical end of the buffer.
+const PADDING_MSG_TYPE: i32 = -1;
+const DATA_MSG_TYPE: i32 = 0;
+/// A record packed by [`RingBuffer::write_coalesced`]: the payload is a
+/// back-to-back sequence of `[u32 LE len][bytes]` sub-frames instead of one
+/// message.
+const COALESCED_MSG_TYPE: i32 = 1;
+
+fn align_up(len: usize) -> usize {
+    (len + RECORD_ALIGNMENT - 1) & !(RECORD_ALIGNMENT - 1)
+}
+
+/// Round a ring buffer capacity down to the nearest [`RECORD_ALIGNMENT`]
+/// multiple (never below it). `write_typed`'s wrap handling relies on every
+/// claimed offset being a multiple of `RECORD_ALIGNMENT`, which only holds if
+/// `capacity` itself is — otherwise `write_padding` can be asked to write its
+/// 8-byte header into a wrap remainder smaller than 8 bytes, corrupting
+/// whatever follows it in the backing storage (heap or mmap'd shared memory).
+/// Callers that construct a buffer from a caller-supplied size (e.g.
+/// `new_wasm`'s `SharedArrayBuffer` length) must pass the size through this
+/// rather than trusting it directly.
+fn align_down_capacity(capacity: usize) -> usize {
+    (capacity & !(RECORD_ALIGNMENT - 1)).max(RECORD_ALIGNMENT)
+}
+
+/// Split a [`COALESCED_MSG_TYPE`] record's payload back into its sub-frames.
+/// Any frame a truncated/malformed packing can't account for is simply
+/// dropped rather than returned as garbage — the record itself already passed
+/// `read_with_latency`'s own-accounting check, so this only guards against a
+/// miscounted length prefix within an otherwise-valid record.
+fn unpack_coalesced(data: &[u8]) -> Vec<Vec<u8>> {
+    let mut frames = Vec::new();
+    let mut offset = 0;
+    while offset + 4 <= data.len() {
+        let len = u32::from_le_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+        offset += 4;
+        if offset + len > data.len() {
+            break;
+        }
+        frames.push(data[offset..offset + len].to_vec());
+        offset += len;
+    }
+    frames
+}
+
+/// A record header that doesn't square with the buffer's own head/tail
+/// accounting: a negative or over-long `data_len`, or a `msg_type` that isn't
+/// one this buffer ever writes. Surfaced instead of trusted, since a peer that
+/// writes a bogus length (or a torn write) would otherwise desynchronize `head`
+/// and return garbage framing on every subsequent read.
+#[derive(Debug, Clone, Copy)]
+pub struct CorruptFrame {
+    pub reported_len: i32,
+    pub msg_type: i32,
+    pub available: usize,
+}
+
+/// Padded to a full cache line so `tail`, `head` and `head_cache` never share one,
+/// which is what made the old mutex-backed buffer show up in `lock_contention_count`.
+#[repr(align(64))]
+struct CachePadded(AtomicI64);
+
+/// Many-to-one (multi-writer / single-reader) lock-free ring buffer, modeled on the
+/// Aeron broadcast/ring buffer layout: writers CAS-advance `tail` to claim space,
+/// wrap with an explicit padding record when a claim would cross the physical end,
+/// and the reader advances `head` past consumed and padding records.
+/// Backing storage for a [`RingBuffer`]: either a heap allocation (used for
+/// standalone/WASM buffers) or a raw region inside an mmap'd `Shmem` segment
+/// (used for real cross-process SPSC transport, see [`RingBuffer::from_shmem_region`]).
+enum RingStorage {
+    Heap(UnsafeCell<Box<[u8]>>),
+    Shared { ptr: *mut u8 },
+}
+
+impl RingStorage {
+    unsafe fn as_mut_ptr(&self) -> *mut u8 {
+        match self {
+            RingStorage::Heap(cell) => (*cell.get()).as_mut_ptr(),
+            RingStorage::Shared { ptr } => *ptr,
+        }
+    }
+}
+
 pub struct RingBuffer {
-    buffer: Arc<parking_lot::Mutex<Vec<u8>>>,
+    buffer: RingStorage,
     capacity: usize,
-    head: AtomicUsize,
-    tail: AtomicUsize,
-    size: AtomicUsize,
+    tail: CachePadded,
+    head: CachePadded,
+    head_cache: CachePadded,
+    /// Enqueue timestamps in FIFO order, matched against dequeues to measure
+    /// real round-trip latency instead of a contention/utilization heuristic.
+    enqueue_times: SegQueue<Instant>,
+    /// Woken by `write()` on every successful claim so an in-process reader can
+    /// block instead of busy-polling. Defaults to a private `Notify`; callers
+    /// that want one poller task to wake for any of several buffers should
+    /// replace it with a shared instance via [`RingBuffer::with_notify`].
+    notify: Arc<tokio::sync::Notify>,
+    /// Sub-frames unpacked from a [`write_coalesced`](Self::write_coalesced)
+    /// record but not yet handed to the caller; drained before the next
+    /// physical record is read, so a coalesced write is invisible to readers
+    /// beyond seeing several `read_with_latency` calls in a row.
+    pending_frames: SegQueue<(Vec<u8>, Option<Duration>)>,
 }
 
+// SAFETY: writers only ever touch the byte range they successfully CAS-claimed via
+// `tail`, and the single reader only touches bytes behind `head`; those ranges never
+// overlap, so concurrent access to the shared backing storage is data-race free. For
+// the `Shared` variant, the caller of `from_shmem_region` additionally guarantees the
+// region is exclusively owned by this buffer (see its safety docs).
+unsafe impl Send for RingBuffer {}
+unsafe impl Sync for RingBuffer {}
+
 impl RingBuffer {
-    /// Create a new ring buffer
+    /// Create a new, heap-backed ring buffer. `capacity` is rounded down to the
+    /// nearest [`RECORD_ALIGNMENT`] multiple (see [`align_down_capacity`]) so
+    /// every wrap remainder has room for a full record header.
     pub fn new(capacity: usize) -> Self {
+        let capacity = align_down_capacity(capacity);
+        Self {
+            buffer: RingStorage::Heap(UnsafeCell::new(vec![0u8; capacity].into_boxed_slice())),
+            capacity,
+            tail: CachePadded(AtomicI64::new(0)),
+            head: CachePadded(AtomicI64::new(0)),
+            head_cache: CachePadded(AtomicI64::new(0)),
+            enqueue_times: SegQueue::new(),
+            notify: Arc::new(tokio::sync::Notify::new()),
+            pending_frames: SegQueue::new(),
+        }
+    }
+
+    /// Replace this buffer's wakeup notification with a shared one, so a single
+    /// poller task can wait on many buffers at once instead of one `Notify` per
+    /// buffer.
+    pub fn with_notify(mut self, notify: Arc<tokio::sync::Notify>) -> Self {
+        self.notify = notify;
+        self
+    }
+
+    /// Create a ring buffer directly over a region of an mmap'd shared memory
+    /// segment, giving two processes a real SPSC channel instead of each holding
+    /// an independent heap-backed buffer that only looks shared.
+    ///
+    /// # Safety
+    /// `ptr` must be valid for reads and writes of `len` bytes for as long as this
+    /// `RingBuffer` (and any peer mapping the same underlying segment) is alive,
+    /// and no other code may write into `[ptr, ptr + len)` outside of the
+    /// `RingBuffer` read/write protocol. `len` is rounded down to the nearest
+    /// [`RECORD_ALIGNMENT`] multiple (see [`align_down_capacity`]); any trailing
+    /// remainder is simply never addressed, which is always safe since it's a
+    /// subrange of the `len` bytes the caller already guaranteed are valid.
+    pub unsafe fn from_shmem_region(ptr: *mut u8, len: usize) -> Self {
+        let capacity = align_down_capacity(len);
         Self {
-            buffer: Arc::new(parking_lot::Mutex::new(vec![0; capacity])),
+            buffer: RingStorage::Shared { ptr },
             capacity,
-            head: AtomicUsize::new(0),
-            tail: AtomicUsize::new(0),
-            size: AtomicUsize::new(0),
+            tail: CachePadded(AtomicI64::new(0)),
+            head: CachePadded(AtomicI64::new(0)),
+            head_cache: CachePadded(AtomicI64::new(0)),
+            enqueue_times: SegQueue::new(),
+            notify: Arc::new(tokio::sync::Notify::new()),
+            pending_frames: SegQueue::new(),
         }
     }
 
     /// Write data to the ring buffer
     pub fn write(&self, data: &[u8]) -> Result<(), TransportError> {
-        let data_len = data.len();
-        let total_len = data_len + size_of::<u32>();
+        self.write_typed(data, DATA_MSG_TYPE)
+    }
 
-        // Check if there's enough space
-        if total_len > self.capacity - self.size.load(Ordering::Acquire) {
-            return Err(TransportError::MessageTooLarge {
-                size: total_len,
-                max: self.capacity - self.size.load(Ordering::Acquire),
-            });
+    /// Pack several small frames into a single record (a u32 LE length prefix
+    /// per frame, back to back) so a burst of small sends costs one CAS/header
+    /// instead of one per message. `read_with_latency` unpacks these
+    /// transparently, handing each frame back on successive calls.
+    pub fn write_coalesced(&self, frames: &[&[u8]]) -> Result<(), TransportError> {
+        let mut packed = Vec::with_capacity(
+            frames.iter().map(|f| 4 + f.len()).sum::<usize>(),
+        );
+        for frame in frames {
+            packed.extend_from_slice(&(frame.len() as u32).to_le_bytes());
+            packed.extend_from_slice(frame);
         }
+        self.write_typed(&packed, COALESCED_MSG_TYPE)
+    }
 
-        // Write length prefix
-        let len_bytes = (data_len as u32).to_le_bytes();
-        let mut write_pos = self.tail.load(Ordering::Acquire);
+    /// Current total capacity of this buffer, in bytes.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
 
-        // Write length and data
-        {
-            let mut buffer = self.buffer.lock();
+    fn write_typed(&self, data: &[u8], msg_type: i32) -> Result<(), TransportError> {
+        let aligned_len = align_up(RECORD_HEADER_LEN + data.len());
+        if aligned_len > self.capacity {
+            return Err(TransportError::MessageTooLarge {
+                size: data.len(),
+                max: self.capacity.saturating_sub(RECORD_HEADER_LEN),
+            });
+        }
 
-            // Write length
-            for &byte in &len_bytes {
-                buffer[write_pos] = byte;
-                write_pos = (write_pos + 1) % self.capacity;
+        loop {
+            let tail = self.tail.0.load(Ordering::Relaxed);
+            let tail_idx = (tail as usize) % self.capacity;
+            let to_buffer_end = self.capacity - tail_idx;
+            let wraps = aligned_len > to_buffer_end;
+            let required = if wraps { to_buffer_end + aligned_len } else { aligned_len };
+
+            let mut head = self.head_cache.0.load(Ordering::Relaxed);
+            if tail + required as i64 - head > self.capacity as i64 {
+                head = self.head.0.load(Ordering::Acquire);
+                self.head_cache.0.store(head, Ordering::Relaxed);
+                if tail + required as i64 - head > self.capacity as i64 {
+                    return Err(TransportError::MessageTooLarge {
+                        size: data.len(),
+                        max: self.capacity.saturating_sub((tail - head).max(0) as usize),
+                    });
+                }
             }
 
-            // Write data
-            for &byte in data {
-                buffer[write_pos] = byte;
-                write_pos = (write_pos + 1) % self.capacity;
+            let new_tail = tail + required as i64;
+            if self
+                .tail
+                .0
+                .compare_exchange(tail, new_tail, Ordering::AcqRel, Ordering::Relaxed)
+                .is_ok()
+            {
+                if wraps {
+                    self.write_padding(tail_idx, to_buffer_end);
+                    self.write_record(0, data, msg_type);
+                } else {
+                    self.write_record(tail_idx, data, msg_type);
+                }
+                self.enqueue_times.push(Instant::now());
+                self.notify.notify_one();
+                return Ok(());
             }
+            // Another writer advanced `tail` first; retry the claim.
         }
+    }
 
-        // Update tail and size
-        self.tail.store(write_pos, Ordering::Release);
-        self.size.fetch_add(total_len, Ordering::AcqRel);
+    /// Read data from the ring buffer. A corrupt record (see [`CorruptFrame`])
+    /// is reported as `None` here; callers that need to tell "empty" apart from
+    /// "corrupt" should use [`RingBuffer::read_with_latency`] directly.
+    pub fn read(&self) -> Option<Vec<u8>> {
+        self.read_with_latency().ok().flatten().map(|(data, _)| data)
+    }
 
-        Ok(())
+    /// Wait until a `write()` lands, without busy-polling `read()` in a loop.
+    /// Resolves spuriously on an unrelated write to the same buffer; callers
+    /// should re-check `read()`/`read_with_latency()` after waking, same as
+    /// any other `Notify`-based wait in this module.
+    pub async fn wait_readable(&self) {
+        self.notify.notified().await;
     }
 
-    /// Read data from the ring buffer
-    pub fn read(&self) -> Option<Vec<u8>> {
-        let current_size = self.size.load(Ordering::Acquire);
-        if current_size < size_of::<u32>() {
-            return None;
+    /// Read data from the ring buffer, also returning the enqueue-to-dequeue
+    /// latency measured against the matching `write` call's timestamp.
+    ///
+    /// Every record is validated against this buffer's own head/tail accounting
+    /// before being handed up: a `data_len` that couldn't have been written by
+    /// this buffer's `write()` (negative, longer than `capacity`, or longer than
+    /// the bytes currently committed between `head` and `tail`) or a `msg_type`
+    /// that isn't one `write()` ever produces is reported as `Err(CorruptFrame)`
+    /// instead of trusted, and `head` is left unmoved so the caller can decide
+    /// how to recover (e.g. drop the peer) rather than spinning on garbage.
+    pub fn read_with_latency(&self) -> Result<Option<(Vec<u8>, Option<Duration>)>, CorruptFrame> {
+        if let Some(pending) = self.pending_frames.pop() {
+            return Ok(Some(pending));
         }
 
-        // Read length prefix and data
-        let mut read_pos = self.head.load(Ordering::Acquire);
-        let (data_len, data) = {
-            let buffer = self.buffer.lock();
-            let mut len_bytes = [0u8; 4];
-
-            // Read length
-            for i in 0..4 {
-                len_bytes[i] = buffer[read_pos];
-                read_pos = (read_pos + 1) % self.capacity;
+        loop {
+            let head = self.head.0.load(Ordering::Relaxed);
+            let head_idx = (head as usize) % self.capacity;
+            let length = self.load_length(head_idx);
+            if length == 0 {
+                return Ok(None);
+            }
+            fence(Ordering::Acquire);
+
+            let msg_type = self.load_type(head_idx);
+            // Re-snapshot `tail` under the same acquire fence as the length/type load
+            // above, so a writer that has claimed space but not yet finished writing
+            // the record (a torn write) can't be mistaken for a complete one.
+            let committed = self.tail.0.load(Ordering::Acquire) - head;
+            let valid_len = length > 0
+                && (length as usize) <= self.capacity.saturating_sub(RECORD_HEADER_LEN)
+                && (RECORD_HEADER_LEN + length as usize) as i64 <= committed;
+            let valid_type = msg_type == PADDING_MSG_TYPE
+                || msg_type == DATA_MSG_TYPE
+                || msg_type == COALESCED_MSG_TYPE;
+            if !valid_len || !valid_type {
+                return Err(CorruptFrame {
+                    reported_len: length,
+                    msg_type,
+                    available: committed.max(0) as usize,
+                });
             }
 
-            let data_len = u32::from_le_bytes(len_bytes) as usize;
+            let aligned_len = align_up(RECORD_HEADER_LEN + length as usize);
 
-            // Check if we have enough data
-            if current_size < size_of::<u32>() + data_len {
-                return None;
+            if msg_type == PADDING_MSG_TYPE {
+                self.reclaim(head_idx, aligned_len);
+                self.head.0.store(head + aligned_len as i64, Ordering::Release);
+                continue;
             }
 
-            // Read data
-            let mut data = vec![0u8; data_len];
-            for i in 0..data_len {
-                data[i] = buffer[read_pos];
-                read_pos = (read_pos + 1) % self.capacity;
+            let data = self.read_payload(head_idx, length as usize);
+            self.reclaim(head_idx, aligned_len);
+            self.head.0.store(head + aligned_len as i64, Ordering::Release);
+            let latency = self.enqueue_times.pop().map(|enqueued_at| enqueued_at.elapsed());
+
+            if msg_type == COALESCED_MSG_TYPE {
+                let mut frames = unpack_coalesced(&data).into_iter();
+                let Some(first) = frames.next() else {
+                    continue;
+                };
+                for frame in frames {
+                    self.pending_frames.push((frame, None));
+                }
+                return Ok(Some((first, latency)));
             }
 
-            (data_len, data)
-        };
-
-        // Update head and size
-        self.head.store(read_pos, Ordering::Release);
-        self.size
-            .fetch_sub(size_of::<u32>() + data_len, Ordering::AcqRel);
-
-        Some(data)
+            return Ok(Some((data, latency)));
+        }
     }
 
     /// Get available space in the buffer
     pub fn available_space(&self) -> usize {
-        self.capacity - self.size.load(Ordering::Acquire)
+        let tail = self.tail.0.load(Ordering::Relaxed);
+        let head = self.head.0.load(Ordering::Acquire);
+        self.capacity.saturating_sub((tail - head).max(0) as usize)
     }
 
     /// Check if buffer is empty
     pub fn is_empty(&self) -> bool {
-        self.size.load(Ordering::Acquire) == 0
+        self.tail.0.load(Ordering::Relaxed) == self.head.0.load(Ordering::Acquire)
+    }
+
+    fn write_record(&self, offset: usize, data: &[u8], msg_type: i32) {
+        unsafe {
+            std::ptr::write_volatile(self.byte_ptr(offset + 4) as *mut i32, msg_type);
+            std::ptr::copy_nonoverlapping(data.as_ptr(), self.byte_ptr(offset + RECORD_HEADER_LEN), data.len());
+        }
+        fence(Ordering::Release);
+        self.store_length(offset, data.len() as i32);
+    }
+
+    fn write_padding(&self, offset: usize, total_len: usize) {
+        let payload_len = total_len.saturating_sub(RECORD_HEADER_LEN) as i32;
+        unsafe {
+            std::ptr::write_volatile(self.byte_ptr(offset + 4) as *mut i32, PADDING_MSG_TYPE);
+        }
+        fence(Ordering::Release);
+        self.store_length(offset, payload_len);
+    }
+
+    fn read_payload(&self, offset: usize, len: usize) -> Vec<u8> {
+        let mut data = vec![0u8; len];
+        unsafe {
+            std::ptr::copy_nonoverlapping(self.byte_ptr(offset + RECORD_HEADER_LEN), data.as_mut_ptr(), len);
+        }
+        data
+    }
+
+    /// Zero a reclaimed record so a stale length can never be mistaken for a ready one.
+    fn reclaim(&self, offset: usize, aligned_len: usize) {
+        unsafe {
+            std::ptr::write_bytes(self.byte_ptr(offset), 0, aligned_len);
+        }
+    }
+
+    fn store_length(&self, offset: usize, length: i32) {
+        unsafe {
+            std::ptr::write_volatile(self.byte_ptr(offset) as *mut i32, length);
+        }
+    }
+
+    fn load_length(&self, offset: usize) -> i32 {
+        unsafe { std::ptr::read_volatile(self.byte_ptr(offset) as *const i32) }
+    }
+
+    fn load_type(&self, offset: usize) -> i32 {
+        unsafe { std::ptr::read_volatile(self.byte_ptr(offset + 4) as *const i32) }
+    }
+
+    unsafe fn byte_ptr(&self, offset: usize) -> *mut u8 {
+        self.buffer.as_mut_ptr().add(offset)
     }
 }
 
+/// Default credit window (in-flight records) granted to a peer when
+/// `TransportConfig::send_window` isn't set.
+const DEFAULT_SEND_WINDOW: usize = 256;
+
+/// Fraction of a buffer's capacity, once occupied, above which
+/// `send_backpressured` starts waiting for the reader to drain space instead
+/// of writing immediately.
+const BACKPRESSURE_HIGH_WATERMARK: f64 = 0.85;
+
+/// Messages at or under this size are eligible for `send_batch_backpressured`
+/// to coalesce into a single ring-buffer record.
+const COALESCE_MAX_MESSAGE_LEN: usize = 256;
+
 /// Shared memory transport implementation
 pub struct SharedMemoryTransport {
     info: SharedMemoryInfo,
@@ -613,13 +1229,49 @@ pub struct SharedMemoryTransport {
     codec: Arc<dyn MessageCodec>,
     local_id: String,
     peers: Arc<DashMap<String, Arc<RingBuffer>>>,
+    /// Per-peer send window: one permit per in-flight record, released by the
+    /// poller once the consumer's `head` advances past that record.
+    peer_credits: Arc<DashMap<String, Arc<tokio::sync::Semaphore>>>,
+    /// Peers quarantined after `read_with_latency` rejected one of their records
+    /// as corrupt: `start_polling` stops draining their buffer so a misbehaving
+    /// or compromised peer can't be used to wedge the whole transport.
+    quarantined_peers: Arc<DashSet<String>>,
+    /// Optional shared token-bucket limiter, built once from `config.rate_limiter`.
+    rate_limiter: Option<Arc<PeerRateLimiter>>,
     incoming_rx: mpsc::Receiver<(String, Message)>,
     incoming_tx: mpsc::Sender<(String, Message)>,
     is_running: Arc<AtomicBool>,
     stats: Arc<RwLock<TransportStats>>,
     metrics: Arc<performance_metrics::SharedMemoryMetrics>,
+    /// Shared wakeup signal: every buffer handed out by `create_buffer()` is
+    /// attached to this `Notify`, so `start_polling`'s single task can block on
+    /// it instead of busy-ticking a 1ms interval. Only wakes the in-process
+    /// poller; a peer in a different process still relies on the fallback tick.
+    buffer_notify: Arc<tokio::sync::Notify>,
+    /// Woken after every successful read in `start_polling`, so
+    /// `send_backpressured` can wait for drained space instead of failing
+    /// immediately or busy-checking `available_space()`.
+    drain_notify: Arc<tokio::sync::Notify>,
     #[cfg(not(target_arch = "wasm32"))]
     shmem: Option<Arc<parking_lot::Mutex<Shmem>>>,
+    /// Bump allocator handing out non-overlapping byte ranges of `shmem` to
+    /// successive `create_buffer()` calls, so each peer's ring buffer lives in
+    /// the real mmap'd segment instead of a private heap allocation.
+    #[cfg(not(target_arch = "wasm32"))]
+    shmem_offset: Arc<AtomicUsize>,
+    /// Ring buffers dedicated to handle-carrying traffic, kept separate from
+    /// `peers` so `start_polling`'s background drain never races a
+    /// `receive_with_handles` caller for the same record.
+    #[cfg(unix)]
+    handle_peers: Arc<DashMap<String, Arc<RingBuffer>>>,
+    /// Per-peer companion socket used purely for `SCM_RIGHTS` ancillary
+    /// transfer; the ring buffer record never carries raw fd bytes.
+    #[cfg(unix)]
+    handle_sockets: Arc<DashMap<String, Arc<UnixDatagram>>>,
+    /// Monotonic id embedded in each handle-carrying record so the receiver
+    /// can match it back up with the fds arriving on the companion socket.
+    #[cfg(unix)]
+    next_handle_slot: Arc<AtomicU64>,
 }
 
 // SAFETY: SharedMemoryTransport is safe to send between threads because:
@@ -656,25 +1308,40 @@ impl SharedMemoryTransport {
         )));
 
         let metrics = Arc::new(performance_metrics::SharedMemoryMetrics::new());
-        
+        let rate_limiter = config.rate_limiter.as_ref().map(|c| Arc::new(PeerRateLimiter::new(c)));
+
         let transport = Self {
             info,
             config,
             codec: Arc::new(BinaryCodec),
             local_id: uuid::Uuid::new_v4().to_string(),
             peers: Arc::new(DashMap::new()),
+            peer_credits: Arc::new(DashMap::new()),
+            quarantined_peers: Arc::new(DashSet::new()),
+            rate_limiter,
             incoming_rx,
             incoming_tx,
             is_running: Arc::new(AtomicBool::new(true)),
             stats: Arc::new(RwLock::new(TransportStats::default())),
             metrics,
+            buffer_notify: Arc::new(tokio::sync::Notify::new()),
+            drain_notify: Arc::new(tokio::sync::Notify::new()),
             #[cfg(not(target_arch = "wasm32"))]
             shmem,
             #[cfg(target_arch = "wasm32")]
             shmem: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            shmem_offset: Arc::new(AtomicUsize::new(0)),
+            #[cfg(unix)]
+            handle_peers: Arc::new(DashMap::new()),
+            #[cfg(unix)]
+            handle_sockets: Arc::new(DashMap::new()),
+            #[cfg(unix)]
+            next_handle_slot: Arc::new(AtomicU64::new(0)),
         };
 
         transport.start_polling();
+        transport.start_stats_reporter(30);
 
         Ok(transport)
     }
@@ -695,30 +1362,79 @@ impl SharedMemoryTransport {
         }
     }
 
-    /// Start polling for messages
+    /// Start polling for messages.
+    ///
+    /// Every in-process writer bumps `buffer_notify` on a successful `write()`, so
+    /// the loop below blocks on that instead of ticking a busy interval. A much
+    /// longer fallback tick stays in place to cover peers in another process,
+    /// where `Notify` can't reach across the shmem boundary without a futex word
+    /// in the segment header (not implemented here).
     fn start_polling(&self) {
         let peers = Arc::clone(&self.peers);
+        let peer_credits = Arc::clone(&self.peer_credits);
+        let quarantined_peers = Arc::clone(&self.quarantined_peers);
         let incoming_tx = self.incoming_tx.clone();
         let is_running = Arc::clone(&self.is_running);
         let codec = Arc::clone(&self.codec);
         let stats = Arc::clone(&self.stats);
         let metrics = Arc::clone(&self.metrics);
+        let buffer_notify = Arc::clone(&self.buffer_notify);
+        let drain_notify = Arc::clone(&self.drain_notify);
 
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(1));
+            const FALLBACK_POLL_INTERVAL: Duration = Duration::from_millis(50);
+            let mut interval = tokio::time::interval(FALLBACK_POLL_INTERVAL);
 
             while is_running.load(Ordering::SeqCst) {
-                interval.tick().await;
+                tokio::select! {
+                    _ = buffer_notify.notified() => {}
+                    _ = interval.tick() => {}
+                }
 
-                // Poll all peer buffers
+                // Poll all peer buffers, skipping any already quarantined for sending
+                // a corrupt record.
                 for peer in peers.iter() {
                     let (peer_id, buffer) = peer.pair();
+                    if quarantined_peers.contains(peer_id) {
+                        continue;
+                    }
+
+                    // Read messages from buffer, timing each read at microsecond resolution
+                    loop {
+                        let read_started = Instant::now();
+                        let data = match buffer.read_with_latency() {
+                            Ok(Some(data)) => {
+                                drain_notify.notify_waiters();
+                                data
+                            }
+                            Ok(None) => break,
+                            Err(frame) => {
+                                let err = TransportError::CorruptFrame {
+                                    peer: peer_id.clone(),
+                                    reported_len: frame.reported_len,
+                                    msg_type: frame.msg_type,
+                                    available: frame.available,
+                                };
+                                error!("{}", err);
+                                metrics.record_corrupt_frame();
+                                stats.write().await.errors += 1;
+                                quarantined_peers.insert(peer_id.clone());
+                                break;
+                            }
+                        };
+                        let (data, latency) = data;
+                        metrics.record_read_timing(read_started.elapsed());
 
-                    // Read messages from buffer
-                    while let Some(data) = buffer.read() {
                         // Update AtomicU64 metrics for ring buffer read
                         metrics.record_ring_buffer_read(data.len());
-                        
+                        if let Some(latency) = latency {
+                            metrics.record_latency_sample(latency);
+                        }
+                        // A record left the buffer: replenish the sender's credit window.
+                        if let Some(credit) = peer_credits.get(peer_id) {
+                            credit.add_permits(1);
+                        }
+
                         match codec.decode(&data) {
                             Ok(msg) => {
                                 // Update AtomicU64 metrics for received message
@@ -748,20 +1464,244 @@ impl SharedMemoryTransport {
         });
     }
 
+    /// Periodically log a stats snapshot, including the microsecond-resolution
+    /// average ring buffer op timings accumulated in `SharedMemoryMetrics`.
+    fn start_stats_reporter(&self, interval_secs: u64) {
+        let metrics = Arc::clone(&self.metrics);
+        let is_running = Arc::clone(&self.is_running);
+        let name = self.info.name.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(interval_secs.max(1)));
+            interval.tick().await; // skip the immediate first tick
+
+            while is_running.load(Ordering::SeqCst) {
+                interval.tick().await;
+
+                let (incoming_bps, outgoing_bps) = metrics.windowed_bandwidth(interval_secs.max(1));
+                info!(
+                    segment = %name,
+                    avg_write_us = metrics.average_write_time_us(),
+                    avg_read_us = metrics.average_read_time_us(),
+                    incoming_bytes_per_sec = incoming_bps,
+                    outgoing_bytes_per_sec = outgoing_bps,
+                    lock_contention_rate = metrics.calculate_lock_contention_rate(),
+                    blocked_on_backpressure = metrics.blocked_on_backpressure.load(Ordering::Relaxed),
+                    "shared memory transport stats"
+                );
+            }
+        });
+    }
+
     /// Register a peer with a ring buffer
     pub fn register_peer(&self, peer_id: String, buffer: Arc<RingBuffer>) {
         self.peers.insert(peer_id.clone(), buffer);
+        let window = self.config.send_window.unwrap_or(DEFAULT_SEND_WINDOW);
+        self.peer_credits
+            .insert(peer_id.clone(), Arc::new(tokio::sync::Semaphore::new(window)));
+        // A fresh buffer means a fresh start: drop any earlier quarantine so the
+        // poller resumes draining this peer.
+        self.quarantined_peers.remove(&peer_id);
         // Update AtomicU64 metrics for peer connection
         self.metrics.record_peer_connection();
         info!("Registered peer: {}", peer_id);
     }
 
-    /// Create a ring buffer for a peer
+    /// Remaining send-window credits for a peer, or `None` if unregistered.
+    pub fn peer_credit_available(&self, peer_id: &str) -> Option<usize> {
+        self.peer_credits.get(peer_id).map(|s| s.available_permits())
+    }
+
+    /// Whether `start_polling` has stopped draining this peer's buffer after it
+    /// sent a corrupt record.
+    pub fn is_peer_quarantined(&self, peer_id: &str) -> bool {
+        self.quarantined_peers.contains(peer_id)
+    }
+
+    /// Wait for a free credit in `peer_id`'s send window rather than overflowing
+    /// the ring buffer. Blocks on the semaphore (recording the stall), bounded by
+    /// `connection_timeout_ms` when set, after which it surfaces a `TransportError`.
+    async fn acquire_send_credit(&self, peer_id: &str) -> Result<(), TransportError> {
+        let Some(credit) = self.peer_credits.get(peer_id).map(|r| Arc::clone(r.value())) else {
+            return Ok(());
+        };
+
+        if let Ok(permit) = credit.clone().try_acquire_owned() {
+            permit.forget();
+            return Ok(());
+        }
+
+        self.metrics.record_backpressure_block();
+        let timeout_ms = self.config.connection_timeout_ms;
+        let acquire = credit.acquire_owned();
+        let permit = if timeout_ms > 0 {
+            tokio::time::timeout(std::time::Duration::from_millis(timeout_ms), acquire)
+                .await
+                .map_err(|_| TransportError::Timeout)?
+        } else {
+            acquire.await
+        }
+        .map_err(|e| TransportError::Other(anyhow::anyhow!(e)))?;
+
+        permit.forget();
+        Ok(())
+    }
+
+    /// Wait until `buffer` has at least `required` bytes free, waking on every
+    /// drain the poller performs. Bounded by `TransportConfig::backpressure_deadline_ms`
+    /// when set.
+    async fn wait_for_drain(&self, buffer: &RingBuffer, required: usize) -> Result<(), TransportError> {
+        let deadline = self.config.backpressure_deadline_ms;
+        let started = Instant::now();
+        while buffer.available_space() < required {
+            self.metrics.record_backpressure_block();
+            let wait = self.drain_notify.notified();
+            match deadline {
+                Some(ms) if ms > 0 => {
+                    let remaining = Duration::from_millis(ms).saturating_sub(started.elapsed());
+                    if remaining.is_zero() {
+                        return Err(TransportError::Timeout);
+                    }
+                    tokio::time::timeout(remaining, wait)
+                        .await
+                        .map_err(|_| TransportError::Timeout)?;
+                }
+                _ => wait.await,
+            }
+        }
+        Ok(())
+    }
+
+    /// Send `msg`, waiting for the receiver to drain space instead of failing
+    /// immediately with `MessageTooLarge` when the target buffer is above its
+    /// backpressure high-watermark.
+    pub async fn send_backpressured(&self, to: &str, msg: Message) -> Result<(), TransportError> {
+        let data = self.codec.encode(&msg)?;
+        let buffer = self.peers.get(to).map(|r| Arc::clone(r.value())).ok_or_else(|| {
+            TransportError::ConnectionError(format!("No shared memory buffer for peer: {}", to))
+        })?;
+
+        self.acquire_send_credit(to).await?;
+        if let Some(limiter) = &self.rate_limiter {
+            if let Err(e) = limiter.acquire(data.len()).await {
+                self.metrics.record_rate_limited();
+                return Err(e);
+            }
+        }
+
+        let watermark = (buffer.capacity() as f64 * (1.0 - BACKPRESSURE_HIGH_WATERMARK)) as usize;
+        let required = (RECORD_HEADER_LEN + data.len()).max(watermark);
+        self.wait_for_drain(&buffer, required).await?;
+
+        let write_started = Instant::now();
+        buffer.write(&data)?;
+        self.metrics.record_write_timing(write_started.elapsed());
+        self.metrics.record_ring_buffer_write(data.len());
+        self.metrics.record_message_sent(data.len());
+
+        let mut stats = self.stats.write().await;
+        stats.messages_sent += 1;
+        stats.bytes_sent += data.len() as u64;
+        stats.last_activity = Some(chrono::Utc::now());
+        Ok(())
+    }
+
+    /// Send a batch of messages to the same peer, coalescing the ones at or
+    /// under `COALESCE_MAX_MESSAGE_LEN` into a single ring-buffer record via
+    /// `RingBuffer::write_coalesced` (cutting CAS/header overhead for a burst
+    /// of small sends) and writing larger ones individually. Waits for drained
+    /// space the same way `send_backpressured` does.
+    pub async fn send_batch_backpressured(
+        &self,
+        to: &str,
+        msgs: Vec<Message>,
+    ) -> Result<(), TransportError> {
+        let buffer = self.peers.get(to).map(|r| Arc::clone(r.value())).ok_or_else(|| {
+            TransportError::ConnectionError(format!("No shared memory buffer for peer: {}", to))
+        })?;
+
+        let mut small = Vec::new();
+        for msg in msgs {
+            let data = self.codec.encode(&msg)?;
+            if data.len() <= COALESCE_MAX_MESSAGE_LEN {
+                small.push(data);
+            } else {
+                self.acquire_send_credit(to).await?;
+                if let Some(limiter) = &self.rate_limiter {
+                    if let Err(e) = limiter.acquire(data.len()).await {
+                        self.metrics.record_rate_limited();
+                        return Err(e);
+                    }
+                }
+                self.wait_for_drain(&buffer, RECORD_HEADER_LEN + data.len()).await?;
+                let write_started = Instant::now();
+                buffer.write(&data)?;
+                self.metrics.record_write_timing(write_started.elapsed());
+                self.metrics.record_ring_buffer_write(data.len());
+                self.metrics.record_message_sent(data.len());
+            }
+        }
+
+        if small.is_empty() {
+            return Ok(());
+        }
+
+        for data in &small {
+            self.acquire_send_credit(to).await?;
+            if let Some(limiter) = &self.rate_limiter {
+                if let Err(e) = limiter.acquire(data.len()).await {
+                    self.metrics.record_rate_limited();
+                    return Err(e);
+                }
+            }
+        }
+
+        let packed_len: usize = small.iter().map(|f| 4 + f.len()).sum();
+        self.wait_for_drain(&buffer, RECORD_HEADER_LEN + packed_len).await?;
+
+        let frames: Vec<&[u8]> = small.iter().map(|f| f.as_slice()).collect();
+        let write_started = Instant::now();
+        buffer.write_coalesced(&frames)?;
+        self.metrics.record_write_timing(write_started.elapsed());
+        self.metrics.record_ring_buffer_write(packed_len);
+
+        let mut stats = self.stats.write().await;
+        for data in &small {
+            self.metrics.record_message_sent(data.len());
+            stats.messages_sent += 1;
+            stats.bytes_sent += data.len() as u64;
+        }
+        stats.last_activity = Some(chrono::Utc::now());
+        Ok(())
+    }
+
+    /// Create a ring buffer for a peer, backed by the mmap'd `Shmem` segment when
+    /// one is available so the buffer is genuinely shared across processes rather
+    /// than merely mirrored in each process's own heap.
     pub fn create_buffer(&self) -> Arc<RingBuffer> {
-        let buffer = Arc::new(RingBuffer::new(self.info.ring_buffer_size));
-        // Record shared memory segment allocation
-        self.metrics.record_shared_memory_segment(self.info.ring_buffer_size);
-        buffer
+        let size = self.info.ring_buffer_size;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(shmem) = &self.shmem {
+            let offset = self.shmem_offset.fetch_add(size, Ordering::Relaxed);
+            let guard = shmem.lock();
+            if offset + size <= guard.len() {
+                let base = guard.as_ptr();
+                drop(guard);
+                self.metrics.record_shared_memory_segment(size);
+                // SAFETY: `offset..offset + size` was reserved exclusively by the
+                // `fetch_add` above, no other caller can claim the same range, and
+                // `shmem` stays mapped for the lifetime of `self`.
+                let buffer = unsafe { RingBuffer::from_shmem_region(base.add(offset), size) };
+                return Arc::new(buffer.with_notify(Arc::clone(&self.buffer_notify)));
+            }
+            // Segment is exhausted; fall through to a heap-backed buffer so the
+            // caller still gets a working buffer instead of a hard failure.
+        }
+
+        let buffer = RingBuffer::new(size).with_notify(Arc::clone(&self.buffer_notify));
+        self.metrics.record_shared_memory_segment(size);
+        Arc::new(buffer)
     }
     
     /// Create high-performance crossbeam channels for async message passing between transports
@@ -776,6 +1716,132 @@ impl SharedMemoryTransport {
     }
 }
 
+/// How often `SharedMemorySelector::recv` re-polls its registered ring buffers
+/// while blocked in `Select`, since a buffer filling up doesn't wake a crossbeam
+/// `Select` the way a channel send does.
+const SELECTOR_POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+/// Multiplexes receipt across many peer ring buffers and arbitrary user
+/// `crossbeam_channel` receivers, using `crossbeam_channel::Select` so the
+/// caller gets whichever source becomes ready first instead of the fixed
+/// per-peer iteration order `SharedMemoryTransport::start_polling` uses.
+/// Peers registered here are polled independently of `start_polling` — don't
+/// register a peer with both, or its ring buffer will be drained twice.
+pub struct SharedMemorySelector {
+    buffers: DashMap<String, Arc<RingBuffer>>,
+    /// Bounded channel per source, fed by `poll_buffers` for peers and directly
+    /// by the caller for `register_receiver` sources.
+    sources: DashMap<String, Receiver<Message>>,
+    peer_txs: DashMap<String, Sender<Message>>,
+    codec: Arc<dyn MessageCodec>,
+}
+
+impl SharedMemorySelector {
+    pub fn new(codec: Arc<dyn MessageCodec>) -> Self {
+        Self {
+            buffers: DashMap::new(),
+            sources: DashMap::new(),
+            peer_txs: DashMap::new(),
+            codec,
+        }
+    }
+
+    /// Register a peer's ring buffer as a selectable source, keyed by `peer_id`.
+    pub fn register_peer(&self, peer_id: String, buffer: Arc<RingBuffer>) {
+        let (tx, rx) = bounded(256);
+        self.peer_txs.insert(peer_id.clone(), tx);
+        self.sources.insert(peer_id.clone(), rx);
+        self.buffers.insert(peer_id, buffer);
+    }
+
+    /// Stop selecting on a peer's buffer.
+    pub fn deregister_peer(&self, peer_id: &str) {
+        self.buffers.remove(peer_id);
+        self.peer_txs.remove(peer_id);
+        self.sources.remove(peer_id);
+    }
+
+    /// Register an arbitrary user `crossbeam_channel` receiver as a selectable
+    /// source, keyed by `name`. Lets callers multiplex e.g. a control channel
+    /// alongside peer traffic.
+    pub fn register_receiver(&self, name: String, rx: Receiver<Message>) {
+        self.sources.insert(name, rx);
+    }
+
+    /// Stop selecting on a previously registered user receiver.
+    pub fn deregister_receiver(&self, name: &str) {
+        self.sources.remove(name);
+    }
+
+    /// Drain every registered peer buffer into its channel so `Select` has
+    /// something to pick up. Non-blocking; a full channel drops the oldest
+    /// demand on the buffer side (the message stays queued in the ring buffer
+    /// until the channel has room, same backpressure semantics as a slow peer).
+    fn poll_buffers(&self) {
+        for entry in self.buffers.iter() {
+            let (peer_id, buffer) = entry.pair();
+            loop {
+                match buffer.read_with_latency() {
+                    Ok(Some((data, _))) => {
+                        if let Ok(msg) = self.codec.decode(&data) {
+                            if let Some(tx) = self.peer_txs.get(peer_id) {
+                                if tx.try_send(msg).is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                    }
+                    Ok(None) => break,
+                    Err(_) => break, // corrupt frame: leave quarantining to the transport's own poller
+                }
+            }
+        }
+    }
+
+    /// Block until any registered peer buffer or user receiver has a message,
+    /// returning `(source_name, message)`. Fair across ready sources — `Select`
+    /// doesn't favor the first-registered one the way a fixed loop would.
+    pub fn recv(&self) -> Result<(String, Message), TransportError> {
+        loop {
+            self.poll_buffers();
+
+            let snapshot: Vec<(String, Receiver<Message>)> =
+                self.sources.iter().map(|e| (e.key().clone(), e.value().clone())).collect();
+            if snapshot.is_empty() {
+                return Err(TransportError::NotAvailable(
+                    "SharedMemorySelector has no registered sources".to_string(),
+                ));
+            }
+
+            let mut select = Select::new();
+            for (_, rx) in &snapshot {
+                select.recv(rx);
+            }
+
+            let Ok(oper) = select.select_timeout(SELECTOR_POLL_INTERVAL) else {
+                continue; // nothing ready yet; re-poll buffers and retry
+            };
+            let (name, rx) = &snapshot[oper.index()];
+            match oper.recv(rx) {
+                Ok(msg) => return Ok((name.clone(), msg)),
+                Err(_) => continue, // source was deregistered between snapshot and recv
+            }
+        }
+    }
+
+    /// Non-blocking version of `recv`: polls buffers once, then returns the
+    /// first ready source, or `None` if nothing is available right now.
+    pub fn try_recv(&self) -> Option<(String, Message)> {
+        self.poll_buffers();
+        for entry in self.sources.iter() {
+            if let Ok(msg) = entry.value().try_recv() {
+                return Some((entry.key().clone(), msg));
+            }
+        }
+        None
+    }
+}
+
 #[cfg(target_arch = "wasm32")]
 impl SharedMemoryTransport {
     /// WASM-specific implementation using SharedArrayBuffer
@@ -796,21 +1862,28 @@ impl SharedMemoryTransport {
         };
 
         let metrics = Arc::new(performance_metrics::SharedMemoryMetrics::new());
-        
+        let rate_limiter = config.rate_limiter.as_ref().map(|c| Arc::new(PeerRateLimiter::new(c)));
+
         let transport = Self {
             info,
             config,
             codec: Arc::new(BinaryCodec),
             local_id: uuid::Uuid::new_v4().to_string(),
             peers: Arc::new(DashMap::new()),
+            peer_credits: Arc::new(DashMap::new()),
+            quarantined_peers: Arc::new(DashSet::new()),
+            rate_limiter,
             incoming_rx,
             incoming_tx,
             is_running: Arc::new(AtomicBool::new(true)),
             stats: Arc::new(RwLock::new(TransportStats::default())),
             metrics,
+            buffer_notify: Arc::new(tokio::sync::Notify::new()),
+            drain_notify: Arc::new(tokio::sync::Notify::new()),
         };
 
         transport.start_polling();
+        transport.start_stats_reporter(30);
 
         Ok(transport)
     }
@@ -826,10 +1899,20 @@ impl Transport for SharedMemoryTransport {
         let data = self.codec.encode(&msg)?;
 
         // Find peer buffer
-        if let Some(buffer) = self.peers.get(to) {
-            // Write to buffer
+        if let Some(buffer) = self.peers.get(to).map(|r| Arc::clone(r.value())) {
+            self.acquire_send_credit(to).await?;
+            if let Some(limiter) = &self.rate_limiter {
+                if let Err(e) = limiter.acquire(data.len()).await {
+                    self.metrics.record_rate_limited();
+                    return Err(e);
+                }
+            }
+
+            // Write to buffer, timing it at microsecond resolution
+            let write_started = Instant::now();
             buffer.write(&data)?;
-            
+            self.metrics.record_write_timing(write_started.elapsed());
+
             // Update AtomicU64 metrics for ring buffer write and message sent
             self.metrics.record_ring_buffer_write(data.len());
             self.metrics.record_message_sent(data.len());
@@ -916,6 +1999,245 @@ impl Transport for SharedMemoryTransport {
         // Use comprehensive AtomicU64 metrics for accurate performance reporting
         self.metrics.get_performance_snapshot()
     }
+
+    #[cfg(unix)]
+    fn supports_handle_passing(&self) -> bool {
+        true
+    }
+}
+
+/// Low-level `SCM_RIGHTS` plumbing for passing file descriptors over a Unix
+/// domain socket, modeled on crosvm's `Tube`. Kept separate from the ring
+/// buffer wire format because fds can't traverse a shared-memory region —
+/// only the slot id correlating a record with its fds does.
+#[cfg(unix)]
+mod handle_passing {
+    use super::*;
+    use std::io;
+    use std::path::PathBuf;
+
+    /// Deterministic per-(channel, peer) path so both sides of a pairing agree
+    /// on where the companion socket lives without an out-of-band rendezvous.
+    pub(super) fn socket_path(channel: &str, peer_id: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("zenflow-shm-{}-{}.fds.sock", channel, peer_id))
+    }
+
+    /// Send `slot_id` plus `fds` as ancillary data over `sock`.
+    pub(super) fn send_fds(sock: &UnixDatagram, slot_id: u64, fds: &[RawFd]) -> io::Result<()> {
+        let iov = [libc::iovec {
+            iov_base: &slot_id as *const u64 as *mut libc::c_void,
+            iov_len: std::mem::size_of::<u64>(),
+        }];
+        let cmsg_space =
+            unsafe { libc::CMSG_SPACE((fds.len() * std::mem::size_of::<RawFd>()) as u32) } as usize;
+        let mut cmsg_buf = vec![0u8; cmsg_space.max(1)];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = iov.as_ptr() as *mut _;
+        msg.msg_iovlen = 1;
+
+        if !fds.is_empty() {
+            msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+            msg.msg_controllen = cmsg_space as _;
+            // SAFETY: `cmsg_buf` is sized by `CMSG_SPACE` for exactly `fds.len()`
+            // descriptors, and `CMSG_FIRSTHDR`/`CMSG_DATA` only ever index within it.
+            unsafe {
+                let cmsg = libc::CMSG_FIRSTHDR(&msg);
+                (*cmsg).cmsg_level = libc::SOL_SOCKET;
+                (*cmsg).cmsg_type = libc::SCM_RIGHTS;
+                (*cmsg).cmsg_len =
+                    libc::CMSG_LEN((fds.len() * std::mem::size_of::<RawFd>()) as u32) as _;
+                std::ptr::copy_nonoverlapping(
+                    fds.as_ptr(),
+                    libc::CMSG_DATA(cmsg) as *mut RawFd,
+                    fds.len(),
+                );
+            }
+        }
+
+        // SAFETY: `msg` describes `iov` and (when non-empty) `cmsg_buf`, both of
+        // which outlive this call.
+        let sent = unsafe { libc::sendmsg(sock.as_raw_fd(), &msg, 0) };
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// Receive a `(slot_id, fds)` pair previously sent by [`send_fds`].
+    pub(super) fn recv_fds(sock: &UnixDatagram, max_fds: usize) -> io::Result<(u64, Vec<OwnedFd>)> {
+        let mut slot_id: u64 = 0;
+        let iov = [libc::iovec {
+            iov_base: &mut slot_id as *mut u64 as *mut libc::c_void,
+            iov_len: std::mem::size_of::<u64>(),
+        }];
+        let cmsg_space =
+            unsafe { libc::CMSG_SPACE((max_fds * std::mem::size_of::<RawFd>()) as u32) } as usize;
+        let mut cmsg_buf = vec![0u8; cmsg_space.max(1)];
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = iov.as_ptr() as *mut _;
+        msg.msg_iovlen = 1;
+        msg.msg_control = cmsg_buf.as_mut_ptr() as *mut libc::c_void;
+        msg.msg_controllen = cmsg_space as _;
+
+        // SAFETY: `msg` describes `iov` and `cmsg_buf`, both live for the call.
+        let received = unsafe { libc::recvmsg(sock.as_raw_fd(), &mut msg, 0) };
+        if received < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut fds = Vec::new();
+        // SAFETY: `CMSG_FIRSTHDR`/`CMSG_NXTHDR`/`CMSG_DATA` only ever walk
+        // within `cmsg_buf`, which `msg.msg_control`/`msg_controllen` describe.
+        unsafe {
+            let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+            while !cmsg.is_null() {
+                if (*cmsg).cmsg_level == libc::SOL_SOCKET && (*cmsg).cmsg_type == libc::SCM_RIGHTS {
+                    let count = ((*cmsg).cmsg_len as usize - libc::CMSG_LEN(0) as usize)
+                        / std::mem::size_of::<RawFd>();
+                    let data = libc::CMSG_DATA(cmsg) as *const RawFd;
+                    for i in 0..count {
+                        fds.push(OwnedFd::from_raw_fd(std::ptr::read_unaligned(data.add(i))));
+                    }
+                }
+                cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+            }
+        }
+        Ok((slot_id, fds))
+    }
+}
+
+/// Handle-passing API: `send_with_handles`/`receive_with_handles` carry OS
+/// file descriptors alongside a message, modeled on crosvm's `Tube`. Since
+/// fds can't traverse the shared-memory ring buffer, each pairing gets its
+/// own companion Unix domain socket used purely for `SCM_RIGHTS` transfer,
+/// keyed by a handle-slot id embedded in the ring-buffer record so the
+/// receiver can match the two back up in order. Traffic here uses
+/// `handle_peers`, never `peers`, so it never races `start_polling`'s
+/// background drain of the normal message path for the same peer.
+#[cfg(unix)]
+impl SharedMemoryTransport {
+    /// Maximum descriptors accepted in a single `receive_with_handles` call.
+    const MAX_PASSED_HANDLES: usize = 16;
+
+    /// Register a ring buffer dedicated to handle-carrying traffic with
+    /// `peer_id`. This is separate from [`Self::register_peer`]: buffers
+    /// registered there are drained by `start_polling` in the background,
+    /// which would otherwise steal records meant for `receive_with_handles`.
+    pub fn register_handle_peer(&self, peer_id: String, buffer: Arc<RingBuffer>) {
+        self.handle_peers.insert(peer_id.clone(), buffer);
+        info!("Registered handle-passing peer: {}", peer_id);
+    }
+
+    /// Lazily bind (or reuse) the companion socket used for `SCM_RIGHTS`
+    /// transfer with `peer_id`.
+    fn handle_socket(&self, peer_id: &str) -> Result<Arc<UnixDatagram>, TransportError> {
+        if let Some(existing) = self.handle_sockets.get(peer_id) {
+            return Ok(Arc::clone(existing.value()));
+        }
+
+        let local_path = handle_passing::socket_path(&self.info.name, &self.local_id);
+        let _ = std::fs::remove_file(&local_path);
+        let sock = UnixDatagram::bind(&local_path).map_err(|e| {
+            TransportError::Other(anyhow::anyhow!("Failed to bind handle-passing socket: {}", e))
+        })?;
+        let peer_path = handle_passing::socket_path(&self.info.name, peer_id);
+        sock.connect(&peer_path).map_err(|e| {
+            TransportError::Other(anyhow::anyhow!(
+                "Failed to connect handle-passing socket to {}: {}",
+                peer_id,
+                e
+            ))
+        })?;
+
+        let sock = Arc::new(sock);
+        self.handle_sockets.insert(peer_id.to_string(), Arc::clone(&sock));
+        Ok(sock)
+    }
+
+    /// Send `msg` to `to` along with `fds`, which travel out-of-band over the
+    /// peer's companion socket rather than through the ring buffer.
+    pub async fn send_with_handles(
+        &self,
+        to: &str,
+        msg: Message,
+        fds: &[RawFd],
+    ) -> Result<(), TransportError> {
+        let buffer = self.handle_peers.get(to).map(|r| Arc::clone(r.value())).ok_or_else(|| {
+            TransportError::ConnectionError(format!("No handle-passing buffer for peer: {}", to))
+        })?;
+
+        let slot_id = self.next_handle_slot.fetch_add(1, Ordering::Relaxed);
+        let encoded = self.codec.encode(&msg)?;
+        let mut framed = Vec::with_capacity(8 + encoded.len());
+        framed.extend_from_slice(&slot_id.to_le_bytes());
+        framed.extend_from_slice(&encoded);
+        buffer.write(&framed)?;
+
+        let sock = self.handle_socket(to)?;
+        let fds = fds.to_vec();
+        tokio::task::spawn_blocking(move || handle_passing::send_fds(&sock, slot_id, &fds))
+            .await
+            .map_err(|e| TransportError::Other(anyhow::anyhow!(e)))?
+            .map_err(|e| TransportError::Other(anyhow::anyhow!("sendmsg failed: {}", e)))?;
+
+        self.metrics.record_message_sent(framed.len());
+        let mut stats = self.stats.write().await;
+        stats.messages_sent += 1;
+        stats.bytes_sent += framed.len() as u64;
+        stats.last_activity = Some(chrono::Utc::now());
+        Ok(())
+    }
+
+    /// Receive the next handle-carrying message from `from`, along with the
+    /// fds sent alongside it. Pairs with [`Self::send_with_handles`].
+    pub async fn receive_with_handles(
+        &self,
+        from: &str,
+    ) -> Result<(String, Message, Vec<OwnedFd>), TransportError> {
+        let buffer = self.handle_peers.get(from).map(|r| Arc::clone(r.value())).ok_or_else(|| {
+            TransportError::ConnectionError(format!("No handle-passing buffer for peer: {}", from))
+        })?;
+
+        let framed = loop {
+            match buffer.read_with_latency() {
+                Ok(Some((data, _))) => break data,
+                Ok(None) => buffer.wait_readable().await,
+                Err(frame) => {
+                    return Err(TransportError::CorruptFrame {
+                        peer: from.to_string(),
+                        reported_len: frame.reported_len,
+                        msg_type: frame.msg_type,
+                        available: frame.available,
+                    })
+                }
+            }
+        };
+
+        if framed.len() < 8 {
+            return Err(TransportError::SerializationError(
+                "Handle-carrying record shorter than its slot-id header".to_string(),
+            ));
+        }
+        let slot_id = u64::from_le_bytes(framed[..8].try_into().unwrap());
+        let msg = self.codec.decode(&framed[8..])?;
+
+        let sock = self.handle_socket(from)?;
+        let (recv_slot_id, fds) =
+            tokio::task::spawn_blocking(move || handle_passing::recv_fds(&sock, Self::MAX_PASSED_HANDLES))
+                .await
+                .map_err(|e| TransportError::Other(anyhow::anyhow!(e)))?
+                .map_err(|e| TransportError::Other(anyhow::anyhow!("recvmsg failed: {}", e)))?;
+
+        if recv_slot_id != slot_id {
+            return Err(TransportError::Other(anyhow::anyhow!(
+                "Handle slot id mismatch: record={}, socket={}",
+                slot_id,
+                recv_slot_id
+            )));
+        }
+
+        Ok((from.to_string(), msg, fds))
+    }
 }
 
 /// Extended metrics methods for SharedMemoryTransport